@@ -31,7 +31,7 @@ import {
 } from "./api/client";
 import { ensureCloudAuth } from "./auth/cloudAuth";
 import { installConsoleTelemetry } from "./utils/console-telemetry";
-import { matchesSearchQuery } from "./utils/issue-search";
+import { matchesSearchQuery, matchesViewFilters, sortIssuesByView } from "./utils/issue-search";
 import type { Issue, IssuesSnapshot, ProjectConfig } from "./types/issues";
 import { useAppearance } from "./hooks/useAppearance";
 
@@ -646,6 +646,7 @@ export default function App() {
     const initial = parseRoute(window.location.pathname, window.location.search);
     return initial.search ?? "";
   });
+  const [savedViewName, setSavedViewName] = useState<string | null>(null);
   const [detailClosing, setDetailClosing] = useState(false);
   const [detailNavDirection, setDetailNavDirection] = useState<NavAction>("none");
   const [enabledProjects, setEnabledProjects] = useState<Set<string> | null>(() => loadStoredEnabledProjects());
@@ -1558,6 +1559,9 @@ export default function App() {
           }
         }
         break;
+      case "apply_view":
+        setSavedViewName(action.name || null);
+        break;
       case "reload_page":
         console.info("[ui_control] reloading page");
         window.location.reload();
@@ -1738,6 +1742,21 @@ export default function App() {
       result = result.filter((issue) => matchesSearchQuery(issue, searchQuery));
     }
 
+    // Apply saved view filters and sort; columns and group_by only shape `kbs list` output
+    const savedView = savedViewName ? config?.views?.[savedViewName] : undefined;
+    if (savedView) {
+      result = result.filter((issue) =>
+        matchesViewFilters(issue, savedView.filters, sourceIssues)
+      );
+      const viewProjects = savedView.filters?.project ?? [];
+      if (viewProjects.length > 0) {
+        result = result.filter((issue) =>
+          viewProjects.includes(getIssueProjectLabel(issue, config ?? null))
+        );
+      }
+      result = sortIssuesByView(result, savedView.sort);
+    }
+
     // Apply project filter
     if (projectLabels.length > 0) {
       result = result.filter((issue) => {
@@ -1755,7 +1774,7 @@ export default function App() {
     }
 
     return result;
-  }, [issues, deferredIssues, resolvedViewMode, routeContext.parentIssue, route.parentId, focusedIssueId, searchQuery, enabledProjects, projectLabels.length, showLocal, showShared, showAllTypes, config]);
+  }, [issues, deferredIssues, resolvedViewMode, routeContext.parentIssue, route.parentId, focusedIssueId, searchQuery, savedViewName, enabledProjects, projectLabels.length, showLocal, showShared, showAllTypes, config]);
 
   const metricsIssues = useMemo(() => {
     if (!config) {
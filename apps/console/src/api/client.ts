@@ -29,14 +29,21 @@ export type UiControlAction =
   | { action: "collapse_column"; column_name: string }
   | { action: "expand_column"; column_name: string }
   | { action: "select_issue"; issue_id: string }
+  | { action: "apply_view"; name: string }
   | { action: "reload_page" };
 
 export type NotificationEvent =
   | { type: "issue_created"; issue_id: string; issue_data: Issue }
   | { type: "issue_updated"; issue_id: string; fields_changed: string[]; issue_data: Issue }
   | { type: "issue_deleted"; issue_id: string }
-  | { type: "issue_focused"; issue_id: string; user?: string; comment_id?: string }
-  | { type: "ui_control"; action: UiControlAction };
+  | {
+      type: "issue_focused";
+      issue_id: string;
+      user?: string;
+      comment_id?: string;
+      session?: string;
+    }
+  | { type: "ui_control"; action: UiControlAction; session?: string };
 
 export type RealtimeBootstrap = {
   mode: string;
@@ -293,12 +300,40 @@ export function subscribeToSnapshots(
   };
 }
 
+const consoleSessionStorageKey = "kanbus.console.session";
+const consoleSessionPattern = /^[A-Za-z0-9._-]{1,64}$/;
+
+/**
+ * Session id this tab registers with the console server, so CLI commands can
+ * target it with `--session`. A `?session=<name>` URL parameter names the
+ * session; otherwise a random id is kept for the life of the tab.
+ */
+export function getConsoleSessionId(): string {
+  const requested = new URLSearchParams(window.location.search).get("session");
+  if (requested && consoleSessionPattern.test(requested)) {
+    window.sessionStorage.setItem(consoleSessionStorageKey, requested);
+    return requested;
+  }
+  const stored = window.sessionStorage.getItem(consoleSessionStorageKey);
+  if (stored && consoleSessionPattern.test(stored)) {
+    return stored;
+  }
+  const bytes = new Uint8Array(4);
+  window.crypto.getRandomValues(bytes);
+  const generated = `tab-${Array.from(bytes, (b) => b.toString(16).padStart(2, "0")).join("")}`;
+  window.sessionStorage.setItem(consoleSessionStorageKey, generated);
+  return generated;
+}
+
 export function subscribeToNotifications(
   apiBase: string,
   onNotification: (event: NotificationEvent) => void,
   onError?: (error: Event) => void
 ): () => void {
-  const source = new EventSource(withAuthQuery(`${apiBase}/events/realtime`));
+  const session = encodeURIComponent(getConsoleSessionId());
+  const source = new EventSource(
+    withAuthQuery(`${apiBase}/events/realtime?session=${session}`)
+  );
 
   source.onopen = () => {};
 
@@ -64,7 +64,7 @@ export function IssueCard({
         <div className="issue-accent-row gap-2 w-full flex items-center justify-between">
           <div className="issue-accent-left gap-1 inline-flex items-center min-w-0">
             <IssueTypeIcon className="issue-accent-icon" />
-            <span className="issue-accent-id">{formatIssueId(issue.id)}</span>
+            <span className="issue-accent-id">{formatIssueId(issue.id, issue.custom?.kanbus_number)}</span>
           </div>
           <div className="issue-accent-priority">{priorityName}</div>
         </div>
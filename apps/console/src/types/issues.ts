@@ -44,11 +44,39 @@ export interface SortOrder {
   [statusKey: string]: SortRule | Record<string, SortRule> | undefined;
 }
 
+/**
+ * `hierarchy_rules` from `.kanbus.yml`; enforced by the CLI, carried here for reference only
+ */
+export interface HierarchyRules {
+  children?: Record<string, string[]>;
+  max_depth?: number;
+  max_children?: Record<string, number>;
+}
+
+export interface ViewFilters {
+  status?: string;
+  type?: string;
+  assignee?: string;
+  label?: string;
+  parent?: string;
+  search?: string;
+  project?: string[];
+}
+
+export interface SavedView {
+  description?: string;
+  filters?: ViewFilters;
+  sort?: string;
+  columns?: string[];
+  group_by?: string;
+}
+
 export interface ProjectConfig {
   project_directory: string;
   virtual_projects: Record<string, { path: string }>;
   project_key: string;
   hierarchy: string[];
+  hierarchy_rules?: HierarchyRules;
   types: string[];
   workflows: Record<string, WorkflowDefinition>;
   transition_labels: TransitionLabelsDefinition;
@@ -62,6 +90,7 @@ export interface ProjectConfig {
   sort_order?: SortOrder;
   type_colors: Record<string, string>;
   beads_compatibility: boolean;
+  views?: Record<string, SavedView>;
 }
 
 export interface IssueComment {
@@ -109,7 +138,9 @@ export type IssueEventType =
   | "dependency_removed"
   | "issue_deleted"
   | "issue_localized"
-  | "issue_promoted";
+  | "issue_promoted"
+  | "issue_claimed"
+  | "issue_released";
 
 export interface IssueEvent {
   schema_version: number;
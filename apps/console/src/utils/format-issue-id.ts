@@ -1,10 +1,14 @@
-export function formatIssueId(value: string): string {
+export function formatIssueId(value: string, number?: unknown): string {
   const normalized = value.trim().replace(/\.$/, "");
   const dashIndex = normalized.indexOf("-");
   if (dashIndex <= 0) {
     return normalized;
   }
-  const prefix = normalized.slice(0, dashIndex).toUpperCase();
+  // Keep the project key as stored so the card shows what the CLI prints and accepts.
+  const prefix = normalized.slice(0, dashIndex);
+  if (typeof number === "number") {
+    return `${prefix}-${number}`;
+  }
   const remainder = normalized.slice(dashIndex + 1);
   if (!remainder) {
     return normalized;
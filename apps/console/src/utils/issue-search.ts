@@ -1,4 +1,4 @@
-import type { Issue } from "../types/issues";
+import type { Issue, ViewFilters } from "../types/issues";
 
 /**
  * Normalizes a search query by trimming whitespace and converting to lowercase
@@ -75,3 +75,120 @@ export function matchesSearchQuery(issue: Issue, query: string): boolean {
 
   return false;
 }
+
+/**
+ * Formats an identifier the way `kbs` abbreviates it (`kanbus-abcdef`, or `abcdef` within a project)
+ */
+function formatIssueKey(identifier: string, projectContext: boolean): string {
+  const dashIndex = identifier.indexOf("-");
+  const hasKey = dashIndex > 0 && dashIndex < identifier.length - 1;
+  const key = hasKey ? identifier.slice(0, dashIndex) : null;
+  const remainder = hasKey ? identifier.slice(dashIndex + 1) : identifier;
+  const dotIndex = remainder.indexOf(".");
+  const base = dotIndex >= 0 ? remainder.slice(0, dotIndex) : remainder;
+  const suffix = dotIndex >= 0 ? remainder.slice(dotIndex) : "";
+  const truncated = base.replace(/-/g, "").slice(0, 6);
+  if (projectContext || !key) {
+    return `${truncated}${suffix}`;
+  }
+  return `${key}-${truncated}${suffix}`;
+}
+
+/**
+ * Checks if a user-supplied identifier refers to a stored one, with the same rules as `kbs`
+ */
+export function issueIdentifierMatches(candidate: string, fullId: string): boolean {
+  if (
+    candidate === fullId ||
+    candidate === formatIssueKey(fullId, false) ||
+    candidate === formatIssueKey(fullId, true)
+  ) {
+    return true;
+  }
+  return candidate.length < fullId.length && fullId.startsWith(candidate);
+}
+
+/**
+ * Resolves a sequential key such as `kanbus-142` to the identifier of the issue holding it
+ */
+function resolveIssueNumber(candidate: string, issues: Issue[]): string {
+  if (!/^[^-]+-\d+$/.test(candidate)) {
+    return candidate;
+  }
+  const holders = issues.filter((issue) => {
+    const number = issue.custom?.kanbus_number;
+    return (
+      typeof number === "number" &&
+      `${issue.id.slice(0, issue.id.indexOf("-"))}-${number}` === candidate
+    );
+  });
+  return holders.length === 1 ? holders[0].id : candidate;
+}
+
+/**
+ * Checks if an issue matches the filters of a saved view, as `kbs list --view` does
+ *
+ * `issues` is the full issue set, used to resolve a numbered `parent` key.
+ * The `project` filter depends on project labels and is applied by the caller.
+ */
+export function matchesViewFilters(
+  issue: Issue,
+  filters: ViewFilters | undefined,
+  issues: Issue[] = []
+): boolean {
+  if (!filters) {
+    return true;
+  }
+  if (filters.status && issue.status !== filters.status) {
+    return false;
+  }
+  if (filters.type && issue.type !== filters.type) {
+    return false;
+  }
+  if (filters.assignee && issue.assignee !== filters.assignee) {
+    return false;
+  }
+  if (filters.label && !(issue.labels ?? []).includes(filters.label)) {
+    return false;
+  }
+  if (filters.parent) {
+    const parent = resolveIssueNumber(filters.parent, issues);
+    if (!issue.parent || !issueIdentifierMatches(parent, issue.parent)) {
+      return false;
+    }
+  }
+  return matchesSearchQuery(issue, filters.search ?? "");
+}
+
+const VIEW_SORT_FIELDS: Record<string, (issue: Issue) => string | number> = {
+  title: (issue) => issue.title,
+  priority: (issue) => issue.priority,
+  status: (issue) => issue.status,
+  id: (issue) => issue.id,
+  identifier: (issue) => issue.id,
+  created_at: (issue) => issue.created_at ?? "",
+  updated_at: (issue) => issue.updated_at ?? "",
+  closed_at: (issue) => issue.closed_at ?? ""
+};
+
+/**
+ * Orders issues by a saved view's sort key; a leading `-` sorts descending
+ *
+ * Unknown keys leave the order unchanged, since the server rejects them when loading views.
+ */
+export function sortIssuesByView(issues: Issue[], sortKey: string | undefined): Issue[] {
+  if (!sortKey) {
+    return issues;
+  }
+  const descending = sortKey.startsWith("-");
+  const field = VIEW_SORT_FIELDS[descending ? sortKey.slice(1) : sortKey];
+  if (!field) {
+    return issues;
+  }
+  const sorted = [...issues].sort((left, right) => {
+    const a = field(left);
+    const b = field(right);
+    return a < b ? -1 : a > b ? 1 : 0;
+  });
+  return descending ? sorted.reverse() : sorted;
+}
@@ -4,79 +4,103 @@ Feature: Console UI state commands
   I want CLI commands to read and write the console UI state
   So that I can inspect and control the console from scripts and workflows
 
-  Scenario: Create focus flag is deprecated
+  Scenario: Create focus flag still creates the issue when the console server is not running
     Given a Kanbus project with default configuration
-    When I run "kanbus create \"Deprecated focus\" --focus"
+    And the console server is not running
+    When I run "kanbus create \"Focus later\" --focus"
+    Then the command should succeed
+    And stdout should contain "Focus later"
+
+  Scenario: Create session flag requires the focus flag
+    Given a Kanbus project with default configuration
+    When I run "kanbus create \"No focus\" --session left"
     Then the command should fail
-    And stderr should contain "deprecated"
-    And stderr should contain "pub/sub convention"
 
   # ---------------------------------------------------------------------------
   # kbs console focus
   # ---------------------------------------------------------------------------
 
-  Scenario: Focus command is deprecated
+  Scenario: Focus command fails when the console server is not running
     Given a Kanbus project with default configuration
     And an issue "kanbus-abc" exists with title "Auth bug"
+    And the console server is not running
     When I run "kanbus console focus kanbus-abc"
     Then the command should fail
-    And stderr should contain "deprecated"
-    And stderr should contain "pub/sub convention"
+    And stderr should contain "console server is not running"
 
-  Scenario: Focus command with comment flag is deprecated
+  Scenario: Focus command with comment flag fails when the console server is not running
     Given a Kanbus project with default configuration
     And an issue "kanbus-abc" exists with title "Auth bug"
+    And the console server is not running
     When I run "kanbus console focus kanbus-abc --comment abc123"
     Then the command should fail
-    And stderr should contain "deprecated"
+    And stderr should contain "console server is not running"
 
-  Scenario: Focus command still fails fast as deprecated even when issue does not exist
+  Scenario: Focus command fails fast when the issue does not exist
     Given a Kanbus project with default configuration
     When I run "kanbus console focus kanbus-does-not-exist"
     Then the command should fail
-    And stderr should contain "deprecated"
+    And stderr should contain "not found"
+
+  Scenario: Focus command rejects an invalid session id
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-abc" exists with title "Auth bug"
+    When I run "kanbus console focus kanbus-abc --session \"not valid\""
+    Then the command should fail
+    And stderr should contain "invalid console session id"
 
   # ---------------------------------------------------------------------------
   # kbs console unfocus
   # ---------------------------------------------------------------------------
 
-  Scenario: Unfocus command is deprecated
+  Scenario: Unfocus command fails when the console server is not running
     Given a Kanbus project with default configuration
+    And the console server is not running
     When I run "kanbus console unfocus"
     Then the command should fail
-    And stderr should contain "deprecated"
+    And stderr should contain "console server is not running"
 
   # ---------------------------------------------------------------------------
   # kbs console view
   # ---------------------------------------------------------------------------
 
-  Scenario: View command with "issues" mode is deprecated
+  Scenario: View command with "issues" mode fails when the console server is not running
     Given a Kanbus project with default configuration
+    And the console server is not running
     When I run "kanbus console view issues"
     Then the command should fail
-    And stderr should contain "deprecated"
+    And stderr should contain "console server is not running"
 
-  Scenario: View command with "epics" mode is deprecated
+  Scenario: View command with "epics" mode fails when the console server is not running
     Given a Kanbus project with default configuration
+    And the console server is not running
     When I run "kanbus console view epics"
     Then the command should fail
-    And stderr should contain "deprecated"
+    And stderr should contain "console server is not running"
 
-  Scenario: View command with "initiatives" mode is deprecated
+  Scenario: View command with "initiatives" mode fails when the console server is not running
     Given a Kanbus project with default configuration
+    And the console server is not running
     When I run "kanbus console view initiatives"
     Then the command should fail
-    And stderr should contain "deprecated"
+    And stderr should contain "console server is not running"
+
+  Scenario: View command rejects an unknown mode
+    Given a Kanbus project with default configuration
+    When I run "kanbus console view board"
+    Then the command should fail
+    And stderr should contain "Unknown view mode"
 
   # ---------------------------------------------------------------------------
   # kbs console search
   # ---------------------------------------------------------------------------
 
-  Scenario: Search command is deprecated
+  Scenario: Search command fails when the console server is not running
     Given a Kanbus project with default configuration
+    And the console server is not running
     When I run "kanbus console search auth"
     Then the command should fail
-    And stderr should contain "deprecated"
+    And stderr should contain "console server is not running"
 
   # ---------------------------------------------------------------------------
   # kbs console status (server offline)
@@ -180,23 +204,42 @@ Feature: Console UI state commands
     And stdout should contain "auth bug"
 
   @console @console-server
-  Scenario: Focus command is deprecated while server is running
+  Scenario: Focus command broadcasts to the console while server is running
     Given a Kanbus project with default configuration
     And an issue "kanbus-abc" exists with title "Auth bug"
     And the console server is running
     When I run "kanbus console focus kanbus-abc"
-    Then the command should fail
-    And stderr should contain "deprecated"
+    Then the command should succeed
+    When I run "kanbus console get focus"
+    Then stdout should contain "kanbus-abc"
 
   @console @console-server
-  Scenario: Unfocus command is deprecated while server is running
+  Scenario: Unfocus command clears focus while server is running
     Given a Kanbus project with default configuration
     And an issue "kanbus-abc" exists with title "Auth bug"
     And the console server is running
     And the console focused issue is "kanbus-abc"
     When I run "kanbus console unfocus"
+    Then the command should succeed
+    When I run "kanbus console get focus"
+    Then stdout should contain "none"
+
+  @console @console-server
+  Scenario: Focus command rejects an unknown session
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-abc" exists with title "Auth bug"
+    And the console server is running
+    When I run "kanbus console focus kanbus-abc --session ghost"
     Then the command should fail
-    And stderr should contain "deprecated"
+    And stderr should contain "unknown console session: ghost"
+
+  @console @console-server
+  Scenario: Sessions command lists no sessions before a browser connects
+    Given a Kanbus project with default configuration
+    And the console server is running
+    When I run "kanbus console sessions"
+    Then the command should succeed
+    And stdout should contain "No console sessions."
 
   @console @console-server
   Scenario: UI state persists across server restarts
@@ -208,3 +251,41 @@ Feature: Console UI state commands
     And I run "kanbus console get focus"
     Then the command should succeed
     And stdout should contain "kanbus-abc"
+
+  @console @console-server
+  Scenario: Focus command targets only the named session
+    Given a Kanbus project with default configuration
+    And an issue "kanbus-abc" exists with title "Auth bug"
+    And the console server is running
+    And a console session "left" has connected
+    And a console session "right" has connected
+    When I run "kanbus console focus kanbus-abc --session left"
+    Then the command should succeed
+    When I run "kanbus console get focus --session left"
+    Then stdout should contain "kanbus-abc"
+    When I run "kanbus console get focus --session right"
+    Then stdout should contain "none"
+    When I run "kanbus console get focus"
+    Then stdout should contain "none"
+
+  @console @console-server
+  Scenario: Sessions command lists connected sessions
+    Given a Kanbus project with default configuration
+    And the console server is running
+    And a console session "left" has connected
+    When I run "kanbus console sessions"
+    Then the command should succeed
+    And stdout should contain "left"
+
+  @console @console-server
+  Scenario: Create focus flag focuses the new issue in the targeted session
+    Given a Kanbus project with default configuration
+    And the console server is running
+    And a console session "left" has connected
+    And a console session "right" has connected
+    When I run "kanbus create \"Focus me\" --focus --session left"
+    Then the command should succeed
+    When I run "kanbus console get focus --session left"
+    Then stdout should contain "kanbus-"
+    When I run "kanbus console get focus --session right"
+    Then stdout should contain "none"
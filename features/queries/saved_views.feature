@@ -0,0 +1,75 @@
+Feature: Saved views
+  As a Kanbus user
+  I want named views shared by the CLI and the console
+  So that the same query is one command away everywhere
+
+  Scenario: List prints the columns of a saved view
+    Given a Kanbus project with default configuration
+    And a file "project/views/open-titles.yml" with content "filters:\n  status: open\nsort: title\ncolumns: [id, title]\n"
+    And I run "kanbus create \"Beta task\""
+    And I run "kanbus create \"Alpha task\""
+    When I run "kanbus list --view open-titles"
+    Then the command should succeed
+    And stdout should contain "title"
+    And stdout should contain "Alpha task"
+    And stdout should contain "Beta task"
+    And stdout should not contain "P2"
+
+  Scenario: List groups issues by the column of a saved view
+    Given a Kanbus project with default configuration
+    And a file "project/views/by-label.yml" with content "group_by: labels\n"
+    And I run "kanbus create \"Beta task\" --label ui"
+    And I run "kanbus create \"Alpha task\" --label api"
+    When I run "kanbus list --view by-label"
+    Then the command should succeed
+    And stdout should contain "api (1)"
+    And stdout should contain "ui (1)"
+
+  Scenario: List rejects an unknown view
+    Given a Kanbus project with default configuration
+    When I run "kanbus list --view missing"
+    Then the command should fail
+    And stderr should contain "unknown view 'missing'"
+
+  Scenario: Console snapshot skips an invalid view file
+    Given a Kanbus project with default configuration
+    And a file "project/views/open-titles.yml" with content "sort: title\n"
+    And a file "project/views/broken.yml" with content "sort: ["
+    When I run "kanbus console snapshot"
+    Then the command should succeed
+    And stdout should contain "open-titles"
+    And stdout should not contain "broken"
+
+  Scenario: Apply-view rejects an unknown view
+    Given a Kanbus project with default configuration
+    When I run "kanbus console apply-view missing"
+    Then the command should fail
+    And stderr should contain "unknown view 'missing'"
+
+  Scenario: Apply-view requires a view name or --clear
+    Given a Kanbus project with default configuration
+    When I run "kanbus console apply-view"
+    Then the command should fail
+    And stderr should contain "apply-view requires a view name or --clear"
+
+  Scenario: Apply-view fails when the console server is not running
+    Given a Kanbus project with default configuration
+    And a file "project/views/open-titles.yml" with content "sort: title\n"
+    And the console server is not running
+    When I run "kanbus console apply-view open-titles"
+    Then the command should fail
+    And stderr should contain "console server is not running"
+
+  @console @console-server
+  Scenario: Apply-view sets and clears the saved view in the console
+    Given a Kanbus project with default configuration
+    And a file "project/views/open-titles.yml" with content "sort: title\n"
+    And the console server is running
+    When I run "kanbus console apply-view open-titles"
+    Then the command should succeed
+    When I run "kanbus console get saved"
+    Then stdout should contain "open-titles"
+    When I run "kanbus console apply-view --clear"
+    Then the command should succeed
+    When I run "kanbus console get saved"
+    Then stdout should contain "none"
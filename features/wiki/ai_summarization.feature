@@ -13,3 +13,14 @@ Feature: AI summarization in wiki templates
     When I run "kanbus wiki render project/wiki/report.md"
     Then the command should succeed
     And the rendered wiki should contain a generated summary for "kanbus-1"
+
+  Scenario: Wiki template passes an unknown argument to summarize
+    Given a Kanbus project with AI configured
+    And an issue "kanbus-1" exists
+    And a wiki page "report.md" with content:
+      """
+      {{ ai_summarize(issue("kanbus-1"), length="short") }}
+      """
+    When I run "kanbus wiki render project/wiki/report.md"
+    Then the command should fail
+    And stderr should contain "ai_summarize only accepts a detail argument"
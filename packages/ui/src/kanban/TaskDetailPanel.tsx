@@ -52,7 +52,9 @@ export type IssueEventType =
   | "dependency_removed"
   | "issue_deleted"
   | "issue_localized"
-  | "issue_promoted";
+  | "issue_promoted"
+  | "issue_claimed"
+  | "issue_released";
 
 export type IssueEvent = {
   schema_version: number;
@@ -422,6 +424,10 @@ export function TaskDetailPanel({
         return `Issue moved from ${String(payload.from_location)} to ${String(payload.to_location)}`;
       case "issue_promoted":
         return `Issue moved from ${String(payload.from_location)} to ${String(payload.to_location)}`;
+      case "issue_claimed":
+        return `Claimed by ${String(payload.holder)} until ${String(payload.expires_at)}`;
+      case "issue_released":
+        return `Claim by ${String(payload.holder)} released (${String(payload.reason)})`;
       default:
         return event.event_type;
     }
@@ -264,3 +264,26 @@ fn given_console_search_query(world: &mut KanbusWorld, query: String) {
         }),
     );
 }
+
+#[given(expr = "a console session {string} has connected")]
+fn given_console_session_connected(world: &mut KanbusWorld, session: String) {
+    let url = format!(
+        "{}/api/events/realtime?session={session}",
+        console_base_url(world)
+    );
+    // Opening the event stream registers the session; it stays known after the
+    // stream closes, so the response is dropped as soon as headers arrive.
+    thread::spawn(move || {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .expect("build http client");
+        let response = client.get(&url).send().expect("open event stream");
+        assert!(
+            response.status().is_success(),
+            "session registration failed"
+        );
+    })
+    .join()
+    .expect("connect session thread");
+}
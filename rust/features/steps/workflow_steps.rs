@@ -518,6 +518,11 @@ fn when_lookup_workflow(world: &mut KanbusWorld, issue_type: String) {
         jira: None,
         snyk: None,
         github_security: None,
+        github_issues: None,
+        dependency_types: BTreeMap::new(),
+        hierarchy_rules: None,
+        views: BTreeMap::new(),
+        sequential_ids: false,
         transition_labels: BTreeMap::new(),
         realtime: RealtimeConfig::default(),
         overlay: OverlayConfig::default(),
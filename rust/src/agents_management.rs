@@ -9,11 +9,14 @@ use std::path::Path;
 use crate::config::default_project_configuration;
 use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
-use crate::file_io::get_configuration_path;
-use crate::models::ProjectConfiguration;
+use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::hooks::list_hooks;
+use crate::models::{HierarchyRules, ProjectConfiguration};
+use crate::policy_loader::load_policies;
 use crate::project_management_template::{
     DEFAULT_PROJECT_MANAGEMENT_TEMPLATE, DEFAULT_PROJECT_MANAGEMENT_TEMPLATE_FILENAME,
 };
+use crate::workflows::resolve_start_status;
 use serde::Serialize;
 
 const KANBUS_SECTION_HEADER: &str = "## Project management with Kanbus";
@@ -89,6 +92,50 @@ pub fn kanbus_section_text() -> String {
     join_lines(&lines)
 }
 
+/// List agent instruction files that differ from what `kbs setup agents --force` would write.
+///
+/// AGENTS.md must contain the current Kanbus section and CONTRIBUTING_AGENT.md
+/// must match the template rendered against the live project configuration,
+/// policies and hooks.
+///
+/// # Arguments
+/// * `root` - Repository root path
+///
+/// # Errors
+/// Returns `KanbusError` if configuration lookup or rendering fails.
+pub fn check_agents_files(root: &Path) -> Result<Vec<String>, KanbusError> {
+    let mut stale = Vec::new();
+    let agents_path = root.join("AGENTS.md");
+    if agents_path.exists() {
+        let contents =
+            fs::read_to_string(&agents_path).map_err(|error| KanbusError::Io(error.to_string()))?;
+        let lines: Vec<String> = contents.lines().map(|line| line.to_string()).collect();
+        // Text after the canonical lines, up to the next header, belongs to the
+        // repository, so only the section's leading lines are compared.
+        let up_to_date = find_kanbus_sections(&lines).first().is_some_and(|section| {
+            let current: Vec<&str> = lines[section.start..section.end]
+                .iter()
+                .map(|line| line.trim_end())
+                .collect();
+            current.starts_with(&KANBUS_SECTION_LINES)
+        });
+        if !up_to_date {
+            stale.push("AGENTS.md (Kanbus section is missing or outdated)".to_string());
+        }
+    } else {
+        stale.push("AGENTS.md (missing)".to_string());
+    }
+
+    let instructions_path = root.join(PROJECT_MANAGEMENT_FILENAME);
+    let expected = build_project_management_text(root)?;
+    match fs::read_to_string(&instructions_path) {
+        Ok(contents) if contents == expected => {}
+        Ok(_) => stale.push(format!("{PROJECT_MANAGEMENT_FILENAME} (outdated)")),
+        Err(_) => stale.push(format!("{PROJECT_MANAGEMENT_FILENAME} (missing)")),
+    }
+    Ok(stale)
+}
+
 /// Return the Kanbus project management text derived from configuration.
 ///
 /// # Arguments
@@ -110,7 +157,9 @@ fn build_project_management_text(root: &Path) -> Result<String, KanbusError> {
         }
         None => DEFAULT_PROJECT_MANAGEMENT_TEMPLATE.to_string(),
     };
-    let context = build_project_management_context(&configuration);
+    let mut context = build_project_management_context(&configuration);
+    context.policies = build_policy_context(root)?;
+    context.hooks = build_hook_context(root);
     let env = minijinja::Environment::new();
     env.render_str(&template_text, context)
         .map_err(|error| KanbusError::IssueOperation(error.to_string()))
@@ -140,6 +189,36 @@ struct WorkflowStatusContext {
     transitions: Vec<String>,
 }
 
+#[derive(Debug, Serialize)]
+struct StatusContext {
+    key: String,
+    name: String,
+    category: String,
+}
+
+#[derive(Debug, Serialize)]
+struct TypeWorkflowContext {
+    issue_type: String,
+    workflow: String,
+    start_status: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PolicyFileContext {
+    file: String,
+    name: String,
+    scenarios: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct HookContext {
+    phase: String,
+    event: String,
+    id: String,
+    command: String,
+    blocking: bool,
+}
+
 #[derive(Debug, Serialize)]
 struct PriorityContext {
     value: u8,
@@ -155,11 +234,16 @@ struct SemanticReleaseMapping {
 #[derive(Debug, Serialize)]
 struct ProjectManagementContext {
     project_key: String,
+    project_directory: String,
     hierarchy_order: String,
     non_hierarchical_types: Vec<String>,
     parent_child_rules: Vec<String>,
     initial_status: String,
+    statuses: Vec<StatusContext>,
     workflows: Vec<WorkflowContext>,
+    type_workflows: Vec<TypeWorkflowContext>,
+    policies: Vec<PolicyFileContext>,
+    hooks: Vec<HookContext>,
     priorities: Vec<PriorityContext>,
     default_priority_value: u8,
     default_priority_name: String,
@@ -208,15 +292,36 @@ fn build_project_management_context(
         .unwrap_or_else(|| configuration.default_priority.to_string());
     ProjectManagementContext {
         project_key: configuration.project_key.clone(),
+        project_directory: configuration.project_directory.clone(),
         hierarchy_order: if hierarchy.is_empty() {
             "none".to_string()
         } else {
             hierarchy.join(" -> ")
         },
         non_hierarchical_types: types.clone(),
-        parent_child_rules: build_parent_child_rules(hierarchy, types),
+        parent_child_rules: match configuration.hierarchy_rules.as_ref() {
+            Some(rules) if !rules.children.is_empty() => build_explicit_child_rules(rules),
+            Some(rules) => {
+                let mut lines = build_parent_child_rules(hierarchy, types);
+                lines.extend(build_hierarchy_limit_rules(rules));
+                lines
+            }
+            None => build_parent_child_rules(hierarchy, types),
+        },
         initial_status: configuration.initial_status.clone(),
+        statuses: configuration
+            .statuses
+            .iter()
+            .map(|status| StatusContext {
+                key: status.key.clone(),
+                name: status.name.clone(),
+                category: status.category.clone(),
+            })
+            .collect(),
         workflows,
+        type_workflows: build_type_workflow_context(configuration),
+        policies: Vec::new(),
+        hooks: Vec::new(),
         priorities,
         default_priority_value: configuration.default_priority,
         default_priority_name,
@@ -233,6 +338,37 @@ fn build_project_management_context(
     }
 }
 
+fn build_explicit_child_rules(rules: &HierarchyRules) -> Vec<String> {
+    let mut lines: Vec<String> = rules
+        .children
+        .iter()
+        .map(|(parent, children)| {
+            if children.is_empty() {
+                format!("{parent} cannot have children.")
+            } else {
+                format!("{parent} can have children {}.", children.join(", "))
+            }
+        })
+        .collect();
+    lines.push("Types not listed above cannot have children.".to_string());
+    lines.extend(build_hierarchy_limit_rules(rules));
+    lines
+}
+
+fn build_hierarchy_limit_rules(rules: &HierarchyRules) -> Vec<String> {
+    let mut lines: Vec<String> = rules
+        .max_children
+        .iter()
+        .map(|(parent, limit)| format!("{parent} can have at most {limit} children."))
+        .collect();
+    if let Some(max_depth) = rules.max_depth {
+        lines.push(format!(
+            "Parent chains are at most {max_depth} levels deep."
+        ));
+    }
+    lines
+}
+
 fn build_parent_child_rules(hierarchy: &[String], types: &[String]) -> Vec<String> {
     let mut rules = Vec::new();
     if hierarchy.len() > 1 {
@@ -280,6 +416,64 @@ fn build_workflow_context(
     context
 }
 
+fn build_type_workflow_context(configuration: &ProjectConfiguration) -> Vec<TypeWorkflowContext> {
+    configuration
+        .hierarchy
+        .iter()
+        .chain(configuration.types.iter())
+        .map(|issue_type| TypeWorkflowContext {
+            issue_type: issue_type.clone(),
+            workflow: if configuration.workflows.contains_key(issue_type) {
+                issue_type.clone()
+            } else {
+                "default".to_string()
+            },
+            start_status: resolve_start_status(
+                configuration,
+                issue_type,
+                &configuration.initial_status,
+            )
+            .ok(),
+        })
+        .collect()
+}
+
+/// Summarize the project's policy files; a project without policies yields none.
+fn build_policy_context(root: &Path) -> Result<Vec<PolicyFileContext>, KanbusError> {
+    let Ok(project_dir) = load_project_directory(root) else {
+        return Ok(Vec::new());
+    };
+    let mut policies: Vec<PolicyFileContext> = load_policies(&project_dir.join("policies"))?
+        .into_iter()
+        .map(|(file, feature)| PolicyFileContext {
+            file,
+            name: feature.name.trim().to_string(),
+            scenarios: feature
+                .scenarios
+                .iter()
+                .map(|scenario| scenario.name.trim().to_string())
+                .collect(),
+        })
+        .collect();
+    policies.sort_by(|left, right| left.file.cmp(&right.file));
+    Ok(policies)
+}
+
+/// Configured lifecycle hooks; the built-in policy guidance hooks are omitted.
+fn build_hook_context(root: &Path) -> Vec<HookContext> {
+    list_hooks(root)
+        .into_iter()
+        .filter(|row| row.source != "built-in")
+        .map(|row| HookContext {
+            phase: row.phase,
+            event: row.event,
+            id: row.id,
+            command: row.command,
+            blocking: row.blocking,
+        })
+        .collect()
+}
+
 fn build_priority_context(
     priorities: &BTreeMap<u8, crate::models::PriorityDefinition>,
 ) -> Vec<PriorityContext> {
@@ -720,6 +914,28 @@ mod tests {
         assert_eq!(empty, vec!["No parent-child relationships are defined."]);
     }
 
+    #[test]
+    fn build_explicit_child_rules_lists_children_and_limits() {
+        let rules = HierarchyRules {
+            children: BTreeMap::from([
+                ("bug".to_string(), Vec::new()),
+                ("epic".to_string(), vec!["story".to_string()]),
+            ]),
+            max_depth: Some(3),
+            max_children: BTreeMap::from([("epic".to_string(), 10)]),
+        };
+        assert_eq!(
+            build_explicit_child_rules(&rules),
+            vec![
+                "bug cannot have children.",
+                "epic can have children story.",
+                "Types not listed above cannot have children.",
+                "epic can have at most 10 children.",
+                "Parent chains are at most 3 levels deep.",
+            ]
+        );
+    }
+
     #[test]
     fn collect_statuses_and_select_status_example_cover_fallbacks() {
         let mut workflow = BTreeMap::new();
@@ -855,4 +1071,102 @@ mod tests {
         assert!(events_dir.join("AGENTS.md").exists());
         assert!(events_dir.join("DO_NOT_EDIT").exists());
     }
+
+    #[test]
+    fn check_agents_files_reports_missing_and_stale_files() {
+        let tmp = TempDir::new().expect("temp dir");
+        let root = tmp.path();
+        crate::file_io::initialize_project(root, false).expect("initialize project");
+        let agents_path = root.join("AGENTS.md");
+        let instructions_path = root.join(PROJECT_MANAGEMENT_FILENAME);
+        let _ = std::fs::remove_file(&agents_path);
+        let _ = std::fs::remove_file(&instructions_path);
+        assert_eq!(
+            check_agents_files(root).expect("check"),
+            vec![
+                "AGENTS.md (missing)".to_string(),
+                format!("{PROJECT_MANAGEMENT_FILENAME} (missing)"),
+            ]
+        );
+
+        ensure_agents_file(root, true).expect("setup agents");
+        assert!(check_agents_files(root).expect("check").is_empty());
+
+        std::fs::write(&agents_path, "# Agents\n\nNo Kanbus here.\n").expect("write agents");
+        std::fs::write(&instructions_path, "old instructions\n").expect("write instructions");
+        assert_eq!(
+            check_agents_files(root).expect("check"),
+            vec![
+                "AGENTS.md (Kanbus section is missing or outdated)".to_string(),
+                format!("{PROJECT_MANAGEMENT_FILENAME} (outdated)"),
+            ]
+        );
+
+        ensure_agents_file(root, true).expect("refresh agents");
+        assert!(check_agents_files(root).expect("check").is_empty());
+
+        let agents = std::fs::read_to_string(&agents_path).expect("read agents");
+        std::fs::write(
+            &agents_path,
+            agents.replace("Use Kanbus for task management.", "Use Kanbus sometimes."),
+        )
+        .expect("edit agents");
+        assert_eq!(
+            check_agents_files(root).expect("check"),
+            vec!["AGENTS.md (Kanbus section is missing or outdated)".to_string()]
+        );
+    }
+
+    #[test]
+    fn default_template_renders_live_workflow_policy_and_hook_context() {
+        let mut configuration = crate::config::default_project_configuration();
+        configuration.project_directory = "tracking".to_string();
+        configuration.workflows.insert(
+            "bug".to_string(),
+            BTreeMap::from([("open".to_string(), vec!["triage".to_string()])]),
+        );
+        configuration
+            .statuses
+            .push(crate::models::StatusDefinition {
+                key: "triage".to_string(),
+                name: "Triage".to_string(),
+                category: "In progress".to_string(),
+                color: None,
+                collapsed: false,
+            });
+        let mut context = build_project_management_context(&configuration);
+        context.policies = vec![PolicyFileContext {
+            file: "owner.policy".to_string(),
+            name: "Work has an owner".to_string(),
+            scenarios: vec!["In-progress tasks need an assignee".to_string()],
+        }];
+        context.hooks = vec![HookContext {
+            phase: "before".to_string(),
+            event: "issue.update".to_string(),
+            id: "lint".to_string(),
+            command: "make lint".to_string(),
+            blocking: true,
+        }];
+
+        let rendered = minijinja::Environment::new()
+            .render_str(DEFAULT_PROJECT_MANAGEMENT_TEMPLATE, context)
+            .expect("render");
+        assert!(rendered.contains("- triage -- Triage (In progress)"));
+        assert!(rendered
+            .contains("- bug uses the bug workflow; start work by moving it from open to triage."));
+        assert!(rendered.contains("- task uses the default workflow;"));
+        assert!(rendered.contains("Policies in tracking/policies/ are evaluated"));
+        assert!(rendered.contains("- owner.policy -- Work has an owner"));
+        assert!(rendered.contains("  - In-progress tasks need an assignee"));
+        assert!(rendered.contains("- before issue.update: lint runs `make lint` (blocking)"));
+
+        let bare = minijinja::Environment::new()
+            .render_str(
+                DEFAULT_PROJECT_MANAGEMENT_TEMPLATE,
+                build_project_management_context(&configuration),
+            )
+            .expect("render");
+        assert!(!bare.contains("## Policies"));
+        assert!(!bare.contains("## Lifecycle Hooks"));
+    }
 }
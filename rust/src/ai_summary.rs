@@ -0,0 +1,440 @@
+//! AI summary providers for wiki templates.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::error::KanbusError;
+use crate::models::AiConfiguration;
+
+const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+const DEFAULT_API_KEY_ENV: &str = "OPENAI_API_KEY";
+const DEFAULT_REQUESTS_PER_MINUTE: u32 = 60;
+const DEFAULT_TIMEOUT_SECONDS: u64 = 30;
+const CONNECT_TIMEOUT_SECONDS: u64 = 10;
+const MAX_RETRY_AFTER_SECONDS: u64 = 30;
+
+/// Requested length of a generated summary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryDetail {
+    Short,
+    Medium,
+    Long,
+}
+
+impl SummaryDetail {
+    /// Parse a detail level name.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::IssueOperation` if the level is unknown.
+    pub fn parse(value: &str) -> Result<Self, KanbusError> {
+        match value {
+            "short" => Ok(Self::Short),
+            "medium" => Ok(Self::Medium),
+            "long" => Ok(Self::Long),
+            _ => Err(KanbusError::IssueOperation(format!(
+                "invalid summary detail '{value}'"
+            ))),
+        }
+    }
+
+    /// Return the detail level name.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Short => "short",
+            Self::Medium => "medium",
+            Self::Long => "long",
+        }
+    }
+
+    fn instruction(self) -> &'static str {
+        match self {
+            Self::Short => "Reply with a single sentence.",
+            Self::Medium => "Reply with one short paragraph.",
+            Self::Long => {
+                "Reply with a few paragraphs covering scope, current state and open questions."
+            }
+        }
+    }
+
+    fn max_tokens(self) -> u32 {
+        match self {
+            Self::Short => 80,
+            Self::Medium => 250,
+            Self::Long => 600,
+        }
+    }
+}
+
+/// Backend that turns content into a summary.
+pub trait SummaryProvider: Send + Sync {
+    /// Summarize the content at the requested detail level.
+    ///
+    /// # Errors
+    /// Returns `KanbusError` if the provider call fails.
+    fn summarize(&self, content: &str, detail: SummaryDetail) -> Result<String, KanbusError>;
+}
+
+/// Provider for OpenAI-compatible chat completions endpoints.
+pub struct OpenAiCompatibleProvider {
+    endpoint: String,
+    model: String,
+    api_key: Option<String>,
+    timeout: Duration,
+    http: reqwest::blocking::Client,
+}
+
+impl OpenAiCompatibleProvider {
+    /// Build a provider from the `ai` configuration section.
+    ///
+    /// A custom `base_url` may be used without an API key (for local servers);
+    /// the default OpenAI endpoint requires one.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::Configuration` if the provider is unsupported or the key is missing.
+    pub fn from_configuration(configuration: &AiConfiguration) -> Result<Self, KanbusError> {
+        if !matches!(
+            configuration.provider.as_str(),
+            "openai" | "openai-compatible"
+        ) {
+            return Err(KanbusError::Configuration(format!(
+                "unsupported AI provider '{}'",
+                configuration.provider
+            )));
+        }
+        let key_env = configuration
+            .api_key_env
+            .as_deref()
+            .unwrap_or(DEFAULT_API_KEY_ENV);
+        let api_key = std::env::var(key_env)
+            .ok()
+            .filter(|value| !value.trim().is_empty());
+        if api_key.is_none() && configuration.base_url.is_none() {
+            return Err(KanbusError::Configuration(format!(
+                "{key_env} environment variable is not set"
+            )));
+        }
+        let base_url = configuration
+            .base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BASE_URL);
+        let timeout = Duration::from_secs(
+            configuration
+                .timeout_seconds
+                .unwrap_or(DEFAULT_TIMEOUT_SECONDS),
+        );
+        let http = reqwest::blocking::Client::builder()
+            .connect_timeout(timeout.min(Duration::from_secs(CONNECT_TIMEOUT_SECONDS)))
+            .timeout(timeout)
+            .build()
+            .map_err(|error| KanbusError::Io(error.to_string()))?;
+        Ok(Self {
+            endpoint: format!("{}/chat/completions", base_url.trim_end_matches('/')),
+            model: configuration.model.clone(),
+            api_key,
+            timeout,
+            http,
+        })
+    }
+
+    fn send(
+        &self,
+        body: &Value,
+        timeout: Duration,
+    ) -> Result<reqwest::blocking::Response, KanbusError> {
+        let mut request = self.http.post(&self.endpoint).json(body).timeout(timeout);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        request
+            .send()
+            .map_err(|error| KanbusError::IssueOperation(format!("AI request failed: {error}")))
+    }
+}
+
+impl SummaryProvider for OpenAiCompatibleProvider {
+    fn summarize(&self, content: &str, detail: SummaryDetail) -> Result<String, KanbusError> {
+        let body = json!({
+            "model": self.model,
+            "max_tokens": detail.max_tokens(),
+            "messages": [
+                {
+                    "role": "system",
+                    "content": format!(
+                        "You summarize project tracking issues for a team wiki. {}",
+                        detail.instruction()
+                    ),
+                },
+                {"role": "user", "content": content},
+            ],
+        });
+        // The timeout bounds the whole call, including a rate-limit retry, since
+        // templates render synchronously.
+        let deadline = Instant::now() + self.timeout;
+        let mut response = self.send(&body, self.timeout)?;
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let wait = response
+                .headers()
+                .get("retry-after")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .unwrap_or(1)
+                .min(MAX_RETRY_AFTER_SECONDS);
+            let wait = Duration::from_secs(wait);
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if wait >= remaining {
+                return Err(KanbusError::IssueOperation(
+                    "AI provider rate limit outlasts the request timeout".to_string(),
+                ));
+            }
+            std::thread::sleep(wait);
+            response = self.send(&body, remaining - wait)?;
+        }
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().unwrap_or_default();
+            return Err(KanbusError::IssueOperation(format!(
+                "AI provider returned {status}: {text}"
+            )));
+        }
+        let payload: Value = response.json().map_err(|error| {
+            KanbusError::IssueOperation(format!("Failed to parse AI response: {error}"))
+        })?;
+        payload["choices"][0]["message"]["content"]
+            .as_str()
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty())
+            .ok_or_else(|| KanbusError::IssueOperation("AI response had no content".to_string()))
+    }
+}
+
+/// Spaces out provider calls to stay under a requests-per-minute budget.
+pub struct RateLimiter {
+    interval: Duration,
+    last_call: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing the given number of requests per minute.
+    pub fn per_minute(requests: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(60) / requests.max(1),
+            last_call: Mutex::new(None),
+        }
+    }
+
+    /// Block until the next call is allowed.
+    pub fn wait(&self) {
+        let mut last_call = self
+            .last_call
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(previous) = *last_call {
+            let elapsed = previous.elapsed();
+            if elapsed < self.interval {
+                std::thread::sleep(self.interval - elapsed);
+            }
+        }
+        *last_call = Some(Instant::now());
+    }
+}
+
+/// Rate-limited summarizer that falls back gracefully when the provider is unavailable.
+pub struct AiSummarizer {
+    provider: Result<Box<dyn SummaryProvider>, KanbusError>,
+    limiter: RateLimiter,
+}
+
+impl AiSummarizer {
+    /// Build a summarizer for the `ai` configuration section.
+    pub fn from_configuration(configuration: &AiConfiguration) -> Self {
+        let provider = OpenAiCompatibleProvider::from_configuration(configuration)
+            .map(|provider| Box::new(provider) as Box<dyn SummaryProvider>);
+        Self::with_provider(
+            provider,
+            configuration
+                .requests_per_minute
+                .unwrap_or(DEFAULT_REQUESTS_PER_MINUTE),
+        )
+    }
+
+    /// Build a summarizer around an existing provider.
+    pub fn with_provider(
+        provider: Result<Box<dyn SummaryProvider>, KanbusError>,
+        requests_per_minute: u32,
+    ) -> Self {
+        Self {
+            provider,
+            limiter: RateLimiter::per_minute(requests_per_minute),
+        }
+    }
+
+    /// Summarize content, returning `None` when the provider is unavailable or fails.
+    pub fn summarize(&self, content: &str, detail: SummaryDetail) -> Option<String> {
+        let provider = self.provider.as_ref().ok()?;
+        self.limiter.wait();
+        provider.summarize(content, detail).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::thread;
+
+    type RecordedRequests = Arc<Mutex<Vec<(String, String)>>>;
+
+    /// Serve the canned (status, body) responses in order, recording request heads and bodies.
+    fn spawn_provider_stub(responses: Vec<(u16, Value)>) -> (String, RecordedRequests) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind test listener");
+        let addr = listener.local_addr().expect("read local addr");
+        let recorded: RecordedRequests = Arc::new(Mutex::new(Vec::new()));
+        let recorded_clone = Arc::clone(&recorded);
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    break;
+                };
+                stream
+                    .set_read_timeout(Some(Duration::from_secs(2)))
+                    .expect("set read timeout");
+                let mut buffer = Vec::new();
+                let mut chunk = [0_u8; 8192];
+                loop {
+                    let read = stream.read(&mut chunk).unwrap_or(0);
+                    buffer.extend_from_slice(&chunk[..read]);
+                    let text = String::from_utf8_lossy(&buffer).to_string();
+                    if let Some(header_end) = text.find("\r\n\r\n") {
+                        let length = text[..header_end]
+                            .lines()
+                            .find_map(|line| {
+                                line.to_ascii_lowercase()
+                                    .strip_prefix("content-length:")
+                                    .map(|value| value.trim().parse::<usize>().unwrap_or(0))
+                            })
+                            .unwrap_or(0);
+                        if buffer.len() >= header_end + 4 + length {
+                            break;
+                        }
+                    }
+                    if read == 0 {
+                        break;
+                    }
+                }
+                let text = String::from_utf8_lossy(&buffer).to_string();
+                let (head, request_body) = text.split_once("\r\n\r\n").unwrap_or((&text, ""));
+                recorded_clone
+                    .lock()
+                    .expect("lock")
+                    .push((head.to_string(), request_body.to_string()));
+                let payload = body.to_string();
+                let reason = if status == 200 { "OK" } else { "Error" };
+                let response = format!(
+                    "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nRetry-After: 0\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{payload}",
+                    payload.len()
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        (format!("http://{addr}/v1"), recorded)
+    }
+
+    fn configuration(base_url: &str) -> AiConfiguration {
+        AiConfiguration {
+            provider: "openai".to_string(),
+            model: "test-model".to_string(),
+            base_url: Some(base_url.to_string()),
+            api_key_env: Some("KANBUS_TEST_UNSET_AI_KEY".to_string()),
+            requests_per_minute: None,
+            timeout_seconds: Some(5),
+        }
+    }
+
+    fn completion(text: &str) -> Value {
+        json!({"choices": [{"message": {"role": "assistant", "content": text}}]})
+    }
+
+    #[test]
+    fn calls_chat_completions_with_model_and_detail() {
+        let (base_url, recorded) = spawn_provider_stub(vec![(200, completion(" Ships login. "))]);
+        let summarizer = AiSummarizer::from_configuration(&configuration(&base_url));
+        let summary = summarizer.summarize("{\"title\":\"Login\"}", SummaryDetail::Long);
+        assert_eq!(summary.as_deref(), Some("Ships login."));
+        let requests = recorded.lock().expect("lock");
+        assert!(requests[0].0.starts_with("POST /v1/chat/completions "));
+        assert!(!requests[0].0.to_ascii_lowercase().contains("authorization"));
+        let body: Value = serde_json::from_str(&requests[0].1).expect("json body");
+        assert_eq!(body["model"], "test-model");
+        assert_eq!(body["max_tokens"], 600);
+        assert_eq!(body["messages"][1]["content"], "{\"title\":\"Login\"}");
+    }
+
+    #[test]
+    fn retries_once_after_rate_limit_response() {
+        let (base_url, recorded) = spawn_provider_stub(vec![
+            (429, json!({"error": "slow down"})),
+            (200, completion("Done.")),
+        ]);
+        let summarizer = AiSummarizer::from_configuration(&configuration(&base_url));
+        assert_eq!(
+            summarizer.summarize("content", SummaryDetail::Short),
+            Some("Done.".to_string())
+        );
+        assert_eq!(recorded.lock().expect("lock").len(), 2);
+    }
+
+    #[test]
+    fn provider_failures_fall_back_to_none() {
+        let (base_url, _) = spawn_provider_stub(vec![(500, json!({"error": "boom"}))]);
+        let summarizer = AiSummarizer::from_configuration(&configuration(&base_url));
+        assert_eq!(summarizer.summarize("content", SummaryDetail::Short), None);
+
+        let mut missing_key = configuration(&base_url);
+        missing_key.base_url = None;
+        assert!(OpenAiCompatibleProvider::from_configuration(&missing_key).is_err());
+        let mut unsupported = configuration(&base_url);
+        unsupported.provider = "carrier-pigeon".to_string();
+        let summarizer = AiSummarizer::from_configuration(&unsupported);
+        assert_eq!(summarizer.summarize("content", SummaryDetail::Short), None);
+    }
+
+    #[test]
+    fn unresponsive_provider_times_out() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind test listener");
+        let addr = listener.local_addr().expect("read local addr");
+        thread::spawn(move || {
+            let _held = listener.accept();
+            thread::sleep(Duration::from_secs(10));
+        });
+        let mut config = configuration(&format!("http://{addr}/v1"));
+        config.timeout_seconds = Some(1);
+        let summarizer = AiSummarizer::from_configuration(&config);
+        let started = Instant::now();
+        assert_eq!(summarizer.summarize("content", SummaryDetail::Short), None);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn rate_limiter_spaces_out_calls() {
+        let limiter = RateLimiter::per_minute(1200);
+        let started = Instant::now();
+        limiter.wait();
+        limiter.wait();
+        limiter.wait();
+        assert!(started.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn detail_levels_parse() {
+        assert_eq!(
+            SummaryDetail::parse("medium").expect("medium"),
+            SummaryDetail::Medium
+        );
+        assert_eq!(SummaryDetail::Long.as_str(), "long");
+        assert!(SummaryDetail::parse("verbose").is_err());
+    }
+}
@@ -0,0 +1,327 @@
+//! Kanbus to Beads export helpers.
+//!
+//! Writes a Beads-compatible `issues.jsonl` from Kanbus issue files. The
+//! mapping mirrors `migration.rs`, so exporting and migrating again yields
+//! the same issues.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::{json, Map, Value};
+
+use crate::error::KanbusError;
+use crate::file_io::load_project_directory;
+use crate::issue_listing::load_issues_from_directory;
+use crate::migration::beads_issue_type;
+use crate::models::IssueData;
+
+/// Custom fields that map onto top-level Beads record fields.
+const BEADS_FIELD_MAP: &[(&str, &str)] = &[
+    ("beads_owner", "owner"),
+    ("beads_notes", "notes"),
+    ("beads_acceptance_criteria", "acceptance_criteria"),
+    ("beads_close_reason", "close_reason"),
+];
+
+/// Record field carrying Kanbus custom fields that Beads has no column for.
+pub const KANBUS_CUSTOM_FIELD: &str = "kanbus_custom";
+
+/// Result of a Beads export run.
+#[derive(Debug, Clone)]
+pub struct BeadsExportResult {
+    pub issue_count: usize,
+    pub path: PathBuf,
+    /// Links dropped because their target was not exported, as `source -> target`.
+    pub skipped_links: Vec<String>,
+}
+
+/// Export the shared Kanbus issues of a project to a Beads `issues.jsonl`.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `output` - Destination file; defaults to `.beads/issues.jsonl` under `root`.
+/// * `force` - Overwrite an existing destination file.
+///
+/// # Errors
+/// Returns `KanbusError` if issues cannot be read, the destination exists
+/// without `force`, or the file cannot be written.
+pub fn export_to_beads(
+    root: &Path,
+    output: Option<&Path>,
+    force: bool,
+) -> Result<BeadsExportResult, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let mut issues = load_issues_from_directory(&project_dir.join("issues"))?;
+    issues.sort_by(|left, right| {
+        left.created_at
+            .cmp(&right.created_at)
+            .then_with(|| left.identifier.cmp(&right.identifier))
+    });
+
+    let path = output
+        .map(|path| root.join(path))
+        .unwrap_or_else(|| root.join(".beads").join("issues.jsonl"));
+    if path.exists() && !force {
+        return Err(KanbusError::IssueOperation(format!(
+            "{} already exists (use --force to overwrite)",
+            path.display()
+        )));
+    }
+
+    let known: HashSet<&str> = issues
+        .iter()
+        .map(|issue| issue.identifier.as_str())
+        .collect();
+    let mut skipped_links = Vec::new();
+    let mut lines = Vec::with_capacity(issues.len());
+    for issue in &issues {
+        let record = issue_to_beads_record(issue, &known, &mut skipped_links);
+        lines.push(
+            serde_json::to_string(&record).map_err(|error| KanbusError::Io(error.to_string()))?,
+        );
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| KanbusError::Io(error.to_string()))?;
+    }
+    let mut file = fs::File::create(&path).map_err(|error| KanbusError::Io(error.to_string()))?;
+    for line in &lines {
+        writeln!(file, "{line}").map_err(|error| KanbusError::Io(error.to_string()))?;
+    }
+
+    Ok(BeadsExportResult {
+        issue_count: issues.len(),
+        path,
+        skipped_links,
+    })
+}
+
+/// Convert a Kanbus issue into a Beads record.
+///
+/// # Arguments
+/// * `issue` - Issue to convert.
+/// * `known` - Identifiers included in the export; links to others are dropped.
+/// * `skipped_links` - Accumulator for dropped links.
+pub fn issue_to_beads_record(
+    issue: &IssueData,
+    known: &HashSet<&str>,
+    skipped_links: &mut Vec<String>,
+) -> Value {
+    let mut record = Map::new();
+    record.insert("id".to_string(), json!(issue.identifier));
+    record.insert("title".to_string(), json!(issue.title));
+    record.insert("description".to_string(), json!(issue.description));
+    record.insert("status".to_string(), json!(issue.status));
+    record.insert("priority".to_string(), json!(issue.priority));
+    let issue_type = issue
+        .custom
+        .get("beads_issue_type")
+        .and_then(Value::as_str)
+        .unwrap_or_else(|| beads_issue_type(&issue.issue_type));
+    record.insert("issue_type".to_string(), json!(issue_type));
+    record.insert(
+        "created_at".to_string(),
+        json!(format_timestamp(&issue.created_at)),
+    );
+    record.insert(
+        "updated_at".to_string(),
+        json!(format_timestamp(&issue.updated_at)),
+    );
+    if let Some(closed_at) = &issue.closed_at {
+        record.insert("closed_at".to_string(), json!(format_timestamp(closed_at)));
+    }
+    if let Some(creator) = &issue.creator {
+        record.insert("created_by".to_string(), json!(creator));
+    }
+    if let Some(assignee) = &issue.assignee {
+        record.insert("assignee".to_string(), json!(assignee));
+    }
+    if !issue.labels.is_empty() {
+        record.insert("labels".to_string(), json!(issue.labels));
+    }
+
+    let created_at = format_timestamp(&issue.created_at);
+    let mut dependencies = Vec::new();
+    let links = issue
+        .parent
+        .iter()
+        .map(|parent| (parent.as_str(), "parent-child"))
+        .chain(
+            issue
+                .dependencies
+                .iter()
+                .map(|link| (link.target.as_str(), link.dependency_type.as_str())),
+        );
+    for (target, dependency_type) in links {
+        if !known.contains(target) {
+            skipped_links.push(format!("{} -> {target}", issue.identifier));
+            continue;
+        }
+        let mut dependency = json!({
+            "issue_id": issue.identifier,
+            "depends_on_id": target,
+            "type": dependency_type,
+            "created_at": created_at,
+        });
+        if let Some(creator) = &issue.creator {
+            dependency["created_by"] = json!(creator);
+        }
+        dependencies.push(dependency);
+    }
+    if !dependencies.is_empty() {
+        record.insert("dependencies".to_string(), Value::Array(dependencies));
+    }
+
+    let comments: Vec<Value> = issue
+        .comments
+        .iter()
+        .enumerate()
+        .map(|(index, comment)| {
+            let id = comment
+                .id
+                .as_ref()
+                .map_or_else(|| json!(index + 1), |id| json!(id));
+            json!({
+                "id": id,
+                "issue_id": issue.identifier,
+                "author": comment.author,
+                "text": comment.text,
+                "created_at": format_timestamp(&comment.created_at),
+            })
+        })
+        .collect();
+    record.insert("comments".to_string(), Value::Array(comments));
+
+    let mut remaining: BTreeMap<String, Value> = issue.custom.clone();
+    remaining.remove("beads_issue_type");
+    for (custom_key, beads_key) in BEADS_FIELD_MAP {
+        if let Some(value) = remaining.remove(*custom_key) {
+            record.insert(beads_key.to_string(), value);
+        }
+    }
+    if !remaining.is_empty() {
+        record.insert(KANBUS_CUSTOM_FIELD.to_string(), json!(remaining));
+    }
+
+    Value::Object(record)
+}
+
+fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_io::initialize_project;
+    use crate::issue_files::write_issue_to_file;
+    use crate::migration::load_beads_issues;
+    use crate::models::{DependencyLink, IssueComment};
+    use chrono::TimeZone;
+
+    fn issue(identifier: &str, issue_type: &str, parent: Option<&str>) -> IssueData {
+        let created_at = Utc.with_ymd_and_hms(2026, 3, 6, 9, 30, 0).unwrap();
+        IssueData {
+            identifier: identifier.to_string(),
+            title: format!("Title {identifier}"),
+            description: "Body".to_string(),
+            issue_type: issue_type.to_string(),
+            status: "open".to_string(),
+            priority: 1,
+            assignee: Some("dev@example.com".to_string()),
+            creator: Some("lead@example.com".to_string()),
+            parent: parent.map(str::to_string),
+            labels: vec!["backend".to_string()],
+            dependencies: Vec::new(),
+            comments: Vec::new(),
+            created_at,
+            updated_at: created_at + chrono::Duration::minutes(5),
+            closed_at: None,
+            custom: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn export_round_trips_through_beads_migration() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        initialize_project(root, false).expect("initialize project");
+        let issues_dir = root.join("project").join("issues");
+
+        let epic = issue("bd-epic", "epic", None);
+        let mut story = issue("bd-story", "story", Some("bd-epic"));
+        story
+            .custom
+            .insert("beads_issue_type".to_string(), json!("feature"));
+        story
+            .custom
+            .insert("beads_notes".to_string(), json!("remember"));
+        story.custom.insert("estimate".to_string(), json!(3));
+        let native_story = issue("bd-native", "story", Some("bd-epic"));
+        let mut task = issue("bd-task", "task", Some("bd-epic"));
+        task.status = "closed".to_string();
+        task.closed_at = Some(task.updated_at);
+        task.dependencies.push(DependencyLink {
+            target: "bd-story".to_string(),
+            dependency_type: "blocked-by".to_string(),
+        });
+        task.dependencies.push(DependencyLink {
+            target: "bd-gone".to_string(),
+            dependency_type: "relates-to".to_string(),
+        });
+        task.comments.push(IssueComment {
+            id: Some("0f8e5d7a-3c1b-4e2a-9b6d-2a4c6e8f0b1d".to_string()),
+            author: "lead@example.com".to_string(),
+            text: "Looks good".to_string(),
+            created_at: task.updated_at,
+        });
+        for item in [&epic, &story, &native_story, &task] {
+            write_issue_to_file(item, &issues_dir.join(format!("{}.json", item.identifier)))
+                .expect("write issue");
+        }
+
+        let result = export_to_beads(root, None, false).expect("export");
+        assert_eq!(result.issue_count, 4);
+        let exported = fs::read_to_string(&result.path).expect("read export");
+        let records: BTreeMap<String, Value> = exported
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).expect("record"))
+            .map(|record| (record["id"].as_str().unwrap_or("").to_string(), record))
+            .collect();
+        assert_eq!(records["bd-native"]["issue_type"], json!("feature"));
+        assert_eq!(records["bd-task"]["issue_type"], json!("task"));
+        assert_eq!(
+            records["bd-task"]["comments"][0]["id"],
+            json!("0f8e5d7a-3c1b-4e2a-9b6d-2a4c6e8f0b1d")
+        );
+        assert_eq!(result.skipped_links, vec!["bd-task -> bd-gone".to_string()]);
+        assert!(export_to_beads(root, None, false).is_err());
+
+        let imported: BTreeMap<String, IssueData> = load_beads_issues(root)
+            .expect("migrate back")
+            .into_iter()
+            .map(|issue| (issue.identifier.clone(), issue))
+            .collect();
+        let story_back = &imported["bd-story"];
+        assert_eq!(story_back.issue_type, "story");
+        assert_eq!(story_back.parent.as_deref(), Some("bd-epic"));
+        assert_eq!(story_back.custom["beads_notes"], json!("remember"));
+        assert_eq!(story_back.custom["estimate"], json!(3));
+        assert_eq!(story_back.labels, story.labels);
+        assert_eq!(story_back.assignee, story.assignee);
+
+        let task_back = &imported["bd-task"];
+        assert_eq!(task_back.status, "closed");
+        assert_eq!(task_back.closed_at, task.closed_at);
+        assert_eq!(task_back.created_at, task.created_at);
+        assert_eq!(task_back.parent.as_deref(), Some("bd-epic"));
+        assert_eq!(task_back.dependencies.len(), 1);
+        assert_eq!(task_back.dependencies[0].target, "bd-story");
+        assert_eq!(task_back.comments[0].text, "Looks good");
+        assert_eq!(task_back.comments[0].id, task.comments[0].id);
+        assert_eq!(imported["bd-native"].issue_type, "story");
+    }
+}
@@ -36,16 +36,19 @@ use tokio_stream::wrappers::IntervalStream;
 use tower_http::cors::{Any, CorsLayer};
 
 use kanbus::console_backend::{find_issue_matches, FileStore};
-use kanbus::console_ui_state::{load_state, save_state, state_path, ConsoleUiState};
+use kanbus::console_ui_state::{
+    load_store, save_store, state_path, ConsoleSessionInfo, ConsoleStateStore,
+};
 use kanbus::console_wiki::{
-    create_page, delete_page, get_page, list_pages, rename_page, render_page, update_page,
-    WikiCreateRequest, WikiRenameRequest, WikiRenderRequestPayload, WikiServiceError,
-    WikiUpdateRequest,
+    backlinks, create_page, delete_page, get_page, list_pages, page_diff, page_history,
+    rename_page, render_page, restore_page, update_page, WikiCreateRequest, WikiRenameRequest,
+    WikiRenderRequestPayload, WikiRestoreRequest, WikiServiceError, WikiUpdateRequest,
 };
+use kanbus::error::KanbusError;
 use kanbus::event_history::{load_issue_events, EventRecord};
 use kanbus::file_io::{detect_repairable_project_issues, repair_project_structure};
 use kanbus::gossip::{run_gossip_bridge, GossipEnvelope};
-use kanbus::notification_events::{NotificationEvent, UiControlAction};
+use kanbus::notification_events::NotificationEvent;
 
 #[cfg(feature = "embed-assets")]
 use rust_embed::RustEmbed;
@@ -64,8 +67,9 @@ struct AppState {
     telemetry_tx: broadcast::Sender<String>,
     telemetry_log: Option<Arc<StdMutex<std::fs::File>>>,
     notification_tx: broadcast::Sender<NotificationEvent>,
-    /// Cache of the last URL route pushed to clients, for CLI query commands.
-    ui_state: Arc<tokio::sync::RwLock<ConsoleUiState>>,
+    /// Cache of the last URL route pushed to clients, overall and per session,
+    /// for CLI query commands.
+    ui_state: Arc<tokio::sync::RwLock<ConsoleStateStore>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -79,6 +83,12 @@ struct WikiPathQuery {
     path: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct WikiDiffQuery {
+    path: String,
+    revision: String,
+}
+
 #[derive(Debug, Serialize)]
 struct IssueEventsResponse {
     issue_id: String,
@@ -177,7 +187,7 @@ async fn main() {
             .join(".cache")
             .join("console_state.json")
     });
-    let initial_ui_state = load_state(&data_root).unwrap_or_default();
+    let initial_ui_state = load_store(&data_root).unwrap_or_default();
     eprintln!("Console UI state loaded from {}", state_file_path.display());
 
     let state = AppState {
@@ -203,6 +213,7 @@ async fn main() {
         .route("/api/auth/bootstrap", get(get_auth_bootstrap_root))
         .route("/api/notifications", post(post_notification_root))
         .route("/api/ui-state", get(get_ui_state_root))
+        .route("/api/ui-sessions", get(get_ui_sessions_root))
         .route(
             "/api/wiki/page",
             get(get_wiki_page_root)
@@ -211,6 +222,10 @@ async fn main() {
                 .delete(delete_wiki_page_root),
         )
         .route("/api/wiki/pages", get(get_wiki_pages_root))
+        .route("/api/wiki/backlinks", get(get_wiki_backlinks_root))
+        .route("/api/wiki/history", get(get_wiki_history_root))
+        .route("/api/wiki/diff", get(get_wiki_diff_root))
+        .route("/api/wiki/restore", post(post_wiki_restore_root))
         .route("/api/wiki/rename", post(post_wiki_rename_root))
         .route("/api/wiki/render", post(post_wiki_render_root))
         .route("/api/render/d2", post(post_render_d2))
@@ -256,6 +271,16 @@ async fn main() {
                 .delete(delete_wiki_page),
         )
         .route("/:account/:project/api/wiki/pages", get(get_wiki_pages))
+        .route(
+            "/:account/:project/api/wiki/backlinks",
+            get(get_wiki_backlinks),
+        )
+        .route("/:account/:project/api/wiki/history", get(get_wiki_history))
+        .route("/:account/:project/api/wiki/diff", get(get_wiki_diff))
+        .route(
+            "/:account/:project/api/wiki/restore",
+            post(post_wiki_restore),
+        )
         .route("/:account/:project/api/wiki/rename", post(post_wiki_rename))
         .route("/:account/:project/api/wiki/render", post(post_wiki_render))
         .route(
@@ -787,145 +812,169 @@ fn build_telemetry_payload(payload: JsonValue, tenant: Option<(String, String)>)
 
 // Notification handlers for real-time issue updates
 
+#[derive(Debug, Default, Deserialize)]
+struct SessionQuery {
+    session: Option<String>,
+}
+
 async fn post_notification_root(
     State(state): State<AppState>,
     Json(event): Json<NotificationEvent>,
-) -> StatusCode {
+) -> Response {
     // Update cached UI state based on the event
-    update_ui_state_from_event(&state, &event).await;
-    // Broadcast the notification to all SSE subscribers
+    if let Err(error) = update_ui_state_from_event(&state, &event).await {
+        return error_response(error.to_string(), StatusCode::NOT_FOUND);
+    }
+    // Broadcast the notification to all SSE subscribers; targeted events are
+    // filtered per session by the subscriber streams.
     let _ = state.notification_tx.send(event);
-    StatusCode::OK
+    StatusCode::OK.into_response()
 }
 
-async fn get_ui_state_root(State(state): State<AppState>) -> axum::Json<ConsoleUiState> {
-    let ui_state = state.ui_state.read().await;
-    axum::Json(ui_state.clone())
+async fn get_ui_state_root(
+    State(state): State<AppState>,
+    Query(query): Query<SessionQuery>,
+) -> Response {
+    let store = state.ui_state.read().await;
+    match store.state_for(query.session.as_deref()) {
+        Some(ui_state) => Json(ui_state.clone()).into_response(),
+        None => error_response(
+            format!(
+                "unknown console session: {}",
+                query.session.unwrap_or_default()
+            ),
+            StatusCode::NOT_FOUND,
+        ),
+    }
+}
+
+async fn get_ui_sessions_root(State(state): State<AppState>) -> Json<Vec<ConsoleSessionInfo>> {
+    let store = state.ui_state.read().await;
+    Json(store.session_list())
 }
 
 /// Update the cached UI state when a relevant notification is received,
 /// then persist to disk.
-async fn update_ui_state_from_event(state: &AppState, event: &NotificationEvent) {
-    let mut changed = false;
-    {
-        let mut ui_state = state.ui_state.write().await;
-        match event {
-            NotificationEvent::IssueFocused {
-                issue_id,
-                comment_id,
-                ..
-            } => {
-                ui_state.focused_issue_id = Some(issue_id.clone());
-                ui_state.focused_comment_id = comment_id.clone();
-                changed = true;
-            }
-            NotificationEvent::UiControl { action } => match action {
-                UiControlAction::ClearFocus => {
-                    ui_state.focused_issue_id = None;
-                    ui_state.focused_comment_id = None;
-                    changed = true;
-                }
-                UiControlAction::SetViewMode { mode } => {
-                    ui_state.view_mode = Some(mode.clone());
-                    changed = true;
-                }
-                UiControlAction::SetSearch { query } => {
-                    ui_state.search_query = if query.is_empty() {
-                        None
-                    } else {
-                        Some(query.clone())
-                    };
-                    changed = true;
-                }
-                _ => {}
-            },
-            _ => {}
-        }
-    }
+async fn update_ui_state_from_event(
+    state: &AppState,
+    event: &NotificationEvent,
+) -> Result<(), KanbusError> {
+    let changed = state.ui_state.write().await.apply_event(event)?;
     if changed {
-        let ui_state = state.ui_state.read().await;
-        if let Err(e) = save_state(&state.base_root, &ui_state) {
-            eprintln!("Warning: failed to persist console UI state: {}", e);
-        }
+        persist_ui_state(state).await;
+    }
+    Ok(())
+}
+
+async fn persist_ui_state(state: &AppState) {
+    let store = state.ui_state.read().await;
+    if let Err(e) = save_store(&state.base_root, &store) {
+        eprintln!("Warning: failed to persist console UI state: {}", e);
+    }
+}
+
+/// Marks a session disconnected when its event stream is dropped.
+struct SessionConnection {
+    state: AppState,
+    session_id: String,
+}
+
+impl Drop for SessionConnection {
+    fn drop(&mut self) {
+        let state = self.state.clone();
+        let session_id = std::mem::take(&mut self.session_id);
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        runtime.spawn(async move {
+            state
+                .ui_state
+                .write()
+                .await
+                .disconnect_session(&session_id, chrono::Utc::now());
+            persist_ui_state(&state).await;
+        });
     }
 }
 
 async fn get_realtime_events_root(
     State(state): State<AppState>,
-) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    // Replay last-known UI state to the new subscriber, if any state has been set
-    let replay_events: Vec<Result<Event, Infallible>> = {
-        let ui_state = state.ui_state.read().await;
-        let mut events = Vec::new();
-        if let Some(ref issue_id) = ui_state.focused_issue_id {
-            let notification = NotificationEvent::IssueFocused {
-                issue_id: issue_id.clone(),
-                user: None,
-                comment_id: ui_state.focused_comment_id.clone(),
-            };
-            if let Ok(data) = serde_json::to_string(&notification) {
-                events.push(Ok(Event::default().data(data)));
-            }
-        } else if ui_state.view_mode.is_some() || ui_state.search_query.is_some() {
-            // Replay view mode if set
-            if let Some(ref mode) = ui_state.view_mode {
-                let notification = NotificationEvent::UiControl {
-                    action: UiControlAction::SetViewMode { mode: mode.clone() },
-                };
-                if let Ok(data) = serde_json::to_string(&notification) {
-                    events.push(Ok(Event::default().data(data)));
-                }
-            }
-            // Replay search query if set
-            if let Some(ref query) = ui_state.search_query {
-                let notification = NotificationEvent::UiControl {
-                    action: UiControlAction::SetSearch {
-                        query: query.clone(),
-                    },
-                };
-                if let Ok(data) = serde_json::to_string(&notification) {
-                    events.push(Ok(Event::default().data(data)));
-                }
-            }
+    Query(query): Query<SessionQuery>,
+) -> Response {
+    // Register the session, then replay its last-known UI state to the new subscriber
+    let (replay, connection) = {
+        let mut store = state.ui_state.write().await;
+        match query.session.as_deref() {
+            Some(session_id) => match store.connect_session(session_id, chrono::Utc::now()) {
+                Ok(session) => (
+                    session.state.replay_events(),
+                    Some(SessionConnection {
+                        state: state.clone(),
+                        session_id: session_id.to_string(),
+                    }),
+                ),
+                Err(error) => return error_response(error.to_string(), StatusCode::BAD_REQUEST),
+            },
+            None => (store.shared.replay_events(), None),
         }
-        events
     };
+    if connection.is_some() {
+        persist_ui_state(&state).await;
+    }
+    let replay_events: Vec<Result<Event, Infallible>> = replay
+        .iter()
+        .filter_map(|notification| serde_json::to_string(notification).ok())
+        .map(|data| Ok(Event::default().data(data)))
+        .collect();
 
+    let session = query.session;
     let receiver = state.notification_tx.subscribe();
     let replay_stream = stream::iter(replay_events);
-    let live_stream = BroadcastStream::new(receiver).filter_map(|event| async move {
-        match event {
-            Ok(notification) => {
-                // Serialize the notification event to JSON
-                match serde_json::to_string(&notification) {
-                    Ok(data) => Some(Ok(Event::default().data(data))),
-                    Err(_) => None,
+    let live_stream = BroadcastStream::new(receiver).filter_map(move |event| {
+        let _connection = &connection;
+        let deliver = match &event {
+            Ok(notification) => notification
+                .target_session()
+                .is_none_or(|target| Some(target) == session.as_deref()),
+            Err(_) => false,
+        };
+        async move {
+            match event {
+                Ok(notification) if deliver => {
+                    // Serialize the notification event to JSON
+                    match serde_json::to_string(&notification) {
+                        Ok(data) => Some(Ok(Event::default().data(data))),
+                        Err(_) => None,
+                    }
                 }
+                _ => None,
             }
-            Err(_) => None,
         }
     });
     let combined: BoxStream<Result<Event, Infallible>> = Box::pin(replay_stream.chain(live_stream));
-    Sse::new(combined).keep_alive(
-        KeepAlive::new()
-            .interval(Duration::from_secs(15))
-            .text(": keep-alive"),
-    )
+    Sse::new(combined)
+        .keep_alive(
+            KeepAlive::new()
+                .interval(Duration::from_secs(15))
+                .text(": keep-alive"),
+        )
+        .into_response()
 }
 
 async fn post_notification(
     State(state): State<AppState>,
     AxumPath((_account, _project)): AxumPath<(String, String)>,
     Json(event): Json<NotificationEvent>,
-) -> StatusCode {
+) -> Response {
     post_notification_root(State(state), Json(event)).await
 }
 
 async fn get_realtime_events(
     State(state): State<AppState>,
     AxumPath((_account, _project)): AxumPath<(String, String)>,
-) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    get_realtime_events_root(State(state)).await
+    query: Query<SessionQuery>,
+) -> Response {
+    get_realtime_events_root(State(state), query).await
 }
 
 #[derive(Debug, Serialize)]
@@ -1030,6 +1079,33 @@ async fn get_wiki_pages(
     }
 }
 
+async fn get_wiki_backlinks_root(State(state): State<AppState>) -> Response {
+    let store = match store_for_root(&state) {
+        Some(store) => store,
+        None => {
+            return error_response(
+                "multi-tenant mode requires /:account/:project",
+                StatusCode::BAD_REQUEST,
+            )
+        }
+    };
+    match backlinks(&store) {
+        Ok(result) => Json(result).into_response(),
+        Err(error) => wiki_error_to_response(error),
+    }
+}
+
+async fn get_wiki_backlinks(
+    State(state): State<AppState>,
+    AxumPath((account, project)): AxumPath<(String, String)>,
+) -> Response {
+    let store = store_for(&state, &account, &project);
+    match backlinks(&store) {
+        Ok(result) => Json(result).into_response(),
+        Err(error) => wiki_error_to_response(error),
+    }
+}
+
 async fn get_wiki_page_root(
     State(state): State<AppState>,
     Query(query): Query<WikiPathQuery>,
@@ -1185,6 +1261,99 @@ async fn post_wiki_rename(
     }
 }
 
+async fn get_wiki_history_root(
+    State(state): State<AppState>,
+    Query(query): Query<WikiPathQuery>,
+) -> Response {
+    let store = match store_for_root(&state) {
+        Some(store) => store,
+        None => {
+            return error_response(
+                "multi-tenant mode requires /:account/:project",
+                StatusCode::BAD_REQUEST,
+            )
+        }
+    };
+    match page_history(&store, &query.path) {
+        Ok(result) => Json(result).into_response(),
+        Err(error) => wiki_error_to_response(error),
+    }
+}
+
+async fn get_wiki_history(
+    State(state): State<AppState>,
+    AxumPath((account, project)): AxumPath<(String, String)>,
+    Query(query): Query<WikiPathQuery>,
+) -> Response {
+    let store = store_for(&state, &account, &project);
+    match page_history(&store, &query.path) {
+        Ok(result) => Json(result).into_response(),
+        Err(error) => wiki_error_to_response(error),
+    }
+}
+
+async fn get_wiki_diff_root(
+    State(state): State<AppState>,
+    Query(query): Query<WikiDiffQuery>,
+) -> Response {
+    let store = match store_for_root(&state) {
+        Some(store) => store,
+        None => {
+            return error_response(
+                "multi-tenant mode requires /:account/:project",
+                StatusCode::BAD_REQUEST,
+            )
+        }
+    };
+    match page_diff(&store, &query.path, &query.revision) {
+        Ok(result) => Json(result).into_response(),
+        Err(error) => wiki_error_to_response(error),
+    }
+}
+
+async fn get_wiki_diff(
+    State(state): State<AppState>,
+    AxumPath((account, project)): AxumPath<(String, String)>,
+    Query(query): Query<WikiDiffQuery>,
+) -> Response {
+    let store = store_for(&state, &account, &project);
+    match page_diff(&store, &query.path, &query.revision) {
+        Ok(result) => Json(result).into_response(),
+        Err(error) => wiki_error_to_response(error),
+    }
+}
+
+async fn post_wiki_restore_root(
+    State(state): State<AppState>,
+    Json(payload): Json<WikiRestoreRequest>,
+) -> Response {
+    let store = match store_for_root(&state) {
+        Some(store) => store,
+        None => {
+            return error_response(
+                "multi-tenant mode requires /:account/:project",
+                StatusCode::BAD_REQUEST,
+            )
+        }
+    };
+    match restore_page(&store, &payload) {
+        Ok(result) => Json(result).into_response(),
+        Err(error) => wiki_error_to_response(error),
+    }
+}
+
+async fn post_wiki_restore(
+    State(state): State<AppState>,
+    AxumPath((account, project)): AxumPath<(String, String)>,
+    Json(payload): Json<WikiRestoreRequest>,
+) -> Response {
+    let store = store_for(&state, &account, &project);
+    match restore_page(&store, &payload) {
+        Ok(result) => Json(result).into_response(),
+        Err(error) => wiki_error_to_response(error),
+    }
+}
+
 async fn post_wiki_render_root(
     State(state): State<AppState>,
     Json(payload): Json<WikiRenderRequestPayload>,
@@ -1665,6 +1834,8 @@ fn serve_asset_from_filesystem(state: &AppState, asset_path: &str) -> Response {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use kanbus::console_ui_state::load_state;
+    use kanbus::notification_events::UiControlAction;
     use std::cell::Cell;
     use std::env;
     use std::sync::Mutex;
@@ -1691,7 +1862,7 @@ mod tests {
             telemetry_tx,
             telemetry_log: None::<Arc<StdMutex<std::fs::File>>>,
             notification_tx,
-            ui_state: Arc::new(RwLock::new(ConsoleUiState::default())),
+            ui_state: Arc::new(RwLock::new(ConsoleStateStore::default())),
         }
     }
 
@@ -2033,7 +2204,7 @@ mod tests {
             telemetry_tx,
             telemetry_log: None,
             notification_tx,
-            ui_state: Arc::new(tokio::sync::RwLock::new(ConsoleUiState::default())),
+            ui_state: Arc::new(tokio::sync::RwLock::new(ConsoleStateStore::default())),
         };
 
         let response = serve_asset(&state, "index.html");
@@ -2060,7 +2231,7 @@ mod tests {
             telemetry_tx,
             telemetry_log: None,
             notification_tx,
-            ui_state: Arc::new(tokio::sync::RwLock::new(ConsoleUiState::default())),
+            ui_state: Arc::new(tokio::sync::RwLock::new(ConsoleStateStore::default())),
         };
 
         let response = serve_asset(&state, "missing-asset.js");
@@ -2127,17 +2298,23 @@ mod tests {
             issue_id: "kanbus-123".to_string(),
             user: None,
             comment_id: Some("cmt-1".to_string()),
+            session: None,
         };
 
-        update_ui_state_from_event(&state, &focus).await;
+        update_ui_state_from_event(&state, &focus)
+            .await
+            .expect("apply focus");
         let persisted = load_state(&root).expect("load state");
         assert_eq!(persisted.focused_issue_id.as_deref(), Some("kanbus-123"));
         assert_eq!(persisted.focused_comment_id.as_deref(), Some("cmt-1"));
 
         let clear = NotificationEvent::UiControl {
             action: UiControlAction::ClearFocus,
+            session: None,
         };
-        update_ui_state_from_event(&state, &clear).await;
+        update_ui_state_from_event(&state, &clear)
+            .await
+            .expect("apply event");
         let cleared = load_state(&root).expect("load cleared state");
         assert!(cleared.focused_issue_id.is_none());
         assert!(cleared.focused_comment_id.is_none());
@@ -2154,15 +2331,21 @@ mod tests {
             action: UiControlAction::SetViewMode {
                 mode: "issues".to_string(),
             },
+            session: None,
         };
-        update_ui_state_from_event(&state, &view_mode).await;
+        update_ui_state_from_event(&state, &view_mode)
+            .await
+            .expect("apply event");
 
         let search = NotificationEvent::UiControl {
             action: UiControlAction::SetSearch {
                 query: "auth".to_string(),
             },
+            session: None,
         };
-        update_ui_state_from_event(&state, &search).await;
+        update_ui_state_from_event(&state, &search)
+            .await
+            .expect("apply event");
         let persisted = load_state(&root).expect("load state");
         assert_eq!(persisted.view_mode.as_deref(), Some("issues"));
         assert_eq!(persisted.search_query.as_deref(), Some("auth"));
@@ -2171,8 +2354,11 @@ mod tests {
             action: UiControlAction::SetSearch {
                 query: String::new(),
             },
+            session: None,
         };
-        update_ui_state_from_event(&state, &clear_search).await;
+        update_ui_state_from_event(&state, &clear_search)
+            .await
+            .expect("apply event");
         let cleared = load_state(&root).expect("load cleared state");
         assert!(cleared.search_query.is_none());
     }
@@ -2188,8 +2374,11 @@ mod tests {
 
         let no_op = NotificationEvent::UiControl {
             action: UiControlAction::MaximizeDetail,
+            session: None,
         };
-        update_ui_state_from_event(&state, &no_op).await;
+        update_ui_state_from_event(&state, &no_op)
+            .await
+            .expect("apply event");
         assert!(!path.exists());
 
         let issue_updated = NotificationEvent::IssueUpdated {
@@ -2214,7 +2403,9 @@ mod tests {
                 custom: std::collections::BTreeMap::new(),
             },
         };
-        update_ui_state_from_event(&state, &issue_updated).await;
+        update_ui_state_from_event(&state, &issue_updated)
+            .await
+            .expect("apply event");
         assert!(!path.exists());
     }
 
@@ -2291,6 +2482,7 @@ mod tests {
                 Json(WikiUpdateRequest {
                     path: "page.md".to_string(),
                     content: "updated".to_string(),
+                    message: None,
                 }),
             )
             .await
@@ -2342,9 +2534,12 @@ mod tests {
 
         let event = NotificationEvent::UiControl {
             action: UiControlAction::ReloadPage,
+            session: None,
         };
         assert_eq!(
-            post_notification_root(State(state.clone()), Json(event.clone())).await,
+            post_notification_root(State(state.clone()), Json(event.clone()))
+                .await
+                .status(),
             StatusCode::OK
         );
         assert_eq!(
@@ -2353,9 +2548,20 @@ mod tests {
                 AxumPath(("acct".to_string(), "proj".to_string())),
                 Json(event),
             )
-            .await,
+            .await
+            .status(),
             StatusCode::OK
         );
+        let targeted = NotificationEvent::UiControl {
+            action: UiControlAction::ReloadPage,
+            session: Some("missing".to_string()),
+        };
+        assert_eq!(
+            post_notification_root(State(state.clone()), Json(targeted))
+                .await
+                .status(),
+            StatusCode::NOT_FOUND
+        );
 
         assert_eq!(
             post_console_telemetry_root(State(state.clone()), Bytes::from("{}")).await,
@@ -2372,23 +2578,73 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn sessions_register_on_connect_and_receive_targeted_state() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path().canonicalize().expect("canonical root");
+        setup_project_root(&root);
+        let state = test_state(root.clone(), root.clone(), false);
+        let connect = |session: &str| SessionQuery {
+            session: Some(session.to_string()),
+        };
+
+        let left = get_realtime_events_root(State(state.clone()), Query(connect("left"))).await;
+        assert_eq!(left.status(), StatusCode::OK);
+        let _right = get_realtime_events_root(State(state.clone()), Query(connect("right"))).await;
+        let invalid =
+            get_realtime_events_root(State(state.clone()), Query(connect("not valid"))).await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+        let focus = NotificationEvent::IssueFocused {
+            issue_id: "kbs-7".to_string(),
+            user: None,
+            comment_id: None,
+            session: Some("left".to_string()),
+        };
+        let response = post_notification_root(State(state.clone()), Json(focus)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let store = load_store(&root).expect("load store");
+        assert_eq!(
+            store.sessions["left"].state.focused_issue_id.as_deref(),
+            Some("kbs-7")
+        );
+        assert!(store.sessions["right"].state.focused_issue_id.is_none());
+        assert!(store.shared.focused_issue_id.is_none());
+
+        let sessions = get_ui_sessions_root(State(state.clone())).await.0;
+        let ids: Vec<&str> = sessions.iter().map(|session| session.id.as_str()).collect();
+        assert_eq!(ids, ["left", "right"]);
+        assert!(sessions.iter().all(|session| session.connected));
+
+        let missing = get_ui_state_root(State(state.clone()), Query(connect("missing"))).await;
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn ui_state_and_sse_endpoints_return_event_stream_content_type() {
         let temp = tempfile::tempdir().expect("tempdir");
         let state = test_state(temp.path().to_path_buf(), temp.path().to_path_buf(), false);
         {
             let mut ui_state = state.ui_state.write().await;
-            ui_state.focused_issue_id = Some("kbs-88".to_string());
-            ui_state.focused_comment_id = Some("comment-2".to_string());
+            ui_state.shared.focused_issue_id = Some("kbs-88".to_string());
+            ui_state.shared.focused_comment_id = Some("comment-2".to_string());
         }
 
-        let ui_state = get_ui_state_root(State(state.clone())).await.0;
+        let response =
+            get_ui_state_root(State(state.clone()), Query(SessionQuery::default())).await;
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("ui state body");
+        let ui_state: kanbus::console_ui_state::ConsoleUiState =
+            serde_json::from_slice(&body).expect("ui state json");
         assert_eq!(ui_state.focused_issue_id.as_deref(), Some("kbs-88"));
         assert_eq!(ui_state.focused_comment_id.as_deref(), Some("comment-2"));
 
-        let realtime = get_realtime_events_root(State(state.clone()))
-            .await
-            .into_response();
+        let realtime =
+            get_realtime_events_root(State(state.clone()), Query(SessionQuery::default()))
+                .await
+                .into_response();
         assert_eq!(realtime.status(), StatusCode::OK);
         assert_eq!(
             realtime
@@ -2685,6 +2941,7 @@ mod tests {
                 Json(WikiUpdateRequest {
                     path: "page.md".to_string(),
                     content: "updated".to_string(),
+                    message: None,
                 }),
             )
             .await
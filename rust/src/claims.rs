@@ -0,0 +1,810 @@
+//! Claim leases for agent work sessions.
+//!
+//! `kbs claim` assigns an issue to the current actor, moves it to the
+//! workflow's in-progress status and writes a lease to
+//! `project/.cache/claims/<id>.json` that expires unless renewed by
+//! `kbs heartbeat`. Leases are working-tree state and are not committed. Expired leases are released
+//! by `kbs ready`, which restores the status and assignee the issue had
+//! before it was claimed, so crashed agents do not hold work forever.
+//!
+//! Every lease change holds an operating-system lock on
+//! `project/.cache/claims.lock`, which `kbs next` also holds while it picks
+//! an issue.
+
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions, TryLockError};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration as StdDuration, Instant};
+
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config_loader::load_project_configuration;
+use crate::error::KanbusError;
+use crate::event_history::{
+    build_update_events, claim_payload, events_dir_for_issue_path, now_timestamp, release_payload,
+    write_events_batch, EventRecord, EventType,
+};
+use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::ids::format_issue_key;
+use crate::issue_files::write_issue_to_file;
+use crate::issue_lookup::load_issue_from_project;
+use crate::issue_update::update_issue;
+use crate::project::discover_project_directories;
+use crate::workflows::{
+    apply_transition_side_effects, is_in_progress_status, resolve_start_status,
+};
+
+/// Lease length used when `--ttl` is not given.
+pub const DEFAULT_CLAIM_TTL_SECONDS: i64 = 30 * 60;
+
+/// Longest lease `--ttl` accepts.
+pub const MAX_CLAIM_TTL_SECONDS: i64 = 365 * 24 * 60 * 60;
+
+/// How long a lease change waits for another caller to release the claims lock.
+const LOCK_TIMEOUT: StdDuration = StdDuration::from_secs(10);
+
+const LOCK_POLL_INTERVAL: StdDuration = StdDuration::from_millis(50);
+
+/// A time-limited claim on an issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimLease {
+    pub issue_id: String,
+    pub holder: String,
+    pub claimed_at: DateTime<Utc>,
+    pub renewed_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub ttl_seconds: i64,
+    /// Status before the claim, restored on release.
+    pub previous_status: String,
+    /// Status the claim moved the issue to.
+    #[serde(default = "default_claimed_status")]
+    pub claimed_status: String,
+    /// Assignee before the claim, restored on release.
+    pub previous_assignee: Option<String>,
+}
+
+fn default_claimed_status() -> String {
+    "in_progress".to_string()
+}
+
+impl ClaimLease {
+    /// Whether the lease has run out at `now`.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// Leases held by one actor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimSession {
+    pub holder: String,
+    pub leases: Vec<ClaimLease>,
+}
+
+/// Exclusive lock serializing lease changes, released on drop.
+///
+/// Leases are created with `create_new`, but releasing an expired lease and
+/// writing its replacement are separate steps, so two callers could each
+/// remove the other's fresh lease without this lock. It is an operating-system
+/// file lock, so a caller that crashes never leaves it held.
+pub(crate) struct ClaimsLock {
+    _file: fs::File,
+}
+
+impl ClaimsLock {
+    /// Take the lock for the project at `root`, waiting up to `LOCK_TIMEOUT`.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::IssueOperation` if the lock stays held by another
+    /// caller, and `KanbusError::Io` if the lock file cannot be opened.
+    pub(crate) fn acquire(root: &Path) -> Result<Self, KanbusError> {
+        let path = load_project_directory(root)?
+            .join(".cache")
+            .join("claims.lock");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| KanbusError::Io(error.to_string()))?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .map_err(|error| KanbusError::Io(error.to_string()))?;
+        let started = Instant::now();
+        loop {
+            match file.try_lock() {
+                Ok(()) => return Ok(Self { _file: file }),
+                Err(TryLockError::WouldBlock) if started.elapsed() < LOCK_TIMEOUT => {
+                    thread::sleep(LOCK_POLL_INTERVAL);
+                }
+                Err(TryLockError::WouldBlock) => {
+                    return Err(KanbusError::IssueOperation(format!(
+                        "timed out waiting for the claims lock ({})",
+                        path.display()
+                    )))
+                }
+                Err(TryLockError::Error(error)) => return Err(KanbusError::Io(error.to_string())),
+            }
+        }
+    }
+}
+
+/// Parse a lease length such as `90s`, `30m`, `2h` or `1d`.
+///
+/// A bare number is read as minutes.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the value is not a positive duration.
+pub fn parse_ttl(value: &str) -> Result<i64, KanbusError> {
+    let trimmed = value.trim();
+    let invalid = || {
+        KanbusError::IssueOperation(format!(
+            "invalid ttl '{value}' (expected a duration such as 30m, 2h or 90s)"
+        ))
+    };
+    let (digits, multiplier) = match trimmed.char_indices().last() {
+        Some((index, 's')) => (&trimmed[..index], 1),
+        Some((index, 'm')) => (&trimmed[..index], 60),
+        Some((index, 'h')) => (&trimmed[..index], 60 * 60),
+        Some((index, 'd')) => (&trimmed[..index], 24 * 60 * 60),
+        _ => (trimmed, 60),
+    };
+    let amount: i64 = digits.parse().map_err(|_| invalid())?;
+    if amount <= 0 {
+        return Err(invalid());
+    }
+    amount
+        .checked_mul(multiplier)
+        .filter(|seconds| *seconds <= MAX_CLAIM_TTL_SECONDS)
+        .ok_or_else(invalid)
+}
+
+/// Expiry of a lease of `ttl_seconds` starting at `now`.
+fn lease_expiry(now: DateTime<Utc>, ttl_seconds: i64) -> Result<DateTime<Utc>, KanbusError> {
+    Duration::try_seconds(ttl_seconds)
+        .and_then(|ttl| now.checked_add_signed(ttl))
+        .ok_or_else(|| KanbusError::IssueOperation(format!("invalid ttl '{ttl_seconds}s'")))
+}
+
+/// Claim an issue for `holder` with a lease of `ttl_seconds`.
+///
+/// The issue moves to the workflow's in-progress status (see
+/// `resolve_start_status`) unless it is already in progress. Claiming an
+/// issue the holder already claimed renews the lease; claiming an issue under
+/// another actor's live lease fails. An expired lease held by someone else is
+/// released first.
+///
+/// # Errors
+/// Returns `KanbusError::ClaimConflict` if the issue is claimed by another
+/// actor, and `KanbusError` if the status transition is not allowed or files
+/// cannot be written.
+pub fn claim_issue(
+    root: &Path,
+    identifier: &str,
+    holder: &str,
+    ttl_seconds: i64,
+) -> Result<ClaimLease, KanbusError> {
+    let lock = ClaimsLock::acquire(root)?;
+    claim_issue_locked(&lock, root, identifier, holder, ttl_seconds)
+}
+
+/// `claim_issue` for a caller already holding the claims lock.
+pub(crate) fn claim_issue_locked(
+    _lock: &ClaimsLock,
+    root: &Path,
+    identifier: &str,
+    holder: &str,
+    ttl_seconds: i64,
+) -> Result<ClaimLease, KanbusError> {
+    let lookup = load_issue_from_project(root, identifier)?;
+    let issue_id = lookup.issue.identifier.clone();
+    let lease_path = lease_path(&lookup.project_dir, &issue_id);
+    let now = Utc::now();
+
+    if let Some(existing) = read_lease(&lease_path)? {
+        if existing.holder == holder {
+            return renew_lease(&lease_path, existing, Some(ttl_seconds), now);
+        }
+        if !existing.is_expired(now) {
+            return Err(KanbusError::ClaimConflict(format!(
+                "{} is claimed by {} until {}",
+                format_issue_key(&issue_id, false),
+                existing.holder,
+                format_time(existing.expires_at)
+            )));
+        }
+        release_lease(root, &lease_path, &existing, holder, "expired")?;
+    }
+
+    let lookup = load_issue_from_project(root, &issue_id)?;
+    let configuration = load_project_configuration(&get_configuration_path(&lookup.project_dir)?)?;
+    let claimed_status = if is_in_progress_status(&configuration, &lookup.issue.status) {
+        lookup.issue.status.clone()
+    } else {
+        resolve_start_status(
+            &configuration,
+            &lookup.issue.issue_type,
+            &lookup.issue.status,
+        )?
+    };
+    let lease = ClaimLease {
+        issue_id: issue_id.clone(),
+        holder: holder.to_string(),
+        claimed_at: now,
+        renewed_at: now,
+        expires_at: lease_expiry(now, ttl_seconds)?,
+        ttl_seconds,
+        previous_status: lookup.issue.status.clone(),
+        claimed_status: claimed_status.clone(),
+        previous_assignee: lookup.issue.assignee.clone(),
+    };
+    create_lease(&lease_path, &lease)?;
+
+    let needs_update =
+        lookup.issue.status != claimed_status || lookup.issue.assignee.as_deref() != Some(holder);
+    if needs_update {
+        if let Err(error) = update_issue(
+            root,
+            &issue_id,
+            None,
+            None,
+            Some(&claimed_status),
+            Some(holder),
+            None,
+            false,
+            true,
+            &[],
+            &[],
+            None,
+            None,
+            None,
+        ) {
+            let _ = fs::remove_file(&lease_path);
+            return Err(error);
+        }
+    }
+
+    let event = EventRecord::new(
+        issue_id,
+        EventType::IssueClaimed,
+        holder,
+        claim_payload(holder, &format_time(lease.expires_at), ttl_seconds),
+        now_timestamp(),
+    );
+    let events_dir = events_dir_for_issue_path(&lookup.project_dir, &lookup.issue_path)?;
+    write_events_batch(&events_dir, &[event])?;
+    Ok(lease)
+}
+
+/// Renew every lease held by `holder`, or only those on `identifiers`.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `holder` - Actor whose leases are renewed.
+/// * `identifiers` - Issues to renew; all of the holder's leases when empty.
+/// * `ttl_seconds` - New lease length; each lease keeps its own when absent.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the holder has no matching leases.
+pub fn heartbeat(
+    root: &Path,
+    holder: &str,
+    identifiers: &[String],
+    ttl_seconds: Option<i64>,
+) -> Result<Vec<ClaimLease>, KanbusError> {
+    let _lock = ClaimsLock::acquire(root)?;
+    let now = Utc::now();
+    let mut targets = Vec::new();
+    for identifier in identifiers {
+        let lookup = load_issue_from_project(root, identifier)?;
+        let path = lease_path(&lookup.project_dir, &lookup.issue.identifier);
+        match read_lease(&path)? {
+            Some(lease) if lease.holder == holder => targets.push((path, lease)),
+            _ => {
+                return Err(KanbusError::IssueOperation(format!(
+                    "{} is not claimed by {holder}",
+                    format_issue_key(&lookup.issue.identifier, false)
+                )))
+            }
+        }
+    }
+    if identifiers.is_empty() {
+        targets = load_leases(root)?
+            .into_iter()
+            .filter(|(_, lease)| lease.holder == holder)
+            .collect();
+    }
+    if targets.is_empty() {
+        return Err(KanbusError::IssueOperation(format!(
+            "no claims held by {holder}"
+        )));
+    }
+    targets
+        .into_iter()
+        .map(|(path, lease)| renew_lease(&path, lease, ttl_seconds, now))
+        .collect()
+}
+
+/// Release a claim held by `actor`.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the issue is not claimed, and
+/// `KanbusError::ClaimConflict` if someone else holds a live lease on it.
+pub fn release_claim(
+    root: &Path,
+    identifier: &str,
+    actor: &str,
+) -> Result<ClaimLease, KanbusError> {
+    let _lock = ClaimsLock::acquire(root)?;
+    let lookup = load_issue_from_project(root, identifier)?;
+    let issue_id = &lookup.issue.identifier;
+    let path = lease_path(&lookup.project_dir, issue_id);
+    let lease = read_lease(&path)?.ok_or_else(|| {
+        KanbusError::IssueOperation(format!(
+            "{} is not claimed",
+            format_issue_key(issue_id, false)
+        ))
+    })?;
+    if lease.holder != actor && !lease.is_expired(Utc::now()) {
+        return Err(KanbusError::ClaimConflict(format!(
+            "{} is claimed by {}",
+            format_issue_key(issue_id, false),
+            lease.holder
+        )));
+    }
+    release_lease(root, &path, &lease, actor, "released")?;
+    Ok(lease)
+}
+
+/// Release every expired lease, restoring the issues' previous status and assignee.
+///
+/// # Errors
+/// Returns `KanbusError` if a lease or issue cannot be updated.
+pub fn release_expired_claims(root: &Path, actor: &str) -> Result<Vec<ClaimLease>, KanbusError> {
+    let lock = ClaimsLock::acquire(root)?;
+    release_expired_claims_locked(&lock, root, actor)
+}
+
+/// `release_expired_claims` for a caller already holding the claims lock.
+pub(crate) fn release_expired_claims_locked(
+    _lock: &ClaimsLock,
+    root: &Path,
+    actor: &str,
+) -> Result<Vec<ClaimLease>, KanbusError> {
+    let now = Utc::now();
+    let mut released = Vec::new();
+    for (path, lease) in load_leases(root)? {
+        if lease.is_expired(now) && release_lease(root, &path, &lease, actor, "expired")? {
+            released.push(lease);
+        }
+    }
+    Ok(released)
+}
+
+/// Group current leases by holder, soonest expiry first within each holder.
+///
+/// # Errors
+/// Returns `KanbusError` if leases cannot be read.
+pub fn list_sessions(root: &Path) -> Result<Vec<ClaimSession>, KanbusError> {
+    let mut by_holder: BTreeMap<String, Vec<ClaimLease>> = BTreeMap::new();
+    for (_, lease) in load_leases(root)? {
+        by_holder
+            .entry(lease.holder.clone())
+            .or_default()
+            .push(lease);
+    }
+    Ok(by_holder
+        .into_iter()
+        .map(|(holder, mut leases)| {
+            leases.sort_by(|left, right| {
+                left.expires_at
+                    .cmp(&right.expires_at)
+                    .then_with(|| left.issue_id.cmp(&right.issue_id))
+            });
+            ClaimSession { holder, leases }
+        })
+        .collect())
+}
+
+/// Render sessions for `kbs sessions`.
+pub fn format_sessions(sessions: &[ClaimSession], now: DateTime<Utc>) -> String {
+    if sessions.is_empty() {
+        return "No active claims.".to_string();
+    }
+    let mut lines = Vec::new();
+    for session in sessions {
+        lines.push(format!(
+            "{} ({} claim{})",
+            session.holder,
+            session.leases.len(),
+            if session.leases.len() == 1 { "" } else { "s" }
+        ));
+        for lease in &session.leases {
+            let remaining = (lease.expires_at - now).num_seconds();
+            let state = if remaining <= 0 {
+                "expired".to_string()
+            } else {
+                format!("expires in {}", format_duration(remaining))
+            };
+            lines.push(format!(
+                "  {}  {}  (last heartbeat {})",
+                format_issue_key(&lease.issue_id, false),
+                state,
+                format_time(lease.renewed_at)
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+fn format_duration(seconds: i64) -> String {
+    if seconds >= 60 * 60 {
+        format!("{}h{:02}m", seconds / 3600, (seconds % 3600) / 60)
+    } else if seconds >= 60 {
+        format!("{}m", seconds / 60)
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+fn format_time(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn claims_dir(project_dir: &Path) -> PathBuf {
+    project_dir.join(".cache").join("claims")
+}
+
+fn lease_path(project_dir: &Path, issue_id: &str) -> PathBuf {
+    claims_dir(project_dir).join(format!("{issue_id}.json"))
+}
+
+fn read_lease(path: &Path) -> Result<Option<ClaimLease>, KanbusError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = fs::read_to_string(path).map_err(|error| KanbusError::Io(error.to_string()))?;
+    serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|error| KanbusError::Io(format!("invalid claim {}: {error}", path.display())))
+}
+
+fn write_lease(path: &Path, lease: &ClaimLease) -> Result<(), KanbusError> {
+    let contents =
+        serde_json::to_string_pretty(lease).map_err(|error| KanbusError::Io(error.to_string()))?;
+    fs::write(path, contents).map_err(|error| KanbusError::Io(error.to_string()))
+}
+
+/// Write a new lease, failing if another process created one first.
+fn create_lease(path: &Path, lease: &ClaimLease) -> Result<(), KanbusError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| KanbusError::Io(error.to_string()))?;
+        // Keep leases out of git even where `project/.cache` is not ignored.
+        let gitignore = parent.join(".gitignore");
+        if !gitignore.exists() {
+            fs::write(&gitignore, "*\n").map_err(|error| KanbusError::Io(error.to_string()))?;
+        }
+    }
+    let contents =
+        serde_json::to_string_pretty(lease).map_err(|error| KanbusError::Io(error.to_string()))?;
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|error| match error.kind() {
+            std::io::ErrorKind::AlreadyExists => KanbusError::ClaimConflict(format!(
+                "{} was claimed concurrently",
+                format_issue_key(&lease.issue_id, false)
+            )),
+            _ => KanbusError::Io(error.to_string()),
+        })?;
+    file.write_all(contents.as_bytes())
+        .map_err(|error| KanbusError::Io(error.to_string()))
+}
+
+fn renew_lease(
+    path: &Path,
+    mut lease: ClaimLease,
+    ttl_seconds: Option<i64>,
+    now: DateTime<Utc>,
+) -> Result<ClaimLease, KanbusError> {
+    if let Some(ttl_seconds) = ttl_seconds {
+        lease.ttl_seconds = ttl_seconds;
+    }
+    lease.renewed_at = now;
+    lease.expires_at = lease_expiry(now, lease.ttl_seconds)?;
+    write_lease(path, &lease)?;
+    Ok(lease)
+}
+
+fn load_leases(root: &Path) -> Result<Vec<(PathBuf, ClaimLease)>, KanbusError> {
+    let mut leases = Vec::new();
+    for project_dir in discover_project_directories(root)? {
+        let dir = claims_dir(&project_dir);
+        if !dir.is_dir() {
+            continue;
+        }
+        let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+            .map_err(|error| KanbusError::Io(error.to_string()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("json"))
+            .collect();
+        paths.sort();
+        for path in paths {
+            if let Some(lease) = read_lease(&path)? {
+                leases.push((path, lease));
+            }
+        }
+    }
+    Ok(leases)
+}
+
+/// Remove a lease and hand the issue back if it is still in the claimed state.
+///
+/// The lease file is removed first so that when several callers release the
+/// same lease only one restores the issue.
+///
+/// # Returns
+/// `false` if the lease was already gone.
+fn release_lease(
+    root: &Path,
+    path: &Path,
+    lease: &ClaimLease,
+    actor: &str,
+    reason: &str,
+) -> Result<bool, KanbusError> {
+    match fs::remove_file(path) {
+        Ok(()) => {}
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(error) => return Err(KanbusError::Io(error.to_string())),
+    }
+    let occurred_at = now_timestamp();
+    let mut events = Vec::new();
+    let mut location = None;
+    match load_issue_from_project(root, &lease.issue_id) {
+        Ok(lookup) => {
+            let before = lookup.issue.clone();
+            let mut after = before.clone();
+            let restore_status = before.status == lease.claimed_status
+                && lease.previous_status != lease.claimed_status;
+            let restore_assignee = before.assignee.as_deref() == Some(lease.holder.as_str())
+                && lease.previous_assignee != before.assignee;
+            if restore_status {
+                after = apply_transition_side_effects(&after, &lease.previous_status, Utc::now());
+                after.status = lease.previous_status.clone();
+            }
+            if restore_assignee {
+                after.assignee = lease.previous_assignee.clone();
+            }
+            if restore_status || restore_assignee {
+                after.updated_at = Utc::now();
+                write_issue_to_file(&after, &lookup.issue_path)?;
+                events = build_update_events(&before, &after, actor, &occurred_at);
+            }
+            events.push(EventRecord::new(
+                lease.issue_id.clone(),
+                EventType::IssueReleased,
+                actor,
+                release_payload(&lease.holder, reason),
+                occurred_at,
+            ));
+            location = Some(events_dir_for_issue_path(
+                &lookup.project_dir,
+                &lookup.issue_path,
+            )?);
+        }
+        Err(KanbusError::IssueOperation(message)) if message == "not found" => {}
+        Err(error) => return Err(error),
+    }
+    if let Some(events_dir) = location {
+        write_events_batch(&events_dir, &events)?;
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lease(holder: &str, issue_id: &str, expires_at: DateTime<Utc>) -> ClaimLease {
+        ClaimLease {
+            issue_id: issue_id.to_string(),
+            holder: holder.to_string(),
+            claimed_at: expires_at - Duration::minutes(30),
+            renewed_at: expires_at - Duration::minutes(30),
+            expires_at,
+            ttl_seconds: 1800,
+            previous_status: "open".to_string(),
+            claimed_status: "in_progress".to_string(),
+            previous_assignee: None,
+        }
+    }
+
+    #[test]
+    fn parse_ttl_accepts_units_and_rejects_nonsense() {
+        assert_eq!(parse_ttl("90s").unwrap(), 90);
+        assert_eq!(parse_ttl("30m").unwrap(), 1800);
+        assert_eq!(parse_ttl("2h").unwrap(), 7200);
+        assert_eq!(parse_ttl("1d").unwrap(), 86400);
+        assert_eq!(parse_ttl("15").unwrap(), 900);
+        assert_eq!(parse_ttl("365d").unwrap(), MAX_CLAIM_TTL_SECONDS);
+        for bad in [
+            "",
+            "m",
+            "0m",
+            "-5m",
+            "ten minutes",
+            "366d",
+            "100000000000000s",
+            "9223372036854775807d",
+        ] {
+            assert!(parse_ttl(bad).is_err(), "{bad} should be rejected");
+        }
+        assert!(lease_expiry(Utc::now(), i64::MAX).is_err());
+    }
+
+    #[test]
+    fn lease_files_are_exclusive_and_renewable() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = lease_path(temp.path(), "kanbus-aaa");
+        let now = Utc::now();
+        let original = lease("agent-1", "kanbus-aaa", now + Duration::minutes(1));
+        create_lease(&path, &original).expect("create");
+        assert!(matches!(
+            create_lease(&path, &original),
+            Err(KanbusError::ClaimConflict(message)) if message.contains("claimed concurrently")
+        ));
+
+        let renewed = renew_lease(&path, original.clone(), Some(600), now).expect("renew");
+        assert_eq!(renewed.expires_at, now + Duration::seconds(600));
+        assert_eq!(read_lease(&path).unwrap(), Some(renewed.clone()));
+        assert!(!renewed.is_expired(now));
+        assert!(renewed.is_expired(now + Duration::seconds(600)));
+    }
+
+    #[test]
+    fn claims_lock_is_exclusive_until_dropped_and_survives_leftover_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        crate::file_io::initialize_project(root, false).expect("initialize project");
+        let path = root.join("project").join(".cache").join("claims.lock");
+        fs::create_dir_all(path.parent().unwrap()).expect("cache dir");
+        fs::write(&path, "left behind by a crashed caller").expect("write lock file");
+
+        let lock = ClaimsLock::acquire(root).expect("acquire");
+        let other = fs::File::open(&path).expect("open lock file");
+        assert!(matches!(other.try_lock(), Err(TryLockError::WouldBlock)));
+        drop(lock);
+        other.try_lock().expect("lock after release");
+    }
+
+    #[test]
+    fn concurrent_claims_of_an_expired_lease_leave_one_holder() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path().to_path_buf();
+        crate::file_io::initialize_project(&root, false).expect("initialize project");
+        let issue_id = create_task(&root, "Contested");
+        claim_issue(&root, &issue_id, "agent-0", 60).expect("claim");
+        expire(&root, &issue_id);
+
+        let handles: Vec<_> = ["agent-1", "agent-2"]
+            .into_iter()
+            .map(|holder| {
+                let root = root.clone();
+                let issue_id = issue_id.clone();
+                thread::spawn(move || claim_issue(&root, &issue_id, holder, 60))
+            })
+            .collect();
+        let results: Vec<_> = handles
+            .into_iter()
+            .map(|handle| handle.join().expect("join"))
+            .collect();
+        let winners: Vec<&ClaimLease> = results.iter().filter_map(|r| r.as_ref().ok()).collect();
+        assert_eq!(winners.len(), 1);
+        assert!(results
+            .iter()
+            .any(|r| matches!(r, Err(KanbusError::ClaimConflict(_)))));
+        let lease = read_lease(&lease_path(&root.join("project"), &issue_id))
+            .unwrap()
+            .expect("lease");
+        assert_eq!(lease.holder, winners[0].holder);
+    }
+
+    fn create_task(root: &Path, title: &str) -> String {
+        let request = crate::issue_creation::IssueCreationRequest {
+            root: root.to_path_buf(),
+            title: title.to_string(),
+            issue_type: Some("task".to_string()),
+            priority: None,
+            assignee: None,
+            parent: None,
+            labels: Vec::new(),
+            description: None,
+            local: false,
+            validate: true,
+        };
+        crate::issue_creation::create_issue(&request)
+            .expect("create issue")
+            .issue
+            .identifier
+    }
+
+    fn expire(root: &Path, issue_id: &str) {
+        let path = lease_path(&root.join("project"), issue_id);
+        let mut lease = read_lease(&path).unwrap().expect("lease");
+        lease.expires_at = Utc::now() - Duration::seconds(1);
+        write_lease(&path, &lease).expect("write lease");
+    }
+
+    #[test]
+    fn expired_claims_restore_the_issue_once_and_leave_later_changes_alone() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        crate::file_io::initialize_project(root, false).expect("initialize project");
+        let claimed = create_task(root, "Claimed");
+        let moved_on = create_task(root, "Moved on");
+
+        let lease = claim_issue(root, &claimed, "agent-1", 60).expect("claim");
+        let path = lease_path(&root.join("project"), &claimed);
+        assert!(path.starts_with(root.join("project").join(".cache")));
+        assert!(path.with_file_name(".gitignore").is_file());
+        let issue = load_issue_from_project(root, &claimed).unwrap().issue;
+        assert_eq!(issue.status, lease.claimed_status);
+        assert_eq!(issue.assignee.as_deref(), Some("agent-1"));
+
+        claim_issue(root, &moved_on, "agent-1", 60).expect("claim");
+        update_issue(
+            root,
+            &moved_on,
+            None,
+            None,
+            Some("closed"),
+            None,
+            None,
+            false,
+            true,
+            &[],
+            &[],
+            None,
+            None,
+            None,
+        )
+        .expect("close");
+
+        expire(root, &claimed);
+        expire(root, &moved_on);
+        let released = release_expired_claims(root, "agent-2").expect("release");
+        assert_eq!(released.len(), 2);
+        assert!(!path.exists());
+
+        let issue = load_issue_from_project(root, &claimed).unwrap().issue;
+        assert_eq!(issue.status, "open");
+        assert_eq!(issue.assignee, None);
+        let issue = load_issue_from_project(root, &moved_on).unwrap().issue;
+        assert_eq!(issue.status, "closed");
+
+        assert!(!release_lease(root, &path, &lease, "agent-2", "expired").expect("release"));
+        assert!(release_expired_claims(root, "agent-2")
+            .expect("release again")
+            .is_empty());
+    }
+
+    #[test]
+    fn format_sessions_groups_by_holder() {
+        let now = Utc::now();
+        let sessions = vec![ClaimSession {
+            holder: "agent-1".to_string(),
+            leases: vec![
+                lease("agent-1", "kanbus-old", now - Duration::minutes(1)),
+                lease("agent-1", "kanbus-new", now + Duration::minutes(90)),
+            ],
+        }];
+        let rendered = format_sessions(&sessions, now);
+        assert!(rendered.starts_with("agent-1 (2 claims)\n  kanbus-old  expired"));
+        assert!(rendered.contains("kanbus-new  expires in 1h"));
+        assert_eq!(format_sessions(&[], now), "No active claims.");
+    }
+}
@@ -9,21 +9,34 @@ use std::path::Path;
 use chrono::Utc;
 use clap::error::ErrorKind;
 use clap::{Parser, Subcommand};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
-use crate::agents_management::ensure_agents_file;
+use crate::agents_management::{check_agents_files, ensure_agents_file};
+use crate::beads_export::export_to_beads;
 use crate::beads_write::{
     add_beads_comment, add_beads_dependency, create_beads_issue, delete_beads_comment,
     delete_beads_issue, remove_beads_dependency, update_beads_comment, update_beads_issue,
 };
+use crate::claims::{
+    claim_issue, format_sessions, heartbeat, list_sessions, parse_ttl, release_claim,
+    release_expired_claims, DEFAULT_CLAIM_TTL_SECONDS,
+};
 use crate::cloud_tokens::{create_cloud_token, list_cloud_tokens, revoke_cloud_token};
 use crate::config_loader::load_project_configuration;
+use crate::console_backend::FileStore;
 use crate::console_snapshot::build_console_snapshot;
 use crate::console_telemetry::stream_console_telemetry;
+use crate::console_ui_state::{validate_session_id, ConsoleSessionInfo, ConsoleUiState};
+use crate::console_wiki::{
+    page_diff, page_history, restore_page, wiki_relative_page, WikiRestoreRequest,
+};
 use crate::content_validation::validate_code_blocks;
 use crate::daemon_client::{request_shutdown, request_status};
 use crate::daemon_server::run_daemon;
-use crate::dependencies::{add_dependency, list_ready_issues, remove_dependency};
+use crate::dependencies::{
+    add_dependency, blocker_resolver_for, explain_readiness, list_ready_issues, remove_dependency,
+};
+use crate::dependency_analysis::{analyze_dependencies, render_dependency_analysis};
 use crate::dependency_tree::{build_dependency_tree, render_dependency_tree};
 use crate::doctor::run_doctor;
 use crate::error::KanbusError;
@@ -31,6 +44,10 @@ use crate::file_io::{
     canonicalize_path, detect_repairable_project_issues, ensure_git_repository,
     get_configuration_path, initialize_project, repair_project_structure, resolve_root,
 };
+use crate::github_issues_sync::{pull_github_issues, push_github_issues};
+use crate::github_scanning_sync::{
+    pull_code_scanning_from_github, pull_secret_scanning_from_github,
+};
 use crate::github_security_sync::{pull_dependabot_from_github, pull_dependabot_from_github_beads};
 use crate::hooks::{
     list_hooks, run_lifecycle_hooks, serialize_issue, validate_hooks, HookEvent,
@@ -41,27 +58,40 @@ use crate::issue_close::close_issue;
 use crate::issue_comment::{add_comment, delete_comment, ensure_issue_comment_ids, update_comment};
 use crate::issue_creation::{create_issue, IssueCreationRequest};
 use crate::issue_delete::delete_issue;
-use crate::issue_display::format_issue_for_display;
+use crate::issue_display::{format_issue_for_display, format_wiki_references};
+use crate::issue_export::{export_issues, ExportFormat};
+use crate::issue_import::{import_issues, parse_column_mapping, ImportFormat, IssueImportRequest};
 use crate::issue_line::{compute_widths, format_issue_line};
 use crate::issue_listing::list_issues;
 use crate::issue_lookup::load_issue_from_project;
+use crate::issue_numbers::{assign_issue_numbers, reconcile_issue_numbers};
 use crate::issue_transfer::{localize_issue, promote_issue};
 use crate::issue_update::update_issue;
 use crate::jira_sync::pull_from_jira;
 use crate::maintenance::{collect_project_stats, validate_project};
+use crate::mcp_server::run_mcp_server;
 use crate::migration::{
-    load_beads_issue_by_id, load_beads_issue_from_workspace, load_beads_issues, migrate_from_beads,
-    migrate_from_beads_into_project,
+    load_beads_issue_by_id, load_beads_issue_from_workspace, load_beads_issues,
+    run_beads_migration, MigrationMapping, MigrationOptions,
 };
-use crate::models::IssueData;
-use crate::queries::{filter_issues, search_issues};
+use crate::models::{IssueData, ProjectConfiguration};
+use crate::notification_events::{NotificationEvent, UiControlAction};
+use crate::queries::{filter_issues, search_issues, sort_issues_by};
 use crate::rich_text_signals::{
     apply_text_quality_signals, emit_signals, start_stderr_capture, take_captured_stderr,
 };
+use crate::sarif_import::import_sarif_report;
+use crate::saved_views::{group_issues, load_view, load_views, ColumnTable};
 use crate::snyk_sync::pull_from_snyk;
-use crate::text_editor::{edit_create, edit_insert, edit_str_replace, edit_view};
+use crate::text_editor::{
+    edit_apply, edit_create, edit_insert, edit_preview, edit_str_replace, edit_undo, edit_view,
+    EditOperation,
+};
 use crate::users::get_current_user;
 use crate::wiki::{list_wiki_pages, render_wiki_page, WikiRenderRequest};
+use crate::wiki_backlinks::load_backlink_index;
+use crate::wiki_export::export_wiki_site;
+use crate::work_queue::{next_issue, NextIssueFilter};
 
 /// Kanbus CLI arguments.
 #[derive(Debug, Parser)]
@@ -149,9 +179,12 @@ enum Commands {
         /// Bypass validation checks.
         #[arg(long = "no-validate")]
         no_validate: bool,
-        /// Deprecated: UI control commands are being migrated to pub/sub.
+        /// Focus the new issue in the console.
         #[arg(long)]
         focus: bool,
+        /// Console session to focus in (default: all sessions).
+        #[arg(long, requires = "focus")]
+        session: Option<String>,
     },
     /// Show an issue.
     Show {
@@ -202,6 +235,47 @@ enum Commands {
         #[arg(long = "no-validate")]
         no_validate: bool,
     },
+    /// Claim an issue with a lease that expires unless renewed.
+    Claim {
+        /// Issue identifier.
+        identifier: String,
+        /// Lease length, e.g. 30m, 2h or 90s (default: 30m).
+        #[arg(long)]
+        ttl: Option<String>,
+    },
+    /// Release a claimed issue, restoring its previous status and assignee.
+    Release {
+        /// Issue identifier.
+        identifier: String,
+    },
+    /// Renew the current actor's claim leases.
+    Heartbeat {
+        /// Issues to renew (default: every claim held by the current actor).
+        identifiers: Vec<String>,
+        /// New lease length (default: each claim's own length).
+        #[arg(long)]
+        ttl: Option<String>,
+    },
+    /// Show which actors hold which claims.
+    Sessions,
+    /// Claim the highest-priority ready issue and print it as JSON.
+    Next {
+        /// Only issues of this type.
+        #[arg(long = "type")]
+        issue_type: Option<String>,
+        /// Only issues with this label.
+        #[arg(long)]
+        label: Option<String>,
+        /// Only issues under this parent (e.g. an epic), at any depth.
+        #[arg(long)]
+        parent: Option<String>,
+        /// Only issues from this project label.
+        #[arg(long)]
+        project: Option<String>,
+        /// Claim lease length, e.g. 30m, 2h or 90s (default: 30m).
+        #[arg(long)]
+        ttl: Option<String>,
+    },
     /// Bulk issue operations.
     Bulk {
         #[command(subcommand)]
@@ -298,7 +372,12 @@ enum Commands {
         /// Show full issue keys even in single-project context.
         #[arg(long = "full-ids")]
         full_ids: bool,
+        /// Apply a saved view; explicit filters and --sort override it.
+        #[arg(long, value_name = "NAME")]
+        view: Option<String>,
     },
+    /// List saved views.
+    Views,
     /// Validate project integrity.
     Validate,
     /// Repair a broken project structure.
@@ -322,7 +401,7 @@ enum Commands {
     /// Manage issue dependencies.
     #[command(name = "dep", trailing_var_arg = true, allow_hyphen_values = true)]
     Dep {
-        /// Raw arguments: <id> <type> <target> | <id> remove <type> <target> | tree <id> [--depth N] [--format FORMAT]
+        /// Raw arguments: <id> <type> <target> | <id> remove <type> <target> | tree <id> [--depth N] [--format FORMAT] | analyze [--epic ID] [--format FORMAT]
         #[arg(num_args = 1..)]
         args: Vec<String>,
     },
@@ -334,6 +413,9 @@ enum Commands {
         /// Show only local issues.
         #[arg(long = "local-only")]
         local_only: bool,
+        /// Explain why an issue is or is not ready, listing its open blockers.
+        #[arg(long, value_name = "ID")]
+        explain: Option<String>,
     },
     /// Jira synchronization commands.
     Jira {
@@ -346,20 +428,62 @@ enum Commands {
         command: SnykCommands,
     },
     /// GitHub security synchronization commands.
-    #[command(name = "github", visible_alias = "gh")]
+    #[command(name = "github", visible_alias = "gh", alias = "github-security")]
     GithubSecurity {
         #[command(subcommand)]
         command: GithubSecurityCommands,
     },
+    /// Import issues from external report formats.
+    Import {
+        #[command(subcommand)]
+        command: ImportCommands,
+    },
+    /// Export issues as CSV, JSON Lines or JSON, or to another tracker.
+    #[command(args_conflicts_with_subcommands = true)]
+    Export {
+        #[command(subcommand)]
+        command: Option<ExportCommands>,
+        /// Output format: csv, jsonl or json.
+        #[arg(long)]
+        format: Option<String>,
+        /// Comma-separated columns (e.g. id,title,custom.points,comment_count).
+        #[arg(long, value_delimiter = ',')]
+        columns: Vec<String>,
+        /// Write to this file instead of stdout.
+        #[arg(long)]
+        output: Option<String>,
+    },
     /// Migrate Beads issues into Kanbus.
     Migrate {
         /// Import Beads issues into an already initialized project.
         /// Repeatable: safely re-runs and overwrites matching issue JSON files.
         #[arg(long = "into-existing")]
         into_existing: bool,
+        /// Show the migration plan without writing any files.
+        #[arg(long)]
+        dry_run: bool,
+        /// Skip Beads issues that were already migrated by an earlier run.
+        #[arg(long)]
+        resume: bool,
+        /// Map a Beads issue type to a Kanbus type (from=to, repeatable).
+        #[arg(long = "map-type", value_name = "FROM=TO")]
+        map_type: Vec<String>,
+        /// Map a Beads status to a Kanbus status (from=to, repeatable).
+        #[arg(long = "map-status", value_name = "FROM=TO")]
+        map_status: Vec<String>,
+        /// Write a JSON report of the run to this path.
+        #[arg(long)]
+        report: Option<String>,
+    },
+    /// Sequential issue number commands.
+    Ids {
+        #[command(subcommand)]
+        command: IdsCommands,
     },
     /// Run environment diagnostics.
     Doctor,
+    /// Serve the Model Context Protocol over stdio for agents.
+    Mcp,
     /// Run the daemon server.
     Daemon {
         /// Repository root path.
@@ -504,6 +628,9 @@ enum SetupCommands {
         /// Overwrite existing Kanbus section without prompting.
         #[arg(long)]
         force: bool,
+        /// Fail if AGENTS.md or CONTRIBUTING_AGENT.md differ from a fresh render (for CI).
+        #[arg(long, conflicts_with = "force")]
+        check: bool,
     },
 }
 
@@ -514,6 +641,9 @@ enum JiraCommands {
         /// Show what would be done without writing any files.
         #[arg(long)]
         dry_run: bool,
+        /// Ignore the last pull time and fetch every issue in the Jira project.
+        #[arg(long)]
+        full: bool,
     },
 }
 
@@ -557,6 +687,9 @@ enum EditCommands {
         /// Replacement text.
         #[arg(long = "new-str")]
         new_str: String,
+        /// Print a unified diff of the change without writing it.
+        #[arg(long)]
+        diff: bool,
     },
     /// Create a new file with the given content.
     Create {
@@ -565,6 +698,9 @@ enum EditCommands {
         /// Content to write.
         #[arg(long = "file-text")]
         file_text: String,
+        /// Print a unified diff of the change without writing it.
+        #[arg(long)]
+        diff: bool,
     },
     /// Insert text after the given line number (0 = beginning).
     Insert {
@@ -576,6 +712,31 @@ enum EditCommands {
         /// Text to insert.
         #[arg(long = "insert-text")]
         insert_text: String,
+        /// Print a unified diff of the change without writing it.
+        #[arg(long)]
+        diff: bool,
+    },
+    /// Apply a JSON list of edits atomically (all succeed or nothing is written).
+    Apply {
+        /// JSON array of edits, each with a `command` of str_replace, create or insert.
+        #[arg(
+            long,
+            conflicts_with = "edits_file",
+            required_unless_present = "edits_file"
+        )]
+        edits: Option<String>,
+        /// Read the JSON array of edits from a file.
+        #[arg(long = "edits-file")]
+        edits_file: Option<String>,
+        /// Print a unified diff of the batch without writing it.
+        #[arg(long)]
+        diff: bool,
+    },
+    /// Revert the most recent edit made to a file with these commands.
+    #[command(name = "undo-edit")]
+    UndoEdit {
+        /// File path.
+        path: String,
     },
 }
 
@@ -586,6 +747,93 @@ enum GithubSecurityCommands {
         #[command(subcommand)]
         command: DependabotCommands,
     },
+    /// Code scanning synchronization commands.
+    CodeScanning {
+        #[command(subcommand)]
+        command: CodeScanningCommands,
+    },
+    /// Secret scanning synchronization commands.
+    SecretScanning {
+        #[command(subcommand)]
+        command: SecretScanningCommands,
+    },
+    /// GitHub Issues synchronization commands.
+    Issues {
+        #[command(subcommand)]
+        command: GithubIssuesCommands,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum CodeScanningCommands {
+    /// Pull code scanning alerts from GitHub into Kanbus.
+    Pull {
+        /// Show what would be done without writing any files.
+        #[arg(long)]
+        dry_run: bool,
+        /// Override GitHub repository slug (owner/repo).
+        #[arg(long)]
+        repo: Option<String>,
+        /// Override minimum severity (critical, high, medium, low).
+        #[arg(long)]
+        min_severity: Option<String>,
+        /// Only import alerts reported by this analysis tool (e.g. CodeQL).
+        #[arg(long)]
+        tool: Option<String>,
+        /// Override parent epic issue ID to attach findings to.
+        #[arg(long)]
+        parent_epic: Option<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum SecretScanningCommands {
+    /// Pull secret scanning alerts from GitHub into Kanbus.
+    Pull {
+        /// Show what would be done without writing any files.
+        #[arg(long)]
+        dry_run: bool,
+        /// Override GitHub repository slug (owner/repo).
+        #[arg(long)]
+        repo: Option<String>,
+        /// Override minimum severity (critical, high, medium, low).
+        #[arg(long)]
+        min_severity: Option<String>,
+        /// Override parent epic issue ID to attach findings to.
+        #[arg(long)]
+        parent_epic: Option<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum GithubIssuesCommands {
+    /// Pull GitHub issues into Kanbus and link pull requests.
+    Pull {
+        /// Show what would be done without writing any files.
+        #[arg(long)]
+        dry_run: bool,
+        /// Override GitHub repository slug (owner/repo).
+        #[arg(long)]
+        repo: Option<String>,
+        /// Override GitHub issue state filter (open, closed, all).
+        #[arg(long)]
+        state: Option<String>,
+    },
+    /// Push local changes of linked issues back to GitHub.
+    Push {
+        /// Show what would be done without calling the GitHub API.
+        #[arg(long)]
+        dry_run: bool,
+        /// Override GitHub repository slug (owner/repo).
+        #[arg(long)]
+        repo: Option<String>,
+        /// Create GitHub issues for open Kanbus issues that are not linked yet.
+        #[arg(long)]
+        create: bool,
+        /// Restrict the push to these issue identifiers.
+        #[arg(long = "id")]
+        ids: Vec<String>,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -610,6 +858,70 @@ enum DependabotCommands {
     },
 }
 
+#[derive(Debug, Subcommand)]
+enum ImportCommands {
+    /// Import findings from a SARIF 2.1 report as bug issues.
+    Sarif {
+        /// Path to the SARIF report file.
+        path: String,
+        /// Show what would be done without writing any files.
+        #[arg(long)]
+        dry_run: bool,
+        /// Attach rule tasks to this epic instead of a per-tool epic.
+        #[arg(long)]
+        parent_epic: Option<String>,
+    },
+    /// Import issues from a CSV file with a header row.
+    Csv {
+        /// Path to the CSV file.
+        path: String,
+        /// Map a column to an issue field (column=field, repeatable).
+        #[arg(long = "map", value_name = "COLUMN=FIELD")]
+        map: Vec<String>,
+        /// Keep values of the id column instead of generating identifiers.
+        #[arg(long)]
+        preserve_ids: bool,
+        /// Validate and show what would be imported without writing files.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Import issues from a JSON Lines file (one issue object per line).
+    Jsonl {
+        /// Path to the JSON Lines file.
+        path: String,
+        /// Map a key to an issue field (key=field, repeatable).
+        #[arg(long = "map", value_name = "KEY=FIELD")]
+        map: Vec<String>,
+        /// Keep values of the id key instead of generating identifiers.
+        #[arg(long)]
+        preserve_ids: bool,
+        /// Validate and show what would be imported without writing files.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum ExportCommands {
+    /// Write the project's issues as a Beads issues.jsonl file.
+    Beads {
+        /// Destination file (defaults to .beads/issues.jsonl).
+        #[arg(long)]
+        output: Option<String>,
+        /// Overwrite an existing destination file.
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum IdsCommands {
+    /// Assign sequential numbers to issues that do not have one.
+    Assign,
+    /// Renumber issues that share a number after a merge.
+    Reconcile,
+}
+
 #[derive(Debug, Subcommand)]
 enum WikiCommands {
     /// Render a wiki page.
@@ -619,6 +931,37 @@ enum WikiCommands {
     },
     /// List wiki pages.
     List,
+    /// Export every wiki page and issue as a static HTML site.
+    Export {
+        /// Output directory for the site.
+        #[arg(long)]
+        out: std::path::PathBuf,
+    },
+    /// Show the recorded revisions of a wiki page.
+    History {
+        /// Wiki page path.
+        page: String,
+        /// Emit JSON output.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Diff a recorded revision against the current page.
+    Diff {
+        /// Wiki page path.
+        page: String,
+        /// Revision id or unique prefix.
+        revision: String,
+    },
+    /// Restore a wiki page to a recorded revision.
+    Restore {
+        /// Wiki page path.
+        page: String,
+        /// Revision id or unique prefix.
+        revision: String,
+        /// Message recorded with the restore.
+        #[arg(long)]
+        message: Option<String>,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -728,13 +1071,23 @@ enum ConsoleCommands {
         /// Optional comment ID to scroll to within the focused issue.
         #[arg(long)]
         comment: Option<String>,
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
     },
     /// Clear the current focus filter and return to the main board view.
-    Unfocus,
+    Unfocus {
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
+    },
     /// Switch between Initiatives/Epics/Issues view modes.
     View {
         /// View mode to switch to: initiatives, epics, or issues.
         mode: String,
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
     },
     /// Set or clear the search query.
     Search {
@@ -743,46 +1096,101 @@ enum ConsoleCommands {
         /// Clear the search query.
         #[arg(long)]
         clear: bool,
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
     },
     /// Maximize the detail panel.
-    Maximize,
+    Maximize {
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
+    },
     /// Restore the detail panel to normal size.
-    Restore,
+    Restore {
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
+    },
     /// Close the detail panel.
-    CloseDetail,
+    CloseDetail {
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
+    },
     /// Toggle the settings panel.
-    ToggleSettings,
+    ToggleSettings {
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
+    },
     /// Reload the console page.
-    Reload,
+    Reload {
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
+    },
     /// Update a specific setting value.
     SetSetting {
         /// Setting key.
         key: String,
         /// Setting value.
         value: String,
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
     },
     /// Collapse a board column.
     CollapseColumn {
         /// Column name to collapse.
         column: String,
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
     },
     /// Expand a board column.
     ExpandColumn {
         /// Column name to expand.
         column: String,
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
     },
     /// Select and navigate to an issue.
     Select {
         /// Issue identifier to select.
         identifier: String,
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
+    },
+    /// Apply a saved view, or clear the active one.
+    ApplyView {
+        /// Saved view name.
+        name: Option<String>,
+        /// Clear the active saved view.
+        #[arg(long)]
+        clear: bool,
+        /// Console session to target (default: all sessions).
+        #[arg(long)]
+        session: Option<String>,
     },
     /// Print a human-readable summary of the current console UI state.
-    Status,
+    Status {
+        /// Show the state of one console session instead of the broadcast state.
+        #[arg(long)]
+        session: Option<String>,
+    },
     /// Query a specific piece of console UI state.
     Get {
-        /// State field to query: focus, view, or search.
+        /// State field to query: focus, view, search, or saved.
         field: String,
+        /// Query one console session instead of the broadcast state.
+        #[arg(long)]
+        session: Option<String>,
     },
+    /// List browser sessions registered with the console server.
+    Sessions,
 }
 
 #[derive(Debug, Subcommand)]
@@ -1010,7 +1418,12 @@ where
     })
 }
 
-fn resolve_beads_mode(root: &Path, beads_flag: bool) -> Result<(bool, bool), KanbusError> {
+/// Decide whether commands use Beads data: forced by `--beads` or enabled in
+/// the project configuration.
+pub(crate) fn resolve_beads_mode(
+    root: &Path,
+    beads_flag: bool,
+) -> Result<(bool, bool), KanbusError> {
     if beads_flag {
         return Ok((true, true));
     }
@@ -1094,55 +1507,525 @@ fn delete_terminal_is_interactive() -> bool {
     std::io::stdin().is_terminal() && std::io::stdout().is_terminal()
 }
 
-fn deprecated_console_control_error(command: &str) -> KanbusError {
-    KanbusError::IssueOperation(format!(
-        "`kbs console {command}` is deprecated. UI control commands are being migrated to the pub/sub convention and are temporarily unavailable."
-    ))
+/// Fields accepted by `kbs create` and the MCP `create_issue` tool.
+pub(crate) struct CreateIssueOptions {
+    pub title: String,
+    pub issue_type: Option<String>,
+    pub priority: Option<u8>,
+    pub assignee: Option<String>,
+    pub parent: Option<String>,
+    pub labels: Vec<String>,
+    pub description: Option<String>,
+    pub local: bool,
+    pub validate: bool,
 }
 
-fn deprecated_create_focus_error() -> KanbusError {
-    KanbusError::IssueOperation(
-        "`kbs create --focus` is deprecated. UI control commands are being migrated to the pub/sub convention and are temporarily unavailable.".to_string(),
-    )
+/// Create an issue the way `kbs create` does.
+///
+/// Repairs and validates the description, runs the lifecycle hooks and
+/// writes to Beads when beads mode is active.
+///
+/// # Returns
+/// The created issue and, outside beads mode, the project configuration.
+///
+/// # Errors
+/// Returns `KanbusError` if validation, a hook or the write fails.
+pub(crate) fn create_issue_with_hooks(
+    root: &Path,
+    options: CreateIssueOptions,
+    beads_mode: bool,
+    hook_options: HookExecutionOptions,
+) -> Result<(IssueData, Option<ProjectConfiguration>), KanbusError> {
+    let CreateIssueOptions {
+        title,
+        issue_type,
+        priority,
+        assignee,
+        parent,
+        labels,
+        description,
+        local,
+        validate,
+    } = options;
+    if title.trim().is_empty() {
+        return Err(KanbusError::IssueOperation("title is required".to_string()));
+    }
+    let raw_description_text = description.unwrap_or_default();
+    let (quality_result, description_text) = if raw_description_text.is_empty() {
+        (None, raw_description_text)
+    } else {
+        let qr = apply_text_quality_signals(&raw_description_text);
+        let repaired = qr.text.clone();
+        (Some(qr), repaired)
+    };
+    if validate && !description_text.is_empty() {
+        validate_code_blocks(&description_text)?;
+    }
+    if beads_mode && local {
+        return Err(KanbusError::IssueOperation(
+            "beads mode does not support local issues".to_string(),
+        ));
+    }
+    run_lifecycle_hooks_for_context(
+        root,
+        HookPhase::Before,
+        HookEvent::IssueCreate,
+        serde_json::json!({
+            "title": title.clone(),
+            "issue_type": issue_type.clone(),
+            "priority": priority,
+            "assignee": assignee.clone(),
+            "parent": parent.clone(),
+            "labels": labels.clone(),
+            "description": if description_text.is_empty() { serde_json::Value::Null } else { serde_json::Value::String(description_text.clone()) },
+            "local": local,
+        }),
+        &[],
+        hook_options,
+    )?;
+    let (issue, configuration) = if beads_mode {
+        let issue = create_beads_issue(
+            &beads_root(root),
+            &title,
+            issue_type.as_deref(),
+            priority,
+            assignee.as_deref(),
+            parent.as_deref(),
+            if description_text.is_empty() {
+                None
+            } else {
+                Some(description_text.as_str())
+            },
+        )?;
+        (issue, None)
+    } else {
+        let request = IssueCreationRequest {
+            root: root.to_path_buf(),
+            title,
+            issue_type,
+            priority,
+            assignee,
+            parent,
+            labels,
+            description: if description_text.is_empty() {
+                None
+            } else {
+                Some(description_text)
+            },
+            local,
+            validate,
+        };
+        let result = create_issue(&request)?;
+        (result.issue, Some(result.configuration))
+    };
+    if let Some(ref qr) = quality_result {
+        emit_signals(qr, "description", Some(&issue.identifier), None, false);
+    }
+    run_lifecycle_hooks_for_context(
+        root,
+        HookPhase::After,
+        HookEvent::IssueCreate,
+        serde_json::json!({
+            "issue": serialize_issue(&issue),
+            "local": local,
+        }),
+        std::slice::from_ref(&issue),
+        hook_options,
+    )?;
+    Ok((issue, configuration))
 }
 
-fn run_lifecycle_hooks_for_context(
-    root: &Path,
-    phase: HookPhase,
-    event: HookEvent,
-    operation: serde_json::Value,
-    issues_for_policy: &[IssueData],
-    options: HookExecutionOptions,
-) -> Result<(), KanbusError> {
-    run_lifecycle_hooks(root, phase, event, operation, issues_for_policy, options)
+/// Fields accepted by `kbs update` and the MCP `update_issue` tool.
+pub(crate) struct UpdateIssueOptions {
+    pub identifier: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub priority: Option<u8>,
+    pub assignee: Option<String>,
+    pub add_labels: Vec<String>,
+    pub remove_labels: Vec<String>,
+    pub set_labels: Option<String>,
+    pub parent: Option<String>,
+    pub issue_type: Option<String>,
+    pub claim: bool,
+    pub validate: bool,
 }
 
-fn execute_command(
-    command: Commands,
+/// Update an issue the way `kbs update` does.
+///
+/// Repairs and validates the description, runs the lifecycle hooks and
+/// writes to Beads when beads mode is active.
+///
+/// # Returns
+/// The updated issue, if it could be reloaded.
+///
+/// # Errors
+/// Returns `KanbusError` if validation, a hook or the write fails.
+pub(crate) fn update_issue_with_hooks(
     root: &Path,
+    options: UpdateIssueOptions,
     beads_mode: bool,
-    _beads_forced: bool,
-    no_guidance: bool,
-    no_hooks: bool,
-) -> Result<Option<String>, KanbusError> {
+    hook_options: HookExecutionOptions,
+) -> Result<Option<IssueData>, KanbusError> {
+    let UpdateIssueOptions {
+        identifier,
+        title,
+        description,
+        status,
+        priority,
+        assignee,
+        add_labels,
+        remove_labels,
+        set_labels,
+        parent,
+        issue_type,
+        claim,
+        validate,
+    } = options;
     let root_for_beads = beads_root(root);
-    let hook_options = HookExecutionOptions {
-        beads_mode,
-        no_hooks,
-        no_guidance,
+    let assignee_value = if claim {
+        Some(get_current_user())
+    } else {
+        assignee.clone()
     };
-    match command {
-        Commands::Init { local } => {
-            ensure_git_repository(root)?;
-            initialize_project(root, local)?;
-            Ok(None)
+    let title_value = title.as_deref().filter(|text| !text.is_empty());
+    let description_value = description.as_deref().filter(|text| !text.is_empty());
+    let (update_quality_result, repaired_description) = if let Some(desc) = description_value {
+        let qr = apply_text_quality_signals(desc);
+        let repaired = qr.text.clone();
+        (Some(qr), Some(repaired))
+    } else {
+        (None, None)
+    };
+    let final_description_value = repaired_description.as_deref();
+    if validate {
+        if let Some(text) = final_description_value {
+            validate_code_blocks(text)?;
         }
-        Commands::Repair { yes } => {
-            let plan = detect_repairable_project_issues(root, false)?;
-            let Some(plan) = plan else {
-                return Ok(Some("Project structure is already healthy.".to_string()));
+    }
+    let before_issue_for_hooks = if beads_mode {
+        load_beads_issue_by_id(&root_for_beads, &identifier).ok()
+    } else {
+        load_issue_from_project(root, &identifier)
+            .ok()
+            .map(|lookup| lookup.issue)
+    };
+    run_lifecycle_hooks_for_context(
+        root,
+        HookPhase::Before,
+        HookEvent::IssueUpdate,
+        serde_json::json!({
+            "identifier": identifier.clone(),
+            "title": title_value,
+            "description": final_description_value,
+            "status": status.clone(),
+            "priority": priority,
+            "assignee": assignee_value.clone(),
+            "add_labels": add_labels.clone(),
+            "remove_labels": remove_labels.clone(),
+            "set_labels": set_labels.clone(),
+            "parent": parent.clone(),
+            "issue_type": issue_type.clone(),
+            "claim": claim,
+            "before_issue": before_issue_for_hooks.as_ref().map(serialize_issue),
+        }),
+        &[],
+        hook_options,
+    )?;
+    let after_issue_for_hooks: Option<IssueData>;
+    if beads_mode {
+        if parent.is_some() {
+            return Err(KanbusError::IssueOperation(
+                "parent update not supported in beads mode".to_string(),
+            ));
+        }
+        if issue_type.is_some() {
+            return Err(KanbusError::IssueOperation(
+                "type update not supported in beads mode".to_string(),
+            ));
+        }
+        let before_issue = load_beads_issue_by_id(&root_for_beads, &identifier)?;
+        let mut proposed_issue = before_issue.clone();
+        if let Some(new_status) = status.as_deref() {
+            proposed_issue.status = new_status.to_string();
+        }
+        if let Some(new_priority) = priority {
+            proposed_issue.priority = i32::from(new_priority);
+        }
+        if let Some(new_title) = title_value {
+            proposed_issue.title = new_title.to_string();
+        }
+        if let Some(new_description) = final_description_value {
+            proposed_issue.description = new_description.to_string();
+        }
+        if let Some(new_assignee) = assignee_value.as_deref() {
+            proposed_issue.assignee = Some(new_assignee.to_string());
+        }
+        if set_labels.is_some() || !add_labels.is_empty() || !remove_labels.is_empty() {
+            let mut labels = if let Some(value) = set_labels.as_deref() {
+                value
+                    .split(',')
+                    .map(|label| label.trim().to_string())
+                    .filter(|label| !label.is_empty())
+                    .collect::<Vec<_>>()
+            } else {
+                proposed_issue.labels.clone()
             };
-
+            for label in &add_labels {
+                let trimmed = label.trim();
+                if !trimmed.is_empty()
+                    && !labels
+                        .iter()
+                        .any(|existing| existing.eq_ignore_ascii_case(trimmed))
+                {
+                    labels.push(trimmed.to_string());
+                }
+            }
+            if !remove_labels.is_empty() {
+                labels.retain(|label| {
+                    !remove_labels
+                        .iter()
+                        .any(|to_remove| label.eq_ignore_ascii_case(to_remove.trim()))
+                });
+            }
+            proposed_issue.labels = labels;
+        }
+        if validate {
+            let mut project_context = None;
+            match get_configuration_path(&root_for_beads) {
+                Ok(config_path) => {
+                    let configuration = load_project_configuration(&config_path)?;
+                    let project_dir = crate::project::load_project_directory(&root_for_beads)?;
+                    project_context = Some((project_dir, configuration));
+                }
+                Err(KanbusError::IssueOperation(message))
+                    if message == "project not initialized" => {}
+                Err(error) => return Err(error),
+            }
+
+            if let Some((_, configuration)) = project_context.as_ref() {
+                if proposed_issue.status != before_issue.status {
+                    crate::workflows::validate_status_value(
+                        configuration,
+                        &proposed_issue.issue_type,
+                        &proposed_issue.status,
+                    )?;
+                    crate::workflows::validate_status_transition(
+                        configuration,
+                        &proposed_issue.issue_type,
+                        &before_issue.status,
+                        &proposed_issue.status,
+                    )?;
+                }
+            }
+
+            if let Some((project_dir, configuration)) = project_context.as_ref() {
+                let policies_dir = project_dir.join("policies");
+                if policies_dir.is_dir() {
+                    let policy_documents = crate::policy_loader::load_policies(&policies_dir)?;
+                    if !policy_documents.is_empty() {
+                        let mut all_issues = load_beads_issues(&root_for_beads)?;
+                        if let Some(existing_issue) = all_issues
+                            .iter_mut()
+                            .find(|issue| issue.identifier == proposed_issue.identifier)
+                        {
+                            *existing_issue = proposed_issue.clone();
+                        }
+                        let transition = if proposed_issue.status != before_issue.status {
+                            Some(crate::policy_context::StatusTransition {
+                                from: before_issue.status.clone(),
+                                to: proposed_issue.status.clone(),
+                            })
+                        } else {
+                            None
+                        };
+                        let policy_context = crate::policy_context::PolicyContext {
+                            current_issue: Some(before_issue.clone()),
+                            proposed_issue: proposed_issue.clone(),
+                            transition,
+                            operation: crate::policy_context::PolicyOperation::Update,
+                            project_configuration: configuration.clone(),
+                            all_issues,
+                        };
+                        crate::policy_evaluator::evaluate_policies(
+                            &policy_context,
+                            &policy_documents,
+                        )?;
+                    }
+                }
+            }
+        }
+        update_beads_issue(
+            &root_for_beads,
+            &identifier,
+            status.as_deref(),
+            priority,
+            title_value,
+            final_description_value,
+            assignee_value.as_deref(),
+            &add_labels,
+            &remove_labels,
+            set_labels.as_deref(),
+        )?;
+        after_issue_for_hooks = load_beads_issue_by_id(&root_for_beads, &identifier).ok();
+    } else {
+        let updated_issue = update_issue(
+            root,
+            &identifier,
+            title_value,
+            final_description_value,
+            status.as_deref(),
+            assignee_value.as_deref(),
+            priority,
+            claim,
+            validate,
+            &add_labels,
+            &remove_labels,
+            set_labels.as_deref(),
+            parent.as_deref(),
+            issue_type.as_deref(),
+        )?;
+        after_issue_for_hooks = Some(updated_issue);
+    }
+    if let Some(ref qr) = update_quality_result {
+        emit_signals(qr, "description", Some(&identifier), None, true);
+    }
+    let issues_for_policy = after_issue_for_hooks
+        .as_ref()
+        .map(|issue| vec![issue.clone()])
+        .unwrap_or_default();
+    run_lifecycle_hooks_for_context(
+        root,
+        HookPhase::After,
+        HookEvent::IssueUpdate,
+        serde_json::json!({
+            "identifier": identifier,
+            "status": status,
+            "priority": priority,
+            "assignee": assignee_value,
+            "add_labels": add_labels,
+            "remove_labels": remove_labels,
+            "set_labels": set_labels,
+            "parent": parent,
+            "issue_type": issue_type,
+            "claim": claim,
+            "before_issue": before_issue_for_hooks.as_ref().map(serialize_issue),
+            "after_issue": after_issue_for_hooks.as_ref().map(serialize_issue),
+        }),
+        &issues_for_policy,
+        hook_options,
+    )?;
+    Ok(after_issue_for_hooks)
+}
+
+/// Close an issue the way `kbs close` does, running the lifecycle hooks and
+/// writing to Beads when beads mode is active.
+///
+/// # Returns
+/// The closed issue, if it could be reloaded.
+///
+/// # Errors
+/// Returns `KanbusError` if a hook or the write fails.
+pub(crate) fn close_issue_with_hooks(
+    root: &Path,
+    identifier: &str,
+    beads_mode: bool,
+    hook_options: HookExecutionOptions,
+) -> Result<Option<IssueData>, KanbusError> {
+    let root_for_beads = beads_root(root);
+    let before_issue_for_hooks = if beads_mode {
+        load_beads_issue_by_id(&root_for_beads, identifier).ok()
+    } else {
+        load_issue_from_project(root, identifier)
+            .ok()
+            .map(|lookup| lookup.issue)
+    };
+    run_lifecycle_hooks_for_context(
+        root,
+        HookPhase::Before,
+        HookEvent::IssueClose,
+        serde_json::json!({
+            "identifier": identifier,
+            "before_issue": before_issue_for_hooks.as_ref().map(serialize_issue),
+        }),
+        &[],
+        hook_options,
+    )?;
+    let after_issue_for_hooks: Option<IssueData> = if beads_mode {
+        update_beads_issue(
+            &root_for_beads,
+            identifier,
+            Some("closed"),
+            None,
+            None,
+            None,
+            None,
+            &[],
+            &[],
+            None,
+        )?;
+        load_beads_issue_by_id(&root_for_beads, identifier).ok()
+    } else {
+        let closed_issue = close_issue(root, identifier)?;
+        Some(closed_issue)
+    };
+    let issues_for_policy = after_issue_for_hooks
+        .as_ref()
+        .map(|issue| vec![issue.clone()])
+        .unwrap_or_default();
+    run_lifecycle_hooks_for_context(
+        root,
+        HookPhase::After,
+        HookEvent::IssueClose,
+        serde_json::json!({
+            "identifier": identifier,
+            "before_issue": before_issue_for_hooks.as_ref().map(serialize_issue),
+            "after_issue": after_issue_for_hooks.as_ref().map(serialize_issue),
+        }),
+        &issues_for_policy,
+        hook_options,
+    )?;
+    Ok(after_issue_for_hooks)
+}
+
+fn run_lifecycle_hooks_for_context(
+    root: &Path,
+    phase: HookPhase,
+    event: HookEvent,
+    operation: serde_json::Value,
+    issues_for_policy: &[IssueData],
+    options: HookExecutionOptions,
+) -> Result<(), KanbusError> {
+    run_lifecycle_hooks(root, phase, event, operation, issues_for_policy, options)
+}
+
+fn execute_command(
+    command: Commands,
+    root: &Path,
+    beads_mode: bool,
+    _beads_forced: bool,
+    no_guidance: bool,
+    no_hooks: bool,
+) -> Result<Option<String>, KanbusError> {
+    let root_for_beads = beads_root(root);
+    let hook_options = HookExecutionOptions {
+        beads_mode,
+        no_hooks,
+        no_guidance,
+    };
+    match command {
+        Commands::Init { local } => {
+            ensure_git_repository(root)?;
+            initialize_project(root, local)?;
+            Ok(None)
+        }
+        Commands::Repair { yes } => {
+            let plan = detect_repairable_project_issues(root, false)?;
+            let Some(plan) = plan else {
+                return Ok(Some("Project structure is already healthy.".to_string()));
+            };
+
             if !yes && std::io::stdin().is_terminal() && std::io::stdout().is_terminal() {
                 let mut missing = Vec::new();
                 if plan.missing_project_dir {
@@ -1177,7 +2060,17 @@ fn execute_command(
             Ok(Some("Project structure repaired.".to_string()))
         }
         Commands::Setup { command } => match command {
-            SetupCommands::Agents { force } => {
+            SetupCommands::Agents { check: true, .. } => {
+                let stale = check_agents_files(root)?;
+                if stale.is_empty() {
+                    return Ok(Some("Agent instructions are up to date.".to_string()));
+                }
+                Err(KanbusError::IssueOperation(format!(
+                    "agent instructions are stale: {}. Run `kbs setup agents --force` to regenerate them.",
+                    stale.join(", ")
+                )))
+            }
+            SetupCommands::Agents { force, .. } => {
                 ensure_agents_file(root, force)?;
                 Ok(None)
             }
@@ -1193,121 +2086,47 @@ fn execute_command(
             local,
             no_validate,
             focus,
+            session,
         } => {
-            let title_text = title.join(" ");
-            if title_text.trim().is_empty() {
-                return Err(KanbusError::IssueOperation("title is required".to_string()));
-            }
-            let raw_description_text = description
-                .as_ref()
-                .map(|values| values.join(" "))
-                .unwrap_or_default();
-            let (quality_result, description_text) = if raw_description_text.is_empty() {
-                (None, raw_description_text)
-            } else {
-                let qr = apply_text_quality_signals(&raw_description_text);
-                let repaired = qr.text.clone();
-                (Some(qr), repaired)
-            };
-            if !no_validate && !description_text.is_empty() {
-                validate_code_blocks(&description_text)?;
+            if let Some(session) = &session {
+                validate_session_id(session)?;
             }
-            if focus {
-                return Err(deprecated_create_focus_error());
-            }
-            if beads_mode && local {
-                return Err(KanbusError::IssueOperation(
-                    "beads mode does not support local issues".to_string(),
-                ));
-            }
-            run_lifecycle_hooks_for_context(
+            let (issue, configuration) = create_issue_with_hooks(
                 root,
-                HookPhase::Before,
-                HookEvent::IssueCreate,
-                serde_json::json!({
-                    "title": title_text.clone(),
-                    "issue_type": issue_type.clone(),
-                    "priority": priority,
-                    "assignee": assignee.clone(),
-                    "parent": parent.clone(),
-                    "labels": label.clone(),
-                    "description": if description_text.is_empty() { serde_json::Value::Null } else { serde_json::Value::String(description_text.clone()) },
-                    "local": local,
-                }),
-                &[],
+                CreateIssueOptions {
+                    title: title.join(" "),
+                    issue_type,
+                    priority,
+                    assignee,
+                    parent,
+                    labels: label,
+                    description: description.map(|values| values.join(" ")),
+                    local,
+                    validate: !no_validate,
+                },
+                beads_mode,
                 hook_options,
             )?;
-            if beads_mode {
-                let issue = create_beads_issue(
-                    &root_for_beads,
-                    &title_text,
-                    issue_type.as_deref(),
-                    priority,
-                    assignee.as_deref(),
-                    parent.as_deref(),
-                    if description_text.is_empty() {
-                        None
-                    } else {
-                        Some(description_text.as_str())
-                    },
-                )?;
-
-                let use_color = should_use_color();
-                let output = format_issue_for_display(&issue, None, use_color, false, None);
-                if let Some(ref qr) = quality_result {
-                    emit_signals(qr, "description", Some(&issue.identifier), None, false);
+            if focus {
+                // The issue exists either way; a console that cannot be reached only loses focus.
+                let event = NotificationEvent::IssueFocused {
+                    issue_id: issue.identifier.clone(),
+                    user: None,
+                    comment_id: None,
+                    session,
+                };
+                if let Err(error) = send_console_notification(root, event) {
+                    eprintln!("Created the issue but could not focus it: {error}");
                 }
-                run_lifecycle_hooks_for_context(
-                    root,
-                    HookPhase::After,
-                    HookEvent::IssueCreate,
-                    serde_json::json!({
-                        "issue": serialize_issue(&issue),
-                        "local": local,
-                    }),
-                    std::slice::from_ref(&issue),
-                    hook_options,
-                )?;
-                return Ok(Some(output));
             }
-            let request = IssueCreationRequest {
-                root: root.to_path_buf(),
-                title: title_text,
-                issue_type,
-                priority,
-                assignee,
-                parent,
-                labels: label,
-                description: if description_text.is_empty() {
-                    None
-                } else {
-                    Some(description_text)
-                },
-                local,
-                validate: !no_validate,
-            };
-            let result = create_issue(&request)?;
-            let configuration = result.configuration;
-            let issue = result.issue;
-
             let use_color = should_use_color();
-            let output =
-                format_issue_for_display(&issue, Some(&configuration), use_color, false, None);
-            if let Some(ref qr) = quality_result {
-                emit_signals(qr, "description", Some(&issue.identifier), None, false);
-            }
-            run_lifecycle_hooks_for_context(
-                root,
-                HookPhase::After,
-                HookEvent::IssueCreate,
-                serde_json::json!({
-                    "issue": serialize_issue(&issue),
-                    "local": local,
-                }),
-                std::slice::from_ref(&issue),
-                hook_options,
-            )?;
-            Ok(Some(output))
+            Ok(Some(format_issue_for_display(
+                &issue,
+                configuration.as_ref(),
+                use_color,
+                false,
+                None,
+            )))
         }
         Commands::Show {
             identifier,
@@ -1390,13 +2209,23 @@ fn execute_command(
                         None
                     }
                 };
-                format_issue_for_display(
+                let mut output = format_issue_for_display(
                     &issue,
                     configuration.as_ref(),
                     use_color,
                     false,
                     all_issues.as_deref(),
-                )
+                );
+                if !beads_mode {
+                    if let Ok(index) = load_backlink_index(lookup_root) {
+                        let pages = index.pages_referencing_issue(&issue.identifier);
+                        if !pages.is_empty() {
+                            output.push('\n');
+                            output.push_str(&format_wiki_references(pages, use_color));
+                        }
+                    }
+                }
+                output
             };
             run_lifecycle_hooks_for_context(
                 root,
@@ -1426,253 +2255,112 @@ fn execute_command(
             claim,
             no_validate,
         } => {
-            let title_text = title
-                .as_ref()
-                .map(|values| values.join(" "))
-                .unwrap_or_default();
-            let description_text = description
-                .as_ref()
-                .map(|values| values.join(" "))
-                .unwrap_or_default();
-            let assignee_value = if claim {
-                Some(get_current_user())
-            } else {
-                assignee.clone()
-            };
-            let title_value = if title_text.is_empty() {
-                None
-            } else {
-                Some(title_text.as_str())
-            };
-            let description_value = if description_text.is_empty() {
-                None
-            } else {
-                Some(description_text.as_str())
-            };
-            let (update_quality_result, repaired_description) =
-                if let Some(desc) = description_value {
-                    let qr = apply_text_quality_signals(desc);
-                    let repaired = qr.text.clone();
-                    (Some(qr), Some(repaired))
-                } else {
-                    (None, None)
-                };
-            let final_description_value = repaired_description.as_deref();
-            if !no_validate {
-                if let Some(text) = final_description_value {
-                    validate_code_blocks(text)?;
-                }
-            }
-            let before_issue_for_hooks = if beads_mode {
-                load_beads_issue_by_id(&root_for_beads, &identifier).ok()
-            } else {
-                load_issue_from_project(root, &identifier)
-                    .ok()
-                    .map(|lookup| lookup.issue)
-            };
-            run_lifecycle_hooks_for_context(
+            let formatted_identifier = format_issue_key(&identifier, false);
+            update_issue_with_hooks(
                 root,
-                HookPhase::Before,
-                HookEvent::IssueUpdate,
-                serde_json::json!({
-                    "identifier": identifier.clone(),
-                    "title": title_value,
-                    "description": final_description_value,
-                    "status": status.clone(),
-                    "priority": priority,
-                    "assignee": assignee_value.clone(),
-                    "add_labels": add_labels.clone(),
-                    "remove_labels": remove_labels.clone(),
-                    "set_labels": set_labels.clone(),
-                    "parent": parent.clone(),
-                    "claim": claim,
-                    "before_issue": before_issue_for_hooks.as_ref().map(serialize_issue),
-                }),
-                &[],
-                hook_options,
-            )?;
-            let after_issue_for_hooks: Option<IssueData>;
-            if beads_mode {
-                if parent.is_some() {
-                    return Err(KanbusError::IssueOperation(
-                        "parent update not supported in beads mode".to_string(),
-                    ));
-                }
-                let before_issue = load_beads_issue_by_id(&root_for_beads, &identifier)?;
-                let mut proposed_issue = before_issue.clone();
-                if let Some(new_status) = status.as_deref() {
-                    proposed_issue.status = new_status.to_string();
-                }
-                if let Some(new_priority) = priority {
-                    proposed_issue.priority = i32::from(new_priority);
-                }
-                if let Some(new_title) = title_value {
-                    proposed_issue.title = new_title.to_string();
-                }
-                if let Some(new_description) = final_description_value {
-                    proposed_issue.description = new_description.to_string();
-                }
-                if let Some(new_assignee) = assignee_value.as_deref() {
-                    proposed_issue.assignee = Some(new_assignee.to_string());
-                }
-                if set_labels.is_some() || !add_labels.is_empty() || !remove_labels.is_empty() {
-                    let mut labels = if let Some(value) = set_labels.as_deref() {
-                        value
-                            .split(',')
-                            .map(|label| label.trim().to_string())
-                            .filter(|label| !label.is_empty())
-                            .collect::<Vec<_>>()
-                    } else {
-                        proposed_issue.labels.clone()
-                    };
-                    for label in &add_labels {
-                        let trimmed = label.trim();
-                        if !trimmed.is_empty()
-                            && !labels
-                                .iter()
-                                .any(|existing| existing.eq_ignore_ascii_case(trimmed))
-                        {
-                            labels.push(trimmed.to_string());
-                        }
-                    }
-                    if !remove_labels.is_empty() {
-                        labels.retain(|label| {
-                            !remove_labels
-                                .iter()
-                                .any(|to_remove| label.eq_ignore_ascii_case(to_remove.trim()))
-                        });
-                    }
-                    proposed_issue.labels = labels;
-                }
-                if !no_validate {
-                    let mut project_context = None;
-                    match get_configuration_path(&root_for_beads) {
-                        Ok(config_path) => {
-                            let configuration = load_project_configuration(&config_path)?;
-                            let project_dir =
-                                crate::project::load_project_directory(&root_for_beads)?;
-                            project_context = Some((project_dir, configuration));
-                        }
-                        Err(KanbusError::IssueOperation(message))
-                            if message == "project not initialized" => {}
-                        Err(error) => return Err(error),
-                    }
-
-                    if let Some((_, configuration)) = project_context.as_ref() {
-                        if proposed_issue.status != before_issue.status {
-                            crate::workflows::validate_status_value(
-                                configuration,
-                                &proposed_issue.issue_type,
-                                &proposed_issue.status,
-                            )?;
-                            crate::workflows::validate_status_transition(
-                                configuration,
-                                &proposed_issue.issue_type,
-                                &before_issue.status,
-                                &proposed_issue.status,
-                            )?;
-                        }
-                    }
-
-                    if let Some((project_dir, configuration)) = project_context.as_ref() {
-                        let policies_dir = project_dir.join("policies");
-                        if policies_dir.is_dir() {
-                            let policy_documents =
-                                crate::policy_loader::load_policies(&policies_dir)?;
-                            if !policy_documents.is_empty() {
-                                let mut all_issues = load_beads_issues(&root_for_beads)?;
-                                if let Some(existing_issue) = all_issues
-                                    .iter_mut()
-                                    .find(|issue| issue.identifier == proposed_issue.identifier)
-                                {
-                                    *existing_issue = proposed_issue.clone();
-                                }
-                                let transition = if proposed_issue.status != before_issue.status {
-                                    Some(crate::policy_context::StatusTransition {
-                                        from: before_issue.status.clone(),
-                                        to: proposed_issue.status.clone(),
-                                    })
-                                } else {
-                                    None
-                                };
-                                let policy_context = crate::policy_context::PolicyContext {
-                                    current_issue: Some(before_issue.clone()),
-                                    proposed_issue: proposed_issue.clone(),
-                                    transition,
-                                    operation: crate::policy_context::PolicyOperation::Update,
-                                    project_configuration: configuration.clone(),
-                                    all_issues,
-                                };
-                                crate::policy_evaluator::evaluate_policies(
-                                    &policy_context,
-                                    &policy_documents,
-                                )?;
-                            }
-                        }
-                    }
-                }
-                update_beads_issue(
-                    &root_for_beads,
-                    &identifier,
-                    status.as_deref(),
-                    priority,
-                    title_value,
-                    final_description_value,
-                    assignee_value.as_deref(),
-                    &add_labels,
-                    &remove_labels,
-                    set_labels.as_deref(),
-                )?;
-                after_issue_for_hooks = load_beads_issue_by_id(&root_for_beads, &identifier).ok();
-            } else {
-                let updated_issue = update_issue(
-                    root,
-                    &identifier,
-                    title_value,
-                    final_description_value,
-                    status.as_deref(),
-                    assignee_value.as_deref(),
+                UpdateIssueOptions {
+                    identifier,
+                    title: title.map(|values| values.join(" ")),
+                    description: description.map(|values| values.join(" ")),
+                    status,
                     priority,
+                    assignee,
+                    add_labels,
+                    remove_labels,
+                    set_labels,
+                    parent,
+                    issue_type: None,
                     claim,
-                    !no_validate,
-                    &add_labels,
-                    &remove_labels,
-                    set_labels.as_deref(),
-                    parent.as_deref(),
-                    None,
-                )?;
-                after_issue_for_hooks = Some(updated_issue);
-            }
-            let formatted_identifier = format_issue_key(&identifier, false);
-            if let Some(ref qr) = update_quality_result {
-                emit_signals(qr, "description", Some(&identifier), None, true);
-            }
-            let issues_for_policy = after_issue_for_hooks
-                .as_ref()
-                .map(|issue| vec![issue.clone()])
-                .unwrap_or_default();
-            run_lifecycle_hooks_for_context(
-                root,
-                HookPhase::After,
-                HookEvent::IssueUpdate,
-                serde_json::json!({
-                    "identifier": identifier,
-                    "status": status,
-                    "priority": priority,
-                    "assignee": assignee_value,
-                    "add_labels": add_labels,
-                    "remove_labels": remove_labels,
-                    "set_labels": set_labels,
-                    "parent": parent,
-                    "claim": claim,
-                    "before_issue": before_issue_for_hooks.as_ref().map(serialize_issue),
-                    "after_issue": after_issue_for_hooks.as_ref().map(serialize_issue),
-                }),
-                &issues_for_policy,
+                    validate: !no_validate,
+                },
+                beads_mode,
                 hook_options,
             )?;
             Ok(Some(format!("Updated {}", formatted_identifier)))
         }
+        Commands::Claim { identifier, ttl } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "claim is not supported in beads mode".to_string(),
+                ));
+            }
+            let holder = get_current_user();
+            let ttl_seconds = match ttl.as_deref() {
+                Some(value) => parse_ttl(value)?,
+                None => DEFAULT_CLAIM_TTL_SECONDS,
+            };
+            let lease = claim_issue(root, &identifier, &holder, ttl_seconds)?;
+            Ok(Some(format!(
+                "Claimed {} for {} until {}",
+                format_issue_key(&lease.issue_id, false),
+                lease.holder,
+                lease
+                    .expires_at
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            )))
+        }
+        Commands::Release { identifier } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "release is not supported in beads mode".to_string(),
+                ));
+            }
+            let lease = release_claim(root, &identifier, &get_current_user())?;
+            Ok(Some(format!(
+                "Released {} (claimed by {})",
+                format_issue_key(&lease.issue_id, false),
+                lease.holder
+            )))
+        }
+        Commands::Heartbeat { identifiers, ttl } => {
+            let ttl_seconds = ttl.as_deref().map(parse_ttl).transpose()?;
+            let leases = heartbeat(root, &get_current_user(), &identifiers, ttl_seconds)?;
+            let lines = leases
+                .iter()
+                .map(|lease| {
+                    format!(
+                        "Renewed {} until {}",
+                        format_issue_key(&lease.issue_id, false),
+                        lease
+                            .expires_at
+                            .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+                    )
+                })
+                .collect::<Vec<_>>();
+            Ok(Some(lines.join("\n")))
+        }
+        Commands::Sessions => Ok(Some(format_sessions(
+            &list_sessions(root)?,
+            chrono::Utc::now(),
+        ))),
+        Commands::Next {
+            issue_type,
+            label,
+            parent,
+            project,
+            ttl,
+        } => {
+            if beads_mode {
+                return Err(KanbusError::IssueOperation(
+                    "next is not supported in beads mode".to_string(),
+                ));
+            }
+            let ttl_seconds = match ttl.as_deref() {
+                Some(value) => parse_ttl(value)?,
+                None => DEFAULT_CLAIM_TTL_SECONDS,
+            };
+            let filter = NextIssueFilter {
+                issue_type,
+                label,
+                parent,
+                project,
+            };
+            let next = next_issue(root, &filter, &get_current_user(), ttl_seconds)?
+                .ok_or_else(|| KanbusError::IssueOperation("no ready issues".to_string()))?;
+            serde_json::to_string_pretty(&next.issue)
+                .map(Some)
+                .map_err(|error| KanbusError::Io(error.to_string()))
+        }
         Commands::Move {
             identifier,
             issue_type,
@@ -1858,60 +2546,11 @@ fn execute_command(
             }
         },
         Commands::Close { identifier } => {
-            let before_issue_for_hooks = if beads_mode {
-                load_beads_issue_by_id(&root_for_beads, &identifier).ok()
-            } else {
-                load_issue_from_project(root, &identifier)
-                    .ok()
-                    .map(|lookup| lookup.issue)
-            };
-            run_lifecycle_hooks_for_context(
-                root,
-                HookPhase::Before,
-                HookEvent::IssueClose,
-                serde_json::json!({
-                    "identifier": identifier.clone(),
-                    "before_issue": before_issue_for_hooks.as_ref().map(serialize_issue),
-                }),
-                &[],
-                hook_options,
-            )?;
-            let after_issue_for_hooks: Option<IssueData> = if beads_mode {
-                update_beads_issue(
-                    &root_for_beads,
-                    &identifier,
-                    Some("closed"),
-                    None,
-                    None,
-                    None,
-                    None,
-                    &[],
-                    &[],
-                    None,
-                )?;
-                load_beads_issue_by_id(&root_for_beads, &identifier).ok()
-            } else {
-                let closed_issue = close_issue(root, &identifier)?;
-                Some(closed_issue)
-            };
-            let formatted_identifier = format_issue_key(&identifier, false);
-            let issues_for_policy = after_issue_for_hooks
-                .as_ref()
-                .map(|issue| vec![issue.clone()])
-                .unwrap_or_default();
-            run_lifecycle_hooks_for_context(
-                root,
-                HookPhase::After,
-                HookEvent::IssueClose,
-                serde_json::json!({
-                    "identifier": identifier,
-                    "before_issue": before_issue_for_hooks.as_ref().map(serialize_issue),
-                    "after_issue": after_issue_for_hooks.as_ref().map(serialize_issue),
-                }),
-                &issues_for_policy,
-                hook_options,
-            )?;
-            Ok(Some(format!("Closed {}", formatted_identifier)))
+            close_issue_with_hooks(root, &identifier, beads_mode, hook_options)?;
+            Ok(Some(format!(
+                "Closed {}",
+                format_issue_key(&identifier, false)
+            )))
         }
         Commands::Delete {
             identifier,
@@ -2318,12 +2957,37 @@ fn execute_command(
             local_only,
             porcelain,
             full_ids,
+            view,
         } => {
+            let saved_view = match view.as_deref() {
+                Some(name) => Some(load_view(root, name)?),
+                None => None,
+            };
+            let defaults = saved_view
+                .as_ref()
+                .map(|saved| saved.filters.clone())
+                .unwrap_or_default();
+            let status = status.or(defaults.status);
+            let issue_type = issue_type.or(defaults.issue_type);
+            let assignee = assignee.or(defaults.assignee);
+            let label = label.or(defaults.label);
+            let parent = parent.or(defaults.parent);
+            let search = search.or(defaults.search);
+            let project = if project.is_empty() {
+                defaults.project
+            } else {
+                project
+            };
+            let view_sort = match sort {
+                Some(_) => None,
+                None => saved_view.as_ref().and_then(|saved| saved.sort.clone()),
+            };
             run_lifecycle_hooks_for_context(
                 root,
                 HookPhase::Before,
                 HookEvent::IssueList,
                 serde_json::json!({
+                    "view": view.clone(),
                     "status": status.clone(),
                     "issue_type": issue_type.clone(),
                     "assignee": assignee.clone(),
@@ -2339,7 +3003,7 @@ fn execute_command(
                 &[],
                 hook_options,
             )?;
-            let issues = if beads_mode {
+            let mut issues = if beads_mode {
                 if local_only || no_local {
                     return Err(KanbusError::IssueOperation(
                         "beads mode does not support local filtering".to_string(),
@@ -2382,6 +3046,9 @@ fn execute_command(
                     local_only,
                 )?
             };
+            if let Some(view_sort) = view_sort.as_deref() {
+                sort_issues_by(&mut issues, view_sort)?;
+            }
             let configuration = if beads_mode {
                 None
             } else {
@@ -2407,24 +3074,56 @@ fn execute_command(
             } else {
                 Some(compute_widths(&issues, project_context))
             };
-            let lines = issues
-                .iter()
-                .map(|issue| {
-                    format_issue_line(
+            let columns = saved_view
+                .as_ref()
+                .map(|saved| saved.columns.clone())
+                .unwrap_or_default();
+            let table = if columns.is_empty() {
+                None
+            } else {
+                Some(ColumnTable::new(&columns, &issues)?)
+            };
+            let render = |issue: &IssueData| -> Result<String, KanbusError> {
+                match &table {
+                    Some(table) => table.row(issue, porcelain),
+                    None => Ok(format_issue_line(
                         issue,
                         widths.as_ref(),
                         porcelain,
                         project_context,
                         configuration.as_ref(),
                         None,
-                    )
-                })
-                .collect::<Vec<_>>();
+                    )),
+                }
+            };
+            let mut lines = Vec::new();
+            if let Some(table) = table.as_ref().filter(|_| !porcelain) {
+                lines.push(table.header());
+            }
+            match saved_view
+                .as_ref()
+                .and_then(|saved| saved.group_by.as_deref())
+            {
+                Some(group_by) => {
+                    for (group, members) in group_issues(&issues, group_by)? {
+                        lines.push(format!("{group} ({})", members.len()));
+                        for issue in members {
+                            lines.push(render(issue)?);
+                        }
+                    }
+                }
+                None => {
+                    for issue in &issues {
+                        lines.push(render(issue)?);
+                    }
+                }
+            }
             run_lifecycle_hooks_for_context(
                 root,
                 HookPhase::After,
                 HookEvent::IssueList,
                 serde_json::json!({
+                    "view": view,
                     "status": status,
                     "issue_type": issue_type,
                     "assignee": assignee,
@@ -2441,6 +3140,25 @@ fn execute_command(
             )?;
             Ok(Some(lines.join("\n")))
         }
+        Commands::Views => {
+            let views = load_views(root)?;
+            if views.is_empty() {
+                return Ok(Some("No saved views".to_string()));
+            }
+            let width = views.keys().map(String::len).max().unwrap_or(0);
+            let lines = views
+                .iter()
+                .map(|(name, saved)| {
+                    format!(
+                        "{name:<width$}  {}",
+                        saved.description.as_deref().unwrap_or("")
+                    )
+                    .trim_end()
+                    .to_string()
+                })
+                .collect::<Vec<_>>();
+            Ok(Some(lines.join("\n")))
+        }
         Commands::Validate => {
             validate_project(root)?;
             Ok(None)
@@ -2500,6 +3218,31 @@ fn execute_command(
                 return Ok(Some(output));
             }
 
+            // Graph analysis: kanbus dep analyze [--epic ID] [--format FORMAT]
+            if args[0] == "analyze" {
+                let mut epic: Option<String> = None;
+                let mut format = "text".to_string();
+                let mut index = 1;
+                while index < args.len() {
+                    match args[index].as_str() {
+                        "--epic" if index + 1 < args.len() => {
+                            epic = Some(args[index + 1].clone());
+                            index += 2;
+                        }
+                        "--format" if index + 1 < args.len() => {
+                            format = args[index + 1].clone();
+                            index += 2;
+                        }
+                        _ => {
+                            index += 1;
+                        }
+                    }
+                }
+                let analysis = analyze_dependencies(root, epic.as_deref())?;
+                let output = render_dependency_analysis(&analysis, &format)?;
+                return Ok(Some(output));
+            }
+
             if args.len() < 2 {
                 return Err(KanbusError::IssueOperation(
                     "usage: kanbus dep <identifier> <type> <target>".to_string(),
@@ -2590,7 +3333,20 @@ fn execute_command(
         Commands::Ready {
             no_local,
             local_only,
+            explain,
         } => {
+            if let Some(identifier) = explain {
+                if beads_mode {
+                    let issues = load_beads_issues(&root_for_beads)?;
+                    let resolver = blocker_resolver_for(root, &issues);
+                    let issue = resolver
+                        .find(&identifier)
+                        .ok_or_else(|| KanbusError::IssueOperation("not found".to_string()))?;
+                    return Ok(Some(resolver.explain(issue)));
+                }
+                release_expired_claims_with_notice(root)?;
+                return Ok(Some(explain_readiness(root, &identifier)?));
+            }
             run_lifecycle_hooks_for_context(
                 root,
                 HookPhase::Before,
@@ -2608,11 +3364,14 @@ fn execute_command(
                         "beads mode does not support local filtering".to_string(),
                     ));
                 }
-                load_beads_issues(&root_for_beads)?
+                let beads_issues = load_beads_issues(&root_for_beads)?;
+                let resolver = blocker_resolver_for(root, &beads_issues);
+                beads_issues
                     .into_iter()
-                    .filter(|issue| issue.status != "closed" && !is_issue_blocked(issue))
+                    .filter(|issue| resolver.is_ready(issue))
                     .collect()
             } else {
+                release_expired_claims_with_notice(root)?;
                 list_ready_issues(root, !no_local, local_only)?
             };
             let mut lines = Vec::new();
@@ -2634,7 +3393,7 @@ fn execute_command(
             Ok(Some(lines.join("\n")))
         }
         Commands::Jira { command } => match command {
-            JiraCommands::Pull { dry_run } => {
+            JiraCommands::Pull { dry_run, full } => {
                 let config_path = get_configuration_path(root)?;
                 let jira_configuration = load_project_configuration(&config_path)?;
                 let jira_config = jira_configuration.jira.as_ref().ok_or_else(|| {
@@ -2648,12 +3407,21 @@ fn execute_command(
                 if dry_run {
                     println!("Dry run — no files will be written.\n");
                 }
-                let result =
-                    pull_from_jira(root, jira_config, &jira_configuration.project_key, dry_run)?;
-                Ok(Some(format!(
-                    "pulled {} new, updated {} existing",
-                    result.pulled, result.updated
-                )))
+                let result = pull_from_jira(
+                    root,
+                    jira_config,
+                    &jira_configuration.project_key,
+                    dry_run,
+                    full,
+                )?;
+                let mut summary = format!(
+                    "pulled {} new, updated {} existing, closed {} removed",
+                    result.pulled, result.updated, result.removed
+                );
+                if result.restored > 0 {
+                    summary.push_str(&format!(", reopened {} restored", result.restored));
+                }
+                Ok(Some(summary))
             }
         },
         Commands::Snyk { command } => match command {
@@ -2685,8 +3453,8 @@ fn execute_command(
                 let result =
                     pull_from_snyk(root, &snyk_config, &configuration.project_key, dry_run)?;
                 Ok(Some(format!(
-                    "pulled {} new, updated {} existing, skipped {} duplicates",
-                    result.pulled, result.updated, result.skipped
+                    "pulled {} new, updated {} existing, skipped {} duplicates, closed {} resolved, reopened {} regressed",
+                    result.pulled, result.updated, result.skipped, result.closed, result.reopened
                 )))
             }
         },
@@ -2696,73 +3464,371 @@ fn execute_command(
                     dry_run,
                     repo,
                     min_severity,
-                    state,
+                    state,
+                    parent_epic,
+                } => {
+                    let config_path = get_configuration_path(root)?;
+                    let configuration = load_project_configuration(&config_path)?;
+                    let mut github_security_config =
+                        configuration.github_security.clone().unwrap_or_default();
+                    let mut dependabot_config = github_security_config
+                        .dependabot
+                        .clone()
+                        .unwrap_or_default();
+                    if let Some(value) = min_severity {
+                        dependabot_config.min_severity = value;
+                    }
+                    if let Some(value) = state {
+                        dependabot_config.state = value;
+                    }
+                    if parent_epic.is_some() {
+                        dependabot_config.parent_epic = parent_epic;
+                    }
+                    if repo.is_some() {
+                        github_security_config.repo = repo;
+                    }
+                    github_security_config.dependabot = Some(dependabot_config);
+
+                    if dry_run {
+                        println!("Dry run — no files will be written.\n");
+                    }
+
+                    let result = if beads_mode {
+                        pull_dependabot_from_github_beads(
+                            &root_for_beads,
+                            &github_security_config,
+                            dry_run,
+                        )?
+                    } else {
+                        pull_dependabot_from_github(
+                            root,
+                            &github_security_config,
+                            &configuration.project_key,
+                            dry_run,
+                        )?
+                    };
+                    Ok(Some(format!(
+                        "pulled {} new, updated {} existing, skipped {} duplicates",
+                        result.pulled, result.updated, result.skipped
+                    )))
+                }
+            },
+            GithubSecurityCommands::CodeScanning { command } => match command {
+                CodeScanningCommands::Pull {
+                    dry_run,
+                    repo,
+                    min_severity,
+                    tool,
+                    parent_epic,
+                } => {
+                    if beads_mode {
+                        return Err(KanbusError::Configuration(
+                            "code scanning sync is not supported in beads mode".to_string(),
+                        ));
+                    }
+                    let config_path = get_configuration_path(root)?;
+                    let configuration = load_project_configuration(&config_path)?;
+                    let mut github_security_config =
+                        configuration.github_security.clone().unwrap_or_default();
+                    let mut code_scanning_config = github_security_config
+                        .code_scanning
+                        .clone()
+                        .unwrap_or_default();
+                    if let Some(value) = min_severity {
+                        code_scanning_config.min_severity = value;
+                    }
+                    if tool.is_some() {
+                        code_scanning_config.tool_name = tool;
+                    }
+                    if parent_epic.is_some() {
+                        code_scanning_config.parent_epic = parent_epic;
+                    }
+                    if repo.is_some() {
+                        github_security_config.repo = repo;
+                    }
+                    github_security_config.code_scanning = Some(code_scanning_config);
+
+                    if dry_run {
+                        println!("Dry run — no files will be written.\n");
+                    }
+                    let result = pull_code_scanning_from_github(
+                        root,
+                        &github_security_config,
+                        &configuration.project_key,
+                        dry_run,
+                    )?;
+                    Ok(Some(format!(
+                        "pulled {} new, updated {} existing, closed {} resolved",
+                        result.pulled, result.updated, result.closed
+                    )))
+                }
+            },
+            GithubSecurityCommands::SecretScanning { command } => match command {
+                SecretScanningCommands::Pull {
+                    dry_run,
+                    repo,
+                    min_severity,
                     parent_epic,
                 } => {
+                    if beads_mode {
+                        return Err(KanbusError::Configuration(
+                            "secret scanning sync is not supported in beads mode".to_string(),
+                        ));
+                    }
                     let config_path = get_configuration_path(root)?;
                     let configuration = load_project_configuration(&config_path)?;
-                    let mut github_security_config = configuration
-                        .github_security
-                        .clone()
-                        .unwrap_or(crate::models::GithubSecurityConfiguration {
-                            repo: None,
-                            dependabot: None,
-                        });
-                    let mut dependabot_config = github_security_config
-                        .dependabot
+                    let mut github_security_config =
+                        configuration.github_security.clone().unwrap_or_default();
+                    let mut secret_scanning_config = github_security_config
+                        .secret_scanning
                         .clone()
                         .unwrap_or_default();
                     if let Some(value) = min_severity {
-                        dependabot_config.min_severity = value;
-                    }
-                    if let Some(value) = state {
-                        dependabot_config.state = value;
+                        secret_scanning_config.min_severity = value;
                     }
                     if parent_epic.is_some() {
-                        dependabot_config.parent_epic = parent_epic;
+                        secret_scanning_config.parent_epic = parent_epic;
                     }
                     if repo.is_some() {
                         github_security_config.repo = repo;
                     }
-                    github_security_config.dependabot = Some(dependabot_config);
+                    github_security_config.secret_scanning = Some(secret_scanning_config);
 
                     if dry_run {
                         println!("Dry run — no files will be written.\n");
                     }
-
-                    let result = if beads_mode {
-                        pull_dependabot_from_github_beads(
-                            &root_for_beads,
-                            &github_security_config,
-                            dry_run,
-                        )?
-                    } else {
-                        pull_dependabot_from_github(
-                            root,
-                            &github_security_config,
-                            &configuration.project_key,
-                            dry_run,
-                        )?
-                    };
+                    let result = pull_secret_scanning_from_github(
+                        root,
+                        &github_security_config,
+                        &configuration.project_key,
+                        dry_run,
+                    )?;
                     Ok(Some(format!(
-                        "pulled {} new, updated {} existing, skipped {} duplicates",
-                        result.pulled, result.updated, result.skipped
+                        "pulled {} new, updated {} existing, closed {} resolved",
+                        result.pulled, result.updated, result.closed
                     )))
                 }
             },
+            GithubSecurityCommands::Issues { command } => {
+                if beads_mode {
+                    return Err(KanbusError::Configuration(
+                        "github issues sync is not supported in beads mode".to_string(),
+                    ));
+                }
+                let config_path = get_configuration_path(root)?;
+                let configuration = load_project_configuration(&config_path)?;
+                let mut issues_config = configuration.github_issues.clone().unwrap_or_default();
+                match command {
+                    GithubIssuesCommands::Pull {
+                        dry_run,
+                        repo,
+                        state,
+                    } => {
+                        if !["pull", "both"].contains(&issues_config.sync_direction.as_str()) {
+                            return Err(KanbusError::Configuration(
+                                "sync_direction must be 'pull' or 'both' to use github issues pull"
+                                    .to_string(),
+                            ));
+                        }
+                        if repo.is_some() {
+                            issues_config.repo = repo;
+                        }
+                        if let Some(value) = state {
+                            issues_config.state = value;
+                        }
+                        if dry_run {
+                            println!("Dry run — no files will be written.\n");
+                        }
+                        let result = pull_github_issues(
+                            root,
+                            &issues_config,
+                            &configuration.project_key,
+                            dry_run,
+                        )?;
+                        Ok(Some(format!(
+                            "pulled {} new, updated {} existing, linked {} pull requests",
+                            result.pulled, result.updated, result.linked
+                        )))
+                    }
+                    GithubIssuesCommands::Push {
+                        dry_run,
+                        repo,
+                        create,
+                        ids,
+                    } => {
+                        if !["push", "both"].contains(&issues_config.sync_direction.as_str()) {
+                            return Err(KanbusError::Configuration(
+                                "sync_direction must be 'push' or 'both' to use github issues push"
+                                    .to_string(),
+                            ));
+                        }
+                        if repo.is_some() {
+                            issues_config.repo = repo;
+                        }
+                        if dry_run {
+                            println!("Dry run — no files will be written.\n");
+                        }
+                        let result =
+                            push_github_issues(root, &issues_config, &ids, create, dry_run)?;
+                        Ok(Some(format!(
+                            "created {} new, updated {} existing, skipped {} unchanged",
+                            result.created, result.updated, result.skipped
+                        )))
+                    }
+                }
+            }
+        },
+        Commands::Import { command } => match command {
+            ImportCommands::Sarif {
+                path,
+                dry_run,
+                parent_epic,
+            } => {
+                if beads_mode {
+                    return Err(KanbusError::Configuration(
+                        "sarif import is not supported in beads mode".to_string(),
+                    ));
+                }
+                let config_path = get_configuration_path(root)?;
+                let configuration = load_project_configuration(&config_path)?;
+                if dry_run {
+                    println!("Dry run — no files will be written.\n");
+                }
+                let result = import_sarif_report(
+                    root,
+                    Path::new(&path),
+                    &configuration.project_key,
+                    parent_epic.as_deref(),
+                    dry_run,
+                )?;
+                Ok(Some(format!(
+                    "imported {} new, updated {} existing, closed {} resolved",
+                    result.imported, result.updated, result.closed
+                )))
+            }
+            ImportCommands::Csv {
+                path,
+                map,
+                preserve_ids,
+                dry_run,
+            } => run_file_import(root, ImportFormat::Csv, &path, &map, preserve_ids, dry_run),
+            ImportCommands::Jsonl {
+                path,
+                map,
+                preserve_ids,
+                dry_run,
+            } => run_file_import(
+                root,
+                ImportFormat::Jsonl,
+                &path,
+                &map,
+                preserve_ids,
+                dry_run,
+            ),
+        },
+        Commands::Export {
+            command,
+            format,
+            columns,
+            output,
+        } => match command {
+            None => {
+                let format = format.ok_or_else(|| {
+                    KanbusError::IssueOperation(
+                        "export requires --format (csv, jsonl or json) or a subcommand".to_string(),
+                    )
+                })?;
+                let result = export_issues(
+                    root,
+                    ExportFormat::parse(&format)?,
+                    &columns,
+                    output.as_deref().map(Path::new),
+                )?;
+                match result.path {
+                    Some(path) => Ok(Some(format!(
+                        "exported {} issues to {}",
+                        result.issue_count,
+                        path.display()
+                    ))),
+                    None => Ok(Some(result.contents)),
+                }
+            }
+            Some(ExportCommands::Beads { output, force }) => {
+                let result = export_to_beads(root, output.as_deref().map(Path::new), force)?;
+                let mut lines: Vec<String> = result
+                    .skipped_links
+                    .iter()
+                    .map(|link| format!("Skipped link {link} (target not exported)"))
+                    .collect();
+                lines.push(format!(
+                    "exported {} issues to {}",
+                    result.issue_count,
+                    result.path.display()
+                ));
+                Ok(Some(lines.join("\n")))
+            }
         },
-        Commands::Migrate { into_existing } => {
-            let result = if into_existing {
-                migrate_from_beads_into_project(&root_for_beads)?
+        Commands::Migrate {
+            into_existing,
+            dry_run,
+            resume,
+            map_type,
+            map_status,
+            report,
+        } => {
+            let mut mapping = MigrationMapping::default();
+            for rule in &map_type {
+                let (from, to) = MigrationMapping::parse_rule(rule)?;
+                mapping.issue_types.insert(from, to);
+            }
+            for rule in &map_status {
+                let (from, to) = MigrationMapping::parse_rule(rule)?;
+                mapping.statuses.insert(from, to);
+            }
+            let options = MigrationOptions {
+                into_existing,
+                dry_run,
+                resume,
+                mapping,
+            };
+            let result = run_beads_migration(&root_for_beads, &options)?;
+            if let Some(path) = report {
+                let contents = serde_json::to_string_pretty(&result.report)
+                    .map_err(|error| KanbusError::Io(error.to_string()))?;
+                std::fs::write(root.join(path), contents)
+                    .map_err(|error| KanbusError::Io(error.to_string()))?;
+            }
+            let mut lines = result.report.summary_lines();
+            if dry_run {
+                lines.insert(0, format!("plan: migrate {} issues", result.issue_count));
             } else {
-                migrate_from_beads(&root_for_beads)?
+                lines.push(format!("migrated {} issues", result.issue_count));
+            }
+            Ok(Some(lines.join("\n")))
+        }
+        Commands::Ids { command } => {
+            let (changes, verb) = match command {
+                IdsCommands::Assign => (assign_issue_numbers(root)?, "Numbered"),
+                IdsCommands::Reconcile => (reconcile_issue_numbers(root)?, "Renumbered"),
             };
-            Ok(Some(format!("migrated {} issues", result.issue_count)))
+            if changes.is_empty() {
+                return Ok(Some("No issues changed".to_string()));
+            }
+            let mut lines: Vec<String> = changes
+                .iter()
+                .map(|change| format!("{verb} {}", change.describe()))
+                .collect();
+            lines.push(format!("{} issues changed", changes.len()));
+            Ok(Some(lines.join("\n")))
         }
         Commands::Doctor => {
             let result = run_doctor(root)?;
             Ok(Some(format!("ok {}", result.project_dir.display())))
         }
+        Commands::Mcp => {
+            run_mcp_server(root)?;
+            Ok(None)
+        }
         Commands::Daemon { root } => {
             run_daemon(Path::new(&root))?;
             Ok(None)
@@ -2781,6 +3847,82 @@ fn execute_command(
                 let output = pages.join("\n");
                 Ok(Some(output))
             }
+            WikiCommands::Export { out } => {
+                let out_dir = if out.is_absolute() {
+                    out
+                } else {
+                    root.join(out)
+                };
+                let result = export_wiki_site(root, &out_dir)?;
+                Ok(Some(format!(
+                    "Exported {} pages and {} issues ({} diagrams) to {}",
+                    result.pages,
+                    result.issues,
+                    result.diagrams,
+                    out_dir.display()
+                )))
+            }
+            WikiCommands::History { page, json } => {
+                let store = FileStore::new(root);
+                let history = page_history(&store, &wiki_relative_page(&store, &page)?)?;
+                if json {
+                    return Ok(Some(
+                        serde_json::to_string_pretty(&history.revisions)
+                            .map_err(|error| KanbusError::Io(error.to_string()))?,
+                    ));
+                }
+                if history.revisions.is_empty() {
+                    return Ok(Some(format!("No history for {}", history.path)));
+                }
+                let lines: Vec<String> = history
+                    .revisions
+                    .iter()
+                    .map(|revision| {
+                        let short_id: String = revision.revision_id.chars().take(8).collect();
+                        let action = serde_json::to_value(revision.action)
+                            .ok()
+                            .and_then(|value| value.as_str().map(str::to_string))
+                            .unwrap_or_default();
+                        let mut line = format!(
+                            "{short_id}  {}  {}  {action}",
+                            revision.occurred_at, revision.actor_id
+                        );
+                        if let Some(previous) = &revision.previous_path {
+                            line.push_str(&format!(" from {previous}"));
+                        }
+                        if let Some(message) = &revision.message {
+                            line.push_str(&format!("  {message}"));
+                        }
+                        line
+                    })
+                    .collect();
+                Ok(Some(lines.join("\n")))
+            }
+            WikiCommands::Diff { page, revision } => {
+                let store = FileStore::new(root);
+                let result = page_diff(&store, &wiki_relative_page(&store, &page)?, &revision)?;
+                if result.diff.is_empty() {
+                    return Ok(Some("No differences".to_string()));
+                }
+                Ok(Some(result.diff))
+            }
+            WikiCommands::Restore {
+                page,
+                revision,
+                message,
+            } => {
+                let store = FileStore::new(root);
+                let result = restore_page(
+                    &store,
+                    &WikiRestoreRequest {
+                        path: wiki_relative_page(&store, &page)?,
+                        revision,
+                        message,
+                    },
+                )?;
+                let short_id: String = result.revision.revision_id.chars().take(8).collect();
+                Ok(Some(format!("Restored {} ({short_id})", result.path)))
+            }
         },
         Commands::Edit { command } => match command {
             EditCommands::View { path, view_range } => {
@@ -2799,12 +3941,38 @@ fn execute_command(
                 path,
                 old_str,
                 new_str,
+                diff: true,
+            } => Ok(Some(edit_preview(
+                root,
+                &[EditOperation::StrReplace {
+                    path,
+                    old_str,
+                    new_str,
+                }],
+            )?)),
+            EditCommands::StrReplace {
+                path,
+                old_str,
+                new_str,
+                diff: false,
             } => {
                 let path_buf = Path::new(&path).to_path_buf();
                 let output = edit_str_replace(root, &path_buf, &old_str, &new_str)?;
                 Ok(Some(output))
             }
-            EditCommands::Create { path, file_text } => {
+            EditCommands::Create {
+                path,
+                file_text,
+                diff: true,
+            } => Ok(Some(edit_preview(
+                root,
+                &[EditOperation::Create { path, file_text }],
+            )?)),
+            EditCommands::Create {
+                path,
+                file_text,
+                diff: false,
+            } => {
                 let path_buf = Path::new(&path).to_path_buf();
                 let output = edit_create(root, &path_buf, &file_text)?;
                 Ok(Some(output))
@@ -2813,11 +3981,51 @@ fn execute_command(
                 path,
                 insert_line,
                 insert_text,
+                diff: true,
+            } => Ok(Some(edit_preview(
+                root,
+                &[EditOperation::Insert {
+                    path,
+                    insert_line,
+                    insert_text,
+                }],
+            )?)),
+            EditCommands::Insert {
+                path,
+                insert_line,
+                insert_text,
+                diff: false,
             } => {
                 let path_buf = Path::new(&path).to_path_buf();
                 let output = edit_insert(root, &path_buf, insert_line, &insert_text)?;
                 Ok(Some(output))
             }
+            EditCommands::Apply {
+                edits,
+                edits_file,
+                diff,
+            } => {
+                let raw = match (edits, edits_file) {
+                    (Some(edits), _) => edits,
+                    (None, Some(edits_file)) => std::fs::read_to_string(root.join(edits_file))
+                        .map_err(|error| KanbusError::Io(error.to_string()))?,
+                    (None, None) => {
+                        return Err(KanbusError::IssueOperation(
+                            "--edits or --edits-file is required".to_string(),
+                        ))
+                    }
+                };
+                let operations: Vec<EditOperation> =
+                    serde_json::from_str(&raw).map_err(|error| {
+                        KanbusError::IssueOperation(format!("invalid edits: {error}"))
+                    })?;
+                if diff {
+                    Ok(Some(edit_preview(root, &operations)?))
+                } else {
+                    Ok(Some(edit_apply(root, &operations)?))
+                }
+            }
+            EditCommands::UndoEdit { path } => Ok(Some(edit_undo(root, Path::new(&path))?)),
         },
         Commands::Gossip { command } => match command {
             GossipCommands::Broker { socket } => {
@@ -3228,61 +4436,134 @@ fn execute_command(
                 stream_console_telemetry(root, output, url)?;
                 Ok(None)
             }
-            ConsoleCommands::Focus { .. } => Err(deprecated_console_control_error("focus")),
-            ConsoleCommands::Unfocus => Err(deprecated_console_control_error("unfocus")),
-            ConsoleCommands::View { .. } => Err(deprecated_console_control_error("view")),
-            ConsoleCommands::Search { .. } => Err(deprecated_console_control_error("search")),
-            ConsoleCommands::Maximize => Err(deprecated_console_control_error("maximize")),
-            ConsoleCommands::Restore => Err(deprecated_console_control_error("restore")),
-            ConsoleCommands::CloseDetail => Err(deprecated_console_control_error("close-detail")),
-            ConsoleCommands::ToggleSettings => {
-                Err(deprecated_console_control_error("toggle-settings"))
-            }
-            ConsoleCommands::Reload => Err(deprecated_console_control_error("reload")),
-            ConsoleCommands::SetSetting { .. } => {
-                Err(deprecated_console_control_error("set-setting"))
-            }
-            ConsoleCommands::CollapseColumn { .. } => {
-                Err(deprecated_console_control_error("collapse-column"))
-            }
-            ConsoleCommands::ExpandColumn { .. } => {
-                Err(deprecated_console_control_error("expand-column"))
-            }
-            ConsoleCommands::Select { .. } => Err(deprecated_console_control_error("select")),
-            ConsoleCommands::Status => {
-                let root_clone = root.to_path_buf();
-                let result = std::thread::spawn(move || fetch_console_ui_state(&root_clone))
-                    .join()
-                    .map_err(|_| {
-                        KanbusError::IssueOperation("fetch thread panicked".to_string())
-                    })?;
-                let output = match result {
-                    Ok(ui_state) => {
+            ConsoleCommands::Focus {
+                identifier,
+                comment,
+                session,
+            } => {
+                let issue_id = load_issue_from_project(root, &identifier)?.issue.identifier;
+                send_console_notification(
+                    root,
+                    NotificationEvent::IssueFocused {
+                        issue_id,
+                        user: None,
+                        comment_id: comment,
+                        session,
+                    },
+                )
+            }
+            ConsoleCommands::Unfocus { session } => {
+                send_ui_control(root, UiControlAction::ClearFocus, session)
+            }
+            ConsoleCommands::View { mode, session } => {
+                if !matches!(mode.as_str(), "initiatives" | "epics" | "issues") {
+                    return Err(KanbusError::IssueOperation(format!(
+                        "Unknown view mode '{mode}'. Valid modes: initiatives, epics, issues"
+                    )));
+                }
+                send_ui_control(root, UiControlAction::SetViewMode { mode }, session)
+            }
+            ConsoleCommands::Search {
+                query,
+                clear,
+                session,
+            } => {
+                let query = match (query, clear) {
+                    (_, true) => String::new(),
+                    (Some(query), false) => query,
+                    (None, false) => {
+                        return Err(KanbusError::IssueOperation(
+                            "search requires a query or --clear".to_string(),
+                        ))
+                    }
+                };
+                send_ui_control(root, UiControlAction::SetSearch { query }, session)
+            }
+            ConsoleCommands::Maximize { session } => {
+                send_ui_control(root, UiControlAction::MaximizeDetail, session)
+            }
+            ConsoleCommands::Restore { session } => {
+                send_ui_control(root, UiControlAction::RestoreDetail, session)
+            }
+            ConsoleCommands::CloseDetail { session } => {
+                send_ui_control(root, UiControlAction::CloseDetail, session)
+            }
+            ConsoleCommands::ToggleSettings { session } => {
+                send_ui_control(root, UiControlAction::ToggleSettings, session)
+            }
+            ConsoleCommands::Reload { session } => {
+                send_ui_control(root, UiControlAction::ReloadPage, session)
+            }
+            ConsoleCommands::SetSetting {
+                key,
+                value,
+                session,
+            } => send_ui_control(root, UiControlAction::SetSetting { key, value }, session),
+            ConsoleCommands::CollapseColumn { column, session } => send_ui_control(
+                root,
+                UiControlAction::CollapseColumn {
+                    column_name: column,
+                },
+                session,
+            ),
+            ConsoleCommands::ExpandColumn { column, session } => send_ui_control(
+                root,
+                UiControlAction::ExpandColumn {
+                    column_name: column,
+                },
+                session,
+            ),
+            ConsoleCommands::Select {
+                identifier,
+                session,
+            } => {
+                let issue_id = load_issue_from_project(root, &identifier)?.issue.identifier;
+                send_ui_control(root, UiControlAction::SelectIssue { issue_id }, session)
+            }
+            ConsoleCommands::ApplyView {
+                name,
+                clear,
+                session,
+            } => {
+                let name = match (name, clear) {
+                    (_, true) => String::new(),
+                    (Some(name), false) => {
+                        load_view(root, &name)?;
+                        name
+                    }
+                    (None, false) => {
+                        return Err(KanbusError::IssueOperation(
+                            "apply-view requires a view name or --clear".to_string(),
+                        ))
+                    }
+                };
+                send_ui_control(root, UiControlAction::ApplyView { name }, session)
+            }
+            ConsoleCommands::Status { session } => {
+                let output = match fetch_console_ui_state(root, session.as_deref())? {
+                    Some(ui_state) => {
                         let focused = ui_state.focused_issue_id.as_deref().unwrap_or("none");
                         let view = ui_state.view_mode.as_deref().unwrap_or("none");
                         let search = ui_state.search_query.as_deref().unwrap_or("none");
-                        format!("focus:  {focused}\nview:   {view}\nsearch: {search}")
+                        let saved = ui_state.saved_view.as_deref().unwrap_or("none");
+                        format!(
+                            "focus:  {focused}\nview:   {view}\nsearch: {search}\nsaved:  {saved}"
+                        )
                     }
-                    Err(_) => "Console server is not running.".to_string(),
+                    None => "Console server is not running.".to_string(),
                 };
                 Ok(Some(output))
             }
-            ConsoleCommands::Get { field } => {
+            ConsoleCommands::Get { field, session } => {
                 let field = field.as_str();
-                if !matches!(field, "focus" | "view" | "search") {
+                if !matches!(field, "focus" | "view" | "search" | "saved") {
                     return Err(KanbusError::IssueOperation(format!(
-                        "Unknown field '{}'. Valid fields: focus, view, search",
+                        "Unknown field '{}'. Valid fields: focus, view, search, saved",
                         field
                     )));
                 }
-                let root_clone = root.to_path_buf();
-                let result = std::thread::spawn(move || fetch_console_ui_state(&root_clone))
-                    .join()
-                    .map_err(|_| {
-                        KanbusError::IssueOperation("fetch thread panicked".to_string())
-                    })?;
-                match result {
-                    Ok(ui_state) => {
+                match fetch_console_ui_state(root, session.as_deref())? {
+                    Some(ui_state) => {
                         let value = match field {
                             "focus" => ui_state
                                 .focused_issue_id
@@ -3295,13 +4576,21 @@ fn execute_command(
                                 .as_deref()
                                 .unwrap_or("none")
                                 .to_string(),
+                            "saved" => ui_state.saved_view.as_deref().unwrap_or("none").to_string(),
                             _ => unreachable!("field validated"),
                         };
                         Ok(Some(value))
                     }
-                    Err(_) => Ok(Some("Console server is not running.".to_string())),
+                    None => Ok(Some("Console server is not running.".to_string())),
                 }
             }
+            ConsoleCommands::Sessions => {
+                let output = match fetch_console_sessions(root)? {
+                    Some(sessions) => format_console_sessions(&sessions),
+                    None => "Console server is not running.".to_string(),
+                };
+                Ok(Some(output))
+            }
         },
         Commands::DaemonStatus => {
             let status = request_status(root).map_err(format_daemon_project_error)?;
@@ -3373,6 +4662,48 @@ where
     result
 }
 
+fn run_file_import(
+    root: &Path,
+    format: ImportFormat,
+    path: &str,
+    map: &[String],
+    preserve_ids: bool,
+    dry_run: bool,
+) -> Result<Option<String>, KanbusError> {
+    let mut mapping = BTreeMap::new();
+    for rule in map {
+        let (column, field) = parse_column_mapping(rule)?;
+        mapping.insert(column, field);
+    }
+    let request = IssueImportRequest {
+        root: root.to_path_buf(),
+        path: root.join(path),
+        format,
+        mapping,
+        preserve_ids,
+        dry_run,
+    };
+    let result = import_issues(&request)?;
+    let mut lines = Vec::new();
+    if dry_run {
+        lines.push("Dry run — no files will be written.\n".to_string());
+    }
+    if !result.ignored_columns.is_empty() {
+        lines.push(format!(
+            "ignored columns: {}",
+            result.ignored_columns.join(", ")
+        ));
+    }
+    for issue in &result.issues {
+        lines.push(format!(
+            "created  {:<10}  {}  \"{}\"",
+            issue.issue_type, issue.identifier, issue.title
+        ));
+    }
+    lines.push(format!("imported {} issues", result.issues.len()));
+    Ok(Some(lines.join("\n")))
+}
+
 fn sort_timestamp(issue: &IssueData) -> f64 {
     let timestamp = issue.closed_at.unwrap_or(issue.updated_at);
     timestamp.timestamp() as f64
@@ -3388,13 +4719,6 @@ fn format_ready_line(issue: &IssueData) -> String {
     format!("{prefix}{}", issue.identifier)
 }
 
-fn is_issue_blocked(issue: &IssueData) -> bool {
-    issue
-        .dependencies
-        .iter()
-        .any(|dependency| dependency.dependency_type == "blocked-by")
-}
-
 fn format_daemon_project_error(error: KanbusError) -> KanbusError {
     match error {
         KanbusError::IssueOperation(message)
@@ -3414,29 +4738,156 @@ fn format_daemon_project_error(error: KanbusError) -> KanbusError {
     }
 }
 
-fn fetch_console_ui_state(
-    root: &Path,
-) -> Result<crate::console_ui_state::ConsoleUiState, KanbusError> {
-    use crate::console_ui_state::ConsoleUiState;
-    use reqwest::blocking::Client;
-
+fn console_base_url(root: &Path) -> Result<String, KanbusError> {
     let config_path = get_configuration_path(root)?;
     let config = load_project_configuration(&config_path)?;
     let port = config.console_port.unwrap_or(5174);
-    let url = format!("http://127.0.0.1:{port}/api/ui-state");
+    Ok(format!("http://127.0.0.1:{port}"))
+}
+
+/// Send a request to the local console server.
+///
+/// Runs on its own thread because the blocking HTTP client cannot be used
+/// from inside an async runtime. Returns `None` if the server is not running.
+fn console_request(
+    root: &Path,
+    method: reqwest::Method,
+    path: &str,
+    session: Option<&str>,
+    body: Option<serde_json::Value>,
+) -> Result<Option<serde_json::Value>, KanbusError> {
+    use reqwest::blocking::Client;
+
+    let url = format!("{}{path}", console_base_url(root)?);
+    let query: Vec<(String, String)> = session
+        .map(|session| vec![("session".to_string(), session.to_string())])
+        .unwrap_or_default();
+    let result = std::thread::spawn(move || {
+        let client = Client::new();
+        let mut request = client
+            .request(method, &url)
+            .query(&query)
+            .timeout(std::time::Duration::from_secs(3));
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let response = match request.send() {
+            Ok(response) => response,
+            Err(error) if error.is_connect() || error.is_timeout() => return Ok(None),
+            Err(error) => return Err(KanbusError::IssueOperation(error.to_string())),
+        };
+        let status = response.status();
+        let text = response
+            .text()
+            .map_err(|error| KanbusError::IssueOperation(error.to_string()))?;
+        let payload: serde_json::Value = if text.trim().is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_str(&text).unwrap_or(serde_json::Value::String(text))
+        };
+        if !status.is_success() {
+            let message = payload
+                .get("error")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("console server returned {status}"));
+            return Err(KanbusError::IssueOperation(message));
+        }
+        Ok(Some(payload))
+    })
+    .join()
+    .map_err(|_| KanbusError::IssueOperation("console request thread panicked".to_string()))?;
+    result
+}
+
+fn fetch_console_ui_state(
+    root: &Path,
+    session: Option<&str>,
+) -> Result<Option<ConsoleUiState>, KanbusError> {
+    if let Some(session) = session {
+        validate_session_id(session)?;
+    }
+    console_request(root, reqwest::Method::GET, "/api/ui-state", session, None)?
+        .map(|payload| {
+            serde_json::from_value(payload).map_err(|e| KanbusError::IssueOperation(e.to_string()))
+        })
+        .transpose()
+}
+
+fn fetch_console_sessions(root: &Path) -> Result<Option<Vec<ConsoleSessionInfo>>, KanbusError> {
+    console_request(root, reqwest::Method::GET, "/api/ui-sessions", None, None)?
+        .map(|payload| {
+            serde_json::from_value(payload).map_err(|e| KanbusError::IssueOperation(e.to_string()))
+        })
+        .transpose()
+}
+
+/// Release expired claims before readiness is computed, noting each on stderr.
+fn release_expired_claims_with_notice(root: &Path) -> Result<(), KanbusError> {
+    for lease in release_expired_claims(root, &get_current_user())? {
+        eprintln!(
+            "Released expired claim on {} (held by {})",
+            format_issue_key(&lease.issue_id, false),
+            lease.holder
+        );
+    }
+    Ok(())
+}
 
-    let client = Client::new();
-    let response = client
-        .get(&url)
-        .timeout(std::time::Duration::from_secs(3))
-        .send()
-        .map_err(|e| KanbusError::IssueOperation(e.to_string()))?;
+fn send_console_notification(
+    root: &Path,
+    event: NotificationEvent,
+) -> Result<Option<String>, KanbusError> {
+    if let Some(session) = event.target_session() {
+        validate_session_id(session)?;
+    }
+    let body = serde_json::to_value(&event).map_err(|e| KanbusError::Io(e.to_string()))?;
+    match console_request(
+        root,
+        reqwest::Method::POST,
+        "/api/notifications",
+        None,
+        Some(body),
+    )? {
+        Some(_) => Ok(None),
+        None => Err(KanbusError::IssueOperation(
+            "console server is not running".to_string(),
+        )),
+    }
+}
 
-    let ui_state: ConsoleUiState = response
-        .json()
-        .map_err(|e| KanbusError::IssueOperation(e.to_string()))?;
+fn send_ui_control(
+    root: &Path,
+    action: UiControlAction,
+    session: Option<String>,
+) -> Result<Option<String>, KanbusError> {
+    send_console_notification(root, NotificationEvent::UiControl { action, session })
+}
 
-    Ok(ui_state)
+fn format_console_sessions(sessions: &[ConsoleSessionInfo]) -> String {
+    if sessions.is_empty() {
+        return "No console sessions.".to_string();
+    }
+    sessions
+        .iter()
+        .map(|session| {
+            let status = if session.connected {
+                "connected"
+            } else {
+                "disconnected"
+            };
+            format!(
+                "{}  {status}  last seen {}  focus: {}  view: {}  search: {}  saved: {}",
+                session.id,
+                session.last_seen_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                session.state.focused_issue_id.as_deref().unwrap_or("none"),
+                session.state.view_mode.as_deref().unwrap_or("none"),
+                session.state.search_query.as_deref().unwrap_or("none"),
+                session.state.saved_view.as_deref().unwrap_or("none"),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 fn resolve_cloud_base_url(cli_value: Option<String>) -> Result<String, KanbusError> {
@@ -3633,7 +5084,8 @@ mod tests {
             dependency_type: "blocked-by".to_string(),
         });
 
-        assert!(is_issue_blocked(&data));
+        let resolver = crate::dependencies::BlockerResolver::new(std::slice::from_ref(&data), None);
+        assert!(!resolver.is_ready(&data));
     }
 
     #[test]
@@ -3671,7 +5123,10 @@ mod tests {
             local: false
         }));
         assert!(!should_check_project_structure(&Commands::Setup {
-            command: SetupCommands::Agents { force: false },
+            command: SetupCommands::Agents {
+                force: false,
+                check: false,
+            },
         }));
         assert!(should_check_project_structure(&Commands::List {
             status: None,
@@ -3686,6 +5141,7 @@ mod tests {
             local_only: false,
             porcelain: false,
             full_ids: false,
+            view: None,
         }));
     }
 
@@ -256,6 +256,11 @@ pub fn default_project_configuration() -> ProjectConfiguration {
         ai: None,
         hooks: HooksConfiguration::default(),
         github_security: None,
+        github_issues: None,
+        dependency_types: BTreeMap::new(),
+        hierarchy_rules: None,
+        views: BTreeMap::new(),
+        sequential_ids: false,
     }
 }
 
@@ -7,8 +7,11 @@ use std::path::Path;
 use serde_yaml::{Mapping, Value};
 
 use crate::config::default_project_configuration;
+use crate::dependency_types::validate_dependency_types;
 use crate::error::KanbusError;
+use crate::hierarchy::validate_hierarchy_rules;
 use crate::models::ProjectConfiguration;
+use crate::saved_views::validate_views;
 
 /// Load a project configuration from disk.
 ///
@@ -300,6 +303,9 @@ pub fn validate_project_configuration(configuration: &ProjectConfiguration) -> V
 
     validate_hooks(configuration, &mut errors);
     validate_sort_order(configuration, &mut errors);
+    validate_dependency_types(configuration, &mut errors);
+    validate_hierarchy_rules(configuration, &mut errors);
+    validate_views(configuration, &mut errors);
 
     errors
 }
@@ -14,6 +14,7 @@ use crate::file_io::{
 use crate::migration::load_beads_issues;
 use crate::models::{IssueData, ProjectConfiguration};
 use crate::overlay::apply_overlay_to_issues;
+use crate::saved_views::load_valid_views_for;
 
 /// Snapshot payload for the console.
 #[derive(Debug, Clone, Serialize)]
@@ -46,9 +47,22 @@ impl FileStore {
     }
 
     /// Load the project configuration for this store.
+    ///
+    /// Saved views from the project's `views/` directory are merged into
+    /// `views`, so clients see every view regardless of where it is defined.
+    /// A view file that cannot be loaded is skipped with a warning rather
+    /// than failing the board and wiki for the whole project.
     pub fn load_config(&self) -> Result<ProjectConfiguration, KanbusError> {
         let configuration_path = get_configuration_path(self.root())?;
-        load_project_configuration(&configuration_path)
+        let mut configuration = load_project_configuration(&configuration_path)?;
+        let base = configuration_path.parent().unwrap_or(self.root());
+        let (views, problems) =
+            load_valid_views_for(&configuration, &base.join(&configuration.project_directory))?;
+        for problem in problems {
+            eprintln!("Warning: skipping saved view: {problem}");
+        }
+        configuration.views = views;
+        Ok(configuration)
     }
 
     /// Load issues for this store using the provided configuration.
@@ -429,6 +443,23 @@ mod tests {
         assert_eq!(ids, vec!["kanbus-a", "kanbus-b"]);
     }
 
+    #[test]
+    fn file_store_skips_invalid_view_files() {
+        let temp_dir = TempDir::new().expect("tempdir");
+        write_base_config(temp_dir.path());
+        let views_dir = temp_dir.path().join("project").join("views");
+        std::fs::create_dir_all(temp_dir.path().join("project").join("issues"))
+            .expect("create issues");
+        std::fs::create_dir_all(&views_dir).expect("create views");
+        std::fs::write(views_dir.join("good.yml"), "sort: title\n").expect("write good");
+        std::fs::write(views_dir.join("bad.yml"), "sort: [").expect("write bad");
+
+        let store = FileStore::new(temp_dir.path());
+        let configuration = store.load_config().expect("config");
+        assert_eq!(configuration.views.keys().collect::<Vec<_>>(), ["good"]);
+        store.build_snapshot_payload().expect("snapshot payload");
+    }
+
     #[test]
     fn resolve_tenant_root_builds_expected_path() {
         let base = Path::new("/tmp/kanbus");
@@ -3,20 +3,32 @@
 //! The server tracks what state it has told browser clients to navigate to.
 //! This is used by CLI query commands (`kbs console status`, `kbs console get focus`, etc.)
 //! and is persisted to `.kanbus/.cache/console_state.json` across server restarts.
+//!
+//! Each browser registers a session id when it connects. Untargeted events are
+//! broadcast and update every session; events carrying a session id only
+//! update that session.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Component, Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
 
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::error::KanbusError;
 use crate::file_io::load_project_directory;
+use crate::notification_events::{NotificationEvent, UiControlAction};
 
 const STATE_FILE_NAME: &str = "console_state.json";
-static STATE_CACHE: OnceLock<Mutex<HashMap<PathBuf, ConsoleUiState>>> = OnceLock::new();
 
-fn state_cache() -> &'static Mutex<HashMap<PathBuf, ConsoleUiState>> {
+/// Disconnected sessions not seen for this long are forgotten.
+const SESSION_RETENTION_DAYS: i64 = 7;
+
+const MAX_SESSION_ID_LENGTH: usize = 64;
+
+static STATE_CACHE: OnceLock<Mutex<HashMap<PathBuf, ConsoleStateStore>>> = OnceLock::new();
+
+fn state_cache() -> &'static Mutex<HashMap<PathBuf, ConsoleStateStore>> {
     STATE_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
 }
 
@@ -34,6 +46,238 @@ pub struct ConsoleUiState {
     pub view_mode: Option<String>,
     /// Active search query, if any.
     pub search_query: Option<String>,
+    /// Name of the applied saved view, if any.
+    pub saved_view: Option<String>,
+}
+
+impl ConsoleUiState {
+    /// Update the state from a notification event.
+    ///
+    /// Returns `true` if the event changed a tracked field.
+    pub fn apply_event(&mut self, event: &NotificationEvent) -> bool {
+        match event {
+            NotificationEvent::IssueFocused {
+                issue_id,
+                comment_id,
+                ..
+            } => {
+                self.focused_issue_id = Some(issue_id.clone());
+                self.focused_comment_id = comment_id.clone();
+                true
+            }
+            NotificationEvent::UiControl { action, .. } => match action {
+                UiControlAction::ClearFocus => {
+                    self.focused_issue_id = None;
+                    self.focused_comment_id = None;
+                    true
+                }
+                UiControlAction::SetViewMode { mode } => {
+                    self.view_mode = Some(mode.clone());
+                    true
+                }
+                UiControlAction::SetSearch { query } => {
+                    self.search_query = if query.is_empty() {
+                        None
+                    } else {
+                        Some(query.clone())
+                    };
+                    true
+                }
+                UiControlAction::ApplyView { name } => {
+                    self.saved_view = if name.is_empty() {
+                        None
+                    } else {
+                        Some(name.clone())
+                    };
+                    true
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Notifications that bring a newly connected client to this state.
+    pub fn replay_events(&self) -> Vec<NotificationEvent> {
+        let mut events = Vec::new();
+        if let Some(issue_id) = &self.focused_issue_id {
+            events.push(NotificationEvent::IssueFocused {
+                issue_id: issue_id.clone(),
+                user: None,
+                comment_id: self.focused_comment_id.clone(),
+                session: None,
+            });
+            return events;
+        }
+        if let Some(mode) = &self.view_mode {
+            events.push(NotificationEvent::UiControl {
+                action: UiControlAction::SetViewMode { mode: mode.clone() },
+                session: None,
+            });
+        }
+        if let Some(name) = &self.saved_view {
+            events.push(NotificationEvent::UiControl {
+                action: UiControlAction::ApplyView { name: name.clone() },
+                session: None,
+            });
+        }
+        if let Some(query) = &self.search_query {
+            events.push(NotificationEvent::UiControl {
+                action: UiControlAction::SetSearch {
+                    query: query.clone(),
+                },
+                session: None,
+            });
+        }
+        events
+    }
+}
+
+/// UI state of one registered browser session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsoleSession {
+    pub connected_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    /// Open event streams for this session; not persisted.
+    #[serde(skip)]
+    pub connections: usize,
+    #[serde(flatten)]
+    pub state: ConsoleUiState,
+}
+
+/// Summary of a session, as listed by `kbs console sessions`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsoleSessionInfo {
+    pub id: String,
+    pub connected: bool,
+    pub connected_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub state: ConsoleUiState,
+}
+
+/// Persisted console state: the broadcast state plus per-session state.
+///
+/// The broadcast fields are stored at the top level, so files written before
+/// sessions existed still load.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConsoleStateStore {
+    #[serde(flatten)]
+    pub shared: ConsoleUiState,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sessions: BTreeMap<String, ConsoleSession>,
+}
+
+impl ConsoleStateStore {
+    /// Register a connection for `session_id`, creating the session from the
+    /// broadcast state if it is new.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::IssueOperation` if the session id is invalid.
+    pub fn connect_session(
+        &mut self,
+        session_id: &str,
+        now: DateTime<Utc>,
+    ) -> Result<&ConsoleSession, KanbusError> {
+        validate_session_id(session_id)?;
+        self.prune_sessions(now);
+        let shared = self.shared.clone();
+        let session = self
+            .sessions
+            .entry(session_id.to_string())
+            .or_insert_with(|| ConsoleSession {
+                connected_at: now,
+                last_seen_at: now,
+                connections: 0,
+                state: shared,
+            });
+        if session.connections == 0 {
+            session.connected_at = now;
+        }
+        session.connections += 1;
+        session.last_seen_at = now;
+        Ok(session)
+    }
+
+    /// Record that one connection for `session_id` closed.
+    pub fn disconnect_session(&mut self, session_id: &str, now: DateTime<Utc>) {
+        if let Some(session) = self.sessions.get_mut(session_id) {
+            session.connections = session.connections.saturating_sub(1);
+            session.last_seen_at = now;
+        }
+    }
+
+    /// Apply an event to the session it targets, or to every session and the
+    /// broadcast state when it has no target.
+    ///
+    /// Returns `true` if any state changed.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::IssueOperation` if the targeted session is unknown.
+    pub fn apply_event(&mut self, event: &NotificationEvent) -> Result<bool, KanbusError> {
+        match event.target_session() {
+            Some(session_id) => {
+                let session = self.sessions.get_mut(session_id).ok_or_else(|| {
+                    KanbusError::IssueOperation(format!("unknown console session: {session_id}"))
+                })?;
+                Ok(session.state.apply_event(event))
+            }
+            None => {
+                let mut changed = self.shared.apply_event(event);
+                for session in self.sessions.values_mut() {
+                    changed |= session.state.apply_event(event);
+                }
+                Ok(changed)
+            }
+        }
+    }
+
+    /// State shown by `session_id`, or the broadcast state when `None`.
+    pub fn state_for(&self, session_id: Option<&str>) -> Option<&ConsoleUiState> {
+        match session_id {
+            Some(session_id) => self.sessions.get(session_id).map(|session| &session.state),
+            None => Some(&self.shared),
+        }
+    }
+
+    /// Sessions ordered by id.
+    pub fn session_list(&self) -> Vec<ConsoleSessionInfo> {
+        self.sessions
+            .iter()
+            .map(|(id, session)| ConsoleSessionInfo {
+                id: id.clone(),
+                connected: session.connections > 0,
+                connected_at: session.connected_at,
+                last_seen_at: session.last_seen_at,
+                state: session.state.clone(),
+            })
+            .collect()
+    }
+
+    fn prune_sessions(&mut self, now: DateTime<Utc>) {
+        let cutoff = now - Duration::days(SESSION_RETENTION_DAYS);
+        self.sessions
+            .retain(|_, session| session.connections > 0 || session.last_seen_at >= cutoff);
+    }
+}
+
+/// Check that a session id is 1-64 ASCII letters, digits, `-`, `_` or `.`.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` describing the problem.
+pub fn validate_session_id(session_id: &str) -> Result<(), KanbusError> {
+    let valid = !session_id.is_empty()
+        && session_id.len() <= MAX_SESSION_ID_LENGTH
+        && session_id
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(KanbusError::IssueOperation(format!(
+            "invalid console session id '{session_id}': use 1-{MAX_SESSION_ID_LENGTH} letters, digits, '-', '_' or '.'"
+        )))
+    }
 }
 
 fn resolve_state_path(root: &Path) -> Result<PathBuf, KanbusError> {
@@ -70,39 +314,53 @@ pub fn state_path(root: &Path) -> Result<PathBuf, KanbusError> {
     resolve_state_path(root)
 }
 
-/// Load `ConsoleUiState` from a JSON file.
+/// Load the broadcast `ConsoleUiState` from a JSON file.
 ///
 /// Returns `Default::default()` if the file does not exist (not an error).
 pub fn load_state(root: &Path) -> Result<ConsoleUiState, KanbusError> {
+    Ok(load_store(root)?.shared)
+}
+
+/// Persist the broadcast `ConsoleUiState`, keeping stored sessions.
+pub fn save_state(root: &Path, state: &ConsoleUiState) -> Result<(), KanbusError> {
+    let mut store = load_store(root)?;
+    store.shared = state.clone();
+    save_store(root, &store)
+}
+
+/// Load the full `ConsoleStateStore` from a JSON file.
+///
+/// Returns `Default::default()` if the file does not exist (not an error).
+pub fn load_store(root: &Path) -> Result<ConsoleStateStore, KanbusError> {
     let path = resolve_state_path(root)?;
     if let Ok(guard) = state_cache().lock() {
-        if let Some(state) = guard.get(&path) {
-            return Ok(state.clone());
+        if let Some(store) = guard.get(&path) {
+            return Ok(store.clone());
         }
     }
     if !path.exists() {
-        return Ok(ConsoleUiState::default());
+        return Ok(ConsoleStateStore::default());
     }
     let json = std::fs::read_to_string(&path).map_err(|e| KanbusError::Io(e.to_string()))?;
     serde_json::from_str(&json).map_err(|e| KanbusError::Io(e.to_string()))
 }
 
-/// Persist `ConsoleUiState` to a JSON file.
+/// Persist `ConsoleStateStore` to a JSON file.
 ///
 /// Creates parent directories if they do not exist.
-pub fn save_state(root: &Path, state: &ConsoleUiState) -> Result<(), KanbusError> {
+pub fn save_store(root: &Path, store: &ConsoleStateStore) -> Result<(), KanbusError> {
     let path = resolve_state_path(root)?;
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent).map_err(|error| KanbusError::Io(error.to_string()))?;
     }
     let json =
-        serde_json::to_string_pretty(state).map_err(|error| KanbusError::Io(error.to_string()))?;
+        serde_json::to_string_pretty(store).map_err(|error| KanbusError::Io(error.to_string()))?;
     std::fs::write(&path, json).map_err(|error| KanbusError::Io(error.to_string()))?;
 
     let mut guard = state_cache().lock().map_err(|_| {
         KanbusError::IssueOperation("console state cache lock poisoned".to_string())
     })?;
-    guard.insert(path, state.clone());
+    guard.insert(path, store.clone());
     Ok(())
 }
 
@@ -144,6 +402,7 @@ mod tests {
             focused_comment_id: Some("comment-1".to_string()),
             view_mode: Some("issues".to_string()),
             search_query: Some("auth".to_string()),
+            saved_view: Some("my-bugs".to_string()),
         };
 
         save_state(&root, &original).expect("save state");
@@ -153,6 +412,110 @@ mod tests {
         assert_eq!(loaded.focused_comment_id, original.focused_comment_id);
         assert_eq!(loaded.view_mode, original.view_mode);
         assert_eq!(loaded.search_query, original.search_query);
+        assert_eq!(loaded.saved_view, original.saved_view);
+    }
+
+    fn focus(issue_id: &str, session: Option<&str>) -> NotificationEvent {
+        NotificationEvent::IssueFocused {
+            issue_id: issue_id.to_string(),
+            user: None,
+            comment_id: None,
+            session: session.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn targeted_events_only_update_their_session() {
+        let now = Utc::now();
+        let mut store = ConsoleStateStore::default();
+        store.connect_session("left", now).expect("connect left");
+        store.connect_session("right", now).expect("connect right");
+
+        assert!(store
+            .apply_event(&focus("kanbus-1", Some("left")))
+            .expect("targeted"));
+        assert_eq!(
+            store
+                .state_for(Some("left"))
+                .unwrap()
+                .focused_issue_id
+                .as_deref(),
+            Some("kanbus-1")
+        );
+        assert!(store
+            .state_for(Some("right"))
+            .unwrap()
+            .focused_issue_id
+            .is_none());
+        assert!(store.shared.focused_issue_id.is_none());
+
+        store
+            .apply_event(&focus("kanbus-2", None))
+            .expect("broadcast");
+        for session in ["left", "right"] {
+            assert_eq!(
+                store
+                    .state_for(Some(session))
+                    .unwrap()
+                    .focused_issue_id
+                    .as_deref(),
+                Some("kanbus-2")
+            );
+        }
+        assert_eq!(store.shared.focused_issue_id.as_deref(), Some("kanbus-2"));
+
+        let error = store
+            .apply_event(&focus("kanbus-3", Some("missing")))
+            .expect_err("unknown session");
+        assert!(error.to_string().contains("unknown console session"));
+    }
+
+    #[test]
+    fn sessions_start_from_broadcast_state_and_track_connections() {
+        let now = Utc::now();
+        let mut store = ConsoleStateStore::default();
+        store
+            .apply_event(&focus("kanbus-1", None))
+            .expect("broadcast");
+        let session = store.connect_session("tab-1", now).expect("connect");
+        assert_eq!(session.state.focused_issue_id.as_deref(), Some("kanbus-1"));
+        assert!(store.session_list()[0].connected);
+
+        store.disconnect_session("tab-1", now);
+        assert!(!store.session_list()[0].connected);
+
+        store
+            .connect_session("tab-2", now + Duration::days(SESSION_RETENTION_DAYS + 1))
+            .expect("connect later");
+        let ids: Vec<String> = store.session_list().into_iter().map(|s| s.id).collect();
+        assert_eq!(ids, ["tab-2"]);
+
+        assert!(store.connect_session("bad id", now).is_err());
+        assert!(store.connect_session("", now).is_err());
+    }
+
+    #[test]
+    fn save_state_keeps_sessions_and_legacy_files_load() {
+        let temp_dir = TempDir::new().expect("tempdir");
+        let root = setup_root(&temp_dir);
+        let path = state_path(&root).expect("state path");
+        std::fs::create_dir_all(path.parent().unwrap()).expect("cache dir");
+        std::fs::write(
+            &path,
+            r#"{"focused_issue_id":"kanbus-old","view_mode":"epics"}"#,
+        )
+        .expect("write legacy state");
+        let store = load_store(&root).expect("load legacy");
+        assert_eq!(store.shared.focused_issue_id.as_deref(), Some("kanbus-old"));
+        assert!(store.sessions.is_empty());
+
+        let mut store = store;
+        store.connect_session("tab-1", Utc::now()).expect("connect");
+        save_store(&root, &store).expect("save store");
+        save_state(&root, &ConsoleUiState::default()).expect("save state");
+        let reloaded = load_store(&root).expect("reload");
+        assert!(reloaded.shared.focused_issue_id.is_none());
+        assert!(reloaded.sessions.contains_key("tab-1"));
     }
 
     #[test]
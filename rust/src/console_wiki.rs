@@ -9,6 +9,12 @@ use serde::{Deserialize, Serialize};
 use crate::console_backend::FileStore;
 use crate::error::KanbusError;
 use crate::wiki::{render_wiki_page, WikiRenderRequest};
+use crate::wiki_backlinks::{load_backlink_index, WikiBacklinkIndex};
+use crate::wiki_history::{
+    find_wiki_revision, load_revision_content, load_wiki_history, record_wiki_revision,
+    restore_wiki_revision, unified_diff, WikiChange, WikiRevision, WikiRevisionAction,
+    WikiRevisionMatch,
+};
 
 /// Response for listing wiki pages.
 #[derive(Debug, Clone, Serialize)]
@@ -46,6 +52,8 @@ pub struct WikiCreateResponse {
 pub struct WikiUpdateRequest {
     pub path: String,
     pub content: String,
+    #[serde(default)]
+    pub message: Option<String>,
 }
 
 /// Response for updating a wiki page.
@@ -79,6 +87,38 @@ pub struct WikiDeleteResponse {
     pub deleted: bool,
 }
 
+/// Response for a wiki page's revision history.
+#[derive(Debug, Clone, Serialize)]
+pub struct WikiHistoryResponse {
+    pub path: String,
+    pub revisions: Vec<WikiRevision>,
+}
+
+/// Response for diffing a revision against the current page.
+#[derive(Debug, Clone, Serialize)]
+pub struct WikiDiffResponse {
+    pub path: String,
+    pub revision: String,
+    pub diff: String,
+}
+
+/// Request for restoring a wiki page revision.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WikiRestoreRequest {
+    pub path: String,
+    pub revision: String,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Response for restoring a wiki page revision.
+#[derive(Debug, Clone, Serialize)]
+pub struct WikiRestoreResponse {
+    pub path: String,
+    pub revision: WikiRevision,
+    pub restored: bool,
+}
+
 /// Request for rendering a wiki page.
 #[derive(Debug, Clone, Deserialize)]
 pub struct WikiRenderRequestPayload {
@@ -104,6 +144,18 @@ pub enum WikiServiceError {
     Render(String),
 }
 
+impl From<WikiServiceError> for KanbusError {
+    fn from(error: WikiServiceError) -> Self {
+        match error {
+            WikiServiceError::Io(message) => KanbusError::Io(message),
+            WikiServiceError::InvalidPath(message)
+            | WikiServiceError::NotFound(message)
+            | WikiServiceError::Conflict(message)
+            | WikiServiceError::Render(message) => KanbusError::IssueOperation(message),
+        }
+    }
+}
+
 /// Normalize and validate a wiki path.
 fn normalize_path(path: &str) -> Result<String, WikiServiceError> {
     let trimmed = path.trim();
@@ -176,6 +228,16 @@ pub fn wiki_list_prefix(store: &FileStore) -> Result<String, KanbusError> {
     }
 }
 
+/// Strip the wiki list prefix from a page path, accepting wiki-root relative paths as-is.
+pub fn wiki_relative_page(store: &FileStore, path: &str) -> Result<String, KanbusError> {
+    let prefix = format!("{}/", wiki_list_prefix(store)?);
+    let normalized = path.trim().replace('\\', "/");
+    Ok(normalized
+        .strip_prefix(&prefix)
+        .map(str::to_string)
+        .unwrap_or(normalized))
+}
+
 fn absolute_page_path(store: &FileStore, path: &str) -> Result<PathBuf, KanbusError> {
     let normalized = normalize_path(path).map_err(|error| match error {
         WikiServiceError::InvalidPath(message) => KanbusError::IssueOperation(message),
@@ -225,6 +287,11 @@ fn collect_markdown(
     Ok(())
 }
 
+/// Return the wiki backlink index for issue references and page links.
+pub fn backlinks(store: &FileStore) -> Result<WikiBacklinkIndex, WikiServiceError> {
+    load_backlink_index(store.root()).map_err(to_service_error)
+}
+
 /// Fetch page content.
 pub fn get_page(store: &FileStore, path: &str) -> Result<WikiPageResponse, WikiServiceError> {
     let absolute = absolute_page_path(store, path).map_err(to_service_error)?;
@@ -256,7 +323,24 @@ pub fn create_page(
         ));
     }
     let content = request.content.clone().unwrap_or_default();
+    let previous = fs::read_to_string(&absolute).ok();
     write_atomic(&absolute, &content)?;
+    let action = if previous.is_some() {
+        WikiRevisionAction::Updated
+    } else {
+        WikiRevisionAction::Created
+    };
+    record_change(
+        store,
+        WikiChange {
+            page: &path,
+            action,
+            content: Some(&content),
+            previous_content: previous.as_deref(),
+            previous_path: None,
+            message: None,
+        },
+    )?;
     Ok(WikiCreateResponse {
         path,
         created: true,
@@ -275,7 +359,19 @@ pub fn update_page(
             "wiki page not found".to_string(),
         ));
     }
+    let previous = fs::read_to_string(&absolute).ok();
     write_atomic(&absolute, &request.content)?;
+    record_change(
+        store,
+        WikiChange {
+            page: &path,
+            action: WikiRevisionAction::Updated,
+            content: Some(&request.content),
+            previous_content: previous.as_deref(),
+            previous_path: None,
+            message: request.message.as_deref(),
+        },
+    )?;
     Ok(WikiUpdateResponse {
         path,
         updated: true,
@@ -305,8 +401,21 @@ pub fn rename_page(
     if let Some(parent) = to_absolute.parent() {
         fs::create_dir_all(parent).map_err(|error| WikiServiceError::Io(error.to_string()))?;
     }
+    let replaced = fs::read_to_string(&to_absolute).ok();
     fs::rename(&from_absolute, &to_absolute)
         .map_err(|error| WikiServiceError::Io(error.to_string()))?;
+    let content = fs::read_to_string(&to_absolute).ok();
+    record_change(
+        store,
+        WikiChange {
+            page: &to_path,
+            action: WikiRevisionAction::Renamed,
+            content: content.as_deref(),
+            previous_content: replaced.as_deref(),
+            previous_path: Some(&from_path),
+            message: None,
+        },
+    )?;
     Ok(WikiRenameResponse {
         from_path,
         to_path,
@@ -323,13 +432,93 @@ pub fn delete_page(store: &FileStore, path: &str) -> Result<WikiDeleteResponse,
             "wiki page not found".to_string(),
         ));
     }
+    let previous = fs::read_to_string(&absolute).ok();
     fs::remove_file(&absolute).map_err(|error| WikiServiceError::Io(error.to_string()))?;
+    record_change(
+        store,
+        WikiChange {
+            page: &normalized,
+            action: WikiRevisionAction::Deleted,
+            content: None,
+            previous_content: previous.as_deref(),
+            previous_path: None,
+            message: None,
+        },
+    )?;
     Ok(WikiDeleteResponse {
         path: normalized,
         deleted: true,
     })
 }
 
+/// List the recorded revisions of a page, newest first.
+pub fn page_history(
+    store: &FileStore,
+    path: &str,
+) -> Result<WikiHistoryResponse, WikiServiceError> {
+    let normalized = normalize_path(path)?;
+    let project_dir = project_dir(store).map_err(to_service_error)?;
+    let revisions = load_wiki_history(&project_dir, &normalized).map_err(to_service_error)?;
+    Ok(WikiHistoryResponse {
+        path: normalized,
+        revisions,
+    })
+}
+
+/// Diff a recorded revision against the current page content.
+pub fn page_diff(
+    store: &FileStore,
+    path: &str,
+    revision: &str,
+) -> Result<WikiDiffResponse, WikiServiceError> {
+    let normalized = normalize_path(path)?;
+    let project_dir = project_dir(store).map_err(to_service_error)?;
+    let absolute = absolute_page_path(store, &normalized).map_err(to_service_error)?;
+    let found = resolve_revision(&project_dir, &normalized, revision)?;
+    let old = if found.content_hash.is_some() {
+        load_revision_content(&project_dir, &found).map_err(to_service_error)?
+    } else {
+        String::new()
+    };
+    let current = fs::read_to_string(&absolute).unwrap_or_default();
+    let short_revision: String = found.revision_id.chars().take(8).collect();
+    let diff = unified_diff(
+        &old,
+        &current,
+        &format!("{normalized}@{short_revision}"),
+        &normalized,
+    );
+    Ok(WikiDiffResponse {
+        path: normalized,
+        revision: found.revision_id,
+        diff,
+    })
+}
+
+/// Restore a page to the content of a recorded revision.
+pub fn restore_page(
+    store: &FileStore,
+    request: &WikiRestoreRequest,
+) -> Result<WikiRestoreResponse, WikiServiceError> {
+    let normalized = normalize_path(&request.path)?;
+    let project_dir = project_dir(store).map_err(to_service_error)?;
+    let root = wiki_root(store).map_err(to_service_error)?;
+    let found = resolve_revision(&project_dir, &normalized, &request.revision)?;
+    let revision = restore_wiki_revision(
+        &project_dir,
+        &root,
+        &normalized,
+        &found,
+        request.message.as_deref(),
+    )
+    .map_err(to_service_error)?;
+    Ok(WikiRestoreResponse {
+        path: normalized,
+        revision,
+        restored: true,
+    })
+}
+
 /// Render a page, using draft content when provided.
 pub fn render_page(
     store: &FileStore,
@@ -379,6 +568,17 @@ pub fn render_page(
     })
 }
 
+fn project_dir(store: &FileStore) -> Result<PathBuf, KanbusError> {
+    let config = store.load_config()?;
+    Ok(store.root().join(&config.project_directory))
+}
+
+fn record_change(store: &FileStore, change: WikiChange<'_>) -> Result<(), WikiServiceError> {
+    let project_dir = project_dir(store).map_err(to_service_error)?;
+    record_wiki_revision(&project_dir, change).map_err(to_service_error)?;
+    Ok(())
+}
+
 fn write_atomic(target: &Path, content: &str) -> Result<(), WikiServiceError> {
     if let Some(parent) = target.parent() {
         fs::create_dir_all(parent).map_err(|error| WikiServiceError::Io(error.to_string()))?;
@@ -425,6 +625,22 @@ fn relative_to_root_for_render(
         .join(normalized))
 }
 
+fn resolve_revision(
+    project_dir: &Path,
+    page: &str,
+    revision: &str,
+) -> Result<WikiRevision, WikiServiceError> {
+    match find_wiki_revision(project_dir, page, revision).map_err(to_service_error)? {
+        WikiRevisionMatch::Found(found) => Ok(found),
+        WikiRevisionMatch::Ambiguous => Err(WikiServiceError::Conflict(
+            "ambiguous wiki revision".to_string(),
+        )),
+        WikiRevisionMatch::NotFound => Err(WikiServiceError::NotFound(
+            "wiki revision not found".to_string(),
+        )),
+    }
+}
+
 fn to_service_error(error: KanbusError) -> WikiServiceError {
     match error {
         KanbusError::Io(message) => WikiServiceError::Io(message),
@@ -619,12 +835,23 @@ mod tests {
         )
         .expect("overwrite create");
         assert_eq!(overwritten.path, "docs/guide.md");
+        let history = page_history(&store, "docs/guide.md").expect("history");
+        let actions: Vec<WikiRevisionAction> = history
+            .revisions
+            .iter()
+            .map(|revision| revision.action)
+            .collect();
+        assert_eq!(
+            actions,
+            vec![WikiRevisionAction::Updated, WikiRevisionAction::Created]
+        );
 
         let updated = update_page(
             &store,
             &WikiUpdateRequest {
                 path: "docs/guide.md".to_string(),
                 content: "v3".to_string(),
+                message: None,
             },
         )
         .expect("update page");
@@ -635,6 +862,7 @@ mod tests {
             &WikiUpdateRequest {
                 path: "docs/missing.md".to_string(),
                 content: "nope".to_string(),
+                message: None,
             },
         )
         .expect_err("update missing");
@@ -690,6 +918,58 @@ mod tests {
         assert!(matches!(delete_missing, WikiServiceError::NotFound(_)));
     }
 
+    #[test]
+    fn page_edits_are_recorded_and_can_be_restored() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        write_config(temp.path(), "");
+        let store = FileStore::new(temp.path());
+
+        create_page(
+            &store,
+            &WikiCreateRequest {
+                path: "guide.md".to_string(),
+                content: Some("one\ntwo\n".to_string()),
+                overwrite: None,
+            },
+        )
+        .expect("create page");
+        update_page(
+            &store,
+            &WikiUpdateRequest {
+                path: "guide.md".to_string(),
+                content: "one\nthree\n".to_string(),
+                message: Some("rewrite".to_string()),
+            },
+        )
+        .expect("update page");
+        delete_page(&store, "guide.md").expect("delete page");
+
+        let history = page_history(&store, "guide.md").expect("history");
+        assert_eq!(history.revisions.len(), 3);
+        assert_eq!(history.revisions[0].action, WikiRevisionAction::Deleted);
+        assert_eq!(history.revisions[1].message.as_deref(), Some("rewrite"));
+        let created = history.revisions[2].revision_id.clone();
+
+        let diff = page_diff(&store, "guide.md", &created).expect("diff");
+        assert!(diff.diff.contains("-one\n-two"));
+
+        let restored = restore_page(
+            &store,
+            &WikiRestoreRequest {
+                path: "guide.md".to_string(),
+                revision: created[..8].to_string(),
+                message: None,
+            },
+        )
+        .expect("restore");
+        assert_eq!(restored.revision.action, WikiRevisionAction::Restored);
+        let page = get_page(&store, "guide.md").expect("restored page");
+        assert_eq!(page.content, "one\ntwo\n");
+
+        let missing = page_diff(&store, "guide.md", "nope").expect_err("missing revision");
+        assert!(matches!(missing, WikiServiceError::NotFound(_)));
+    }
+
     #[test]
     fn render_page_not_found_and_draft_error_cleanup_path() {
         let temp = tempfile::tempdir().expect("tempdir");
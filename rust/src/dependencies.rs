@@ -3,6 +3,8 @@
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
+use crate::config_loader::load_project_configuration;
+use crate::dependency_types::DependencyTypes;
 use crate::error::KanbusError;
 use crate::event_history::{
     dependency_payload, events_dir_for_issue_path, now_timestamp, write_events_batch, EventRecord,
@@ -10,15 +12,14 @@ use crate::event_history::{
 };
 use crate::file_io::{
     discover_kanbus_projects, discover_project_directories, find_project_local_directory,
-    load_project_directory,
+    get_configuration_path, load_project_directory,
 };
+use crate::ids::match_issue_identifier;
 use crate::issue_files::{read_issue_from_file, write_issue_to_file};
 use crate::issue_lookup::{load_issue_from_project, IssueLookupResult};
-use crate::models::{DependencyLink, IssueData};
+use crate::models::{DependencyLink, IssueData, ProjectConfiguration};
 use crate::users::get_current_user;
 
-const ALLOWED_DEPENDENCY_TYPES: [&str; 2] = ["blocked-by", "relates-to"];
-
 /// Add a dependency to an issue.
 ///
 /// # Arguments
@@ -38,12 +39,13 @@ pub fn add_dependency(
     target_id: &str,
     dependency_type: &str,
 ) -> Result<IssueData, KanbusError> {
-    validate_dependency_type(dependency_type)?;
+    let dependency_types = load_dependency_types(root)?;
+    dependency_types.validate(dependency_type)?;
     let source_lookup = load_issue_from_project(root, source_id)?;
     let target_lookup = load_issue_from_project(root, target_id)?;
 
-    // Prevent blocked-by relationships that mirror parent-child edges (cycle-like).
-    if dependency_type == "blocked-by" {
+    // Prevent cycle-checked relationships that mirror parent-child edges (cycle-like).
+    if dependency_types.checks_cycles(dependency_type) {
         if source_lookup.issue.parent.as_deref() == Some(target_id) {
             return Err(KanbusError::IssueOperation(
                 "circular dependency: cannot block on parent".to_string(),
@@ -56,8 +58,8 @@ pub fn add_dependency(
         }
     }
 
-    if dependency_type == "blocked-by" {
-        ensure_no_cycle(root, source_id, target_id)?;
+    if dependency_types.checks_cycles(dependency_type) {
+        ensure_no_cycle(root, &dependency_types, source_id, target_id)?;
     }
 
     if has_dependency(&source_lookup.issue, target_id, dependency_type) {
@@ -124,12 +126,16 @@ pub fn remove_dependency(
     target_id: &str,
     dependency_type: &str,
 ) -> Result<IssueData, KanbusError> {
-    validate_dependency_type(dependency_type)?;
+    let dependency_types = load_dependency_types(root)?;
     let IssueLookupResult {
         issue,
         issue_path,
         project_dir,
     } = load_issue_from_project(root, source_id)?;
+    // Links of types dropped from the configuration can still be removed.
+    if !has_dependency(&issue, target_id, dependency_type) {
+        dependency_types.validate(dependency_type)?;
+    }
 
     let filtered: Vec<DependencyLink> = issue
         .dependencies
@@ -176,6 +182,158 @@ pub fn remove_dependency(
     Ok(updated_issue)
 }
 
+/// An open blocker that keeps an issue from being ready.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenBlocker {
+    /// Issue carrying the blocking link (the issue itself or an ancestor).
+    pub blocked_issue: String,
+    /// Blocking issue identifier.
+    pub target: String,
+    /// Live status of the target, or `None` when it cannot be found.
+    pub status: Option<String>,
+}
+
+/// Resolves readiness against the live status of dependency targets.
+///
+/// A status counts as done when it is `closed` or belongs to the `Done`
+/// status category, so custom workflows with other terminal statuses work.
+#[derive(Debug, Default)]
+pub struct BlockerResolver {
+    issues: HashMap<String, IssueData>,
+    done_statuses: HashSet<String>,
+    dependency_types: DependencyTypes,
+}
+
+impl BlockerResolver {
+    /// Build a resolver over every known issue.
+    ///
+    /// # Arguments
+    /// * `issues` - All issues that may be dependency targets, across projects.
+    /// * `configuration` - Project configuration providing status categories and dependency types.
+    pub fn new(issues: &[IssueData], configuration: Option<&ProjectConfiguration>) -> Self {
+        let mut done_statuses = HashSet::from(["closed".to_string()]);
+        if let Some(configuration) = configuration {
+            for status in &configuration.statuses {
+                if status.category.eq_ignore_ascii_case("done") {
+                    done_statuses.insert(status.key.clone());
+                }
+            }
+        }
+        Self {
+            issues: issues
+                .iter()
+                .map(|issue| (issue.identifier.clone(), issue.clone()))
+                .collect(),
+            done_statuses,
+            dependency_types: DependencyTypes::from_configuration(configuration),
+        }
+    }
+
+    /// Return true if the status belongs to the done category.
+    pub fn is_done(&self, status: &str) -> bool {
+        self.done_statuses.contains(status)
+    }
+
+    /// Return true if the issue is not done and has no open blockers.
+    pub fn is_ready(&self, issue: &IssueData) -> bool {
+        !self.is_done(&issue.status) && self.open_blockers(issue).is_empty()
+    }
+
+    /// Find a known issue by full or abbreviated identifier.
+    pub fn find(&self, identifier: &str) -> Option<&IssueData> {
+        if let Some(issue) = self.issues.get(identifier) {
+            return Some(issue);
+        }
+        match match_issue_identifier(identifier, self.issues.values()).as_slice() {
+            [issue] => Some(*issue),
+            _ => None,
+        }
+    }
+
+    /// List the open blockers of an issue, including those inherited from ancestors.
+    ///
+    /// Targets that cannot be found are reported as open so that links to
+    /// unavailable projects never make work look ready by accident.
+    pub fn open_blockers(&self, issue: &IssueData) -> Vec<OpenBlocker> {
+        let mut blockers = Vec::new();
+        let mut visited = HashSet::new();
+        let mut current = Some(issue);
+        while let Some(node) = current {
+            if !visited.insert(node.identifier.clone()) {
+                break;
+            }
+            for dependency in &node.dependencies {
+                if !self.dependency_types.blocks(&dependency.dependency_type) {
+                    continue;
+                }
+                let status = self
+                    .issues
+                    .get(&dependency.target)
+                    .map(|target| target.status.clone());
+                if status.as_deref().is_some_and(|status| self.is_done(status)) {
+                    continue;
+                }
+                blockers.push(OpenBlocker {
+                    blocked_issue: node.identifier.clone(),
+                    target: dependency.target.clone(),
+                    status,
+                });
+            }
+            current = node
+                .parent
+                .as_ref()
+                .and_then(|parent| self.issues.get(parent));
+        }
+        blockers
+    }
+
+    /// Describe why an issue is or is not ready, following chains of open blockers.
+    pub fn explain(&self, issue: &IssueData) -> String {
+        if self.is_done(&issue.status) {
+            return format!("{} is {} (done)", issue.identifier, issue.status);
+        }
+        let blockers = self.open_blockers(issue);
+        if blockers.is_empty() {
+            return format!("{} is ready", issue.identifier);
+        }
+        let mut lines = vec![format!("{} is blocked by:", issue.identifier)];
+        let mut visited = HashSet::from([issue.identifier.clone()]);
+        self.explain_blockers(issue, &blockers, 1, &mut visited, &mut lines);
+        lines.join("\n")
+    }
+
+    fn explain_blockers(
+        &self,
+        issue: &IssueData,
+        blockers: &[OpenBlocker],
+        depth: usize,
+        visited: &mut HashSet<String>,
+        lines: &mut Vec<String>,
+    ) {
+        let indent = "  ".repeat(depth);
+        for blocker in blockers {
+            let via = if blocker.blocked_issue == issue.identifier {
+                String::new()
+            } else {
+                format!(" (via ancestor {})", blocker.blocked_issue)
+            };
+            let Some(target) = self.issues.get(&blocker.target) else {
+                lines.push(format!("{indent}{} [not found]{via}", blocker.target));
+                continue;
+            };
+            lines.push(format!(
+                "{indent}{} [{}] {}{via}",
+                target.identifier, target.status, target.title
+            ));
+            if !visited.insert(target.identifier.clone()) {
+                continue;
+            }
+            let nested = self.open_blockers(target);
+            self.explain_blockers(target, &nested, depth + 1, visited, lines);
+        }
+    }
+}
+
 /// List issues that are not blocked by dependencies.
 ///
 /// # Arguments
@@ -191,6 +349,45 @@ pub fn list_ready_issues(
     include_local: bool,
     local_only: bool,
 ) -> Result<Vec<IssueData>, KanbusError> {
+    let (issues, resolver) = load_ready_candidates(root, include_local, local_only)?;
+    Ok(issues
+        .into_iter()
+        .filter(|issue| resolver.is_ready(issue))
+        .collect())
+}
+
+/// Explain the readiness of a single issue.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `identifier` - Full or abbreviated issue identifier.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the issue cannot be found.
+pub fn explain_readiness(root: &Path, identifier: &str) -> Result<String, KanbusError> {
+    let (_, resolver) = load_ready_candidates(root, true, false)?;
+    let issue = resolver
+        .find(identifier)
+        .ok_or_else(|| KanbusError::IssueOperation("not found".to_string()))?;
+    Ok(resolver.explain(issue))
+}
+
+/// Build a resolver for issues loaded outside of project directories (e.g. Beads).
+pub fn blocker_resolver_for(root: &Path, issues: &[IssueData]) -> BlockerResolver {
+    BlockerResolver::new(issues, load_root_configuration(root).as_ref())
+}
+
+fn load_root_configuration(root: &Path) -> Option<ProjectConfiguration> {
+    get_configuration_path(root)
+        .ok()
+        .and_then(|path| load_project_configuration(&path).ok())
+}
+
+fn load_ready_candidates(
+    root: &Path,
+    include_local: bool,
+    local_only: bool,
+) -> Result<(Vec<IssueData>, BlockerResolver), KanbusError> {
     if local_only && !include_local {
         return Err(KanbusError::IssueOperation(
             "local-only conflicts with no-local".to_string(),
@@ -208,22 +405,28 @@ pub fn list_ready_issues(
         ));
     }
     let mut issues = Vec::new();
+    let mut all_issues = Vec::new();
     if projects.len() == 1 {
         let project_dir = load_project_directory(root)?;
         issues =
             load_ready_issues_for_project(root, &project_dir, include_local, local_only, false)?;
+        all_issues = load_ready_issues_for_project(root, &project_dir, true, false, false)?;
     } else {
         for project_dir in &projects {
             let project_issues =
                 load_ready_issues_for_project(root, project_dir, include_local, local_only, true)?;
             issues.extend(project_issues);
+            all_issues.extend(load_ready_issues_for_project(
+                root,
+                project_dir,
+                true,
+                false,
+                false,
+            )?);
         }
     }
-    let ready: Vec<IssueData> = issues
-        .into_iter()
-        .filter(|issue| issue.status != "closed" && !is_blocked(issue))
-        .collect();
-    Ok(ready)
+    let resolver = BlockerResolver::new(&all_issues, load_root_configuration(root).as_ref());
+    Ok((issues, resolver))
 }
 
 fn load_ready_issues_for_project(
@@ -287,20 +490,15 @@ fn load_issues_from_directory(issues_dir: &Path) -> Result<Vec<IssueData>, Kanbu
     Ok(issues)
 }
 
-fn is_blocked(issue: &IssueData) -> bool {
-    issue
-        .dependencies
-        .iter()
-        .any(|dependency| dependency.dependency_type == "blocked-by")
-}
-
-fn validate_dependency_type(dependency_type: &str) -> Result<(), KanbusError> {
-    if !ALLOWED_DEPENDENCY_TYPES.contains(&dependency_type) {
-        return Err(KanbusError::IssueOperation(
-            "invalid dependency type".to_string(),
-        ));
-    }
-    Ok(())
+/// Load the dependency types declared by the project at the root.
+///
+/// # Errors
+/// Returns `KanbusError` if the project configuration cannot be loaded.
+pub fn load_dependency_types(root: &Path) -> Result<DependencyTypes, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let configuration =
+        load_project_configuration(&get_configuration_path(project_dir.as_path())?)?;
+    Ok(DependencyTypes::from_configuration(Some(&configuration)))
 }
 
 fn has_dependency(issue: &IssueData, target_id: &str, dependency_type: &str) -> bool {
@@ -309,8 +507,13 @@ fn has_dependency(issue: &IssueData, target_id: &str, dependency_type: &str) ->
     })
 }
 
-fn ensure_no_cycle(root: &Path, source_id: &str, target_id: &str) -> Result<(), KanbusError> {
-    let mut graph = build_dependency_graph(root)?;
+fn ensure_no_cycle(
+    root: &Path,
+    dependency_types: &DependencyTypes,
+    source_id: &str,
+    target_id: &str,
+) -> Result<(), KanbusError> {
+    let mut graph = build_dependency_graph(root, dependency_types)?;
     graph
         .edges
         .entry(source_id.to_string())
@@ -326,7 +529,10 @@ struct DependencyGraph {
     edges: HashMap<String, Vec<String>>,
 }
 
-fn build_dependency_graph(root: &Path) -> Result<DependencyGraph, KanbusError> {
+fn build_dependency_graph(
+    root: &Path,
+    dependency_types: &DependencyTypes,
+) -> Result<DependencyGraph, KanbusError> {
     let project_dir = load_project_directory(root)?;
     let issues_dir = project_dir.join("issues");
     let mut edges: HashMap<String, Vec<String>> = HashMap::new();
@@ -342,7 +548,7 @@ fn build_dependency_graph(root: &Path) -> Result<DependencyGraph, KanbusError> {
         let blocked_targets: Vec<String> = issue
             .dependencies
             .iter()
-            .filter(|dependency| dependency.dependency_type == "blocked-by")
+            .filter(|dependency| dependency_types.checks_cycles(&dependency.dependency_type))
             .map(|dependency| dependency.target.clone())
             .collect();
         if !blocked_targets.is_empty() {
@@ -383,3 +589,159 @@ fn detect_cycle(graph: &DependencyGraph, start: &str) -> bool {
 
     visit(start, graph, &mut visited, &mut stack)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::collections::BTreeMap;
+
+    fn issue(id: &str, status: &str, parent: Option<&str>, blocked_by: &[&str]) -> IssueData {
+        let now = Utc::now();
+        IssueData {
+            identifier: id.to_string(),
+            title: format!("Title {id}"),
+            description: String::new(),
+            issue_type: "task".to_string(),
+            status: status.to_string(),
+            priority: 2,
+            assignee: None,
+            creator: None,
+            parent: parent.map(str::to_string),
+            labels: Vec::new(),
+            dependencies: blocked_by
+                .iter()
+                .map(|target| DependencyLink {
+                    target: target.to_string(),
+                    dependency_type: "blocked-by".to_string(),
+                })
+                .collect(),
+            comments: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            closed_at: None,
+            custom: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn closed_blockers_no_longer_block() {
+        let issues = vec![
+            issue("kanbus-a", "open", None, &["kanbus-b"]),
+            issue("kanbus-b", "closed", None, &[]),
+        ];
+        let resolver = BlockerResolver::new(&issues, None);
+        assert!(resolver.is_ready(&issues[0]));
+        assert!(!resolver.is_ready(&issues[1]));
+    }
+
+    #[test]
+    fn done_category_statuses_count_as_resolved() {
+        let mut configuration = crate::config::default_project_configuration();
+        configuration
+            .statuses
+            .push(crate::models::StatusDefinition {
+                key: "wont_fix".to_string(),
+                name: "Won't fix".to_string(),
+                category: "Done".to_string(),
+                color: None,
+                collapsed: true,
+            });
+        let issues = vec![
+            issue("kanbus-a", "open", None, &["kanbus-b"]),
+            issue("kanbus-b", "wont_fix", None, &[]),
+        ];
+        assert!(!BlockerResolver::new(&issues, None).is_ready(&issues[0]));
+        assert!(BlockerResolver::new(&issues, Some(&configuration)).is_ready(&issues[0]));
+    }
+
+    #[test]
+    fn blockers_are_inherited_from_ancestors_and_missing_targets_block() {
+        let issues = vec![
+            issue("kanbus-epic", "open", None, &["kanbus-gate"]),
+            issue("kanbus-gate", "in_progress", None, &[]),
+            issue("kanbus-task", "open", Some("kanbus-epic"), &[]),
+            issue("kanbus-other", "open", None, &["other-missing"]),
+        ];
+        let resolver = BlockerResolver::new(&issues, None);
+        let blockers = resolver.open_blockers(&issues[2]);
+        assert_eq!(
+            blockers,
+            vec![OpenBlocker {
+                blocked_issue: "kanbus-epic".to_string(),
+                target: "kanbus-gate".to_string(),
+                status: Some("in_progress".to_string()),
+            }]
+        );
+        assert!(!resolver.is_ready(&issues[3]));
+        assert_eq!(resolver.open_blockers(&issues[3])[0].status, None);
+    }
+
+    #[test]
+    fn explain_prints_chain_of_open_blockers() {
+        let issues = vec![
+            issue("kanbus-a", "open", None, &["kanbus-b", "kanbus-done"]),
+            issue("kanbus-b", "open", None, &["kanbus-c"]),
+            issue("kanbus-c", "in_progress", None, &["kanbus-a"]),
+            issue("kanbus-done", "closed", None, &[]),
+        ];
+        let resolver = BlockerResolver::new(&issues, None);
+        assert_eq!(
+            resolver.explain(&issues[0]),
+            "kanbus-a is blocked by:\n  kanbus-b [open] Title kanbus-b\n    kanbus-c [in_progress] Title kanbus-c\n      kanbus-a [open] Title kanbus-a"
+        );
+        assert_eq!(resolver.explain(&issues[3]), "kanbus-done is closed (done)");
+        assert_eq!(
+            resolver
+                .find("kanbus-do")
+                .map(|issue| issue.identifier.as_str()),
+            Some("kanbus-done")
+        );
+    }
+
+    #[test]
+    fn configured_dependency_types_decide_what_blocks() {
+        let mut configuration = crate::config::default_project_configuration();
+        configuration.dependency_types.insert(
+            "follows".to_string(),
+            crate::models::DependencyTypeDefinition {
+                inverse: Some("precedes".to_string()),
+                blocks: true,
+                cycles: true,
+                label: None,
+                inverse_label: None,
+            },
+        );
+        configuration.dependency_types.insert(
+            "duplicates".to_string(),
+            crate::models::DependencyTypeDefinition {
+                inverse: Some("duplicated-by".to_string()),
+                blocks: false,
+                cycles: false,
+                label: None,
+                inverse_label: None,
+            },
+        );
+        let mut follower = issue("kanbus-a", "open", None, &[]);
+        follower.dependencies = vec![
+            DependencyLink {
+                target: "kanbus-b".to_string(),
+                dependency_type: "follows".to_string(),
+            },
+            DependencyLink {
+                target: "kanbus-c".to_string(),
+                dependency_type: "duplicates".to_string(),
+            },
+        ];
+        let issues = vec![
+            follower,
+            issue("kanbus-b", "open", None, &[]),
+            issue("kanbus-c", "open", None, &[]),
+        ];
+        let resolver = BlockerResolver::new(&issues, Some(&configuration));
+        let blockers = resolver.open_blockers(&issues[0]);
+        assert_eq!(blockers.len(), 1);
+        assert_eq!(blockers[0].target, "kanbus-b");
+        assert!(BlockerResolver::new(&issues, None).is_ready(&issues[0]));
+    }
+}
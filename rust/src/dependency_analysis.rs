@@ -0,0 +1,652 @@
+//! Critical path and bottleneck analysis over the blocking dependency graph.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::config_loader::load_project_configuration;
+use crate::dependency_types::DependencyTypes;
+use crate::error::KanbusError;
+use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::ids::match_issue_identifier;
+use crate::issue_files::read_issue_from_file;
+use crate::models::{IssueData, ProjectConfiguration};
+
+/// Custom field holding an issue estimate.
+pub const ESTIMATE_FIELD: &str = "estimate";
+
+const DEFAULT_ESTIMATE: f64 = 1.0;
+const MAX_BOTTLENECKS: usize = 5;
+
+/// Scheduling data for one open issue in the analyzed graph.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AnalysisNode {
+    #[serde(rename = "id")]
+    pub identifier: String,
+    pub title: String,
+    pub status: String,
+    pub estimate: f64,
+    pub earliest_start: f64,
+    pub earliest_finish: f64,
+    pub slack: f64,
+}
+
+/// Blocking edge from a blocker to the issue it blocks.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct AnalysisEdge {
+    pub from: String,
+    pub to: String,
+}
+
+/// Issue that holds up downstream work.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Bottleneck {
+    #[serde(rename = "id")]
+    pub identifier: String,
+    pub title: String,
+    pub blocked_count: usize,
+    pub blocked_estimate: f64,
+}
+
+/// Result of analyzing a project's blocking dependency graph.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DependencyAnalysis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epic: Option<String>,
+    pub issues: Vec<AnalysisNode>,
+    pub edges: Vec<AnalysisEdge>,
+    pub critical_path: Vec<String>,
+    pub critical_path_length: f64,
+    pub bottlenecks: Vec<Bottleneck>,
+}
+
+/// Analyze the blocking dependency graph of a project.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `epic` - Optional issue whose descendants bound the analysis.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the epic is unknown or the graph has a cycle.
+pub fn analyze_dependencies(
+    root: &Path,
+    epic: Option<&str>,
+) -> Result<DependencyAnalysis, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let configuration =
+        load_project_configuration(&get_configuration_path(project_dir.as_path())?)?;
+    let mut issues = Vec::new();
+    for entry in fs::read_dir(project_dir.join("issues"))
+        .map_err(|error| KanbusError::Io(error.to_string()))?
+    {
+        let path = entry
+            .map_err(|error| KanbusError::Io(error.to_string()))?
+            .path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        issues.push(read_issue_from_file(&path)?);
+    }
+    analyze_issues(&issues, Some(&configuration), epic)
+}
+
+/// Analyze the blocking dependency graph of the given issues.
+///
+/// Done issues are left out, so the critical path covers remaining work only.
+/// Issues without a numeric `estimate` custom field count as one unit.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the epic is unknown or the graph has a cycle.
+pub fn analyze_issues(
+    issues: &[IssueData],
+    configuration: Option<&ProjectConfiguration>,
+    epic: Option<&str>,
+) -> Result<DependencyAnalysis, KanbusError> {
+    let by_id: BTreeMap<&str, &IssueData> = issues
+        .iter()
+        .map(|issue| (issue.identifier.as_str(), issue))
+        .collect();
+    let epic = match epic {
+        Some(epic) => Some(resolve_identifier(&by_id, epic)?),
+        None => None,
+    };
+    let done_statuses = done_statuses(configuration);
+    let dependency_types = DependencyTypes::from_configuration(configuration);
+
+    let scope: BTreeMap<&str, &IssueData> = by_id
+        .iter()
+        .filter(|(_, issue)| !done_statuses.contains(issue.status.as_str()))
+        .filter(|(_, issue)| {
+            epic.as_deref()
+                .is_none_or(|epic| is_descendant(issue, epic, &by_id))
+        })
+        .map(|(id, issue)| (*id, *issue))
+        .collect();
+
+    let mut edges = Vec::new();
+    let mut successors: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut predecessors: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (id, issue) in &scope {
+        for dependency in &issue.dependencies {
+            if !dependency_types.blocks(&dependency.dependency_type) {
+                continue;
+            }
+            // `kbs dep` stores targets as typed, so abbreviated identifiers are resolved here.
+            let Ok(target) = resolve_identifier(&by_id, &dependency.target) else {
+                continue;
+            };
+            let Some((blocker, _)) = scope.get_key_value(target.as_str()) else {
+                continue;
+            };
+            if predecessors
+                .get(id)
+                .is_some_and(|list| list.contains(blocker))
+            {
+                continue;
+            }
+            successors.entry(blocker).or_default().push(id);
+            predecessors.entry(id).or_default().push(blocker);
+            edges.push(AnalysisEdge {
+                from: blocker.to_string(),
+                to: id.to_string(),
+            });
+        }
+    }
+
+    let order = topological_order(&scope, &successors, &predecessors)?;
+    let estimates: BTreeMap<&str, f64> = scope
+        .iter()
+        .map(|(id, issue)| (*id, issue_estimate(issue)))
+        .collect();
+
+    let mut earliest_finish: BTreeMap<&str, f64> = BTreeMap::new();
+    let mut critical_predecessor: BTreeMap<&str, &str> = BTreeMap::new();
+    for id in &order {
+        let mut start = 0.0;
+        for blocker in predecessors.get(id).into_iter().flatten() {
+            let finish = earliest_finish[blocker];
+            if finish > start {
+                start = finish;
+                critical_predecessor.insert(id, blocker);
+            }
+        }
+        earliest_finish.insert(id, start + estimates[id]);
+    }
+
+    let mut critical_end: Option<&str> = None;
+    for id in &order {
+        if critical_end.is_none_or(|end| earliest_finish[id] > earliest_finish[end]) {
+            critical_end = Some(id);
+        }
+    }
+    let critical_path_length = critical_end.map_or(0.0, |end| earliest_finish[end]);
+    let mut critical_path = Vec::new();
+    let mut cursor = critical_end;
+    while let Some(id) = cursor {
+        critical_path.push(id.to_string());
+        cursor = critical_predecessor.get(id).copied();
+    }
+    critical_path.reverse();
+
+    let mut latest_finish: BTreeMap<&str, f64> = BTreeMap::new();
+    for id in order.iter().rev() {
+        let latest = successors
+            .get(id)
+            .into_iter()
+            .flatten()
+            .map(|next| latest_finish[next] - estimates[next])
+            .fold(critical_path_length, f64::min);
+        latest_finish.insert(id, latest);
+    }
+
+    let nodes = scope
+        .iter()
+        .map(|(id, issue)| AnalysisNode {
+            identifier: id.to_string(),
+            title: issue.title.clone(),
+            status: issue.status.clone(),
+            estimate: estimates[id],
+            earliest_start: earliest_finish[id] - estimates[id],
+            earliest_finish: earliest_finish[id],
+            slack: latest_finish[id] - earliest_finish[id],
+        })
+        .collect();
+
+    let mut bottlenecks: Vec<Bottleneck> = scope
+        .iter()
+        .filter_map(|(id, issue)| {
+            let downstream = downstream_of(id, &successors);
+            if downstream.is_empty() {
+                return None;
+            }
+            Some(Bottleneck {
+                identifier: id.to_string(),
+                title: issue.title.clone(),
+                blocked_count: downstream.len(),
+                blocked_estimate: downstream.iter().map(|next| estimates[next]).sum(),
+            })
+        })
+        .collect();
+    bottlenecks.sort_by(|left, right| {
+        right
+            .blocked_count
+            .cmp(&left.blocked_count)
+            .then(right.blocked_estimate.total_cmp(&left.blocked_estimate))
+            .then(left.identifier.cmp(&right.identifier))
+    });
+    bottlenecks.truncate(MAX_BOTTLENECKS);
+
+    Ok(DependencyAnalysis {
+        epic,
+        issues: nodes,
+        edges,
+        critical_path,
+        critical_path_length,
+        bottlenecks,
+    })
+}
+
+/// Render a dependency analysis in the requested format.
+///
+/// # Arguments
+/// * `analysis` - Analysis result.
+/// * `output_format` - Output format (text, json, dot, mermaid).
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if format is unsupported.
+pub fn render_dependency_analysis(
+    analysis: &DependencyAnalysis,
+    output_format: &str,
+) -> Result<String, KanbusError> {
+    match output_format {
+        "json" => serde_json::to_string_pretty(analysis)
+            .map_err(|error| KanbusError::Io(error.to_string())),
+        "dot" => Ok(render_dot(analysis)),
+        "mermaid" => Ok(render_mermaid(analysis)),
+        "text" => Ok(render_text(analysis)),
+        _ => Err(KanbusError::IssueOperation("invalid format".to_string())),
+    }
+}
+
+fn resolve_identifier(
+    by_id: &BTreeMap<&str, &IssueData>,
+    identifier: &str,
+) -> Result<String, KanbusError> {
+    if by_id.contains_key(identifier) {
+        return Ok(identifier.to_string());
+    }
+    let matches = match_issue_identifier(identifier, by_id.values().copied());
+    match matches.as_slice() {
+        [single] => Ok(single.identifier.clone()),
+        [] => Err(KanbusError::IssueOperation("not found".to_string())),
+        _ => Err(KanbusError::IssueOperation(format!(
+            "ambiguous identifier '{identifier}'"
+        ))),
+    }
+}
+
+fn done_statuses(configuration: Option<&ProjectConfiguration>) -> HashSet<&str> {
+    let mut statuses = HashSet::from(["closed"]);
+    if let Some(configuration) = configuration {
+        for status in &configuration.statuses {
+            if status.category.eq_ignore_ascii_case("done") {
+                statuses.insert(status.key.as_str());
+            }
+        }
+    }
+    statuses
+}
+
+fn is_descendant(issue: &IssueData, ancestor: &str, by_id: &BTreeMap<&str, &IssueData>) -> bool {
+    let mut visited = HashSet::new();
+    let mut parent = issue.parent.as_deref();
+    while let Some(current) = parent {
+        if current == ancestor {
+            return true;
+        }
+        if !visited.insert(current) {
+            return false;
+        }
+        parent = by_id.get(current).and_then(|issue| issue.parent.as_deref());
+    }
+    false
+}
+
+fn issue_estimate(issue: &IssueData) -> f64 {
+    let value = match issue.custom.get(ESTIMATE_FIELD) {
+        Some(serde_json::Value::Number(number)) => number.as_f64(),
+        Some(serde_json::Value::String(text)) => text.trim().parse::<f64>().ok(),
+        _ => None,
+    };
+    value
+        .filter(|estimate| estimate.is_finite() && *estimate >= 0.0)
+        .unwrap_or(DEFAULT_ESTIMATE)
+}
+
+fn topological_order<'a>(
+    scope: &BTreeMap<&'a str, &IssueData>,
+    successors: &BTreeMap<&'a str, Vec<&'a str>>,
+    predecessors: &BTreeMap<&'a str, Vec<&'a str>>,
+) -> Result<Vec<&'a str>, KanbusError> {
+    let mut remaining: BTreeMap<&str, usize> = scope
+        .keys()
+        .map(|id| (*id, predecessors.get(id).map_or(0, Vec::len)))
+        .collect();
+    let mut queue: VecDeque<&str> = remaining
+        .iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(id, _)| *id)
+        .collect();
+    let mut order = Vec::new();
+    while let Some(id) = queue.pop_front() {
+        order.push(id);
+        for next in successors.get(id).into_iter().flatten() {
+            let count = remaining.get_mut(next).expect("successor in scope");
+            *count -= 1;
+            if *count == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+    if order.len() < scope.len() {
+        let placed: HashSet<&str> = order.iter().copied().collect();
+        let cyclic: Vec<&str> = scope
+            .keys()
+            .copied()
+            .filter(|id| !placed.contains(id))
+            .collect();
+        return Err(KanbusError::IssueOperation(format!(
+            "cycle detected: {}",
+            cyclic.join(", ")
+        )));
+    }
+    Ok(order)
+}
+
+fn downstream_of<'a>(id: &str, successors: &BTreeMap<&'a str, Vec<&'a str>>) -> BTreeSet<&'a str> {
+    let mut seen = BTreeSet::new();
+    let mut stack: Vec<&str> = successors.get(id).cloned().unwrap_or_default();
+    while let Some(next) = stack.pop() {
+        if seen.insert(next) {
+            stack.extend(successors.get(next).into_iter().flatten());
+        }
+    }
+    seen
+}
+
+fn format_estimate(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    }
+}
+
+fn render_text(analysis: &DependencyAnalysis) -> String {
+    let mut lines = Vec::new();
+    if let Some(epic) = &analysis.epic {
+        lines.push(format!("Scope: {epic}"));
+    }
+    lines.push(format!(
+        "Open issues: {}, blocking links: {}",
+        analysis.issues.len(),
+        analysis.edges.len()
+    ));
+    if analysis.critical_path.is_empty() {
+        lines.push("Critical path: none".to_string());
+    } else {
+        lines.push(format!(
+            "Critical path (length {}):",
+            format_estimate(analysis.critical_path_length)
+        ));
+        for id in &analysis.critical_path {
+            if let Some(node) = analysis.issues.iter().find(|node| &node.identifier == id) {
+                lines.push(format!(
+                    "  {}  est {}  finish {}  {}",
+                    node.identifier,
+                    format_estimate(node.estimate),
+                    format_estimate(node.earliest_finish),
+                    node.title
+                ));
+            }
+        }
+    }
+    if analysis.bottlenecks.is_empty() {
+        lines.push("Bottlenecks: none".to_string());
+    } else {
+        lines.push("Bottlenecks:".to_string());
+        for bottleneck in &analysis.bottlenecks {
+            lines.push(format!(
+                "  {}  blocks {} (est {})  {}",
+                bottleneck.identifier,
+                bottleneck.blocked_count,
+                format_estimate(bottleneck.blocked_estimate),
+                bottleneck.title
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+fn critical_edges(analysis: &DependencyAnalysis) -> HashSet<(&str, &str)> {
+    analysis
+        .critical_path
+        .windows(2)
+        .map(|pair| (pair[0].as_str(), pair[1].as_str()))
+        .collect()
+}
+
+fn render_dot(analysis: &DependencyAnalysis) -> String {
+    let critical: HashSet<&str> = analysis.critical_path.iter().map(String::as_str).collect();
+    let critical_edges = critical_edges(analysis);
+    let mut lines = vec![
+        "digraph dependencies {".to_string(),
+        "  rankdir=LR;".to_string(),
+    ];
+    for node in &analysis.issues {
+        let label = format!(
+            "{}\\n{}\\nest {}",
+            node.identifier,
+            node.title.replace('"', "\\\""),
+            format_estimate(node.estimate)
+        );
+        let style = if critical.contains(node.identifier.as_str()) {
+            ", color=red, penwidth=2"
+        } else {
+            ""
+        };
+        lines.push(format!(
+            "  \"{}\" [label=\"{}\"{}];",
+            node.identifier, label, style
+        ));
+    }
+    for edge in &analysis.edges {
+        let style = if critical_edges.contains(&(edge.from.as_str(), edge.to.as_str())) {
+            " [color=red, penwidth=2]"
+        } else {
+            ""
+        };
+        lines.push(format!("  \"{}\" -> \"{}\"{};", edge.from, edge.to, style));
+    }
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+fn mermaid_id(identifier: &str) -> String {
+    identifier
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+        .collect()
+}
+
+fn render_mermaid(analysis: &DependencyAnalysis) -> String {
+    let critical_edges = critical_edges(analysis);
+    let mut lines = vec!["graph LR".to_string()];
+    for node in &analysis.issues {
+        lines.push(format!(
+            "  {}[\"{}: {} ({})\"]",
+            mermaid_id(&node.identifier),
+            node.identifier,
+            node.title.replace('"', "#quot;"),
+            format_estimate(node.estimate)
+        ));
+    }
+    for edge in &analysis.edges {
+        let arrow = if critical_edges.contains(&(edge.from.as_str(), edge.to.as_str())) {
+            "==>"
+        } else {
+            "-->"
+        };
+        lines.push(format!(
+            "  {} {} {}",
+            mermaid_id(&edge.from),
+            arrow,
+            mermaid_id(&edge.to)
+        ));
+    }
+    if !analysis.critical_path.is_empty() {
+        lines.push("  classDef critical stroke:#d33,stroke-width:3px".to_string());
+        let ids: Vec<String> = analysis
+            .critical_path
+            .iter()
+            .map(|id| mermaid_id(id))
+            .collect();
+        lines.push(format!("  class {} critical", ids.join(",")));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DependencyLink;
+    use chrono::Utc;
+
+    fn issue(id: &str, estimate: Option<f64>, blocked_by: &[&str]) -> IssueData {
+        let now = Utc::now();
+        let mut custom = BTreeMap::new();
+        if let Some(estimate) = estimate {
+            custom.insert(ESTIMATE_FIELD.to_string(), serde_json::json!(estimate));
+        }
+        IssueData {
+            identifier: id.to_string(),
+            title: format!("Title {id}"),
+            description: String::new(),
+            issue_type: "task".to_string(),
+            status: "open".to_string(),
+            priority: 2,
+            assignee: None,
+            creator: None,
+            parent: None,
+            labels: Vec::new(),
+            dependencies: blocked_by
+                .iter()
+                .map(|target| DependencyLink {
+                    target: target.to_string(),
+                    dependency_type: "blocked-by".to_string(),
+                })
+                .collect(),
+            comments: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            closed_at: None,
+            custom,
+        }
+    }
+
+    #[test]
+    fn critical_path_follows_longest_estimated_chain() {
+        let issues = vec![
+            issue("kanbus-a", Some(2.0), &[]),
+            issue("kanbus-b", Some(5.0), &["kanbus-a"]),
+            issue("kanbus-c", Some(1.0), &["kanbus-a"]),
+            issue("kanbus-d", None, &["kanbus-b", "kanbus-c"]),
+        ];
+        let analysis = analyze_issues(&issues, None, None).expect("analysis");
+        assert_eq!(
+            analysis.critical_path,
+            vec!["kanbus-a", "kanbus-b", "kanbus-d"]
+        );
+        assert_eq!(analysis.critical_path_length, 8.0);
+        let c = analysis
+            .issues
+            .iter()
+            .find(|node| node.identifier == "kanbus-c")
+            .expect("node");
+        assert_eq!(c.slack, 4.0);
+        assert_eq!(analysis.bottlenecks[0].identifier, "kanbus-a");
+        assert_eq!(analysis.bottlenecks[0].blocked_count, 3);
+        assert_eq!(analysis.bottlenecks[0].blocked_estimate, 7.0);
+    }
+
+    #[test]
+    fn epic_scope_and_done_issues_are_excluded() {
+        let mut child = issue(
+            "kanbus-child",
+            None,
+            &["kanbus-outside", "kanbus-done", "kanbus-sib"],
+        );
+        child.parent = Some("kanbus-epic".to_string());
+        let mut done = issue("kanbus-done", None, &[]);
+        done.status = "closed".to_string();
+        let mut sibling = issue("kanbus-sibling", None, &[]);
+        sibling.parent = Some("kanbus-epic".to_string());
+        let issues = vec![
+            issue("kanbus-epic", None, &[]),
+            child,
+            done,
+            issue("kanbus-outside", None, &[]),
+            sibling,
+        ];
+        let analysis = analyze_issues(&issues, None, Some("kanbus-epic")).expect("analysis");
+        assert_eq!(analysis.epic.as_deref(), Some("kanbus-epic"));
+        assert_eq!(analysis.issues.len(), 2);
+        assert_eq!(
+            analysis.edges,
+            vec![AnalysisEdge {
+                from: "kanbus-sibling".to_string(),
+                to: "kanbus-child".to_string(),
+            }]
+        );
+        assert_eq!(
+            analysis.critical_path,
+            vec!["kanbus-sibling", "kanbus-child"]
+        );
+    }
+
+    #[test]
+    fn cycles_are_reported() {
+        let issues = vec![
+            issue("kanbus-a", None, &["kanbus-b"]),
+            issue("kanbus-b", None, &["kanbus-a"]),
+        ];
+        let error = analyze_issues(&issues, None, None).expect_err("cycle");
+        assert_eq!(
+            error.to_string(),
+            "cycle detected: kanbus-a, kanbus-b".to_string()
+        );
+    }
+
+    #[test]
+    fn renders_graph_formats() {
+        let issues = vec![
+            issue("kanbus-a", Some(1.5), &[]),
+            issue("kanbus-b", None, &["kanbus-a"]),
+        ];
+        let analysis = analyze_issues(&issues, None, None).expect("analysis");
+        let dot = render_dependency_analysis(&analysis, "dot").expect("dot");
+        assert!(dot.contains("\"kanbus-a\" -> \"kanbus-b\" [color=red, penwidth=2];"));
+        let mermaid = render_dependency_analysis(&analysis, "mermaid").expect("mermaid");
+        assert!(mermaid.contains("kanbus_a ==> kanbus_b"));
+        assert!(mermaid.contains("class kanbus_a,kanbus_b critical"));
+        let text = render_dependency_analysis(&analysis, "text").expect("text");
+        assert!(text.contains("Critical path (length 2.5):"));
+        assert!(text.contains("  kanbus-a  blocks 1 (est 1)  Title kanbus-a"));
+        assert!(render_dependency_analysis(&analysis, "svg").is_err());
+    }
+}
@@ -0,0 +1,225 @@
+//! Dependency link type registry.
+
+use std::collections::BTreeMap;
+
+use crate::error::KanbusError;
+use crate::models::{DependencyTypeDefinition, ProjectConfiguration};
+
+/// Dependency types known to a project: the built-ins plus `dependency_types`.
+#[derive(Debug, Clone)]
+pub struct DependencyTypes {
+    definitions: BTreeMap<String, DependencyTypeDefinition>,
+}
+
+impl Default for DependencyTypes {
+    fn default() -> Self {
+        Self::from_configuration(None)
+    }
+}
+
+impl DependencyTypes {
+    /// Build the registry for a project configuration.
+    ///
+    /// # Arguments
+    /// * `configuration` - Project configuration, or `None` for built-ins only.
+    pub fn from_configuration(configuration: Option<&ProjectConfiguration>) -> Self {
+        let mut definitions = builtin_definitions();
+        if let Some(configuration) = configuration {
+            for (key, definition) in &configuration.dependency_types {
+                definitions
+                    .entry(key.clone())
+                    .or_insert_with(|| definition.clone());
+            }
+        }
+        Self { definitions }
+    }
+
+    /// Return the definition of a dependency type.
+    pub fn get(&self, dependency_type: &str) -> Option<&DependencyTypeDefinition> {
+        self.definitions.get(dependency_type)
+    }
+
+    /// Return true if the dependency type is declared.
+    pub fn contains(&self, dependency_type: &str) -> bool {
+        self.definitions.contains_key(dependency_type)
+    }
+
+    /// Return true if an open target of this type keeps the source from being ready.
+    pub fn blocks(&self, dependency_type: &str) -> bool {
+        self.get(dependency_type)
+            .is_some_and(|definition| definition.blocks)
+    }
+
+    /// Return true if links of this type participate in cycle detection.
+    pub fn checks_cycles(&self, dependency_type: &str) -> bool {
+        self.get(dependency_type)
+            .is_some_and(|definition| definition.cycles)
+    }
+
+    /// Display label for an outgoing link, defaulting to the type key.
+    pub fn label<'a>(&'a self, dependency_type: &'a str) -> &'a str {
+        self.get(dependency_type)
+            .and_then(|definition| definition.label.as_deref())
+            .unwrap_or(dependency_type)
+    }
+
+    /// Display label for an incoming link, seen from the target issue.
+    pub fn inverse_label(&self, dependency_type: &str) -> String {
+        match self.get(dependency_type) {
+            Some(definition) => definition
+                .inverse_label
+                .clone()
+                .or_else(|| definition.inverse.clone())
+                .unwrap_or_else(|| format!("{dependency_type} (incoming)")),
+            None => format!("{dependency_type} (incoming)"),
+        }
+    }
+
+    /// Validate that a dependency type is declared.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::IssueOperation` if the type is unknown.
+    pub fn validate(&self, dependency_type: &str) -> Result<(), KanbusError> {
+        if !self.contains(dependency_type) {
+            return Err(KanbusError::IssueOperation(
+                "invalid dependency type".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn builtin_definitions() -> BTreeMap<String, DependencyTypeDefinition> {
+    BTreeMap::from([
+        (
+            "blocked-by".to_string(),
+            DependencyTypeDefinition {
+                inverse: Some("blocks".to_string()),
+                blocks: true,
+                cycles: true,
+                label: None,
+                inverse_label: None,
+            },
+        ),
+        (
+            "relates-to".to_string(),
+            DependencyTypeDefinition {
+                inverse: Some("relates-to".to_string()),
+                blocks: false,
+                cycles: false,
+                label: None,
+                inverse_label: None,
+            },
+        ),
+    ])
+}
+
+/// Validate the `dependency_types` section of a project configuration.
+///
+/// # Arguments
+/// * `configuration` - Project configuration to check.
+/// * `errors` - Accumulator for validation messages.
+pub fn validate_dependency_types(configuration: &ProjectConfiguration, errors: &mut Vec<String>) {
+    let builtins = builtin_definitions();
+    for (key, definition) in &configuration.dependency_types {
+        if key.trim().is_empty() || key.chars().any(char::is_whitespace) {
+            errors.push(format!(
+                "dependency type '{key}' must be a non-empty name without spaces"
+            ));
+            continue;
+        }
+        if builtins.contains_key(key) {
+            errors.push(format!("dependency type '{key}' is built in"));
+            continue;
+        }
+        let Some(inverse) = definition.inverse.as_deref() else {
+            continue;
+        };
+        if inverse.trim().is_empty() || inverse.chars().any(char::is_whitespace) {
+            errors.push(format!(
+                "dependency type '{key}' inverse must be a non-empty name without spaces"
+            ));
+        } else if inverse != key
+            && (builtins.contains_key(inverse)
+                || configuration.dependency_types.contains_key(inverse))
+        {
+            errors.push(format!(
+                "dependency type '{key}' inverse '{inverse}' conflicts with a dependency type"
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::default_project_configuration;
+
+    fn definition(inverse: Option<&str>, blocks: bool, cycles: bool) -> DependencyTypeDefinition {
+        DependencyTypeDefinition {
+            inverse: inverse.map(str::to_string),
+            blocks,
+            cycles,
+            label: None,
+            inverse_label: None,
+        }
+    }
+
+    #[test]
+    fn builtins_are_always_available() {
+        let types = DependencyTypes::default();
+        assert!(types.blocks("blocked-by"));
+        assert!(types.checks_cycles("blocked-by"));
+        assert!(!types.blocks("relates-to"));
+        assert_eq!(types.inverse_label("blocked-by"), "blocks");
+        assert!(types.validate("duplicates").is_err());
+    }
+
+    #[test]
+    fn configured_types_carry_semantics_and_labels() {
+        let mut configuration = default_project_configuration();
+        let mut follows = definition(Some("precedes"), true, true);
+        follows.label = Some("follows after".to_string());
+        configuration
+            .dependency_types
+            .insert("follows".to_string(), follows);
+        configuration.dependency_types.insert(
+            "duplicates".to_string(),
+            definition(Some("duplicated-by"), false, false),
+        );
+        let types = DependencyTypes::from_configuration(Some(&configuration));
+        assert!(types.validate("follows").is_ok());
+        assert!(types.blocks("follows"));
+        assert!(!types.checks_cycles("duplicates"));
+        assert_eq!(types.label("follows"), "follows after");
+        assert_eq!(types.label("duplicates"), "duplicates");
+        assert_eq!(types.inverse_label("duplicates"), "duplicated-by");
+    }
+
+    #[test]
+    fn validation_rejects_conflicting_definitions() {
+        let mut configuration = default_project_configuration();
+        configuration.dependency_types.insert(
+            "blocked-by".to_string(),
+            definition(Some("blocks"), false, false),
+        );
+        configuration.dependency_types.insert(
+            "tests".to_string(),
+            definition(Some("relates-to"), false, false),
+        );
+        configuration
+            .dependency_types
+            .insert("has space".to_string(), definition(None, false, false));
+        let mut errors = Vec::new();
+        validate_dependency_types(&configuration, &mut errors);
+        assert_eq!(
+            errors,
+            vec![
+                "dependency type 'blocked-by' is built in".to_string(),
+                "dependency type 'has space' must be a non-empty name without spaces".to_string(),
+                "dependency type 'tests' inverse 'relates-to' conflicts with a dependency type"
+                    .to_string(),
+            ]
+        );
+    }
+}
@@ -28,6 +28,8 @@ pub enum KanbusError {
     InvalidHierarchy(String),
     /// Issue operation failed.
     IssueOperation(String),
+    /// The issue is held by another actor's claim.
+    ClaimConflict(String),
     /// Protocol validation failed.
     ProtocolError(String),
     /// Policy violation occurred.
@@ -57,6 +59,7 @@ impl Display for KanbusError {
             KanbusError::InvalidTransition(message) => write!(formatter, "{message}"),
             KanbusError::InvalidHierarchy(message) => write!(formatter, "{message}"),
             KanbusError::IssueOperation(message) => write!(formatter, "{message}"),
+            KanbusError::ClaimConflict(message) => write!(formatter, "{message}"),
             KanbusError::ProtocolError(message) => write!(formatter, "{message}"),
             KanbusError::PolicyViolation {
                 policy_file,
@@ -101,6 +104,7 @@ mod tests {
             KanbusError::InvalidTransition("transition".to_string()),
             KanbusError::InvalidHierarchy("hierarchy".to_string()),
             KanbusError::IssueOperation("issue".to_string()),
+            KanbusError::ClaimConflict("claim".to_string()),
             KanbusError::ProtocolError("protocol".to_string()),
         ];
         let rendered: Vec<String> = variants.into_iter().map(|e| e.to_string()).collect();
@@ -114,6 +118,7 @@ mod tests {
                 "transition",
                 "hierarchy",
                 "issue",
+                "claim",
                 "protocol"
             ]
         );
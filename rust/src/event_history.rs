@@ -28,6 +28,8 @@ pub enum EventType {
     IssueDeleted,
     IssueLocalized,
     IssuePromoted,
+    IssueClaimed,
+    IssueReleased,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -242,6 +244,21 @@ pub fn transfer_payload(from_location: &str, to_location: &str) -> Value {
     })
 }
 
+pub fn claim_payload(holder: &str, expires_at: &str, ttl_seconds: i64) -> Value {
+    json!({
+        "holder": holder,
+        "expires_at": expires_at,
+        "ttl_seconds": ttl_seconds,
+    })
+}
+
+pub fn release_payload(holder: &str, reason: &str) -> Value {
+    json!({
+        "holder": holder,
+        "reason": reason,
+    })
+}
+
 pub fn field_update_payload(before: &IssueData, after: &IssueData) -> Option<Value> {
     let mut changes = Map::new();
     push_change(
@@ -0,0 +1,1091 @@
+//! GitHub Issues synchronization support.
+//!
+//! Pulls ordinary GitHub issues into Kanbus issues and pushes local changes back,
+//! mapping labels, milestones and assignees. Pull requests are linked to Kanbus
+//! issues by scanning their titles and bodies for Kanbus issue keys.
+//! The token is read from the GITHUB_TOKEN or GH_TOKEN environment variable.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::error::KanbusError;
+use crate::file_io::load_project_directory;
+use crate::github_security_sync::{
+    detect_repo_from_git, parse_next_link, GITHUB_API_BASE, GITHUB_API_VERSION,
+};
+use crate::ids::{generate_issue_identifier, issue_identifier_matches, IssueIdentifierRequest};
+use crate::issue_files::{
+    issue_path_for_identifier, list_issue_identifiers, read_issue_from_file, write_issue_to_file,
+};
+use crate::models::{GithubIssuesConfiguration, IssueData};
+
+/// Result of a GitHub Issues pull operation.
+#[derive(Debug)]
+pub struct GithubIssuesPullResult {
+    pub pulled: usize,
+    pub updated: usize,
+    /// Kanbus issues whose linked pull request list changed.
+    pub linked: usize,
+}
+
+/// Result of a GitHub Issues push operation.
+#[derive(Debug)]
+pub struct GithubIssuesPushResult {
+    pub created: usize,
+    pub updated: usize,
+    pub skipped: usize,
+}
+
+/// Minimal blocking client for the GitHub REST API.
+pub(crate) struct GithubApiClient {
+    base_url: String,
+    token: String,
+    http: reqwest::blocking::Client,
+}
+
+impl GithubApiClient {
+    /// Build a client from GITHUB_TOKEN or GH_TOKEN.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::Configuration` if no token is set.
+    pub(crate) fn from_env(api_url: Option<&str>) -> Result<Self, KanbusError> {
+        let token = std::env::var("GITHUB_TOKEN")
+            .or_else(|_| std::env::var("GH_TOKEN"))
+            .map_err(|_| {
+                KanbusError::Configuration(
+                    "GITHUB_TOKEN or GH_TOKEN environment variable is not set".to_string(),
+                )
+            })?;
+        let base_url = api_url
+            .map(str::to_string)
+            .or_else(|| std::env::var("GITHUB_API_URL").ok())
+            .unwrap_or_else(|| GITHUB_API_BASE.to_string());
+        Ok(Self::new(&base_url, &token))
+    }
+
+    pub(crate) fn new(base_url: &str, token: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token: token.to_string(),
+            http: reqwest::blocking::Client::new(),
+        }
+    }
+
+    fn request(&self, method: reqwest::Method, url: &str) -> reqwest::blocking::RequestBuilder {
+        self.http
+            .request(method, url)
+            .bearer_auth(&self.token)
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
+            .header("User-Agent", "kanbus-cli")
+    }
+
+    /// Fetch every page of a list endpoint, following `Link: rel="next"` headers.
+    pub(crate) fn get_all(&self, path: &str) -> Result<Vec<Value>, KanbusError> {
+        self.get_all_with_query(path, &[])
+    }
+
+    /// Fetch every page of a list endpoint with encoded query parameters.
+    ///
+    /// The parameters are sent on the first request only; GitHub repeats them
+    /// in the `next` links it returns.
+    pub(crate) fn get_all_with_query(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Vec<Value>, KanbusError> {
+        let mut items = Vec::new();
+        let mut next_url = Some(format!("{}{path}", self.base_url));
+        let mut query = Some(query);
+        while let Some(url) = next_url {
+            let mut request = self.request(reqwest::Method::GET, &url);
+            if let Some(query) = query.take() {
+                request = request.query(query);
+            }
+            let response = request.send().map_err(|error| {
+                KanbusError::IssueOperation(format!("GitHub request failed: {error}"))
+            })?;
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().unwrap_or_default();
+                return Err(KanbusError::IssueOperation(format!(
+                    "GitHub API returned {status}: {body}"
+                )));
+            }
+            let headers = response.headers().clone();
+            let page: Vec<Value> = response.json().map_err(|error| {
+                KanbusError::IssueOperation(format!("Failed to parse GitHub response: {error}"))
+            })?;
+            items.extend(page);
+            let link = headers.get("link").and_then(|value| value.to_str().ok());
+            next_url = parse_next_link(link);
+        }
+        Ok(items)
+    }
+
+    /// Send a JSON body and return the decoded JSON response.
+    pub(crate) fn send_json(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: &Value,
+    ) -> Result<Value, KanbusError> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self
+            .request(method, &url)
+            .json(body)
+            .send()
+            .map_err(|error| {
+                KanbusError::IssueOperation(format!("GitHub request failed: {error}"))
+            })?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().unwrap_or_default();
+            return Err(KanbusError::IssueOperation(format!(
+                "GitHub API returned {status}: {body}"
+            )));
+        }
+        response.json().map_err(|error| {
+            KanbusError::IssueOperation(format!("Failed to parse GitHub response: {error}"))
+        })
+    }
+}
+
+/// Pull GitHub issues into the local Kanbus project and link pull requests.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `config` - GitHub Issues configuration from .kanbus.yml.
+/// * `project_key` - Kanbus project key (issue ID prefix).
+/// * `dry_run` - If true, print what would be done without writing any files.
+///
+/// # Errors
+/// Returns `KanbusError` if config, authentication, or file operations fail.
+pub fn pull_github_issues(
+    root: &Path,
+    config: &GithubIssuesConfiguration,
+    project_key: &str,
+    dry_run: bool,
+) -> Result<GithubIssuesPullResult, KanbusError> {
+    let client = GithubApiClient::from_env(config.api_url.as_deref())?;
+    let repo = resolve_repo(root, config)?;
+    pull_github_issues_with_client(&client, root, config, &repo, project_key, dry_run)
+}
+
+/// Push local changes of GitHub-linked Kanbus issues back to GitHub.
+///
+/// Only issues changed locally since they were last synchronized are sent.
+/// With `create`, open issues that are not linked yet are created on GitHub.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `config` - GitHub Issues configuration from .kanbus.yml.
+/// * `identifiers` - Optional issue identifiers to restrict the push to.
+/// * `create` - Create GitHub issues for unlinked open Kanbus issues.
+/// * `dry_run` - If true, print what would be done without calling the API.
+///
+/// # Errors
+/// Returns `KanbusError` if config, authentication, or file operations fail.
+pub fn push_github_issues(
+    root: &Path,
+    config: &GithubIssuesConfiguration,
+    identifiers: &[String],
+    create: bool,
+    dry_run: bool,
+) -> Result<GithubIssuesPushResult, KanbusError> {
+    let client = GithubApiClient::from_env(config.api_url.as_deref())?;
+    let repo = resolve_repo(root, config)?;
+    push_github_issues_with_client(&client, root, config, &repo, identifiers, create, dry_run)
+}
+
+fn resolve_repo(root: &Path, config: &GithubIssuesConfiguration) -> Result<String, KanbusError> {
+    config
+        .repo
+        .clone()
+        .or_else(|| detect_repo_from_git(root))
+        .ok_or_else(|| {
+            KanbusError::Configuration(
+                "could not determine GitHub repository slug (use --repo or github_issues.repo)"
+                    .to_string(),
+            )
+        })
+}
+
+fn validate_issue_state(state: &str) -> Result<(), KanbusError> {
+    let valid = ["open", "closed", "all"];
+    if valid.contains(&state) {
+        Ok(())
+    } else {
+        Err(KanbusError::Configuration(format!(
+            "invalid github issue state '{state}' (expected one of: {})",
+            valid.join(", ")
+        )))
+    }
+}
+
+fn pull_github_issues_with_client(
+    client: &GithubApiClient,
+    root: &Path,
+    config: &GithubIssuesConfiguration,
+    repo: &str,
+    project_key: &str,
+    dry_run: bool,
+) -> Result<GithubIssuesPullResult, KanbusError> {
+    validate_issue_state(&config.state)?;
+    let project_dir = load_project_directory(root)?;
+    let issues_dir = project_dir.join("issues");
+    if !issues_dir.exists() {
+        return Err(KanbusError::IssueOperation(
+            "issues directory does not exist".to_string(),
+        ));
+    }
+
+    // The issues endpoint also lists pull requests; those are only used for linking.
+    let remote_issues: Vec<Value> = client
+        .get_all(&format!(
+            "/repos/{repo}/issues?state={}&per_page=100",
+            config.state
+        ))?
+        .into_iter()
+        .filter(|item| item.get("pull_request").is_none())
+        .collect();
+
+    let mut all_existing = list_issue_identifiers(&issues_dir)?;
+    let issue_index = build_github_issue_index(&all_existing, &issues_dir, repo);
+
+    let mut pulled = 0usize;
+    let mut updated = 0usize;
+
+    for remote in &remote_issues {
+        let number = remote["number"].as_i64().unwrap_or_default();
+        if number <= 0 {
+            continue;
+        }
+        let existing_kanbus_id = issue_index.get(&number);
+        let (kanbus_id, action) = if let Some(id) = existing_kanbus_id {
+            (id.clone(), "updated")
+        } else {
+            let request = IssueIdentifierRequest {
+                title: remote["title"].as_str().unwrap_or("Untitled").to_string(),
+                existing_ids: all_existing.clone(),
+                prefix: project_key.to_string(),
+            };
+            let result = generate_issue_identifier(&request)?;
+            all_existing.insert(result.identifier.clone());
+            (result.identifier, "pulled ")
+        };
+
+        let mut issue = map_github_issue_to_kanbus(remote, config, repo);
+        issue.identifier = kanbus_id.clone();
+
+        let issue_path = issue_path_for_identifier(&issues_dir, &kanbus_id);
+        if action == "updated" {
+            if let Ok(existing) = read_issue_from_file(&issue_path) {
+                merge_local_fields(&mut issue, existing);
+            }
+        }
+
+        println!(
+            "{action}  {:<8}  {:<14}  \"{}\"",
+            format!("#{number}"),
+            short_identifier(&kanbus_id),
+            issue.title
+        );
+
+        if !dry_run {
+            write_issue_to_file(&issue, &issue_path)?;
+        }
+
+        if action == "updated" {
+            updated += 1;
+        } else {
+            pulled += 1;
+        }
+    }
+
+    let pulls = client.get_all(&format!("/repos/{repo}/pulls?state=all&per_page=100"))?;
+    let linked = link_pull_requests(&issues_dir, &all_existing, project_key, &pulls, dry_run)?;
+
+    Ok(GithubIssuesPullResult {
+        pulled,
+        updated,
+        linked,
+    })
+}
+
+fn push_github_issues_with_client(
+    client: &GithubApiClient,
+    root: &Path,
+    config: &GithubIssuesConfiguration,
+    repo: &str,
+    identifiers: &[String],
+    create: bool,
+    dry_run: bool,
+) -> Result<GithubIssuesPushResult, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let issues_dir = project_dir.join("issues");
+    let mut ids: Vec<String> = list_issue_identifiers(&issues_dir)?.into_iter().collect();
+    ids.sort();
+    if !identifiers.is_empty() {
+        ids.retain(|id| {
+            identifiers
+                .iter()
+                .any(|candidate| issue_identifier_matches(candidate, id))
+        });
+    }
+
+    let milestones: BTreeMap<String, i64> = client
+        .get_all(&format!("/repos/{repo}/milestones?state=all&per_page=100"))?
+        .iter()
+        .filter_map(|milestone| {
+            Some((
+                milestone["title"].as_str()?.to_string(),
+                milestone["number"].as_i64()?,
+            ))
+        })
+        .collect();
+
+    let mut created = 0usize;
+    let mut updated = 0usize;
+    let mut skipped = 0usize;
+
+    for id in ids {
+        let issue_path = issue_path_for_identifier(&issues_dir, &id);
+        let mut issue = read_issue_from_file(&issue_path)?;
+        let linked_repo = issue
+            .custom
+            .get("github_repository")
+            .and_then(Value::as_str);
+        let number = issue
+            .custom
+            .get("github_issue_number")
+            .and_then(Value::as_i64);
+
+        let payload = build_push_payload(&issue, config, &milestones);
+        let response = match (number, linked_repo) {
+            (Some(number), Some(linked)) if linked == repo => {
+                if !needs_push(&issue) {
+                    skipped += 1;
+                    continue;
+                }
+                println!(
+                    "updated  {:<8}  {:<14}  \"{}\"",
+                    format!("#{number}"),
+                    short_identifier(&id),
+                    issue.title
+                );
+                updated += 1;
+                if dry_run {
+                    continue;
+                }
+                client.send_json(
+                    reqwest::Method::PATCH,
+                    &format!("/repos/{repo}/issues/{number}"),
+                    &payload,
+                )?
+            }
+            (None, _) if create && issue.status != "closed" => {
+                println!(
+                    "created  {:<8}  {:<14}  \"{}\"",
+                    "#new",
+                    short_identifier(&id),
+                    issue.title
+                );
+                created += 1;
+                if dry_run {
+                    continue;
+                }
+                let mut create_payload = payload.clone();
+                if let Some(object) = create_payload.as_object_mut() {
+                    object.remove("state");
+                }
+                client.send_json(
+                    reqwest::Method::POST,
+                    &format!("/repos/{repo}/issues"),
+                    &create_payload,
+                )?
+            }
+            _ => {
+                skipped += 1;
+                continue;
+            }
+        };
+
+        record_github_link(&mut issue, &response, repo);
+        write_issue_to_file(&issue, &issue_path)?;
+    }
+
+    Ok(GithubIssuesPushResult {
+        created,
+        updated,
+        skipped,
+    })
+}
+
+/// Map a GitHub issue JSON to a Kanbus IssueData.
+fn map_github_issue_to_kanbus(
+    remote: &Value,
+    config: &GithubIssuesConfiguration,
+    repo: &str,
+) -> IssueData {
+    let labels: Vec<String> = remote["labels"]
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|label| label["name"].as_str().or_else(|| label.as_str()))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    let issue_type = labels
+        .iter()
+        .find_map(|label| config.type_mappings.get(label))
+        .cloned()
+        .unwrap_or_else(|| config.default_type.clone());
+    let assignees: Vec<String> = remote["assignees"]
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|user| user["login"].as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    let status = if remote["state"].as_str() == Some("closed") {
+        "closed"
+    } else {
+        "open"
+    };
+
+    let now = Utc::now();
+    let created_at = parse_github_datetime(&remote["created_at"]).unwrap_or(now);
+    let updated_at = parse_github_datetime(&remote["updated_at"]).unwrap_or(now);
+    let closed_at = if status == "closed" {
+        parse_github_datetime(&remote["closed_at"]).or(Some(updated_at))
+    } else {
+        None
+    };
+
+    let mut issue = IssueData {
+        identifier: String::new(),
+        title: remote["title"].as_str().unwrap_or("Untitled").to_string(),
+        description: remote["body"].as_str().unwrap_or("").to_string(),
+        issue_type,
+        status: status.to_string(),
+        priority: 2,
+        assignee: assignees.first().cloned(),
+        creator: remote["user"]["login"].as_str().map(str::to_string),
+        parent: config.parent.clone(),
+        labels,
+        dependencies: Vec::new(),
+        comments: Vec::new(),
+        created_at,
+        updated_at,
+        closed_at,
+        custom: BTreeMap::new(),
+    };
+    if !assignees.is_empty() {
+        issue
+            .custom
+            .insert("github_assignees".to_string(), json!(assignees));
+    }
+    if let Some(milestone) = remote["milestone"]["title"].as_str() {
+        issue
+            .custom
+            .insert(config.milestone_field.clone(), json!(milestone));
+    }
+    record_github_link(&mut issue, remote, repo);
+    issue
+}
+
+/// Store the GitHub identity and sync timestamp of a remote issue on a Kanbus issue.
+fn record_github_link(issue: &mut IssueData, remote: &Value, repo: &str) {
+    let custom = &mut issue.custom;
+    custom.insert("github_repository".to_string(), json!(repo));
+    if let Some(number) = remote["number"].as_i64() {
+        custom.insert("github_issue_number".to_string(), json!(number));
+    }
+    if let Some(url) = remote["html_url"].as_str() {
+        custom.insert("github_html_url".to_string(), json!(url));
+    }
+    let synced_at = parse_github_datetime(&remote["updated_at"]).unwrap_or(issue.updated_at);
+    custom.insert(
+        "github_updated_at".to_string(),
+        json!(synced_at.to_rfc3339()),
+    );
+    if issue.updated_at < synced_at {
+        issue.updated_at = synced_at;
+    }
+}
+
+/// Carry over local state that GitHub does not manage onto a freshly mapped issue.
+fn merge_local_fields(issue: &mut IssueData, existing: IssueData) {
+    issue.created_at = existing.created_at;
+    issue.priority = existing.priority;
+    issue.comments = existing.comments;
+    issue.dependencies = existing.dependencies;
+    if existing.parent.is_some() {
+        issue.parent = existing.parent;
+    }
+    for (key, value) in existing.custom {
+        issue.custom.entry(key).or_insert(value);
+    }
+}
+
+fn needs_push(issue: &IssueData) -> bool {
+    let synced_at = issue
+        .custom
+        .get("github_updated_at")
+        .and_then(parse_github_datetime);
+    synced_at.is_none_or(|synced_at| issue.updated_at > synced_at)
+}
+
+/// Build the GitHub create/update payload for a Kanbus issue.
+fn build_push_payload(
+    issue: &IssueData,
+    config: &GithubIssuesConfiguration,
+    milestones: &BTreeMap<String, i64>,
+) -> Value {
+    let mut labels = issue.labels.clone();
+    let type_label = config
+        .type_mappings
+        .iter()
+        .find(|(_, issue_type)| **issue_type == issue.issue_type)
+        .map(|(label, _)| label.clone());
+    if let Some(label) = type_label {
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+    let mut payload = json!({
+        "title": issue.title,
+        "body": issue.description,
+        "state": if issue.status == "closed" { "closed" } else { "open" },
+        "labels": labels,
+    });
+    let milestone = issue
+        .custom
+        .get(&config.milestone_field)
+        .and_then(Value::as_str)
+        .and_then(|title| milestones.get(title));
+    if let Some(number) = milestone {
+        payload["milestone"] = json!(number);
+    }
+    payload["assignees"] = json!(push_assignees(issue));
+    payload
+}
+
+/// GitHub assignees for a Kanbus issue.
+///
+/// Keeps the co-assignees recorded at the last pull while the local assignee
+/// is still the primary one; otherwise only the local assignee is sent.
+fn push_assignees(issue: &IssueData) -> Vec<String> {
+    let Some(assignee) = issue.assignee.as_deref() else {
+        return Vec::new();
+    };
+    let synced: Vec<String> = issue
+        .custom
+        .get("github_assignees")
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    if synced.first().map(String::as_str) == Some(assignee) {
+        synced
+    } else {
+        vec![assignee.to_string()]
+    }
+}
+
+fn build_github_issue_index(
+    existing_ids: &HashSet<String>,
+    issues_dir: &Path,
+    repo: &str,
+) -> BTreeMap<i64, String> {
+    let mut index = BTreeMap::new();
+    for id in existing_ids {
+        let path = issue_path_for_identifier(issues_dir, id);
+        if let Ok(issue) = read_issue_from_file(&path) {
+            let repository = issue
+                .custom
+                .get("github_repository")
+                .and_then(Value::as_str)
+                .unwrap_or("");
+            let number = issue
+                .custom
+                .get("github_issue_number")
+                .and_then(Value::as_i64)
+                .unwrap_or_default();
+            if repository == repo && number > 0 {
+                index.insert(number, id.clone());
+            }
+        }
+    }
+    index
+}
+
+/// Record pull requests that mention Kanbus issue keys on the mentioned issues.
+///
+/// Returns the number of Kanbus issues whose pull request list changed.
+fn link_pull_requests(
+    issues_dir: &Path,
+    existing_ids: &HashSet<String>,
+    project_key: &str,
+    pulls: &[Value],
+    dry_run: bool,
+) -> Result<usize, KanbusError> {
+    let mut links: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+    for pull in pulls {
+        let text = format!(
+            "{}\n{}",
+            pull["title"].as_str().unwrap_or(""),
+            pull["body"].as_str().unwrap_or("")
+        );
+        let record = pull_request_record(pull);
+        for identifier in find_referenced_issues(&text, project_key, existing_ids) {
+            let entry = links.entry(identifier).or_default();
+            if !entry.contains(&record) {
+                entry.push(record.clone());
+            }
+        }
+    }
+
+    let mut changed = 0usize;
+    for (identifier, records) in links {
+        let issue_path = issue_path_for_identifier(issues_dir, &identifier);
+        let Ok(mut issue) = read_issue_from_file(&issue_path) else {
+            continue;
+        };
+        let value = Value::Array(records);
+        if issue.custom.get("github_pull_requests") == Some(&value) {
+            continue;
+        }
+        let count = value.as_array().map_or(0, Vec::len);
+        println!(
+            "linked   {:<8}  {:<14}  \"{}\"",
+            format!("{count} PR"),
+            short_identifier(&identifier),
+            issue.title
+        );
+        // Pull request links are sync metadata, so `updated_at` is left alone
+        // and the issue is not queued for the next push.
+        issue
+            .custom
+            .insert("github_pull_requests".to_string(), value);
+        if !dry_run {
+            write_issue_to_file(&issue, &issue_path)?;
+        }
+        changed += 1;
+    }
+    Ok(changed)
+}
+
+fn pull_request_record(pull: &Value) -> Value {
+    let state = if pull["merged_at"].as_str().is_some() {
+        "merged"
+    } else {
+        pull["state"].as_str().unwrap_or("open")
+    };
+    json!({
+        "number": pull["number"].as_i64().unwrap_or_default(),
+        "title": pull["title"].as_str().unwrap_or(""),
+        "state": state,
+        "url": pull["html_url"].as_str().unwrap_or(""),
+    })
+}
+
+/// Find Kanbus issues referenced by key in free text.
+///
+/// Keys may be full identifiers, display keys, or unique prefixes; ambiguous
+/// references are ignored.
+fn find_referenced_issues(
+    text: &str,
+    project_key: &str,
+    existing_ids: &HashSet<String>,
+) -> Vec<String> {
+    let pattern = format!(
+        r"(?i)\b{}-[0-9a-f][0-9a-f-]{{5,}}",
+        regex::escape(project_key)
+    );
+    let Ok(matcher) = Regex::new(&pattern) else {
+        return Vec::new();
+    };
+    let mut referenced = Vec::new();
+    for found in matcher.find_iter(text) {
+        let candidate = found.as_str().trim_end_matches('-').to_lowercase();
+        let candidate = format!("{project_key}{}", &candidate[project_key.len()..]);
+        let matches: Vec<&String> = existing_ids
+            .iter()
+            .filter(|id| issue_identifier_matches(&candidate, id))
+            .collect();
+        if let [single] = matches.as_slice() {
+            if !referenced.contains(*single) {
+                referenced.push((*single).clone());
+            }
+        }
+    }
+    referenced.sort();
+    referenced
+}
+
+fn parse_github_datetime(value: &Value) -> Option<DateTime<Utc>> {
+    value
+        .as_str()
+        .and_then(|text| DateTime::parse_from_rfc3339(text).ok())
+        .map(|parsed| parsed.with_timezone(&Utc))
+}
+
+fn short_identifier(kanbus_id: &str) -> &str {
+    &kanbus_id[..kanbus_id
+        .len()
+        .min(kanbus_id.find('-').map_or(6, |i| i + 7))]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    type RecordedRequests = Arc<Mutex<Vec<(String, String, String)>>>;
+
+    /// Serve canned JSON responses keyed by "METHOD path-prefix" until the test ends.
+    fn spawn_github_stub(routes: Vec<(&'static str, Value)>) -> (String, RecordedRequests) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind test listener");
+        let addr = listener.local_addr().expect("read local addr");
+        let recorded: RecordedRequests = Arc::new(Mutex::new(Vec::new()));
+        let recorded_clone = Arc::clone(&recorded);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                stream
+                    .set_read_timeout(Some(Duration::from_secs(2)))
+                    .expect("set read timeout");
+                let mut buffer = Vec::new();
+                let mut chunk = [0_u8; 8192];
+                loop {
+                    let read = stream.read(&mut chunk).unwrap_or(0);
+                    buffer.extend_from_slice(&chunk[..read]);
+                    let text = String::from_utf8_lossy(&buffer).to_string();
+                    if let Some(header_end) = text.find("\r\n\r\n") {
+                        let length = text[..header_end]
+                            .lines()
+                            .find_map(|line| {
+                                line.to_ascii_lowercase()
+                                    .strip_prefix("content-length:")
+                                    .map(|value| value.trim().parse::<usize>().unwrap_or(0))
+                            })
+                            .unwrap_or(0);
+                        if buffer.len() >= header_end + 4 + length || read == 0 {
+                            break;
+                        }
+                    }
+                    if read == 0 {
+                        break;
+                    }
+                }
+                let text = String::from_utf8_lossy(&buffer).to_string();
+                let request_line = text.lines().next().unwrap_or("").to_string();
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap_or("").to_string();
+                let path = parts.next().unwrap_or("").to_string();
+                let body = text
+                    .split_once("\r\n\r\n")
+                    .map(|(_, body)| body.to_string())
+                    .unwrap_or_default();
+                recorded_clone.lock().expect("lock requests").push((
+                    method.clone(),
+                    path.clone(),
+                    body,
+                ));
+                let response_body = routes
+                    .iter()
+                    .find(|(route, _)| {
+                        let (route_method, route_path) =
+                            route.split_once(' ').expect("route format");
+                        route_method == method && path.starts_with(route_path)
+                    })
+                    .map(|(_, value)| value.to_string())
+                    .unwrap_or_else(|| "[]".to_string());
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response_body}",
+                    response_body.len()
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        (format!("http://{addr}"), recorded)
+    }
+
+    fn setup_project(temp: &TempDir) -> std::path::PathBuf {
+        let root = temp.path().to_path_buf();
+        std::fs::create_dir_all(root.join("project").join("issues")).expect("create issues");
+        let config = crate::config::default_project_configuration();
+        let yaml = serde_yaml::to_string(&config).expect("serialize config");
+        std::fs::write(root.join(".kanbus.yml"), yaml).expect("write config");
+        root
+    }
+
+    fn load_issues(root: &Path) -> Vec<IssueData> {
+        let issues_dir = root.join("project").join("issues");
+        let mut issues: Vec<IssueData> = std::fs::read_dir(&issues_dir)
+            .expect("read issues")
+            .flatten()
+            .map(|entry| read_issue_from_file(&entry.path()).expect("read issue"))
+            .collect();
+        issues.sort_by_key(|issue| issue.title.clone());
+        issues
+    }
+
+    fn configuration() -> GithubIssuesConfiguration {
+        GithubIssuesConfiguration {
+            type_mappings: BTreeMap::from([("bug".to_string(), "bug".to_string())]),
+            ..GithubIssuesConfiguration::default()
+        }
+    }
+
+    fn remote_issue(number: i64, title: &str, labels: &[&str], state: &str) -> Value {
+        json!({
+            "number": number,
+            "title": title,
+            "body": format!("Body of {title}"),
+            "state": state,
+            "labels": labels.iter().map(|name| json!({"name": name})).collect::<Vec<_>>(),
+            "assignees": [{"login": "octocat"}],
+            "user": {"login": "reporter"},
+            "milestone": {"title": "v1.0", "number": 3},
+            "html_url": format!("https://github.com/acme/widgets/issues/{number}"),
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-02T00:00:00Z",
+            "closed_at": if state == "closed" { json!("2026-01-02T00:00:00Z") } else { Value::Null },
+        })
+    }
+
+    #[test]
+    fn map_github_issue_to_kanbus_maps_labels_milestone_and_assignees() {
+        let remote = remote_issue(7, "Crash on save", &["bug", "ui"], "closed");
+        let issue = map_github_issue_to_kanbus(&remote, &configuration(), "acme/widgets");
+        assert_eq!(issue.issue_type, "bug");
+        assert_eq!(issue.status, "closed");
+        assert!(issue.closed_at.is_some());
+        assert_eq!(issue.assignee.as_deref(), Some("octocat"));
+        assert_eq!(issue.creator.as_deref(), Some("reporter"));
+        assert_eq!(issue.labels, vec!["bug".to_string(), "ui".to_string()]);
+        assert_eq!(issue.custom.get("milestone"), Some(&json!("v1.0")));
+        assert_eq!(issue.custom.get("github_issue_number"), Some(&json!(7)));
+        assert_eq!(
+            issue.custom.get("github_assignees"),
+            Some(&json!(["octocat"]))
+        );
+
+        let untyped = map_github_issue_to_kanbus(
+            &remote_issue(8, "Docs", &["docs"], "open"),
+            &configuration(),
+            "acme/widgets",
+        );
+        assert_eq!(untyped.issue_type, "task");
+        assert_eq!(untyped.status, "open");
+        assert!(untyped.closed_at.is_none());
+    }
+
+    #[test]
+    fn find_referenced_issues_resolves_unique_keys_only() {
+        let ids: HashSet<String> = HashSet::from([
+            "kanbus-0123456789ab".to_string(),
+            "kanbus-abcdef000000".to_string(),
+            "kanbus-abcdef111111".to_string(),
+        ]);
+        let found = find_referenced_issues(
+            "Fixes KANBUS-012345 and kanbus-abcdef (ambiguous)",
+            "kanbus",
+            &ids,
+        );
+        assert_eq!(found, vec!["kanbus-0123456789ab".to_string()]);
+    }
+
+    #[test]
+    fn build_push_payload_adds_type_label_and_milestone_number() {
+        let remote = remote_issue(7, "Crash on save", &["ui"], "open");
+        let mut issue = map_github_issue_to_kanbus(&remote, &configuration(), "acme/widgets");
+        issue.issue_type = "bug".to_string();
+        let milestones = BTreeMap::from([("v1.0".to_string(), 3)]);
+        let payload = build_push_payload(&issue, &configuration(), &milestones);
+        assert_eq!(payload["labels"], json!(["ui", "bug"]));
+        assert_eq!(payload["milestone"], json!(3));
+        assert_eq!(payload["state"], json!("open"));
+        assert_eq!(payload["assignees"], json!(["octocat"]));
+
+        issue.assignee = Some("hubot".to_string());
+        let payload = build_push_payload(&issue, &configuration(), &milestones);
+        assert_eq!(payload["assignees"], json!(["hubot"]));
+        issue.assignee = None;
+        let payload = build_push_payload(&issue, &configuration(), &milestones);
+        assert_eq!(payload["assignees"], json!([]));
+    }
+
+    #[test]
+    fn pull_imports_issues_links_pull_requests_and_is_idempotent() {
+        let temp = TempDir::new().expect("tempdir");
+        let root = setup_project(&temp);
+        let issues = json!([
+            remote_issue(1, "Login fails", &["bug"], "open"),
+            {"number": 2, "title": "A pull request", "pull_request": {"url": "x"}},
+        ]);
+        let (base_url, _) = spawn_github_stub(vec![
+            ("GET /repos/acme/widgets/issues", issues),
+            ("GET /repos/acme/widgets/pulls", json!([])),
+        ]);
+        let client = GithubApiClient::new(&base_url, "token");
+        let result = pull_github_issues_with_client(
+            &client,
+            &root,
+            &configuration(),
+            "acme/widgets",
+            "kanbus",
+            false,
+        )
+        .expect("pull issues");
+        assert_eq!(result.pulled, 1);
+        let imported = load_issues(&root);
+        assert_eq!(imported.len(), 1);
+        let identifier = imported[0].identifier.clone();
+        assert_eq!(imported[0].issue_type, "bug");
+
+        let short_key = crate::ids::format_issue_key(&identifier, false);
+        let pulls = json!([{
+            "number": 42,
+            "title": format!("Fix login ({short_key})"),
+            "body": null,
+            "state": "closed",
+            "merged_at": "2026-01-03T00:00:00Z",
+            "html_url": "https://github.com/acme/widgets/pull/42",
+        }]);
+        let (base_url, _) = spawn_github_stub(vec![
+            (
+                "GET /repos/acme/widgets/issues",
+                json!([remote_issue(1, "Login fails", &["bug"], "open")]),
+            ),
+            ("GET /repos/acme/widgets/pulls", pulls),
+        ]);
+        let client = GithubApiClient::new(&base_url, "token");
+        let result = pull_github_issues_with_client(
+            &client,
+            &root,
+            &configuration(),
+            "acme/widgets",
+            "kanbus",
+            false,
+        )
+        .expect("pull issues again");
+        assert_eq!(result.pulled, 0);
+        assert_eq!(result.updated, 1);
+        assert_eq!(result.linked, 1);
+        let imported = load_issues(&root);
+        assert_eq!(imported.len(), 1);
+        assert_eq!(
+            imported[0].custom.get("github_pull_requests"),
+            Some(&json!([{
+                "number": 42,
+                "title": format!("Fix login ({short_key})"),
+                "state": "merged",
+                "url": "https://github.com/acme/widgets/pull/42",
+            }]))
+        );
+        assert!(!needs_push(&imported[0]));
+    }
+
+    #[test]
+    fn push_updates_changed_issues_and_creates_unlinked_ones() {
+        let temp = TempDir::new().expect("tempdir");
+        let root = setup_project(&temp);
+        let issues_dir = root.join("project").join("issues");
+
+        let mut linked = map_github_issue_to_kanbus(
+            &remote_issue(5, "Linked", &["bug"], "open"),
+            &configuration(),
+            "acme/widgets",
+        );
+        linked.identifier = "kanbus-aaaaaa".to_string();
+        linked.status = "closed".to_string();
+        linked.updated_at = Utc::now();
+        write_issue_to_file(
+            &linked,
+            &issue_path_for_identifier(&issues_dir, "kanbus-aaaaaa"),
+        )
+        .expect("write linked");
+
+        let mut unchanged = map_github_issue_to_kanbus(
+            &remote_issue(6, "Unchanged", &[], "open"),
+            &configuration(),
+            "acme/widgets",
+        );
+        unchanged.identifier = "kanbus-bbbbbb".to_string();
+        write_issue_to_file(
+            &unchanged,
+            &issue_path_for_identifier(&issues_dir, "kanbus-bbbbbb"),
+        )
+        .expect("write unchanged");
+
+        let mut local = unchanged.clone();
+        local.identifier = "kanbus-cccccc".to_string();
+        local.title = "Local only".to_string();
+        local.custom = BTreeMap::new();
+        write_issue_to_file(
+            &local,
+            &issue_path_for_identifier(&issues_dir, "kanbus-cccccc"),
+        )
+        .expect("write local");
+
+        let (base_url, requests) = spawn_github_stub(vec![
+            ("GET /repos/acme/widgets/milestones", json!([])),
+            (
+                "PATCH /repos/acme/widgets/issues/5",
+                json!({"number": 5, "updated_at": "2030-01-01T00:00:00Z"}),
+            ),
+            (
+                "POST /repos/acme/widgets/issues",
+                json!({"number": 9, "html_url": "https://github.com/acme/widgets/issues/9", "updated_at": "2030-01-01T00:00:00Z"}),
+            ),
+        ]);
+        let client = GithubApiClient::new(&base_url, "token");
+        let result = push_github_issues_with_client(
+            &client,
+            &root,
+            &configuration(),
+            "acme/widgets",
+            &[],
+            true,
+            false,
+        )
+        .expect("push issues");
+        assert_eq!(result.updated, 1);
+        assert_eq!(result.created, 1);
+        assert_eq!(result.skipped, 1);
+
+        let recorded = requests.lock().expect("lock requests").clone();
+        let patch = recorded
+            .iter()
+            .find(|(method, _, _)| method == "PATCH")
+            .expect("patch request");
+        let patch_body: Value = serde_json::from_str(&patch.2).expect("patch body");
+        assert_eq!(patch_body["state"], json!("closed"));
+
+        let created =
+            read_issue_from_file(&issue_path_for_identifier(&issues_dir, "kanbus-cccccc"))
+                .expect("read created");
+        assert_eq!(created.custom.get("github_issue_number"), Some(&json!(9)));
+    }
+}
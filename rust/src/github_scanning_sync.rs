@@ -0,0 +1,775 @@
+//! GitHub code scanning and secret scanning synchronization support.
+//!
+//! Imports code scanning and secret scanning alerts into the same managed
+//! initiative/epic/task/sub-task hierarchy used for Dependabot alerts, and
+//! closes local issues whose alerts were dismissed, fixed, or resolved.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use chrono::Utc;
+use serde_json::Value;
+
+use crate::error::KanbusError;
+use crate::file_io::load_project_directory;
+use crate::github_issues_sync::GithubApiClient;
+use crate::github_security_sync::{
+    build_alert_index, build_manifest_task_index, detect_repo_from_git, resolve_manifest_task,
+    resolve_provider_epic, severity_to_priority, AlertProvider, ManifestTaskContext,
+};
+use crate::ids::{generate_issue_identifier, IssueIdentifierRequest};
+use crate::issue_files::{
+    issue_path_for_identifier, list_issue_identifiers, read_issue_from_file, write_issue_to_file,
+};
+use crate::models::{GithubSecurityConfiguration, IssueData};
+
+const CODE_SCANNING_PROVIDER: AlertProvider = AlertProvider {
+    name: "code-scanning",
+    epic_title: "GitHub Code Scanning Alerts",
+    epic_description: "Code scanning alerts imported from GitHub Security.",
+    task_description: "Code scanning alerts",
+};
+
+const SECRET_SCANNING_PROVIDER: AlertProvider = AlertProvider {
+    name: "secret-scanning",
+    epic_title: "GitHub Secret Scanning Alerts",
+    epic_description: "Secret scanning alerts imported from GitHub Security.",
+    task_description: "Secret scanning alerts",
+};
+
+/// Result of a code scanning or secret scanning pull operation.
+#[derive(Debug)]
+pub struct ScanningPullResult {
+    pub pulled: usize,
+    pub updated: usize,
+    pub closed: usize,
+}
+
+/// The kind of GitHub scanning alert being imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScanningKind {
+    CodeScanning,
+    SecretScanning,
+}
+
+impl ScanningKind {
+    fn provider(self) -> &'static AlertProvider {
+        match self {
+            ScanningKind::CodeScanning => &CODE_SCANNING_PROVIDER,
+            ScanningKind::SecretScanning => &SECRET_SCANNING_PROVIDER,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ScanningKind::CodeScanning => "Code Scanning",
+            ScanningKind::SecretScanning => "Secret Scanning",
+        }
+    }
+}
+
+/// Settings shared by both scanning pulls after CLI overrides are applied.
+struct ScanningPullOptions<'a> {
+    repo: &'a str,
+    project_key: &'a str,
+    min_severity: &'a str,
+    parent_epic: Option<&'a str>,
+    dry_run: bool,
+}
+
+/// Pull code scanning alerts from GitHub and create/update/close Kanbus issues.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `github_security_config` - GitHub security configuration from .kanbus.yml.
+/// * `project_key` - Kanbus project key (issue ID prefix).
+/// * `dry_run` - If true, print what would be done without writing any files.
+///
+/// # Errors
+/// Returns `KanbusError` if config, authentication, or file operations fail.
+pub fn pull_code_scanning_from_github(
+    root: &Path,
+    github_security_config: &GithubSecurityConfiguration,
+    project_key: &str,
+    dry_run: bool,
+) -> Result<ScanningPullResult, KanbusError> {
+    let client = GithubApiClient::from_env(None)?;
+    let repo = resolve_repo(root, github_security_config)?;
+    let config = github_security_config
+        .code_scanning
+        .clone()
+        .unwrap_or_default();
+    let mut query = vec![("per_page", "100")];
+    if let Some(tool) = config.tool_name.as_deref() {
+        query.push(("tool_name", tool));
+    }
+    let alerts =
+        client.get_all_with_query(&format!("/repos/{repo}/code-scanning/alerts"), &query)?;
+    let options = ScanningPullOptions {
+        repo: &repo,
+        project_key,
+        min_severity: &config.min_severity,
+        parent_epic: config.parent_epic.as_deref(),
+        dry_run,
+    };
+    import_scanning_alerts(root, ScanningKind::CodeScanning, &alerts, &options)
+}
+
+/// Pull secret scanning alerts from GitHub and create/update/close Kanbus issues.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `github_security_config` - GitHub security configuration from .kanbus.yml.
+/// * `project_key` - Kanbus project key (issue ID prefix).
+/// * `dry_run` - If true, print what would be done without writing any files.
+///
+/// # Errors
+/// Returns `KanbusError` if config, authentication, or file operations fail.
+pub fn pull_secret_scanning_from_github(
+    root: &Path,
+    github_security_config: &GithubSecurityConfiguration,
+    project_key: &str,
+    dry_run: bool,
+) -> Result<ScanningPullResult, KanbusError> {
+    let client = GithubApiClient::from_env(None)?;
+    let repo = resolve_repo(root, github_security_config)?;
+    let config = github_security_config
+        .secret_scanning
+        .clone()
+        .unwrap_or_default();
+    let alerts = client.get_all(&format!(
+        "/repos/{repo}/secret-scanning/alerts?per_page=100"
+    ))?;
+    let options = ScanningPullOptions {
+        repo: &repo,
+        project_key,
+        min_severity: &config.min_severity,
+        parent_epic: config.parent_epic.as_deref(),
+        dry_run,
+    };
+    import_scanning_alerts(root, ScanningKind::SecretScanning, &alerts, &options)
+}
+
+fn resolve_repo(
+    root: &Path,
+    github_security_config: &GithubSecurityConfiguration,
+) -> Result<String, KanbusError> {
+    github_security_config
+        .repo
+        .clone()
+        .or_else(|| detect_repo_from_git(root))
+        .ok_or_else(|| {
+            KanbusError::Configuration(
+                "could not determine GitHub repository slug (use --repo or github_security.repo)"
+                    .to_string(),
+            )
+        })
+}
+
+fn import_scanning_alerts(
+    root: &Path,
+    kind: ScanningKind,
+    alerts: &[Value],
+    options: &ScanningPullOptions<'_>,
+) -> Result<ScanningPullResult, KanbusError> {
+    let provider = kind.provider();
+    let min_priority = severity_to_priority(options.min_severity);
+
+    let project_dir = load_project_directory(root)?;
+    let issues_dir = project_dir.join("issues");
+    if !issues_dir.exists() {
+        return Err(KanbusError::IssueOperation(
+            "issues directory does not exist".to_string(),
+        ));
+    }
+
+    let mut all_existing = list_issue_identifiers(&issues_dir)?;
+    let alert_index = build_alert_index(provider, &all_existing, &issues_dir);
+    let task_index = build_manifest_task_index(provider, &all_existing, &issues_dir);
+
+    let mut closed = 0usize;
+    let mut grouped: BTreeMap<String, Vec<&Value>> = BTreeMap::new();
+    for alert in alerts {
+        let number = alert_number(alert);
+        if number <= 0 {
+            continue;
+        }
+        if is_resolved(alert) {
+            let index_key = format!("{}#{number}", options.repo);
+            if let Some(kanbus_id) = alert_index.get(&index_key) {
+                if close_resolved_alert(kind, alert, &issues_dir, kanbus_id, options.dry_run)? {
+                    closed += 1;
+                }
+            }
+            continue;
+        }
+        if severity_to_priority(&alert_severity(kind, alert)) > min_priority {
+            continue;
+        }
+        grouped
+            .entry(target_key(kind, alert))
+            .or_default()
+            .push(alert);
+    }
+
+    let mut pulled = 0usize;
+    let mut updated = 0usize;
+    if grouped.is_empty() {
+        return Ok(ScanningPullResult {
+            pulled,
+            updated,
+            closed,
+        });
+    }
+
+    let parent_epic = resolve_provider_epic(
+        provider,
+        &issues_dir,
+        options.project_key,
+        options.parent_epic,
+        options.dry_run,
+        &mut all_existing,
+    )?;
+
+    for (target, alerts_for_target) in grouped {
+        let priority = alerts_for_target
+            .iter()
+            .map(|alert| severity_to_priority(&alert_severity(kind, alert)))
+            .min()
+            .unwrap_or(3);
+        let task_ctx = ManifestTaskContext {
+            provider,
+            issues_dir: &issues_dir,
+            project_key: options.project_key,
+            repo: options.repo,
+            parent_epic: &parent_epic,
+            priority,
+            dry_run: options.dry_run,
+        };
+        let task_id = resolve_manifest_task(&task_ctx, &target, &task_index, &mut all_existing)?;
+
+        for alert in alerts_for_target {
+            let number = alert_number(alert);
+            let index_key = format!("{}#{number}", options.repo);
+            let mut issue = map_scanning_alert_to_kanbus(kind, alert, options.repo, &task_id);
+            let (kanbus_id, action) = match alert_index.get(&index_key) {
+                Some(id) => (id.clone(), "updated"),
+                None => (
+                    new_identifier(&issue.title, options.project_key, &mut all_existing)?,
+                    "pulled ",
+                ),
+            };
+            issue.identifier = kanbus_id.clone();
+
+            let issue_path = issue_path_for_identifier(&issues_dir, &kanbus_id);
+            if action == "updated" {
+                if let Ok(existing) = read_issue_from_file(&issue_path) {
+                    // Keep what people added locally; the alert only owns its own fields.
+                    issue.created_at = existing.created_at;
+                    issue.comments = existing.comments;
+                    issue.assignee = existing.assignee;
+                }
+            }
+
+            println!(
+                "{action}  [{:<8}]  {:<14}  \"{}\"",
+                alert_severity(kind, alert),
+                format!("alert#{number}"),
+                issue.title
+            );
+
+            if !options.dry_run {
+                write_issue_to_file(&issue, &issue_path)?;
+            }
+
+            if action == "updated" {
+                updated += 1;
+            } else {
+                pulled += 1;
+            }
+        }
+    }
+
+    Ok(ScanningPullResult {
+        pulled,
+        updated,
+        closed,
+    })
+}
+
+fn new_identifier(
+    title: &str,
+    project_key: &str,
+    all_existing: &mut HashSet<String>,
+) -> Result<String, KanbusError> {
+    let request = IssueIdentifierRequest {
+        title: title.to_string(),
+        existing_ids: all_existing.clone(),
+        prefix: project_key.to_string(),
+    };
+    let identifier = generate_issue_identifier(&request)?.identifier;
+    all_existing.insert(identifier.clone());
+    Ok(identifier)
+}
+
+/// Close the local issue for an alert GitHub no longer considers open.
+///
+/// Returns `true` when the issue was (or, in dry-run mode, would be) closed.
+fn close_resolved_alert(
+    kind: ScanningKind,
+    alert: &Value,
+    issues_dir: &Path,
+    kanbus_id: &str,
+    dry_run: bool,
+) -> Result<bool, KanbusError> {
+    let issue_path = issue_path_for_identifier(issues_dir, kanbus_id);
+    let Ok(mut issue) = read_issue_from_file(&issue_path) else {
+        return Ok(false);
+    };
+    if issue.status == "closed" {
+        return Ok(false);
+    }
+    let state = alert_state(alert);
+    let now = Utc::now();
+    issue.status = "closed".to_string();
+    issue.closed_at = Some(now);
+    issue.updated_at = now;
+    issue
+        .custom
+        .insert("github_alert_state".to_string(), Value::String(state));
+
+    println!(
+        "closed   [{:<8}]  {:<14}  \"{}\"",
+        alert_severity(kind, alert),
+        format!("alert#{}", alert_number(alert)),
+        issue.title
+    );
+
+    if !dry_run {
+        write_issue_to_file(&issue, &issue_path)?;
+    }
+    Ok(true)
+}
+
+fn map_scanning_alert_to_kanbus(
+    kind: ScanningKind,
+    alert: &Value,
+    repo: &str,
+    task_id: &str,
+) -> IssueData {
+    let provider = kind.provider();
+    let number = alert_number(alert);
+    let severity = alert_severity(kind, alert);
+    let state = alert_state(alert);
+    let target = target_key(kind, alert);
+    let html_url = alert["html_url"].as_str().unwrap_or("").to_string();
+
+    let mut custom = BTreeMap::new();
+    custom.insert(
+        "github_provider".to_string(),
+        Value::String(provider.name.to_string()),
+    );
+    custom.insert(
+        "github_alert_number".to_string(),
+        Value::Number(number.into()),
+    );
+    custom.insert(
+        "github_repository".to_string(),
+        Value::String(repo.to_string()),
+    );
+    custom.insert(
+        "github_manifest_path".to_string(),
+        Value::String(target.clone()),
+    );
+    custom.insert(
+        "github_severity".to_string(),
+        Value::String(severity.clone()),
+    );
+    custom.insert(
+        "github_alert_state".to_string(),
+        Value::String(state.clone()),
+    );
+    custom.insert(
+        "github_html_url".to_string(),
+        Value::String(html_url.clone()),
+    );
+
+    let (title, details) = match kind {
+        ScanningKind::CodeScanning => {
+            let rule_id = alert["rule"]["id"].as_str().unwrap_or("unknown-rule");
+            let rule_description = alert["rule"]["description"]
+                .as_str()
+                .unwrap_or("Code scanning alert");
+            let tool = alert["tool"]["name"].as_str().unwrap_or("unknown");
+            let start_line = alert["most_recent_instance"]["location"]["start_line"]
+                .as_i64()
+                .unwrap_or_default();
+            let message = alert["most_recent_instance"]["message"]["text"]
+                .as_str()
+                .unwrap_or("");
+            custom.insert(
+                "github_rule_id".to_string(),
+                Value::String(rule_id.to_string()),
+            );
+            custom.insert("github_tool".to_string(), Value::String(tool.to_string()));
+            (
+                format!("[Code Scanning] {rule_id} in {target}"),
+                format!(
+                    "## {rule_description}\n\n\
+                     **Provider:** GitHub Code Scanning\n\
+                     **Repository:** `{repo}`\n\
+                     **Alert Number:** {number}\n\
+                     **Severity:** {severity}\n\
+                     **State:** {state}\n\
+                     **Tool:** {tool}\n\
+                     **Rule:** `{rule_id}`\n\
+                     **Location:** `{target}:{start_line}`\n\n\
+                     ### Message\n\
+                     {message}"
+                ),
+            )
+        }
+        ScanningKind::SecretScanning => {
+            let secret_type = alert["secret_type"].as_str().unwrap_or("unknown");
+            let validity = alert["validity"].as_str().unwrap_or("unknown");
+            custom.insert(
+                "github_secret_type".to_string(),
+                Value::String(secret_type.to_string()),
+            );
+            (
+                format!("[Secret Scanning] {target} #{number}"),
+                format!(
+                    "## Exposed {target}\n\n\
+                     **Provider:** GitHub Secret Scanning\n\
+                     **Repository:** `{repo}`\n\
+                     **Alert Number:** {number}\n\
+                     **Severity:** {severity}\n\
+                     **State:** {state}\n\
+                     **Secret Type:** `{secret_type}`\n\
+                     **Validity:** {validity}"
+                ),
+            )
+        }
+    };
+
+    let now = Utc::now();
+    IssueData {
+        identifier: String::new(),
+        title,
+        description: format!("{details}\n\n### Reference\n- {html_url}"),
+        issue_type: "sub-task".to_string(),
+        status: "open".to_string(),
+        priority: severity_to_priority(&severity),
+        assignee: None,
+        creator: None,
+        parent: Some(task_id.to_string()),
+        labels: vec![
+            "security".to_string(),
+            "github".to_string(),
+            provider.name.to_string(),
+        ],
+        dependencies: Vec::new(),
+        comments: Vec::new(),
+        created_at: now,
+        updated_at: now,
+        closed_at: None,
+        custom,
+    }
+}
+
+fn alert_number(alert: &Value) -> i64 {
+    alert["number"].as_i64().unwrap_or_default()
+}
+
+fn alert_state(alert: &Value) -> String {
+    alert["state"].as_str().unwrap_or("open").to_string()
+}
+
+/// Code scanning alerts close as `dismissed` or `fixed`; secret scanning as `resolved`.
+fn is_resolved(alert: &Value) -> bool {
+    alert_state(alert) != "open"
+}
+
+/// Normalize an alert's severity to critical, high, medium, or low.
+///
+/// Code scanning prefers the security severity level and falls back to the
+/// rule severity (error, warning, note). Secret scanning has no severity, so
+/// secrets GitHub reports as still active are critical and all others high.
+fn alert_severity(kind: ScanningKind, alert: &Value) -> String {
+    match kind {
+        ScanningKind::CodeScanning => {
+            if let Some(level) = alert["rule"]["security_severity_level"].as_str() {
+                return level.to_lowercase();
+            }
+            match alert["rule"]["severity"].as_str().unwrap_or("") {
+                "error" => "high",
+                "warning" => "medium",
+                _ => "low",
+            }
+            .to_string()
+        }
+        ScanningKind::SecretScanning => {
+            if alert["validity"].as_str() == Some("active") {
+                "critical".to_string()
+            } else {
+                "high".to_string()
+            }
+        }
+    }
+}
+
+/// Key of the task that groups an alert: file path or secret type.
+fn target_key(kind: ScanningKind, alert: &Value) -> String {
+    let key = match kind {
+        ScanningKind::CodeScanning => alert["most_recent_instance"]["location"]["path"].as_str(),
+        ScanningKind::SecretScanning => alert["secret_type_display_name"]
+            .as_str()
+            .or_else(|| alert["secret_type"].as_str()),
+    };
+    match key {
+        Some(value) if !value.is_empty() => value.to_string(),
+        _ => format!("unknown {}", kind.label().to_lowercase()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn setup_project(temp: &TempDir) -> std::path::PathBuf {
+        let root = temp.path().to_path_buf();
+        std::fs::create_dir_all(root.join("project").join("issues")).expect("create issues");
+        let config = crate::config::default_project_configuration();
+        let yaml = serde_yaml::to_string(&config).expect("serialize config");
+        std::fs::write(root.join(".kanbus.yml"), yaml).expect("write config");
+        root
+    }
+
+    fn load_issues(root: &Path) -> Vec<IssueData> {
+        let issues_dir = root.join("project").join("issues");
+        let mut issues: Vec<IssueData> = std::fs::read_dir(&issues_dir)
+            .expect("read issues")
+            .flatten()
+            .map(|entry| read_issue_from_file(&entry.path()).expect("read issue"))
+            .collect();
+        issues.sort_by_key(|issue| issue.title.clone());
+        issues
+    }
+
+    fn options(dry_run: bool) -> ScanningPullOptions<'static> {
+        ScanningPullOptions {
+            repo: "acme/widgets",
+            project_key: "kanbus",
+            min_severity: "medium",
+            parent_epic: None,
+            dry_run,
+        }
+    }
+
+    fn code_alert(number: i64, path: &str, level: &str, state: &str) -> Value {
+        json!({
+            "number": number,
+            "state": state,
+            "html_url": format!("https://github.com/acme/widgets/security/code-scanning/{number}"),
+            "rule": {
+                "id": "js/sql-injection",
+                "description": "Database query built from user-controlled sources",
+                "security_severity_level": level
+            },
+            "tool": { "name": "CodeQL" },
+            "most_recent_instance": {
+                "location": { "path": path, "start_line": 12 },
+                "message": { "text": "This query depends on a user-provided value." }
+            }
+        })
+    }
+
+    #[test]
+    fn alert_severity_maps_rule_and_secret_validity() {
+        let rule_only = json!({ "rule": { "severity": "warning" } });
+        assert_eq!(
+            alert_severity(ScanningKind::CodeScanning, &rule_only),
+            "medium"
+        );
+        let with_level =
+            json!({ "rule": { "severity": "note", "security_severity_level": "CRITICAL" } });
+        assert_eq!(
+            alert_severity(ScanningKind::CodeScanning, &with_level),
+            "critical"
+        );
+        assert_eq!(
+            alert_severity(
+                ScanningKind::SecretScanning,
+                &json!({ "validity": "active" })
+            ),
+            "critical"
+        );
+        assert_eq!(
+            alert_severity(
+                ScanningKind::SecretScanning,
+                &json!({ "validity": "inactive" })
+            ),
+            "high"
+        );
+    }
+
+    #[test]
+    fn target_key_uses_location_path_and_secret_type() {
+        let alert = code_alert(1, "src/db.js", "high", "open");
+        assert_eq!(target_key(ScanningKind::CodeScanning, &alert), "src/db.js");
+        let secret = json!({ "secret_type": "github_pat", "secret_type_display_name": "GitHub Personal Access Token" });
+        assert_eq!(
+            target_key(ScanningKind::SecretScanning, &secret),
+            "GitHub Personal Access Token"
+        );
+        assert_eq!(
+            target_key(ScanningKind::SecretScanning, &json!({})),
+            "unknown secret scanning"
+        );
+    }
+
+    #[test]
+    fn import_builds_hierarchy_and_applies_severity_threshold() {
+        let temp = TempDir::new().expect("tempdir");
+        let root = setup_project(&temp);
+        let alerts = vec![
+            code_alert(1, "src/db.js", "high", "open"),
+            code_alert(2, "src/db.js", "critical", "open"),
+            code_alert(3, "src/ui.js", "low", "open"),
+        ];
+
+        let result =
+            import_scanning_alerts(&root, ScanningKind::CodeScanning, &alerts, &options(false))
+                .expect("import");
+        assert_eq!(result.pulled, 2);
+        assert_eq!(result.updated, 0);
+
+        let issues = load_issues(&root);
+        let titles: Vec<&str> = issues.iter().map(|issue| issue.title.as_str()).collect();
+        assert!(titles.contains(&"GitHub Security Remediation"));
+        assert!(titles.contains(&"GitHub Code Scanning Alerts"));
+        assert!(titles.contains(&"acme/widgets:src/db.js"));
+        assert!(!titles.iter().any(|title| title.contains("src/ui.js")));
+
+        let task = issues
+            .iter()
+            .find(|issue| issue.title == "acme/widgets:src/db.js")
+            .expect("task");
+        assert_eq!(task.priority, 0);
+        let sub_tasks: Vec<&IssueData> = issues
+            .iter()
+            .filter(|issue| issue.issue_type == "sub-task")
+            .collect();
+        assert_eq!(sub_tasks.len(), 2);
+        assert!(sub_tasks.iter().all(|issue| issue.parent.as_deref()
+            == Some(task.identifier.as_str())
+            && issue.labels.contains(&"code-scanning".to_string())));
+
+        let mut triaged = sub_tasks[0].clone();
+        triaged.assignee = Some("dev@example.com".into());
+        triaged.comments.push(crate::models::IssueComment {
+            id: None,
+            author: "dev@example.com".into(),
+            text: "False positive in tests only".into(),
+            created_at: Utc::now(),
+        });
+        let triaged_path =
+            issue_path_for_identifier(&root.join("project").join("issues"), &triaged.identifier);
+        write_issue_to_file(&triaged, &triaged_path).expect("write triaged");
+
+        let rerun =
+            import_scanning_alerts(&root, ScanningKind::CodeScanning, &alerts, &options(false))
+                .expect("rerun");
+        assert_eq!(rerun.pulled, 0);
+        assert_eq!(rerun.updated, 2);
+        assert_eq!(load_issues(&root).len(), issues.len());
+        let kept = read_issue_from_file(&triaged_path).expect("read triaged");
+        assert_eq!(kept.assignee.as_deref(), Some("dev@example.com"));
+        assert_eq!(kept.comments.len(), 1);
+    }
+
+    #[test]
+    fn import_closes_issues_for_dismissed_or_fixed_alerts() {
+        let temp = TempDir::new().expect("tempdir");
+        let root = setup_project(&temp);
+        let open = vec![
+            code_alert(1, "src/db.js", "high", "open"),
+            code_alert(2, "src/db.js", "high", "open"),
+        ];
+        import_scanning_alerts(&root, ScanningKind::CodeScanning, &open, &options(false))
+            .expect("import");
+
+        let resolved = vec![
+            code_alert(1, "src/db.js", "high", "dismissed"),
+            code_alert(2, "src/db.js", "high", "open"),
+            code_alert(9, "src/other.js", "high", "fixed"),
+        ];
+        let dry_run =
+            import_scanning_alerts(&root, ScanningKind::CodeScanning, &resolved, &options(true))
+                .expect("dry run");
+        assert_eq!(dry_run.closed, 1);
+        assert!(load_issues(&root)
+            .iter()
+            .filter(|issue| issue.issue_type == "sub-task")
+            .all(|issue| issue.status == "open"));
+
+        let result = import_scanning_alerts(
+            &root,
+            ScanningKind::CodeScanning,
+            &resolved,
+            &options(false),
+        )
+        .expect("close");
+        assert_eq!(result.closed, 1);
+        assert_eq!(result.updated, 1);
+        assert_eq!(result.pulled, 0);
+        let closed: Vec<IssueData> = load_issues(&root)
+            .into_iter()
+            .filter(|issue| issue.status == "closed")
+            .collect();
+        assert_eq!(closed.len(), 1);
+        assert_eq!(
+            closed[0].custom.get("github_alert_state"),
+            Some(&Value::String("dismissed".to_string()))
+        );
+        assert!(closed[0].closed_at.is_some());
+    }
+
+    #[test]
+    fn secret_scanning_alerts_use_their_own_epic() {
+        let temp = TempDir::new().expect("tempdir");
+        let root = setup_project(&temp);
+        let alerts = vec![json!({
+            "number": 4,
+            "state": "open",
+            "secret_type": "github_pat",
+            "secret_type_display_name": "GitHub Personal Access Token",
+            "validity": "active",
+            "html_url": "https://github.com/acme/widgets/security/secret-scanning/4"
+        })];
+        let result = import_scanning_alerts(
+            &root,
+            ScanningKind::SecretScanning,
+            &alerts,
+            &options(false),
+        )
+        .expect("import");
+        assert_eq!(result.pulled, 1);
+
+        let issues = load_issues(&root);
+        let sub_task = issues
+            .iter()
+            .find(|issue| issue.issue_type == "sub-task")
+            .expect("sub-task");
+        assert_eq!(
+            sub_task.title,
+            "[Secret Scanning] GitHub Personal Access Token #4"
+        );
+        assert_eq!(sub_task.priority, 0);
+        assert!(issues
+            .iter()
+            .any(|issue| issue.title == "GitHub Secret Scanning Alerts"));
+    }
+}
@@ -19,11 +19,31 @@ use crate::issue_files::{
 use crate::migration::load_beads_issues;
 use crate::models::{GithubSecurityConfiguration, IssueData};
 
-const GITHUB_API_BASE: &str = "https://api.github.com";
-const GITHUB_API_VERSION: &str = "2022-11-28";
+pub(crate) const GITHUB_API_BASE: &str = "https://api.github.com";
+pub(crate) const GITHUB_API_VERSION: &str = "2022-11-28";
 const GITHUB_SECURITY_INITIATIVE_TITLE: &str = "GitHub Security Remediation";
 const GITHUB_DEPENDABOT_EPIC_TITLE: &str = "GitHub Dependabot Alerts";
 
+/// A GitHub security alert source with its own managed epic.
+///
+/// The provider name is used as the `github_provider` custom field and as an
+/// issue label, so each provider owns a separate epic/task/sub-task subtree
+/// under the shared security initiative.
+#[derive(Debug)]
+pub(crate) struct AlertProvider {
+    pub(crate) name: &'static str,
+    pub(crate) epic_title: &'static str,
+    pub(crate) epic_description: &'static str,
+    pub(crate) task_description: &'static str,
+}
+
+pub(crate) const DEPENDABOT_PROVIDER: AlertProvider = AlertProvider {
+    name: "dependabot",
+    epic_title: GITHUB_DEPENDABOT_EPIC_TITLE,
+    epic_description: "Dependabot alerts imported from GitHub Security.",
+    task_description: "Dependabot alerts",
+};
+
 /// Result of a Dependabot pull operation.
 #[derive(Debug)]
 pub struct DependabotPullResult {
@@ -80,10 +100,11 @@ pub fn pull_dependabot_from_github(
         .collect();
 
     let mut all_existing = list_issue_identifiers(&issues_dir)?;
-    let alert_index = build_alert_index(&all_existing, &issues_dir);
-    let task_index = build_manifest_task_index(&all_existing, &issues_dir);
+    let alert_index = build_alert_index(&DEPENDABOT_PROVIDER, &all_existing, &issues_dir);
+    let task_index = build_manifest_task_index(&DEPENDABOT_PROVIDER, &all_existing, &issues_dir);
 
-    let parent_epic = resolve_dependabot_epic(
+    let parent_epic = resolve_provider_epic(
+        &DEPENDABOT_PROVIDER,
         &issues_dir,
         project_key,
         dependabot_config.parent_epic.as_deref(),
@@ -105,6 +126,7 @@ pub fn pull_dependabot_from_github(
 
     for (target_key, alerts_for_target) in grouped {
         let task_ctx = ManifestTaskContext {
+            provider: &DEPENDABOT_PROVIDER,
             issues_dir: &issues_dir,
             project_key,
             repo: &repo,
@@ -343,7 +365,12 @@ fn validate_dependabot_state(state: &str) -> Result<(), KanbusError> {
     }
 }
 
-fn resolve_dependabot_epic(
+/// Resolve (or create) the managed epic for an alert provider.
+///
+/// A configured epic wins when it exists; otherwise the latest provider epic
+/// under the security initiative is reused, creating both when missing.
+pub(crate) fn resolve_provider_epic(
+    provider: &AlertProvider,
     issues_dir: &Path,
     project_key: &str,
     configured_id: Option<&str>,
@@ -360,13 +387,14 @@ fn resolve_dependabot_epic(
     let initiative_id =
         resolve_security_initiative(issues_dir, project_key, dry_run, all_existing)?;
 
-    if let Some(existing) = find_existing_dependabot_epic(issues_dir, all_existing, &initiative_id)
+    if let Some(existing) =
+        find_existing_provider_epic(provider, issues_dir, all_existing, &initiative_id)
     {
         return Ok(existing);
     }
 
     let request = IssueIdentifierRequest {
-        title: GITHUB_DEPENDABOT_EPIC_TITLE.to_string(),
+        title: provider.epic_title.to_string(),
         existing_ids: all_existing.clone(),
         prefix: project_key.to_string(),
     };
@@ -377,8 +405,8 @@ fn resolve_dependabot_epic(
     let now = Utc::now();
     let epic = IssueData {
         identifier: epic_id.clone(),
-        title: GITHUB_DEPENDABOT_EPIC_TITLE.to_string(),
-        description: "Dependabot alerts imported from GitHub Security.".to_string(),
+        title: provider.epic_title.to_string(),
+        description: provider.epic_description.to_string(),
         issue_type: "epic".to_string(),
         status: "open".to_string(),
         priority: 1,
@@ -388,7 +416,7 @@ fn resolve_dependabot_epic(
         labels: vec![
             "security".to_string(),
             "github".to_string(),
-            "dependabot".to_string(),
+            provider.name.to_string(),
         ],
         dependencies: Vec::new(),
         comments: Vec::new(),
@@ -398,7 +426,7 @@ fn resolve_dependabot_epic(
         custom: BTreeMap::new(),
     };
 
-    println!("created  [epic    ]  \"{GITHUB_DEPENDABOT_EPIC_TITLE}\"");
+    println!("created  [epic    ]  \"{}\"", provider.epic_title);
 
     if !dry_run {
         let path = issue_path_for_identifier(issues_dir, &epic_id);
@@ -487,7 +515,8 @@ fn find_existing_security_initiative(
     best_id
 }
 
-fn find_existing_dependabot_epic(
+fn find_existing_provider_epic(
+    provider: &AlertProvider,
     issues_dir: &Path,
     all_existing: &HashSet<String>,
     parent_initiative: &str,
@@ -501,10 +530,10 @@ fn find_existing_dependabot_epic(
             if issue.issue_type != "epic" {
                 continue;
             }
-            if issue.title != GITHUB_DEPENDABOT_EPIC_TITLE {
+            if issue.title != provider.epic_title {
                 continue;
             }
-            if !issue.labels.iter().any(|l| l == "dependabot") {
+            if !issue.labels.iter().any(|l| l == provider.name) {
                 continue;
             }
             if issue.parent.as_deref() != Some(parent_initiative) {
@@ -521,7 +550,8 @@ fn find_existing_dependabot_epic(
     best_id
 }
 
-fn resolve_manifest_task(
+/// Resolve (or create) the task that groups a provider's alerts for one target.
+pub(crate) fn resolve_manifest_task(
     ctx: &ManifestTaskContext<'_>,
     target_key: &str,
     task_index: &BTreeMap<String, String>,
@@ -544,8 +574,8 @@ fn resolve_manifest_task(
                     issue.labels.push("github".to_string());
                     changed = true;
                 }
-                if !issue.labels.iter().any(|l| l == "dependabot") {
-                    issue.labels.push("dependabot".to_string());
+                if !issue.labels.iter().any(|l| l == ctx.provider.name) {
+                    issue.labels.push(ctx.provider.name.to_string());
                     changed = true;
                 }
                 if !issue.labels.iter().any(|l| l == "security") {
@@ -576,7 +606,7 @@ fn resolve_manifest_task(
     let mut custom = BTreeMap::new();
     custom.insert(
         "github_provider".to_string(),
-        Value::String("dependabot".to_string()),
+        Value::String(ctx.provider.name.to_string()),
     );
     custom.insert(
         "github_repository".to_string(),
@@ -590,7 +620,7 @@ fn resolve_manifest_task(
     let task = IssueData {
         identifier: task_id.clone(),
         title: title.clone(),
-        description: format!("Dependabot alerts for `{target_key}`."),
+        description: format!("{} for `{target_key}`.", ctx.provider.task_description),
         issue_type: "task".to_string(),
         status: "open".to_string(),
         priority: ctx.priority,
@@ -600,7 +630,7 @@ fn resolve_manifest_task(
         labels: vec![
             "security".to_string(),
             "github".to_string(),
-            "dependabot".to_string(),
+            ctx.provider.name.to_string(),
         ],
         dependencies: Vec::new(),
         comments: Vec::new(),
@@ -619,13 +649,14 @@ fn resolve_manifest_task(
     Ok(task_id)
 }
 
-struct ManifestTaskContext<'a> {
-    issues_dir: &'a Path,
-    project_key: &'a str,
-    repo: &'a str,
-    parent_epic: &'a str,
-    priority: i32,
-    dry_run: bool,
+pub(crate) struct ManifestTaskContext<'a> {
+    pub(crate) provider: &'a AlertProvider,
+    pub(crate) issues_dir: &'a Path,
+    pub(crate) project_key: &'a str,
+    pub(crate) repo: &'a str,
+    pub(crate) parent_epic: &'a str,
+    pub(crate) priority: i32,
+    pub(crate) dry_run: bool,
 }
 
 fn map_dependabot_to_kanbus(alert: &Value, repo: &str, task_id: &str) -> IssueData {
@@ -734,7 +765,9 @@ fn map_dependabot_to_kanbus(alert: &Value, repo: &str, task_id: &str) -> IssueDa
     }
 }
 
-fn build_alert_index(
+/// Index existing alert sub-tasks of a provider by `repository#number`.
+pub(crate) fn build_alert_index(
+    provider: &AlertProvider,
     existing_ids: &HashSet<String>,
     issues_dir: &Path,
 ) -> BTreeMap<String, String> {
@@ -742,7 +775,7 @@ fn build_alert_index(
     for id in existing_ids {
         let path = issue_path_for_identifier(issues_dir, id);
         if let Ok(issue) = read_issue_from_file(&path) {
-            let provider = issue
+            let issue_provider = issue
                 .custom
                 .get("github_provider")
                 .and_then(Value::as_str)
@@ -757,7 +790,7 @@ fn build_alert_index(
                 .get("github_repository")
                 .and_then(Value::as_str)
                 .unwrap_or("");
-            if provider == "dependabot" && number > 0 && !repository.is_empty() {
+            if issue_provider == provider.name && number > 0 && !repository.is_empty() {
                 index.insert(format!("{repository}#{number}"), id.clone());
             }
         }
@@ -765,7 +798,9 @@ fn build_alert_index(
     index
 }
 
-fn build_manifest_task_index(
+/// Index existing grouping tasks of a provider by target key.
+pub(crate) fn build_manifest_task_index(
+    provider: &AlertProvider,
     existing_ids: &HashSet<String>,
     issues_dir: &Path,
 ) -> BTreeMap<String, String> {
@@ -776,7 +811,7 @@ fn build_manifest_task_index(
             if issue.issue_type != "task" {
                 continue;
             }
-            let provider = issue
+            let issue_provider = issue
                 .custom
                 .get("github_provider")
                 .and_then(Value::as_str)
@@ -786,7 +821,7 @@ fn build_manifest_task_index(
                 .get("github_manifest_path")
                 .and_then(Value::as_str)
                 .unwrap_or("");
-            if provider == "dependabot" && !manifest.is_empty() {
+            if issue_provider == provider.name && !manifest.is_empty() {
                 index.insert(manifest.to_string(), id.clone());
             }
         }
@@ -839,7 +874,7 @@ fn fetch_dependabot_alerts(
     Ok(alerts)
 }
 
-fn parse_next_link(link_header: Option<&str>) -> Option<String> {
+pub(crate) fn parse_next_link(link_header: Option<&str>) -> Option<String> {
     let header = link_header?;
     for part in header.split(',') {
         let trimmed = part.trim();
@@ -926,7 +961,7 @@ fn dependabot_alert_title(alert: &Value) -> String {
     format!("[Dependabot] {advisory} in {package} #{number}")
 }
 
-fn severity_to_priority(severity: &str) -> i32 {
+pub(crate) fn severity_to_priority(severity: &str) -> i32 {
     match severity.to_lowercase().as_str() {
         "critical" => 0,
         "high" => 1,
@@ -1217,7 +1252,7 @@ fn map_dependabot_to_beads_description(alert: &Value, repo: &str) -> String {
     append_marker(&description, &metadata_marker_alert(repo, number))
 }
 
-fn detect_repo_from_git(root: &Path) -> Option<String> {
+pub(crate) fn detect_repo_from_git(root: &Path) -> Option<String> {
     let output = std::process::Command::new("git")
         .args(["remote", "get-url", "origin"])
         .current_dir(root)
@@ -1427,8 +1462,8 @@ mod tests {
             "kbs-3".to_string(),
         ]);
 
-        let alert_index = build_alert_index(&ids, &issues_dir);
-        let task_index = build_manifest_task_index(&ids, &issues_dir);
+        let alert_index = build_alert_index(&DEPENDABOT_PROVIDER, &ids, &issues_dir);
+        let task_index = build_manifest_task_index(&DEPENDABOT_PROVIDER, &ids, &issues_dir);
 
         assert_eq!(
             alert_index.get("example/repo#10"),
@@ -1474,6 +1509,7 @@ mod tests {
         let task_index = BTreeMap::from([("Cargo.toml".to_string(), issue.identifier.clone())]);
         let mut all_existing = HashSet::from([issue.identifier.clone()]);
         let ctx = ManifestTaskContext {
+            provider: &DEPENDABOT_PROVIDER,
             issues_dir: &issues_dir,
             project_key: "kbs",
             repo: "example/repo",
@@ -1568,6 +1604,7 @@ mod tests {
         let task_index = BTreeMap::new();
         let mut all_existing = HashSet::new();
         let ctx = ManifestTaskContext {
+            provider: &DEPENDABOT_PROVIDER,
             issues_dir: &issues_dir,
             project_key: "kbs",
             repo: "example/repo",
@@ -1653,7 +1690,7 @@ mod tests {
     }
 
     #[test]
-    fn find_existing_dependabot_epic_filters_by_parent_and_label() -> Result<(), KanbusError> {
+    fn find_existing_provider_epic_filters_by_parent_and_label() -> Result<(), KanbusError> {
         let temp = TempDir::new().expect("tempdir");
         let issues_dir = temp.path().join("issues");
         fs::create_dir_all(&issues_dir).expect("issues dir");
@@ -1704,7 +1741,7 @@ mod tests {
             newer_match.identifier.clone(),
         ]);
         assert_eq!(
-            find_existing_dependabot_epic(&issues_dir, &ids, "kbs-init"),
+            find_existing_provider_epic(&DEPENDABOT_PROVIDER, &issues_dir, &ids, "kbs-init"),
             Some("kbs-epic.3".to_string())
         );
         Ok(())
@@ -1759,7 +1796,7 @@ mod tests {
     }
 
     #[test]
-    fn resolve_dependabot_epic_prefers_existing_and_configured_issue() -> Result<(), KanbusError> {
+    fn resolve_provider_epic_prefers_existing_and_configured_issue() -> Result<(), KanbusError> {
         let temp = TempDir::new().expect("tempdir");
         let issues_dir = temp.path().join("issues");
         fs::create_dir_all(&issues_dir).expect("issues dir");
@@ -1811,12 +1848,24 @@ mod tests {
         )?;
 
         let mut existing = HashSet::from([initiative.identifier.clone(), epic.identifier.clone()]);
-        let resolved_existing =
-            resolve_dependabot_epic(&issues_dir, "kbs", None, false, &mut existing)?;
+        let resolved_existing = resolve_provider_epic(
+            &DEPENDABOT_PROVIDER,
+            &issues_dir,
+            "kbs",
+            None,
+            false,
+            &mut existing,
+        )?;
         assert_eq!(resolved_existing, epic.identifier);
 
-        let resolved_configured =
-            resolve_dependabot_epic(&issues_dir, "kbs", Some("kbs-epic.9"), false, &mut existing)?;
+        let resolved_configured = resolve_provider_epic(
+            &DEPENDABOT_PROVIDER,
+            &issues_dir,
+            "kbs",
+            Some("kbs-epic.9"),
+            false,
+            &mut existing,
+        )?;
         assert_eq!(resolved_configured, "kbs-epic.9");
         Ok(())
     }
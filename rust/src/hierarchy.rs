@@ -1,10 +1,16 @@
 //! Hierarchy validation for parent-child relationships.
 
+use std::collections::{BTreeMap, HashSet};
+
 use crate::error::KanbusError;
-use crate::models::ProjectConfiguration;
+use crate::models::{IssueData, ProjectConfiguration};
 
 /// Return the allowed child types for a parent issue type.
 ///
+/// When `hierarchy_rules.children` is declared it is authoritative: parent
+/// types it does not list may not have children. Otherwise each level of the
+/// linear `hierarchy` may contain the next level plus all free `types`.
+///
 /// # Arguments
 /// * `configuration` - Project configuration containing hierarchy rules.
 /// * `parent_type` - Parent issue type to validate.
@@ -15,6 +21,14 @@ pub fn get_allowed_child_types(
     configuration: &ProjectConfiguration,
     parent_type: &str,
 ) -> Vec<String> {
+    if let Some(rules) = configuration
+        .hierarchy_rules
+        .as_ref()
+        .filter(|rules| !rules.children.is_empty())
+    {
+        return rules.children.get(parent_type).cloned().unwrap_or_default();
+    }
+
     let parent_index = configuration
         .hierarchy
         .iter()
@@ -54,3 +68,281 @@ pub fn validate_parent_child_relationship(
     }
     Ok(())
 }
+
+/// Return true if the project limits hierarchy depth or child counts.
+pub fn has_hierarchy_limits(configuration: &ProjectConfiguration) -> bool {
+    configuration
+        .hierarchy_rules
+        .as_ref()
+        .is_some_and(|rules| rules.max_depth.is_some() || !rules.max_children.is_empty())
+}
+
+/// Validate that a parent of the given type may hold `child_count` children.
+///
+/// # Errors
+/// Returns `KanbusError::InvalidHierarchy` if the per-type child limit is exceeded.
+pub fn validate_child_limit(
+    configuration: &ProjectConfiguration,
+    parent_type: &str,
+    parent_identifier: &str,
+    child_count: usize,
+) -> Result<(), KanbusError> {
+    let limit = configuration
+        .hierarchy_rules
+        .as_ref()
+        .and_then(|rules| rules.max_children.get(parent_type));
+    match limit {
+        Some(limit) if child_count > *limit => Err(KanbusError::InvalidHierarchy(format!(
+            "'{parent_type}' {parent_identifier} cannot have more than {limit} children"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Validate that a parent chain of `depth` levels is permitted.
+///
+/// # Errors
+/// Returns `KanbusError::InvalidHierarchy` if the maximum depth is exceeded.
+pub fn validate_hierarchy_depth(
+    configuration: &ProjectConfiguration,
+    depth: usize,
+) -> Result<(), KanbusError> {
+    let max_depth = configuration
+        .hierarchy_rules
+        .as_ref()
+        .and_then(|rules| rules.max_depth);
+    match max_depth {
+        Some(max_depth) if depth > max_depth => Err(KanbusError::InvalidHierarchy(format!(
+            "hierarchy depth {depth} exceeds the maximum of {max_depth}"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Validate depth and child limits for placing an issue under a parent.
+///
+/// # Arguments
+/// * `configuration` - Project configuration containing hierarchy rules.
+/// * `parent` - Prospective parent issue.
+/// * `child_identifier` - Identifier of the issue being placed, or `None` for a new issue.
+/// * `issues` - Issues of the project, used to count siblings and measure depth.
+///
+/// # Errors
+/// Returns `KanbusError::InvalidHierarchy` if a limit would be exceeded.
+pub fn validate_hierarchy_placement(
+    configuration: &ProjectConfiguration,
+    parent: &IssueData,
+    child_identifier: Option<&str>,
+    issues: &[IssueData],
+) -> Result<(), KanbusError> {
+    let siblings = issues
+        .iter()
+        .filter(|issue| issue.parent.as_deref() == Some(parent.identifier.as_str()))
+        .filter(|issue| Some(issue.identifier.as_str()) != child_identifier)
+        .count();
+    validate_child_limit(
+        configuration,
+        &parent.issue_type,
+        &parent.identifier,
+        siblings + 1,
+    )?;
+
+    let by_identifier: BTreeMap<&str, &IssueData> = issues
+        .iter()
+        .map(|issue| (issue.identifier.as_str(), issue))
+        .collect();
+    let parent_depth = ancestor_depth(&parent.identifier, &by_identifier);
+    let subtree_height = child_identifier
+        .map(|identifier| subtree_height(identifier, issues, &mut HashSet::new()))
+        .unwrap_or(1);
+    validate_hierarchy_depth(configuration, parent_depth + subtree_height)
+}
+
+/// Count the levels from an issue up to its top-level ancestor, inclusive.
+pub fn ancestor_depth(identifier: &str, issues: &BTreeMap<&str, &IssueData>) -> usize {
+    let mut depth = 1;
+    let mut seen: HashSet<&str> = HashSet::from([identifier]);
+    let mut current = issues
+        .get(identifier)
+        .and_then(|issue| issue.parent.as_deref());
+    while let Some(parent) = current {
+        if !seen.insert(parent) {
+            break;
+        }
+        depth += 1;
+        current = issues.get(parent).and_then(|issue| issue.parent.as_deref());
+    }
+    depth
+}
+
+fn subtree_height<'a>(
+    identifier: &'a str,
+    issues: &'a [IssueData],
+    seen: &mut HashSet<&'a str>,
+) -> usize {
+    if !seen.insert(identifier) {
+        return 0;
+    }
+    1 + issues
+        .iter()
+        .filter(|issue| issue.parent.as_deref() == Some(identifier))
+        .map(|child| subtree_height(&child.identifier, issues, seen))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Validate the `hierarchy_rules` section of a project configuration.
+///
+/// # Arguments
+/// * `configuration` - Project configuration to check.
+/// * `errors` - Accumulator for validation messages.
+pub fn validate_hierarchy_rules(configuration: &ProjectConfiguration, errors: &mut Vec<String>) {
+    let Some(rules) = &configuration.hierarchy_rules else {
+        return;
+    };
+    let known: HashSet<&str> = configuration
+        .hierarchy
+        .iter()
+        .chain(configuration.types.iter())
+        .map(String::as_str)
+        .collect();
+    let mut unknown: Vec<&str> = rules
+        .children
+        .iter()
+        .flat_map(|(parent, children)| {
+            std::iter::once(parent.as_str()).chain(children.iter().map(String::as_str))
+        })
+        .chain(rules.max_children.keys().map(String::as_str))
+        .filter(|issue_type| !known.contains(issue_type))
+        .collect();
+    unknown.sort_unstable();
+    unknown.dedup();
+    for issue_type in unknown {
+        errors.push(format!(
+            "hierarchy_rules references unknown issue type '{issue_type}'"
+        ));
+    }
+    if rules.max_depth == Some(0) {
+        errors.push("hierarchy_rules max_depth must be at least 1".to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::default_project_configuration;
+    use crate::models::HierarchyRules;
+    use chrono::{TimeZone, Utc};
+
+    fn issue(identifier: &str, issue_type: &str, parent: Option<&str>) -> IssueData {
+        let timestamp = Utc.with_ymd_and_hms(2026, 3, 6, 0, 0, 0).unwrap();
+        IssueData {
+            identifier: identifier.to_string(),
+            title: identifier.to_string(),
+            description: String::new(),
+            issue_type: issue_type.to_string(),
+            status: "open".to_string(),
+            priority: 2,
+            assignee: None,
+            creator: None,
+            parent: parent.map(str::to_string),
+            labels: Vec::new(),
+            dependencies: Vec::new(),
+            comments: Vec::new(),
+            created_at: timestamp,
+            updated_at: timestamp,
+            closed_at: None,
+            custom: BTreeMap::new(),
+        }
+    }
+
+    fn graph_configuration() -> ProjectConfiguration {
+        let mut configuration = default_project_configuration();
+        configuration.types.push("spike".to_string());
+        configuration.hierarchy_rules = Some(HierarchyRules {
+            children: BTreeMap::from([
+                (
+                    "epic".to_string(),
+                    vec!["story".to_string(), "spike".to_string()],
+                ),
+                (
+                    "story".to_string(),
+                    vec!["task".to_string(), "bug".to_string()],
+                ),
+            ]),
+            max_depth: Some(3),
+            max_children: BTreeMap::from([("story".to_string(), 2)]),
+        });
+        configuration
+    }
+
+    #[test]
+    fn linear_hierarchy_allows_next_level_and_free_types() {
+        let configuration = default_project_configuration();
+        let allowed = get_allowed_child_types(&configuration, "epic");
+        assert_eq!(allowed[0], "task");
+        assert!(allowed.contains(&"bug".to_string()));
+        assert!(get_allowed_child_types(&configuration, "bug").is_empty());
+    }
+
+    #[test]
+    fn explicit_children_map_replaces_linear_chain() {
+        let configuration = graph_configuration();
+        assert!(validate_parent_child_relationship(&configuration, "story", "bug").is_ok());
+        assert!(validate_parent_child_relationship(&configuration, "epic", "spike").is_ok());
+        assert!(validate_parent_child_relationship(&configuration, "epic", "task").is_err());
+        assert!(validate_parent_child_relationship(&configuration, "bug", "sub-task").is_err());
+    }
+
+    #[test]
+    fn placement_enforces_child_limits_and_depth() {
+        let configuration = graph_configuration();
+        let issues = vec![
+            issue("kanbus-epic", "epic", None),
+            issue("kanbus-story", "story", Some("kanbus-epic")),
+            issue("kanbus-task1", "task", Some("kanbus-story")),
+            issue("kanbus-task2", "task", Some("kanbus-story")),
+            issue("kanbus-other", "story", None),
+            issue("kanbus-bug", "bug", Some("kanbus-other")),
+        ];
+        let story = &issues[1];
+        let error = validate_hierarchy_placement(&configuration, story, None, &issues)
+            .expect_err("limit exceeded");
+        assert!(error.to_string().contains("more than 2 children"));
+        assert!(
+            validate_hierarchy_placement(&configuration, story, Some("kanbus-task1"), &issues)
+                .is_ok()
+        );
+
+        let epic = &issues[0];
+        let error = validate_hierarchy_placement(&configuration, &issues[2], None, &issues)
+            .expect_err("too deep");
+        assert!(error
+            .to_string()
+            .contains("depth 4 exceeds the maximum of 3"));
+        assert!(
+            validate_hierarchy_placement(&configuration, epic, Some("kanbus-other"), &issues)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn rules_must_reference_known_types() {
+        let mut configuration = graph_configuration();
+        if let Some(rules) = configuration.hierarchy_rules.as_mut() {
+            rules
+                .children
+                .insert("saga".to_string(), vec!["task".to_string()]);
+            rules.max_depth = Some(0);
+        }
+        let mut errors = Vec::new();
+        validate_hierarchy_rules(&configuration, &mut errors);
+        assert_eq!(
+            errors,
+            vec![
+                "hierarchy_rules references unknown issue type 'saga'".to_string(),
+                "hierarchy_rules max_depth must be at least 1".to_string(),
+            ]
+        );
+    }
+}
@@ -1,10 +1,16 @@
 //! Issue identifier generation.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::{Mutex, OnceLock};
 use uuid::Uuid;
 
 use crate::error::KanbusError;
+use crate::models::IssueData;
+
+/// Custom field holding an issue's sequential number.
+pub const ISSUE_NUMBER_FIELD: &str = "kanbus_number";
+/// Custom field holding numbers an issue gave up during reconciliation.
+pub const ISSUE_NUMBER_ALIASES_FIELD: &str = "kanbus_number_aliases";
 
 /// Request to generate a unique issue identifier.
 #[derive(Debug, Clone)]
@@ -101,6 +107,8 @@ pub fn format_issue_key(identifier: &str, project_context: bool) -> String {
 /// Check if a candidate identifier matches a full issue identifier.
 ///
 /// Accepts full identifiers, project-context short ids, and abbreviated prefixes.
+/// Sequential numbers (`KAN-142`) are not part of the identifier; use
+/// [`match_issue_identifier`] to resolve both forms against loaded issues.
 ///
 /// # Arguments
 /// * `candidate` - User-provided identifier value.
@@ -128,6 +136,151 @@ pub fn issue_identifier_matches(candidate: &str, full_id: &str) -> bool {
     full_id.starts_with(candidate)
 }
 
+/// Return the sequential number assigned to an issue, if any.
+pub fn issue_number(issue: &IssueData) -> Option<u64> {
+    issue
+        .custom
+        .get(ISSUE_NUMBER_FIELD)
+        .and_then(serde_json::Value::as_u64)
+}
+
+/// Return the numbers an issue held before it was renumbered.
+pub fn issue_number_aliases(issue: &IssueData) -> Vec<u64> {
+    issue
+        .custom
+        .get(ISSUE_NUMBER_ALIASES_FIELD)
+        .and_then(serde_json::Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(serde_json::Value::as_u64)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Format a sequential number with the project key of an identifier.
+///
+/// # Arguments
+/// * `identifier` - Full issue identifier supplying the project key.
+/// * `number` - Sequential issue number.
+///
+/// # Returns
+/// Key such as `KAN-142`.
+pub fn format_issue_number(identifier: &str, number: u64) -> String {
+    let key = identifier
+        .split_once('-')
+        .map(|(key, _)| key)
+        .unwrap_or(identifier);
+    format!("{key}-{number}")
+}
+
+/// Return true if a candidate has the `KEY-<digits>` shape of a sequential number.
+pub fn is_issue_number_key(candidate: &str) -> bool {
+    candidate.split_once('-').is_some_and(|(key, number)| {
+        !key.is_empty() && !number.is_empty() && number.bytes().all(|byte| byte.is_ascii_digit())
+    })
+}
+
+/// Produce the display key for an issue, preferring its sequential number.
+///
+/// # Arguments
+/// * `issue` - Issue to format.
+/// * `project_context` - When true, omit the project key from hash keys.
+pub fn display_issue_key(issue: &IssueData, project_context: bool) -> String {
+    match issue_number(issue) {
+        Some(number) => format_issue_number(&issue.identifier, number),
+        None => format_issue_key(&issue.identifier, project_context),
+    }
+}
+
+/// Select the issues a user-supplied identifier refers to.
+///
+/// A sequential number held by an issue wins over identifier prefixes, so
+/// `KAN-142` never resolves to a UUID that happens to start with `142`.
+/// Numbers given up during reconciliation resolve only while no issue holds them.
+///
+/// # Arguments
+/// * `candidate` - User-provided identifier value.
+/// * `issues` - Issues to search.
+///
+/// # Returns
+/// Matching issues; more than one means the candidate is ambiguous.
+pub fn match_issue_identifier<'a, I>(candidate: &str, issues: I) -> Vec<&'a IssueData>
+where
+    I: IntoIterator<Item = &'a IssueData>,
+    I::IntoIter: Clone,
+{
+    let issues = issues.into_iter();
+    if let Some(exact) = issues.clone().find(|issue| issue.identifier == candidate) {
+        return vec![exact];
+    }
+    let numbered = |numbers: fn(&IssueData) -> Vec<u64>| -> Vec<&'a IssueData> {
+        issues
+            .clone()
+            .filter(|issue| {
+                numbers(issue)
+                    .into_iter()
+                    .any(|number| format_issue_number(&issue.identifier, number) == candidate)
+            })
+            .collect()
+    };
+    let current = numbered(|issue| issue_number(issue).into_iter().collect());
+    if !current.is_empty() {
+        return current;
+    }
+    let aliased = numbered(issue_number_aliases);
+    if !aliased.is_empty() {
+        return aliased;
+    }
+    issues
+        .filter(|issue| issue_identifier_matches(candidate, &issue.identifier))
+        .collect()
+}
+
+/// Map every numbered key (`KAN-142`) to the identifier it resolves to.
+///
+/// Current numbers take precedence over aliases left by reconciliation.
+pub fn issue_number_index(issues: &[IssueData]) -> BTreeMap<String, String> {
+    let mut index = BTreeMap::new();
+    for issue in issues {
+        for alias in issue_number_aliases(issue) {
+            index.insert(
+                format_issue_number(&issue.identifier, alias),
+                issue.identifier.clone(),
+            );
+        }
+    }
+    for issue in issues {
+        if let Some(number) = issue_number(issue) {
+            index.insert(
+                format_issue_number(&issue.identifier, number),
+                issue.identifier.clone(),
+            );
+        }
+    }
+    index
+}
+
+/// Return the next free sequential number for a set of issues.
+///
+/// Aliases count as taken so that renumbered references stay unambiguous.
+pub fn next_issue_number<'a, I>(issues: I) -> u64
+where
+    I: IntoIterator<Item = &'a IssueData>,
+{
+    issues
+        .into_iter()
+        .flat_map(|issue| {
+            issue_number(issue)
+                .into_iter()
+                .chain(issue_number_aliases(issue))
+        })
+        .max()
+        .unwrap_or(0)
+        + 1
+}
+
 /// Generate a unique issue ID using a UUID.
 ///
 /// # Arguments
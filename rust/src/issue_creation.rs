@@ -9,11 +9,18 @@ use crate::event_history::{
     events_dir_for_local, events_dir_for_project, issue_created_payload, now_timestamp,
     write_events_batch, EventRecord, EventType,
 };
-use crate::hierarchy::validate_parent_child_relationship;
-use crate::ids::{generate_issue_identifier, issue_identifier_matches, IssueIdentifierRequest};
+use crate::hierarchy::{
+    has_hierarchy_limits, validate_hierarchy_placement, validate_parent_child_relationship,
+};
+use crate::ids::{
+    generate_issue_identifier, is_issue_number_key, issue_identifier_matches,
+    IssueIdentifierRequest,
+};
 use crate::issue_files::{
     issue_path_for_identifier, list_issue_identifiers, read_issue_from_file, write_issue_to_file,
 };
+use crate::issue_listing::load_issues_from_directory;
+use crate::issue_numbers::{allocate_issue_number, find_issues_by_number, load_numbered_issues};
 use crate::models::{IssueData, ProjectConfiguration};
 use crate::users::get_current_user;
 use crate::workflows::validate_status_value;
@@ -91,6 +98,10 @@ pub fn create_issue(request: &IssueCreationRequest) -> Result<IssueCreationResul
                 &parent_issue.issue_type,
                 resolved_type,
             )?;
+            if has_hierarchy_limits(&configuration) {
+                let issues = load_issues_from_directory(&issues_dir)?;
+                validate_hierarchy_placement(&configuration, &parent_issue, None, &issues)?;
+            }
         }
 
         if let Some(duplicate_identifier) = find_duplicate_title(&issues_dir, &request.title)? {
@@ -124,7 +135,7 @@ pub fn create_issue(request: &IssueCreationRequest) -> Result<IssueCreationResul
         .clone()
         .or_else(|| configuration.assignee.clone());
 
-    let issue = IssueData {
+    let mut issue = IssueData {
         identifier,
         title: request.title.clone(),
         description: request.description.clone().unwrap_or_default(),
@@ -142,6 +153,9 @@ pub fn create_issue(request: &IssueCreationRequest) -> Result<IssueCreationResul
         closed_at: None,
         custom: std::collections::BTreeMap::new(),
     };
+    if configuration.sequential_ids {
+        allocate_issue_number(&mut issue, &load_numbered_issues(&project_dir)?);
+    }
 
     let policies_dir = project_dir.join("policies");
     if policies_dir.is_dir() {
@@ -210,7 +224,7 @@ pub fn create_issue(request: &IssueCreationRequest) -> Result<IssueCreationResul
     })
 }
 
-fn validate_issue_type(
+pub(crate) fn validate_issue_type(
     configuration: &ProjectConfiguration,
     issue_type: &str,
 ) -> Result<(), KanbusError> {
@@ -247,8 +261,9 @@ fn find_duplicate_title(issues_dir: &Path, title: &str) -> Result<Option<String>
 
 /// Resolve an issue identifier from a user-provided value.
 ///
-/// Accepts a full id, a unique short id (`{project_key}-{prefix}` up to 6 chars),
-/// or a project-context short id (no project key).
+/// Accepts a full id, a sequential number (`{project_key}-{number}`), a unique
+/// short id (`{project_key}-{prefix}` up to 6 chars), or a project-context short
+/// id (no project key).
 pub fn resolve_issue_identifier(
     issues_dir: &Path,
     _project_key: &str,
@@ -260,12 +275,20 @@ pub fn resolve_issue_identifier(
         return Ok(candidate.to_string());
     }
 
+    // Sequential numbers take precedence over identifier prefixes.
+    let mut matches = if is_issue_number_key(candidate) {
+        find_issues_by_number(issues_dir, candidate)?
+    } else {
+        Vec::new()
+    };
+
     // Otherwise, attempt a unique short-id match.
-    let identifiers = list_issue_identifiers(issues_dir)?;
-    let mut matches: Vec<String> = identifiers
-        .into_iter()
-        .filter(|full_id| issue_identifier_matches(candidate, full_id))
-        .collect();
+    if matches.is_empty() {
+        matches = list_issue_identifiers(issues_dir)?
+            .into_iter()
+            .filter(|full_id| issue_identifier_matches(candidate, full_id))
+            .collect();
+    }
 
     match matches.len() {
         1 => Ok(matches.pop().expect("single match")),
@@ -408,6 +431,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn resolve_issue_identifier_finds_numbers_past_malformed_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let issues_dir = temp.path().join("issues");
+        std::fs::create_dir_all(&issues_dir).expect("mkdir");
+        let mut numbered = make_issue("kanbus-f00d", "Numbered");
+        numbered.custom.insert(
+            crate::ids::ISSUE_NUMBER_FIELD.to_string(),
+            serde_json::json!(142),
+        );
+        write_issue_to_file(&numbered, &issues_dir.join("kanbus-f00d.json")).expect("write");
+        write_issue_to_file(
+            &make_issue("kanbus-142abc", "Prefix"),
+            &issues_dir.join("kanbus-142abc.json"),
+        )
+        .expect("write prefix");
+        std::fs::write(issues_dir.join("kanbus-broken.json"), "{not json").expect("write broken");
+
+        let resolved =
+            resolve_issue_identifier(&issues_dir, "kanbus", "kanbus-142").expect("number");
+        assert_eq!(resolved, "kanbus-f00d");
+        assert!(temp
+            .path()
+            .join(".cache")
+            .join("issue_numbers.json")
+            .is_file());
+
+        numbered.custom.remove(crate::ids::ISSUE_NUMBER_FIELD);
+        write_issue_to_file(&numbered, &issues_dir.join("kanbus-f00d.json")).expect("rewrite");
+        let file = std::fs::File::options()
+            .write(true)
+            .open(issues_dir.join("kanbus-f00d.json"))
+            .expect("open");
+        file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(5))
+            .expect("touch");
+        let resolved =
+            resolve_issue_identifier(&issues_dir, "kanbus", "kanbus-142").expect("prefix");
+        assert_eq!(resolved, "kanbus-142abc");
+    }
+
     #[test]
     fn create_issue_rejects_unknown_type_when_validate_enabled() {
         let temp = tempfile::tempdir().expect("tempdir");
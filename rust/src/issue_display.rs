@@ -2,7 +2,8 @@
 
 use owo_colors::{AnsiColors, OwoColorize};
 
-use crate::ids::format_issue_key;
+use crate::dependency_types::DependencyTypes;
+use crate::ids::display_issue_key;
 use crate::models::{IssueData, ProjectConfiguration};
 use crate::wiki;
 
@@ -142,7 +143,7 @@ pub fn format_issue_for_display(
     let assignee = issue.assignee.clone().unwrap_or_else(|| "-".to_string());
     let parent = issue.parent.clone().unwrap_or_else(|| "-".to_string());
 
-    let formatted_identifier = format_issue_key(&issue.identifier, project_context);
+    let formatted_identifier = display_issue_key(issue, project_context);
 
     let rows = vec![
         ("ID:", formatted_identifier, None, false),
@@ -196,15 +197,38 @@ pub fn format_issue_for_display(
         lines.push(dim("Description:", use_color));
         lines.push(paint(&description, None, use_color));
     }
+    let dependency_types = DependencyTypes::from_configuration(configuration);
     if !issue.dependencies.is_empty() {
         lines.push(dim("Dependencies:", use_color));
         for dependency in &issue.dependencies {
             lines.push(format!(
                 "  {}: {}",
-                dependency.dependency_type, dependency.target
+                dependency_types.label(&dependency.dependency_type),
+                dependency.target
             ));
         }
     }
+    let incoming: Vec<String> = all_issues
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|source| {
+            source
+                .dependencies
+                .iter()
+                .filter(|dependency| dependency.target == issue.identifier)
+                .map(|dependency| {
+                    format!(
+                        "  {}: {}",
+                        dependency_types.inverse_label(&dependency.dependency_type),
+                        source.identifier
+                    )
+                })
+        })
+        .collect();
+    if !incoming.is_empty() {
+        lines.push(dim("Linked from:", use_color));
+        lines.extend(incoming);
+    }
     if !issue.comments.is_empty() {
         lines.push(dim("Comments:", use_color));
         for (i, comment) in issue.comments.iter().enumerate() {
@@ -241,3 +265,16 @@ pub fn format_issue_for_display(
     }
     lines.join("\n")
 }
+
+/// Format the wiki pages that reference an issue.
+///
+/// # Arguments
+/// * `pages` - Wiki page paths relative to the wiki root.
+/// * `use_color` - Whether to apply ANSI colors.
+pub fn format_wiki_references(pages: &[String], use_color: bool) -> String {
+    format!(
+        "{} {}",
+        dim("Referenced by wiki:", use_color),
+        pages.join(", ")
+    )
+}
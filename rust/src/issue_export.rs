@@ -0,0 +1,432 @@
+//! Bulk issue export to CSV, JSON Lines and JSON.
+//!
+//! Columns are issue field names as they appear in issue files (`id`, `type`,
+//! `status`, ...), plus the derived `comment_count` and `custom.<name>` for
+//! custom fields. In CSV, list columns are joined with `;`, dependencies are
+//! written as `type:target` and custom fields keep their JSON type, all of
+//! which `issue_import` reads back. Cells that a spreadsheet would evaluate as
+//! a formula are prefixed with `'`.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Map, Value};
+
+use crate::error::KanbusError;
+use crate::file_io::load_project_directory;
+use crate::issue_listing::load_issues_from_directory;
+use crate::models::IssueData;
+
+/// Columns exported to CSV when none are selected.
+pub const DEFAULT_EXPORT_COLUMNS: &[&str] = &[
+    "id",
+    "title",
+    "type",
+    "status",
+    "priority",
+    "assignee",
+    "parent",
+    "labels",
+    "created_at",
+    "updated_at",
+    "closed_at",
+];
+
+/// Every built-in column; custom fields are selected as `custom.<name>`.
+pub const EXPORT_COLUMNS: &[&str] = &[
+    "id",
+    "title",
+    "description",
+    "type",
+    "status",
+    "priority",
+    "assignee",
+    "creator",
+    "parent",
+    "labels",
+    "dependencies",
+    "comment_count",
+    "created_at",
+    "updated_at",
+    "closed_at",
+];
+
+/// Prefix selecting a custom field as a column.
+pub const CUSTOM_COLUMN_PREFIX: &str = "custom.";
+
+/// Separator for list values inside a CSV cell.
+pub const CSV_LIST_SEPARATOR: char = ';';
+
+/// Prefix that stops spreadsheets from evaluating a CSV cell as a formula.
+const FORMULA_GUARD: char = '\'';
+
+/// Supported export formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Jsonl,
+    Json,
+}
+
+impl ExportFormat {
+    /// Parse a format name.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::IssueOperation` for unknown formats.
+    pub fn parse(value: &str) -> Result<Self, KanbusError> {
+        match value.to_ascii_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "jsonl" => Ok(Self::Jsonl),
+            "json" => Ok(Self::Json),
+            other => Err(KanbusError::IssueOperation(format!(
+                "unknown export format '{other}' (expected csv, jsonl or json)"
+            ))),
+        }
+    }
+}
+
+/// Result of an export run.
+#[derive(Debug, Clone)]
+pub struct IssueExportResult {
+    pub issue_count: usize,
+    /// Rendered export, without a trailing newline.
+    pub contents: String,
+    /// File the export was written to, if any.
+    pub path: Option<PathBuf>,
+}
+
+/// Export the shared issues of a project.
+///
+/// Without `columns`, CSV uses `DEFAULT_EXPORT_COLUMNS` and the JSON formats
+/// write complete issues.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `format` - Output format.
+/// * `columns` - Selected columns, in output order.
+/// * `output` - Destination file; the export is only returned when absent.
+///
+/// # Errors
+/// Returns `KanbusError` if a column is unknown, issues cannot be read, or
+/// the destination cannot be written.
+pub fn export_issues(
+    root: &Path,
+    format: ExportFormat,
+    columns: &[String],
+    output: Option<&Path>,
+) -> Result<IssueExportResult, KanbusError> {
+    for column in columns {
+        validate_column(column)?;
+    }
+    let project_dir = load_project_directory(root)?;
+    let mut issues = load_issues_from_directory(&project_dir.join("issues"))?;
+    issues.sort_by(|left, right| {
+        left.created_at
+            .cmp(&right.created_at)
+            .then_with(|| left.identifier.cmp(&right.identifier))
+    });
+
+    let contents = render_issues(&issues, format, columns)?;
+    let path = output.map(|path| root.join(path));
+    if let Some(path) = &path {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|error| KanbusError::Io(error.to_string()))?;
+        }
+        std::fs::write(path, format!("{contents}\n"))
+            .map_err(|error| KanbusError::Io(error.to_string()))?;
+    }
+    Ok(IssueExportResult {
+        issue_count: issues.len(),
+        contents,
+        path,
+    })
+}
+
+/// Render issues in the given format.
+///
+/// # Errors
+/// Returns `KanbusError` if a column is unknown or serialization fails.
+pub fn render_issues(
+    issues: &[IssueData],
+    format: ExportFormat,
+    columns: &[String],
+) -> Result<String, KanbusError> {
+    if format != ExportFormat::Csv {
+        let rows = issues
+            .iter()
+            .map(|issue| issue_row(issue, columns))
+            .collect::<Result<Vec<Value>, KanbusError>>()?;
+        let rendered = if format == ExportFormat::Jsonl {
+            rows.iter()
+                .map(serde_json::to_string)
+                .collect::<Result<Vec<String>, _>>()
+                .map(|lines| lines.join("\n"))
+        } else {
+            serde_json::to_string_pretty(&rows)
+        };
+        return rendered.map_err(|error| KanbusError::Io(error.to_string()));
+    }
+    let columns: Vec<String> = if columns.is_empty() {
+        DEFAULT_EXPORT_COLUMNS
+            .iter()
+            .map(|column| column.to_string())
+            .collect()
+    } else {
+        columns.to_vec()
+    };
+    let mut lines = vec![columns
+        .iter()
+        .map(|column| csv_escape(column))
+        .collect::<Vec<_>>()
+        .join(",")];
+    for issue in issues {
+        let mut cells = Vec::with_capacity(columns.len());
+        for column in &columns {
+            let value = column_value(issue, column)?;
+            let cell = if column.starts_with(CUSTOM_COLUMN_PREFIX) {
+                custom_csv_cell(&value)
+            } else {
+                csv_cell(&value)
+            };
+            cells.push(csv_escape(&cell));
+        }
+        lines.push(cells.join(","));
+    }
+    Ok(lines.join("\n"))
+}
+
+fn issue_row(issue: &IssueData, columns: &[String]) -> Result<Value, KanbusError> {
+    if columns.is_empty() {
+        return serde_json::to_value(issue).map_err(|error| KanbusError::Io(error.to_string()));
+    }
+    let mut row = Map::new();
+    for column in columns {
+        row.insert(column.clone(), column_value(issue, column)?);
+    }
+    Ok(Value::Object(row))
+}
+
+/// Check that a column is built in or names a custom field.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the column is unknown.
+pub fn validate_column(column: &str) -> Result<(), KanbusError> {
+    let custom = column
+        .strip_prefix(CUSTOM_COLUMN_PREFIX)
+        .is_some_and(|name| !name.is_empty());
+    if custom || EXPORT_COLUMNS.contains(&column) {
+        return Ok(());
+    }
+    Err(KanbusError::IssueOperation(format!(
+        "unknown column '{column}' (expected one of {} or custom.<name>)",
+        EXPORT_COLUMNS.join(", ")
+    )))
+}
+
+/// Read a single column of an issue as JSON.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the column is unknown.
+pub fn column_value(issue: &IssueData, column: &str) -> Result<Value, KanbusError> {
+    if let Some(name) = column.strip_prefix(CUSTOM_COLUMN_PREFIX) {
+        validate_column(column)?;
+        return Ok(issue.custom.get(name).cloned().unwrap_or(Value::Null));
+    }
+    let value = match column {
+        "id" => json!(issue.identifier),
+        "title" => json!(issue.title),
+        "description" => json!(issue.description),
+        "type" => json!(issue.issue_type),
+        "status" => json!(issue.status),
+        "priority" => json!(issue.priority),
+        "assignee" => json!(issue.assignee),
+        "creator" => json!(issue.creator),
+        "parent" => json!(issue.parent),
+        "labels" => json!(issue.labels),
+        "dependencies" => json!(issue.dependencies),
+        "comment_count" => json!(issue.comments.len()),
+        "created_at" => json!(issue.created_at),
+        "updated_at" => json!(issue.updated_at),
+        "closed_at" => json!(issue.closed_at),
+        other => {
+            validate_column(other)?;
+            Value::Null
+        }
+    };
+    Ok(value)
+}
+
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                Value::Object(link) => format!(
+                    "{}:{}",
+                    link.get("type").and_then(Value::as_str).unwrap_or(""),
+                    link.get("target").and_then(Value::as_str).unwrap_or("")
+                ),
+                other => csv_cell(other),
+            })
+            .collect::<Vec<_>>()
+            .join(&CSV_LIST_SEPARATOR.to_string()),
+        other => other.to_string(),
+    }
+}
+
+/// Write a custom field so that `issue_import` restores its JSON type.
+///
+/// Non-string values are written as JSON. Strings are written as they are,
+/// unless they would read back as another JSON value (`3`, `true`), in which
+/// case they are written as a JSON string.
+fn custom_csv_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) if serde_json::from_str::<Value>(text).is_err() => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Read back a custom field cell written by `custom_csv_cell`.
+pub fn parse_custom_csv_cell(cell: &str) -> Value {
+    serde_json::from_str(cell).unwrap_or_else(|_| Value::String(cell.to_string()))
+}
+
+/// Whether a spreadsheet would evaluate a cell starting like `cell` as a formula.
+fn starts_like_formula(cell: &str) -> bool {
+    cell.starts_with(['=', '+', '-', '@', '\t', '\r'])
+}
+
+/// Whether `cell` carries the `'` that `csv_escape` adds before formulas.
+///
+/// A value that itself starts with such a guard is guarded again, so one
+/// leading `'` can always be removed.
+fn has_formula_guard(cell: &str) -> bool {
+    cell.strip_prefix(FORMULA_GUARD)
+        .is_some_and(|rest| starts_like_formula(rest) || rest.starts_with(FORMULA_GUARD))
+}
+
+/// Remove the formula guard added by `csv_escape`.
+pub fn strip_formula_guard(cell: &str) -> &str {
+    if has_formula_guard(cell) {
+        &cell[FORMULA_GUARD.len_utf8()..]
+    } else {
+        cell
+    }
+}
+
+/// Quote a CSV field when it contains a delimiter, quote or line break, and
+/// prefix `'` to fields that a spreadsheet would evaluate as a formula.
+pub fn csv_escape(field: &str) -> String {
+    let field = if starts_like_formula(field) || has_formula_guard(field) {
+        format!("{FORMULA_GUARD}{field}")
+    } else {
+        field.to_string()
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{DependencyLink, IssueComment};
+    use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
+
+    fn issue() -> IssueData {
+        let timestamp = Utc.with_ymd_and_hms(2026, 3, 6, 9, 30, 0).unwrap();
+        IssueData {
+            identifier: "kanbus-aaa".to_string(),
+            title: "Parse \"quoted\", titles".to_string(),
+            description: String::new(),
+            issue_type: "task".to_string(),
+            status: "open".to_string(),
+            priority: 1,
+            assignee: None,
+            creator: None,
+            parent: Some("kanbus-epic".to_string()),
+            labels: vec!["ui".to_string(), "api".to_string()],
+            dependencies: vec![DependencyLink {
+                target: "kanbus-bbb".to_string(),
+                dependency_type: "blocked-by".to_string(),
+            }],
+            comments: vec![IssueComment {
+                id: None,
+                author: "dev".to_string(),
+                text: "hi".to_string(),
+                created_at: timestamp,
+            }],
+            created_at: timestamp,
+            updated_at: timestamp,
+            closed_at: None,
+            custom: BTreeMap::from([("estimate".to_string(), json!(3))]),
+        }
+    }
+
+    #[test]
+    fn csv_export_selects_columns_and_quotes_cells() {
+        let columns: Vec<String> = ["id", "title", "labels", "dependencies", "comment_count"]
+            .into_iter()
+            .chain(["custom.estimate", "assignee"])
+            .map(str::to_string)
+            .collect();
+        let rendered = render_issues(&[issue()], ExportFormat::Csv, &columns).expect("render");
+        assert_eq!(
+            rendered,
+            "id,title,labels,dependencies,comment_count,custom.estimate,assignee\n\
+             kanbus-aaa,\"Parse \"\"quoted\"\", titles\",ui;api,blocked-by:kanbus-bbb,1,3,"
+        );
+    }
+
+    #[test]
+    fn csv_export_guards_formulas_and_keeps_custom_types() {
+        for (value, escaped) in [
+            ("=HYPERLINK(\"x\")", "\"'=HYPERLINK(\"\"x\"\")\""),
+            ("+1", "'+1"),
+            ("-2", "'-2"),
+            ("@SUM(A1)", "'@SUM(A1)"),
+            ("'=quoted", "''=quoted"),
+            ("'plain", "'plain"),
+            ("safe", "safe"),
+        ] {
+            assert_eq!(csv_escape(value), escaped);
+            let unquoted = escaped.trim_matches('"').replace("\"\"", "\"");
+            assert_eq!(strip_formula_guard(&unquoted), value);
+        }
+
+        let mut issue = issue();
+        issue.title = "=cmd|' /C calc'!A0".to_string();
+        issue.custom = BTreeMap::from([
+            ("estimate".to_string(), json!(-3)),
+            ("code".to_string(), json!("42")),
+            ("flag".to_string(), json!(true)),
+        ]);
+        let columns: Vec<String> = ["title", "custom.estimate", "custom.code", "custom.flag"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let rendered = render_issues(&[issue], ExportFormat::Csv, &columns).expect("render");
+        let row = rendered.lines().nth(1).expect("row");
+        assert_eq!(row, "'=cmd|' /C calc'!A0,'-3,\"\"\"42\"\"\",true");
+        assert_eq!(parse_custom_csv_cell("-3"), json!(-3));
+        assert_eq!(parse_custom_csv_cell("\"42\""), json!("42"));
+        assert_eq!(parse_custom_csv_cell("007"), json!("007"));
+        assert_eq!(parse_custom_csv_cell("high"), json!("high"));
+    }
+
+    #[test]
+    fn json_export_projects_columns_and_rejects_unknown_ones() {
+        let columns = vec!["id".to_string(), "custom.estimate".to_string()];
+        let rendered = render_issues(&[issue()], ExportFormat::Jsonl, &columns).expect("render");
+        assert_eq!(rendered, r#"{"custom.estimate":3,"id":"kanbus-aaa"}"#);
+        let full = render_issues(&[issue()], ExportFormat::Json, &[]).expect("render");
+        assert!(full.contains("\"comments\""));
+        assert!(render_issues(&[issue()], ExportFormat::Csv, &["bogus".to_string()]).is_err());
+        assert!(ExportFormat::parse("xml").is_err());
+    }
+}
@@ -0,0 +1,788 @@
+//! Bulk issue import from CSV and JSON Lines.
+//!
+//! Each row becomes one issue. Columns are matched to issue fields by name
+//! (the names `issue_export` writes), after applying any `column=field`
+//! mapping; unknown columns are ignored and reported. Parent and dependency
+//! references may point at other rows (by their `id` column) or at existing
+//! issues. The whole file is validated, including project policies, before
+//! anything is written. CSV cells lose the formula guard `issue_export` adds,
+//! and CSV custom fields get back their JSON type.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::config_loader::load_project_configuration;
+use crate::dependency_types::DependencyTypes;
+use crate::error::KanbusError;
+use crate::event_history::{
+    events_dir_for_project, issue_created_payload, now_timestamp, write_events_batch, EventRecord,
+    EventType,
+};
+use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::hierarchy::{
+    has_hierarchy_limits, validate_hierarchy_placement, validate_parent_child_relationship,
+};
+use crate::ids::{generate_issue_identifier, match_issue_identifier, IssueIdentifierRequest};
+use crate::issue_creation::validate_issue_type;
+use crate::issue_export::{
+    parse_custom_csv_cell, strip_formula_guard, CSV_LIST_SEPARATOR, CUSTOM_COLUMN_PREFIX,
+};
+use crate::issue_files::{issue_path_for_identifier, write_issue_to_file};
+use crate::issue_numbers::{allocate_issue_number, load_numbered_issues};
+use crate::models::{DependencyLink, IssueComment, IssueData, ProjectConfiguration};
+use crate::users::get_current_user;
+use crate::workflows::validate_status_value;
+
+/// Issue fields a column can be mapped to, besides `custom.<name>`.
+pub const IMPORT_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "description",
+    "type",
+    "status",
+    "priority",
+    "assignee",
+    "creator",
+    "parent",
+    "labels",
+    "dependencies",
+    "comments",
+    "custom",
+    "created_at",
+    "updated_at",
+    "closed_at",
+];
+
+/// Supported import formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Csv,
+    Jsonl,
+}
+
+/// Request to import issues from a file.
+#[derive(Debug, Clone)]
+pub struct IssueImportRequest {
+    pub root: PathBuf,
+    pub path: PathBuf,
+    pub format: ImportFormat,
+    /// Column name to issue field name.
+    pub mapping: BTreeMap<String, String>,
+    /// Keep `id` values instead of generating new identifiers.
+    pub preserve_ids: bool,
+    /// Validate and report without writing any files.
+    pub dry_run: bool,
+}
+
+/// Result of an import run.
+#[derive(Debug, Clone)]
+pub struct IssueImportResult {
+    pub issues: Vec<IssueData>,
+    /// Columns that do not map to an issue field.
+    pub ignored_columns: Vec<String>,
+}
+
+/// Parse a `column=field` mapping rule.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the rule is malformed or names an
+/// unknown field.
+pub fn parse_column_mapping(rule: &str) -> Result<(String, String), KanbusError> {
+    let Some((column, field)) = rule.split_once('=') else {
+        return Err(KanbusError::IssueOperation(format!(
+            "invalid mapping '{rule}' (expected column=field)"
+        )));
+    };
+    let (column, field) = (column.trim(), field.trim());
+    if column.is_empty() || !is_import_field(field) {
+        return Err(KanbusError::IssueOperation(format!(
+            "invalid mapping '{rule}' (fields: {} or custom.<name>)",
+            IMPORT_FIELDS.join(", ")
+        )));
+    }
+    Ok((column.to_string(), field.to_string()))
+}
+
+fn is_import_field(field: &str) -> bool {
+    IMPORT_FIELDS.contains(&field)
+        || field
+            .strip_prefix(CUSTOM_COLUMN_PREFIX)
+            .is_some_and(|name| !name.is_empty())
+}
+
+/// Import issues from a CSV or JSON Lines file.
+///
+/// # Errors
+/// Returns `KanbusError` if the file cannot be parsed, any row fails
+/// validation (all row errors are reported together), or writes fail.
+pub fn import_issues(request: &IssueImportRequest) -> Result<IssueImportResult, KanbusError> {
+    let contents = std::fs::read_to_string(&request.path).map_err(|error| {
+        KanbusError::IssueOperation(format!(
+            "failed to read {}: {error}",
+            request.path.display()
+        ))
+    })?;
+    let raw_rows = match request.format {
+        ImportFormat::Csv => csv_rows(&contents)?,
+        ImportFormat::Jsonl => jsonl_rows(&contents)?,
+    };
+
+    let mut ignored: BTreeSet<String> = BTreeSet::new();
+    let rows: Vec<(usize, BTreeMap<String, Value>)> = raw_rows
+        .into_iter()
+        .map(|(line, row)| {
+            let mut fields = BTreeMap::new();
+            for (column, value) in row {
+                let field = request.mapping.get(&column).unwrap_or(&column);
+                if is_import_field(field) {
+                    let value = match (&value, request.format) {
+                        (Value::String(cell), ImportFormat::Csv)
+                            if field.starts_with(CUSTOM_COLUMN_PREFIX) =>
+                        {
+                            parse_custom_csv_cell(cell)
+                        }
+                        _ => value,
+                    };
+                    fields.insert(field.clone(), value);
+                } else {
+                    ignored.insert(column);
+                }
+            }
+            (line, fields)
+        })
+        .collect();
+
+    let project_dir = load_project_directory(&request.root)?;
+    let configuration = load_project_configuration(&get_configuration_path(&request.root)?)?;
+    let existing = load_numbered_issues(&project_dir)?;
+    let mut errors = Vec::new();
+
+    let mut taken: HashSet<String> = existing
+        .iter()
+        .map(|issue| issue.identifier.clone())
+        .collect();
+    let mut identifiers = Vec::with_capacity(rows.len());
+    let mut id_map: HashMap<String, String> = HashMap::new();
+    for (line, fields) in &rows {
+        let source_id = fields.get("id").and_then(text_value);
+        let identifier = match (&source_id, request.preserve_ids) {
+            (Some(source_id), true) => {
+                if !is_preservable_identifier(source_id) {
+                    errors.push(format!(
+                        "row {line}: invalid issue id '{source_id}': use letters, digits, '-', '_' or '.'"
+                    ));
+                } else if taken.contains(source_id) {
+                    errors.push(format!("row {line}: issue {source_id} already exists"));
+                }
+                source_id.clone()
+            }
+            _ => {
+                let request = IssueIdentifierRequest {
+                    title: fields.get("title").and_then(text_value).unwrap_or_default(),
+                    existing_ids: taken.clone(),
+                    prefix: configuration.project_key.clone(),
+                };
+                generate_issue_identifier(&request)?.identifier
+            }
+        };
+        taken.insert(identifier.clone());
+        if let Some(source_id) = source_id {
+            id_map.insert(source_id, identifier.clone());
+        }
+        identifiers.push(identifier);
+    }
+
+    let now = Utc::now();
+    let mut issues = Vec::with_capacity(rows.len());
+    for ((line, fields), identifier) in rows.iter().zip(identifiers) {
+        match build_issue(identifier, fields, &configuration, now) {
+            Ok(issue) => issues.push((*line, issue)),
+            Err(message) => errors.push(format!("row {line}: {message}")),
+        }
+    }
+    for (line, issue) in &mut issues {
+        if let Err(message) = resolve_references(issue, &id_map, &existing) {
+            errors.push(format!("row {line}: {message}"));
+        }
+    }
+    let mut combined: Vec<IssueData> = existing.clone();
+    combined.extend(issues.iter().map(|(_, issue)| issue.clone()));
+    let dependency_types = DependencyTypes::from_configuration(Some(&configuration));
+    for (line, issue) in &issues {
+        if let Err(error) = validate_issue(issue, &configuration, &dependency_types, &combined) {
+            errors.push(format!("row {line}: {error}"));
+        }
+    }
+    let policies_dir = project_dir.join("policies");
+    if errors.is_empty() && policies_dir.is_dir() {
+        let policy_documents = crate::policy_loader::load_policies(&policies_dir)?;
+        if !policy_documents.is_empty() {
+            for (line, issue) in &issues {
+                let context = crate::policy_context::PolicyContext {
+                    current_issue: None,
+                    proposed_issue: issue.clone(),
+                    transition: None,
+                    operation: crate::policy_context::PolicyOperation::Create,
+                    project_configuration: configuration.clone(),
+                    all_issues: combined.clone(),
+                };
+                if let Err(error) =
+                    crate::policy_evaluator::evaluate_policies(&context, &policy_documents)
+                {
+                    errors.push(format!("row {line}: {error}"));
+                }
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return Err(KanbusError::IssueOperation(format!(
+            "import failed:\n{}",
+            errors.join("\n")
+        )));
+    }
+
+    let mut issues: Vec<IssueData> = issues.into_iter().map(|(_, issue)| issue).collect();
+    if configuration.sequential_ids {
+        let mut numbered = existing;
+        for issue in &mut issues {
+            allocate_issue_number(issue, &numbered);
+            numbered.push(issue.clone());
+        }
+    }
+
+    if !request.dry_run && !issues.is_empty() {
+        let issues_dir = project_dir.join("issues");
+        for issue in &issues {
+            write_issue_to_file(
+                issue,
+                &issue_path_for_identifier(&issues_dir, &issue.identifier),
+            )?;
+        }
+        let actor_id = get_current_user();
+        let occurred_at = now_timestamp();
+        let events: Vec<EventRecord> = issues
+            .iter()
+            .map(|issue| {
+                EventRecord::new(
+                    issue.identifier.clone(),
+                    EventType::IssueCreated,
+                    actor_id.clone(),
+                    issue_created_payload(issue),
+                    occurred_at.clone(),
+                )
+            })
+            .collect();
+        write_events_batch(&events_dir_for_project(&project_dir), &events)?;
+    }
+
+    Ok(IssueImportResult {
+        issues,
+        ignored_columns: ignored.into_iter().collect(),
+    })
+}
+
+fn build_issue(
+    identifier: String,
+    fields: &BTreeMap<String, Value>,
+    configuration: &ProjectConfiguration,
+    now: DateTime<Utc>,
+) -> Result<IssueData, String> {
+    let text = |field: &str| fields.get(field).and_then(text_value);
+    let title = text("title").ok_or_else(|| "title is required".to_string())?;
+    let priority = match fields.get("priority") {
+        None | Some(Value::Null) => configuration.default_priority as i32,
+        Some(Value::Number(number)) => number
+            .as_i64()
+            .ok_or_else(|| "invalid priority".to_string())?
+            as i32,
+        Some(value) => text_value(value)
+            .and_then(|text| text.parse::<i32>().ok())
+            .ok_or_else(|| "invalid priority".to_string())?,
+    };
+    let created_at = timestamp(fields.get("created_at"), "created_at")?.unwrap_or(now);
+    let updated_at = timestamp(fields.get("updated_at"), "updated_at")?.unwrap_or(created_at);
+
+    let mut custom = BTreeMap::new();
+    if let Some(value) = fields.get("custom") {
+        let Value::Object(object) = value else {
+            return Err("custom must be an object".to_string());
+        };
+        custom.extend(object.clone());
+    }
+    for (field, value) in fields {
+        if let Some(name) = field.strip_prefix(CUSTOM_COLUMN_PREFIX) {
+            custom.insert(name.to_string(), value.clone());
+        }
+    }
+
+    let comments = match fields.get("comments") {
+        None | Some(Value::Null) => Vec::new(),
+        Some(value) => serde_json::from_value::<Vec<IssueComment>>(value.clone())
+            .map_err(|error| format!("invalid comments: {error}"))?,
+    };
+
+    Ok(IssueData {
+        identifier,
+        title,
+        description: text("description").unwrap_or_default(),
+        issue_type: text("type").unwrap_or_else(|| "task".to_string()),
+        status: text("status").unwrap_or_else(|| configuration.initial_status.clone()),
+        priority,
+        assignee: text("assignee"),
+        creator: text("creator"),
+        parent: text("parent"),
+        labels: list_value(fields.get("labels"))?,
+        dependencies: dependency_links(fields.get("dependencies"))?,
+        comments,
+        created_at,
+        updated_at,
+        closed_at: timestamp(fields.get("closed_at"), "closed_at")?,
+        custom,
+    })
+}
+
+fn resolve_references(
+    issue: &mut IssueData,
+    id_map: &HashMap<String, String>,
+    existing: &[IssueData],
+) -> Result<(), String> {
+    let resolve = |reference: &str| -> Result<String, String> {
+        if let Some(identifier) = id_map.get(reference) {
+            return Ok(identifier.clone());
+        }
+        match match_issue_identifier(reference, existing).as_slice() {
+            [issue] => Ok(issue.identifier.clone()),
+            [] => Err(format!("'{reference}' not found")),
+            _ => Err(format!("'{reference}' is ambiguous")),
+        }
+    };
+    if let Some(parent) = issue.parent.take() {
+        issue.parent = Some(resolve(&parent).map_err(|error| format!("parent {error}"))?);
+    }
+    for link in &mut issue.dependencies {
+        link.target = resolve(&link.target).map_err(|error| format!("dependency {error}"))?;
+    }
+    Ok(())
+}
+
+fn validate_issue(
+    issue: &IssueData,
+    configuration: &ProjectConfiguration,
+    dependency_types: &DependencyTypes,
+    combined: &[IssueData],
+) -> Result<(), KanbusError> {
+    validate_issue_type(configuration, &issue.issue_type)?;
+    if !u8::try_from(issue.priority)
+        .is_ok_and(|value| configuration.priorities.contains_key(&value))
+    {
+        return Err(KanbusError::IssueOperation("invalid priority".to_string()));
+    }
+    validate_status_value(configuration, &issue.issue_type, &issue.status)?;
+    for link in &issue.dependencies {
+        dependency_types.validate(&link.dependency_type)?;
+    }
+    if let Some(parent_id) = &issue.parent {
+        let parent = combined
+            .iter()
+            .find(|candidate| &candidate.identifier == parent_id)
+            .ok_or_else(|| KanbusError::IssueOperation("not found".to_string()))?;
+        validate_parent_child_relationship(configuration, &parent.issue_type, &issue.issue_type)?;
+        if has_hierarchy_limits(configuration) {
+            validate_hierarchy_placement(configuration, parent, Some(&issue.identifier), combined)?;
+        }
+    }
+    Ok(())
+}
+
+/// Preserved ids become file names, so keep them to the identifier alphabet.
+fn is_preservable_identifier(identifier: &str) -> bool {
+    !identifier.starts_with('.')
+        && !identifier.contains("..")
+        && identifier
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'))
+}
+
+fn text_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) if !text.trim().is_empty() => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    }
+}
+
+fn timestamp(value: Option<&Value>, field: &str) -> Result<Option<DateTime<Utc>>, String> {
+    match value.and_then(text_value) {
+        None => Ok(None),
+        Some(text) => DateTime::parse_from_rfc3339(text.trim())
+            .map(|value| Some(value.with_timezone(&Utc)))
+            .map_err(|_| format!("invalid {field}")),
+    }
+}
+
+fn list_value(value: Option<&Value>) -> Result<Vec<String>, String> {
+    match value {
+        None | Some(Value::Null) => Ok(Vec::new()),
+        Some(Value::String(text)) => Ok(text
+            .split(CSV_LIST_SEPARATOR)
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect()),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| text_value(item).ok_or_else(|| "invalid labels".to_string()))
+            .collect(),
+        Some(_) => Err("invalid labels".to_string()),
+    }
+}
+
+fn dependency_links(value: Option<&Value>) -> Result<Vec<DependencyLink>, String> {
+    let parse = |text: &str| match text.trim().split_once(':') {
+        Some((dependency_type, target))
+            if !dependency_type.trim().is_empty() && !target.trim().is_empty() =>
+        {
+            Ok(DependencyLink {
+                target: target.trim().to_string(),
+                dependency_type: dependency_type.trim().to_string(),
+            })
+        }
+        _ => Err(format!(
+            "invalid dependency '{text}' (expected type:target)"
+        )),
+    };
+    match value {
+        None | Some(Value::Null) => Ok(Vec::new()),
+        Some(Value::String(text)) => text
+            .split(CSV_LIST_SEPARATOR)
+            .filter(|item| !item.trim().is_empty())
+            .map(parse)
+            .collect(),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| match item {
+                Value::String(text) => parse(text),
+                other => serde_json::from_value::<DependencyLink>(other.clone())
+                    .map_err(|error| format!("invalid dependency: {error}")),
+            })
+            .collect(),
+        Some(_) => Err("invalid dependencies".to_string()),
+    }
+}
+
+type RawRow = (usize, Vec<(String, Value)>);
+
+fn jsonl_rows(contents: &str) -> Result<Vec<RawRow>, KanbusError> {
+    let mut rows = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let value: Value = serde_json::from_str(line).map_err(|error| {
+            KanbusError::IssueOperation(format!("row {}: invalid JSON: {error}", index + 1))
+        })?;
+        let Value::Object(object) = value else {
+            return Err(KanbusError::IssueOperation(format!(
+                "row {}: expected a JSON object",
+                index + 1
+            )));
+        };
+        rows.push((index + 1, object.into_iter().collect()));
+    }
+    Ok(rows)
+}
+
+fn csv_rows(contents: &str) -> Result<Vec<RawRow>, KanbusError> {
+    let mut records = parse_csv(contents)?.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Ok(Vec::new());
+    };
+    let mut rows = Vec::new();
+    for (line, cells) in records {
+        if cells.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        if cells.len() > header.len() {
+            return Err(KanbusError::IssueOperation(format!(
+                "row {line}: {} cells but {} columns",
+                cells.len(),
+                header.len()
+            )));
+        }
+        let row = header
+            .iter()
+            .zip(cells)
+            .filter(|(_, cell)| !cell.is_empty())
+            .map(|(column, cell)| {
+                let cell = strip_formula_guard(&cell).to_string();
+                (column.trim().to_string(), Value::String(cell))
+            })
+            .collect();
+        rows.push((line, row));
+    }
+    Ok(rows)
+}
+
+/// Parse RFC 4180 CSV into records, each with the line it starts on.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` for an unterminated quoted field.
+pub fn parse_csv(contents: &str) -> Result<Vec<(usize, Vec<String>)>, KanbusError> {
+    let contents = contents.strip_prefix('\u{feff}').unwrap_or(contents);
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = contents.chars().peekable();
+    while let Some(character) = chars.next() {
+        if in_quotes {
+            match character {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                other => {
+                    if other == '\n' {
+                        line += 1;
+                    }
+                    field.push(other);
+                }
+            }
+            continue;
+        }
+        match character {
+            '"' => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push((record_line, std::mem::take(&mut record)));
+                line += 1;
+                record_line = line;
+            }
+            other => field.push(other),
+        }
+    }
+    if in_quotes {
+        return Err(KanbusError::IssueOperation(format!(
+            "row {record_line}: unterminated quoted field"
+        )));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((record_line, record));
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_io::initialize_project;
+    use crate::issue_export::{render_issues, ExportFormat};
+    use crate::issue_listing::load_issues_from_directory;
+    use std::path::Path;
+
+    fn request(root: &Path, path: PathBuf, format: ImportFormat) -> IssueImportRequest {
+        IssueImportRequest {
+            root: root.to_path_buf(),
+            path,
+            format,
+            mapping: BTreeMap::new(),
+            preserve_ids: false,
+            dry_run: false,
+        }
+    }
+
+    #[test]
+    fn parse_csv_handles_quotes_and_embedded_line_breaks() {
+        let records = parse_csv("a,b\r\n\"x, \"\"y\"\"\",\"two\nlines\"\n3,\n").expect("parse");
+        assert_eq!(
+            records,
+            vec![
+                (1, vec!["a".to_string(), "b".to_string()]),
+                (2, vec!["x, \"y\"".to_string(), "two\nlines".to_string()]),
+                (4, vec!["3".to_string(), String::new()]),
+            ]
+        );
+        assert!(parse_csv("\"open").is_err());
+    }
+
+    #[test]
+    fn csv_import_maps_columns_links_rows_and_validates_everything_first() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        initialize_project(root, false).expect("initialize project");
+        let path = root.join("issues.csv");
+        std::fs::write(
+            &path,
+            "Key,Summary,type,status,parent,labels,dependencies,Points,Notes\n\
+             E-1,Checkout epic,epic,open,,web,,,\n\
+             T-1,Pay by card,task,in_progress,E-1,web;payments,,5,later\n\
+             T-2,Refunds,task,open,E-1,,blocked-by:T-1,2,\n",
+        )
+        .expect("write csv");
+        let mut import = request(root, path.clone(), ImportFormat::Csv);
+        import.mapping = BTreeMap::from([
+            ("Key".to_string(), "id".to_string()),
+            ("Summary".to_string(), "title".to_string()),
+            ("Points".to_string(), "custom.points".to_string()),
+        ]);
+        import.dry_run = true;
+
+        let planned = import_issues(&import).expect("dry run");
+        assert_eq!(planned.issues.len(), 3);
+        assert_eq!(planned.ignored_columns, vec!["Notes".to_string()]);
+        let issues_dir = root.join("project").join("issues");
+        assert!(load_issues_from_directory(&issues_dir)
+            .expect("load")
+            .is_empty());
+
+        import.dry_run = false;
+        let result = import_issues(&import).expect("import");
+        let epic = &result.issues[0];
+        let card = &result.issues[1];
+        assert!(epic.identifier.starts_with("kanbus-"));
+        assert_eq!(card.parent.as_deref(), Some(epic.identifier.as_str()));
+        assert_eq!(card.labels, vec!["web".to_string(), "payments".to_string()]);
+        assert_eq!(card.custom["points"], serde_json::json!(5));
+        assert_eq!(result.issues[2].dependencies[0].target, card.identifier);
+        assert_eq!(
+            load_issues_from_directory(&issues_dir).expect("load").len(),
+            3
+        );
+
+        std::fs::write(
+            &path,
+            "title,type,status,parent\nGood,task,open,\nBad,widget,open,\nOrphan,task,open,kanbus-nope\n",
+        )
+        .expect("write csv");
+        let error =
+            import_issues(&request(root, path, ImportFormat::Csv)).expect_err("invalid rows");
+        let message = error.to_string();
+        assert!(message.contains("row 3: unknown issue type"));
+        assert!(message.contains("row 4: parent 'kanbus-nope' not found"));
+        assert_eq!(
+            load_issues_from_directory(&issues_dir).expect("load").len(),
+            3
+        );
+    }
+
+    #[test]
+    fn csv_export_round_trips_formulas_and_custom_types() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        initialize_project(root, false).expect("initialize project");
+        let path = root.join("issues.jsonl");
+        std::fs::write(
+            &path,
+            r#"{"title":"=SUM(A1:A9)","custom":{"points":-3,"ratio":0.5,"code":"42","tag":"'=x"}}"#,
+        )
+        .expect("write jsonl");
+        let source = import_issues(&request(root, path, ImportFormat::Jsonl))
+            .expect("import jsonl")
+            .issues;
+        let columns: Vec<String> = [
+            "title",
+            "custom.points",
+            "custom.ratio",
+            "custom.code",
+            "custom.tag",
+        ]
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+        let csv = render_issues(&source, ExportFormat::Csv, &columns).expect("render");
+        assert!(csv.contains("'=SUM(A1:A9)"));
+
+        let path = root.join("issues.csv");
+        std::fs::write(&path, csv).expect("write csv");
+        let imported = import_issues(&request(root, path, ImportFormat::Csv))
+            .expect("import csv")
+            .issues;
+        assert_eq!(imported[0].title, "=SUM(A1:A9)");
+        assert_eq!(imported[0].custom, source[0].custom);
+    }
+
+    #[test]
+    fn import_enforces_creation_policies() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        initialize_project(root, false).expect("initialize project");
+        let policies_dir = root.join("project").join("policies");
+        std::fs::create_dir_all(&policies_dir).expect("create policies");
+        std::fs::write(
+            policies_dir.join("require-description.policy"),
+            "Feature: Require description\n\n  Scenario: All issues need description\n    When creating an issue\n    Then the description must not be empty\n",
+        )
+        .expect("write policy");
+        let path = root.join("issues.csv");
+        std::fs::write(&path, "title,description\nDescribed,Has one\nBare,\n").expect("write csv");
+        let error =
+            import_issues(&request(root, path, ImportFormat::Csv)).expect_err("policy violation");
+        assert!(error.to_string().contains("row 3: policy violation"));
+        assert!(
+            load_issues_from_directory(&root.join("project").join("issues"))
+                .expect("load")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn jsonl_export_round_trips_with_preserved_ids() {
+        let source = tempfile::tempdir().expect("tempdir");
+        initialize_project(source.path(), false).expect("initialize project");
+        let path = source.path().join("issues.csv");
+        std::fs::write(&path, "title,type,labels\nEpic,epic,a\n").expect("write csv");
+        let imported = import_issues(&request(source.path(), path, ImportFormat::Csv))
+            .expect("import")
+            .issues;
+        let jsonl = render_issues(&imported, ExportFormat::Jsonl, &[]).expect("render");
+
+        let target = tempfile::tempdir().expect("tempdir");
+        initialize_project(target.path(), false).expect("initialize project");
+        let path = target.path().join("issues.jsonl");
+        std::fs::write(&path, jsonl).expect("write jsonl");
+        let mut import = request(target.path(), path, ImportFormat::Jsonl);
+        import.preserve_ids = true;
+        let result = import_issues(&import).expect("import jsonl");
+        assert_eq!(result.issues[0].identifier, imported[0].identifier);
+        assert_eq!(result.issues[0].created_at, imported[0].created_at);
+        assert_eq!(result.issues[0].labels, imported[0].labels);
+        assert!(import_issues(&import)
+            .expect_err("duplicate ids")
+            .to_string()
+            .contains("already exists"));
+    }
+
+    #[test]
+    fn preserved_ids_cannot_escape_the_issues_directory() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        initialize_project(temp.path(), false).expect("initialize project");
+        let path = temp.path().join("issues.csv");
+        std::fs::write(
+            &path,
+            "id,title,type
+../../escaped,Escape,task
+.hidden,Hidden,task
+kb-1.2,Fine,task
+",
+        )
+        .expect("write csv");
+        let mut import = request(temp.path(), path, ImportFormat::Csv);
+        import.preserve_ids = true;
+        let message = import_issues(&import)
+            .expect_err("traversal id")
+            .to_string();
+        assert!(message.contains("row 2: invalid issue id '../../escaped'"));
+        assert!(message.contains("row 3: invalid issue id '.hidden'"));
+        assert!(!message.contains("row 4"));
+        assert!(!temp.path().join("escaped.json").exists());
+    }
+}
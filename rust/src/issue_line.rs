@@ -2,7 +2,7 @@
 
 use owo_colors::{AnsiColors, OwoColorize};
 
-use crate::ids::format_issue_key;
+use crate::ids::{display_issue_key, format_issue_key};
 use crate::models::{IssueData, ProjectConfiguration};
 
 /// Column widths for list output.
@@ -29,7 +29,7 @@ pub fn compute_widths(issues: &[IssueData], project_context: bool) -> Widths {
         widths.issue_type = widths.issue_type.max(1);
         widths.status = widths.status.max(issue.status.len());
         widths.priority = widths.priority.max(format!("P{}", issue.priority).len());
-        let formatted_identifier = format_issue_key(&issue.identifier, project_context);
+        let formatted_identifier = display_issue_key(issue, project_context);
         widths.identifier = widths.identifier.max(formatted_identifier.len());
         let parent_value = issue.parent.as_deref().unwrap_or("-");
         let parent_display = if parent_value == "-" {
@@ -57,7 +57,7 @@ pub fn format_issue_line(
     use_color_override: Option<bool>,
 ) -> String {
     let parent_value = issue.parent.clone().unwrap_or_else(|| "-".to_string());
-    let formatted_identifier = format_issue_key(&issue.identifier, project_context);
+    let formatted_identifier = display_issue_key(issue, project_context);
     let parent_display = if parent_value == "-" {
         parent_value.clone()
     } else {
@@ -320,6 +320,11 @@ mod tests {
             overlay: Default::default(),
             hooks: Default::default(),
             github_security: None,
+            github_issues: None,
+            dependency_types: BTreeMap::new(),
+            hierarchy_rules: None,
+            views: BTreeMap::new(),
+            sequential_ids: false,
         }
     }
 
@@ -8,8 +8,9 @@ use crate::error::KanbusError;
 use crate::file_io::{
     find_project_local_directory, get_configuration_path, resolve_labeled_projects,
 };
-use crate::ids::issue_identifier_matches;
+use crate::ids::{is_issue_number_key, issue_identifier_matches};
 use crate::issue_files::{issue_path_for_identifier, read_issue_from_file};
+use crate::issue_numbers::find_issues_by_number;
 use crate::models::IssueData;
 use crate::overlay::{load_overlay_issue, load_tombstone, resolve_issue_with_overlay};
 use crate::project::discover_project_directories;
@@ -210,6 +211,17 @@ fn find_matching_issues(
         return Ok(matches);
     }
 
+    // Sequential numbers are stored inside the issue files, not in their names.
+    if is_issue_number_key(identifier) {
+        for full_id in find_issues_by_number(issues_dir, identifier)? {
+            let path = issue_path_for_identifier(issues_dir, &full_id);
+            matches.push((full_id, path));
+        }
+        if !matches.is_empty() {
+            return Ok(matches);
+        }
+    }
+
     let entries = fs::read_dir(issues_dir).map_err(|error| {
         KanbusError::IssueOperation(format!("cannot read issues directory: {error}"))
     })?;
@@ -0,0 +1,474 @@
+//! Sequential issue numbers: allocation, migration and merge reconciliation.
+//!
+//! Numbers live in the issue's `custom` map next to its UUID identifier, so
+//! two branches that allocate the same number never collide on disk. After a
+//! merge the duplicate is resolved by keeping the number on the oldest issue
+//! and renumbering the others, whose previous numbers remain as aliases.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::cache::collect_issue_file_mtimes;
+use crate::error::KanbusError;
+use crate::event_history::{
+    events_dir_for_local, events_dir_for_project, now_timestamp, write_events_batch, EventRecord,
+    EventType,
+};
+use crate::file_io::{find_project_local_directory, load_project_directory};
+use crate::ids::{
+    format_issue_number, issue_number, issue_number_aliases, next_issue_number,
+    ISSUE_NUMBER_ALIASES_FIELD, ISSUE_NUMBER_FIELD,
+};
+use crate::issue_files::{issue_path_for_identifier, read_issue_from_file, write_issue_to_file};
+use crate::issue_listing::load_issues_from_directory;
+use crate::models::IssueData;
+use crate::users::get_current_user;
+
+/// A number given to, or taken from, an issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueNumberChange {
+    /// Full issue identifier.
+    pub identifier: String,
+    /// Number held before the change, if any.
+    pub previous: Option<u64>,
+    /// Number held after the change.
+    pub number: u64,
+}
+
+impl IssueNumberChange {
+    /// Render the change as a single line of CLI output.
+    pub fn describe(&self) -> String {
+        let number = format_issue_number(&self.identifier, self.number);
+        match self.previous {
+            Some(previous) => format!(
+                "{} -> {} ({})",
+                format_issue_number(&self.identifier, previous),
+                number,
+                self.identifier
+            ),
+            None => format!("{} ({})", number, self.identifier),
+        }
+    }
+}
+
+/// Give a freshly created issue the next number of its project.
+///
+/// # Arguments
+/// * `issue` - New issue to number.
+/// * `issues` - Existing shared and local issues of the project.
+pub fn allocate_issue_number(issue: &mut IssueData, issues: &[IssueData]) {
+    let number = next_issue_number(issues);
+    issue
+        .custom
+        .insert(ISSUE_NUMBER_FIELD.to_string(), json!(number));
+}
+
+/// Plan numbers for issues that do not have one yet.
+///
+/// Issues are numbered in creation order after the highest number in use.
+pub fn plan_number_assignment(issues: &[IssueData]) -> Vec<IssueNumberChange> {
+    let mut next = next_issue_number(issues);
+    let mut unnumbered: Vec<&IssueData> = issues
+        .iter()
+        .filter(|issue| issue_number(issue).is_none())
+        .collect();
+    unnumbered.sort_by(|left, right| {
+        left.created_at
+            .cmp(&right.created_at)
+            .then_with(|| left.identifier.cmp(&right.identifier))
+    });
+    unnumbered
+        .into_iter()
+        .map(|issue| {
+            let change = IssueNumberChange {
+                identifier: issue.identifier.clone(),
+                previous: None,
+                number: next,
+            };
+            next += 1;
+            change
+        })
+        .collect()
+}
+
+/// Plan renumbering for numbers held by more than one issue.
+///
+/// The oldest holder keeps the number; every other holder moves to the next
+/// free number.
+pub fn plan_number_reconciliation(issues: &[IssueData]) -> Vec<IssueNumberChange> {
+    let mut next = next_issue_number(issues);
+    let mut changes = Vec::new();
+    for (number, mut holders) in duplicate_number_holders(issues) {
+        holders.sort_by(|left, right| {
+            left.created_at
+                .cmp(&right.created_at)
+                .then_with(|| left.identifier.cmp(&right.identifier))
+        });
+        for holder in holders.into_iter().skip(1) {
+            changes.push(IssueNumberChange {
+                identifier: holder.identifier.clone(),
+                previous: Some(number),
+                number: next,
+            });
+            next += 1;
+        }
+    }
+    changes
+}
+
+/// List numbers held by more than one issue.
+///
+/// # Returns
+/// Map of number to the identifiers holding it.
+pub fn find_duplicate_numbers(issues: &[IssueData]) -> BTreeMap<u64, Vec<String>> {
+    duplicate_number_holders(issues)
+        .into_iter()
+        .map(|(number, holders)| {
+            let mut identifiers: Vec<String> = holders
+                .into_iter()
+                .map(|issue| issue.identifier.clone())
+                .collect();
+            identifiers.sort();
+            (number, identifiers)
+        })
+        .collect()
+}
+
+fn duplicate_number_holders(issues: &[IssueData]) -> BTreeMap<u64, Vec<&IssueData>> {
+    let mut holders: BTreeMap<u64, Vec<&IssueData>> = BTreeMap::new();
+    for issue in issues {
+        if let Some(number) = issue_number(issue) {
+            holders.entry(number).or_default().push(issue);
+        }
+    }
+    holders.retain(|_, holders| holders.len() > 1);
+    holders
+}
+
+/// Assign numbers to every issue of the project that lacks one.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError` if issues cannot be read or written.
+pub fn assign_issue_numbers(root: &Path) -> Result<Vec<IssueNumberChange>, KanbusError> {
+    apply_number_plan(root, plan_number_assignment)
+}
+
+/// Renumber issues that share a number after a merge.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError` if issues cannot be read or written.
+pub fn reconcile_issue_numbers(root: &Path) -> Result<Vec<IssueNumberChange>, KanbusError> {
+    apply_number_plan(root, plan_number_reconciliation)
+}
+
+/// Load the shared and local issues of a project for number allocation.
+///
+/// # Errors
+/// Returns `KanbusError` if an issues directory cannot be read.
+pub fn load_numbered_issues(project_dir: &Path) -> Result<Vec<IssueData>, KanbusError> {
+    Ok(issue_locations(project_dir)?
+        .into_iter()
+        .flat_map(|(_, issues, _)| issues)
+        .collect())
+}
+
+/// Numbers held by one issue file, cached with the file's modification time.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct NumberIndexEntry {
+    mtime: f64,
+    /// Identifier of the issue, or `None` if the file could not be parsed.
+    identifier: Option<String>,
+    number: Option<u64>,
+    aliases: Vec<u64>,
+}
+
+/// Find the issues holding a `KEY-<digits>` number.
+///
+/// Numbers are read from an index under the project's `.cache` directory,
+/// so only issue files that changed since the last lookup are parsed. Files
+/// that cannot be parsed are skipped rather than failing the lookup. As with
+/// [`crate::ids::match_issue_identifier`], current numbers win over aliases.
+///
+/// # Arguments
+/// * `issues_dir` - Issues directory to search.
+/// * `candidate` - Numbered key such as `kanbus-142`.
+///
+/// # Errors
+/// Returns `KanbusError` if the issues directory cannot be read.
+pub fn find_issues_by_number(
+    issues_dir: &Path,
+    candidate: &str,
+) -> Result<Vec<String>, KanbusError> {
+    let index = load_number_index(issues_dir)?;
+    let holders = |numbers: fn(&NumberIndexEntry) -> Vec<u64>| -> Vec<String> {
+        let mut identifiers: Vec<String> = index
+            .values()
+            .filter_map(|entry| {
+                let identifier = entry.identifier.as_ref()?;
+                numbers(entry)
+                    .into_iter()
+                    .any(|number| format_issue_number(identifier, number) == candidate)
+                    .then(|| identifier.clone())
+            })
+            .collect();
+        identifiers.sort();
+        identifiers
+    };
+    let current = holders(|entry| entry.number.into_iter().collect());
+    if !current.is_empty() {
+        return Ok(current);
+    }
+    Ok(holders(|entry| entry.aliases.clone()))
+}
+
+fn number_index_path(issues_dir: &Path) -> Option<PathBuf> {
+    issues_dir
+        .parent()
+        .map(|parent| parent.join(".cache").join("issue_numbers.json"))
+}
+
+fn load_number_index(issues_dir: &Path) -> Result<BTreeMap<String, NumberIndexEntry>, KanbusError> {
+    let mtimes = collect_issue_file_mtimes(issues_dir)?;
+    let cache_path = number_index_path(issues_dir);
+    let mut cached: BTreeMap<String, NumberIndexEntry> = cache_path
+        .as_ref()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default();
+    let mut changed = cached.len() != mtimes.len();
+    let mut index = BTreeMap::new();
+    for (file_name, mtime) in mtimes {
+        let entry = match cached.remove(&file_name) {
+            Some(entry) if entry.mtime == mtime => entry,
+            _ => {
+                changed = true;
+                index_entry(&issues_dir.join(&file_name), mtime)
+            }
+        };
+        index.insert(file_name, entry);
+    }
+    if changed {
+        if let Some(path) = cache_path {
+            write_number_index(&path, &index);
+        }
+    }
+    Ok(index)
+}
+
+fn write_number_index(path: &Path, index: &BTreeMap<String, NumberIndexEntry>) {
+    // The index is only a cache; a failed write means the next lookup rescans.
+    if let Some(parent) = path.parent() {
+        if std::fs::create_dir_all(parent).is_err() {
+            return;
+        }
+    }
+    if let Ok(contents) = serde_json::to_string(index) {
+        let _ = std::fs::write(path, contents);
+    }
+}
+
+fn index_entry(path: &Path, mtime: f64) -> NumberIndexEntry {
+    match read_issue_from_file(path) {
+        Ok(issue) => NumberIndexEntry {
+            mtime,
+            number: issue_number(&issue),
+            aliases: issue_number_aliases(&issue),
+            identifier: Some(issue.identifier),
+        },
+        Err(_) => NumberIndexEntry {
+            mtime,
+            ..NumberIndexEntry::default()
+        },
+    }
+}
+
+fn issue_locations(
+    project_dir: &Path,
+) -> Result<Vec<(PathBuf, Vec<IssueData>, bool)>, KanbusError> {
+    let mut locations = vec![(
+        project_dir.join("issues"),
+        load_issues_from_directory(&project_dir.join("issues"))?,
+        false,
+    )];
+    if let Some(local_dir) = find_project_local_directory(project_dir) {
+        let local_issues = local_dir.join("issues");
+        if local_issues.is_dir() {
+            let issues = load_issues_from_directory(&local_issues)?;
+            locations.push((local_issues, issues, true));
+        }
+    }
+    Ok(locations)
+}
+
+fn apply_number_plan(
+    root: &Path,
+    plan: fn(&[IssueData]) -> Vec<IssueNumberChange>,
+) -> Result<Vec<IssueNumberChange>, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let locations = issue_locations(&project_dir)?;
+    let all_issues: Vec<IssueData> = locations
+        .iter()
+        .flat_map(|(_, issues, _)| issues.iter().cloned())
+        .collect();
+    let changes = plan(&all_issues);
+    if changes.is_empty() {
+        return Ok(changes);
+    }
+    let by_identifier: BTreeMap<&str, &IssueNumberChange> = changes
+        .iter()
+        .map(|change| (change.identifier.as_str(), change))
+        .collect();
+
+    let actor_id = get_current_user();
+    let occurred_at = now_timestamp();
+    for (issues_dir, issues, local) in locations {
+        let mut events = Vec::new();
+        for mut issue in issues {
+            let Some(change) = by_identifier.get(issue.identifier.as_str()) else {
+                continue;
+            };
+            apply_change(&mut issue, change);
+            write_issue_to_file(
+                &issue,
+                &issue_path_for_identifier(&issues_dir, &issue.identifier),
+            )?;
+            events.push(EventRecord::new(
+                issue.identifier.clone(),
+                EventType::FieldUpdated,
+                actor_id.clone(),
+                json!({
+                    "changes": {
+                        ISSUE_NUMBER_FIELD: { "from": change.previous, "to": change.number }
+                    }
+                }),
+                occurred_at.clone(),
+            ));
+        }
+        if events.is_empty() {
+            continue;
+        }
+        let events_dir = if local {
+            events_dir_for_local(&project_dir)?
+        } else {
+            events_dir_for_project(&project_dir)
+        };
+        write_events_batch(&events_dir, &events)?;
+    }
+    Ok(changes)
+}
+
+fn apply_change(issue: &mut IssueData, change: &IssueNumberChange) {
+    if let Some(previous) = change.previous {
+        let mut aliases = issue_number_aliases(issue);
+        if !aliases.contains(&previous) {
+            aliases.push(previous);
+        }
+        issue
+            .custom
+            .insert(ISSUE_NUMBER_ALIASES_FIELD.to_string(), Value::from(aliases));
+    }
+    issue
+        .custom
+        .insert(ISSUE_NUMBER_FIELD.to_string(), json!(change.number));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ids::{display_issue_key, match_issue_identifier};
+    use chrono::{TimeZone, Utc};
+
+    fn issue(identifier: &str, day: u32, number: Option<u64>) -> IssueData {
+        let timestamp = Utc.with_ymd_and_hms(2026, 3, day, 0, 0, 0).unwrap();
+        let mut custom = BTreeMap::new();
+        if let Some(number) = number {
+            custom.insert(ISSUE_NUMBER_FIELD.to_string(), json!(number));
+        }
+        IssueData {
+            identifier: identifier.to_string(),
+            title: identifier.to_string(),
+            description: String::new(),
+            issue_type: "task".to_string(),
+            status: "open".to_string(),
+            priority: 2,
+            assignee: None,
+            creator: None,
+            parent: None,
+            labels: Vec::new(),
+            dependencies: Vec::new(),
+            comments: Vec::new(),
+            created_at: timestamp,
+            updated_at: timestamp,
+            closed_at: None,
+            custom,
+        }
+    }
+
+    #[test]
+    fn assignment_numbers_unnumbered_issues_in_creation_order() {
+        let issues = vec![
+            issue("kanbus-bbb", 3, None),
+            issue("kanbus-aaa", 2, Some(4)),
+            issue("kanbus-ccc", 1, None),
+        ];
+        let changes = plan_number_assignment(&issues);
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| (change.identifier.as_str(), change.number))
+                .collect::<Vec<_>>(),
+            vec![("kanbus-ccc", 5), ("kanbus-bbb", 6)]
+        );
+        assert_eq!(changes[0].describe(), "kanbus-5 (kanbus-ccc)");
+    }
+
+    #[test]
+    fn reconciliation_keeps_oldest_holder_and_records_alias() {
+        let mut issues = vec![
+            issue("kanbus-new", 5, Some(7)),
+            issue("kanbus-old", 1, Some(7)),
+            issue("kanbus-other", 2, Some(8)),
+        ];
+        let changes = plan_number_reconciliation(&issues);
+        assert_eq!(
+            changes,
+            vec![IssueNumberChange {
+                identifier: "kanbus-new".to_string(),
+                previous: Some(7),
+                number: 9,
+            }]
+        );
+        apply_change(&mut issues[0], &changes[0]);
+        assert!(find_duplicate_numbers(&issues).is_empty());
+        assert_eq!(display_issue_key(&issues[0], false), "kanbus-9");
+
+        let resolved = match_issue_identifier("kanbus-7", &issues);
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].identifier, "kanbus-old");
+        issues[1].custom.remove(ISSUE_NUMBER_FIELD);
+        let resolved = match_issue_identifier("kanbus-7", &issues);
+        assert_eq!(resolved[0].identifier, "kanbus-new");
+    }
+
+    #[test]
+    fn numbers_take_precedence_over_identifier_prefixes() {
+        let issues = vec![
+            issue("kanbus-142abc", 1, None),
+            issue("kanbus-f00d", 2, Some(142)),
+        ];
+        let resolved = match_issue_identifier("kanbus-142", &issues);
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].identifier, "kanbus-f00d");
+        let resolved = match_issue_identifier("kanbus-142a", &issues);
+        assert_eq!(resolved[0].identifier, "kanbus-142abc");
+    }
+}
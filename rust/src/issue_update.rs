@@ -12,6 +12,7 @@ use crate::event_history::{
 use crate::file_io::get_configuration_path;
 use crate::issue_creation::resolve_issue_identifier;
 use crate::issue_files::{read_issue_from_file, write_issue_to_file};
+use crate::issue_listing::load_issues_from_directory;
 use crate::issue_lookup::load_issue_from_project;
 use crate::models::IssueData;
 use crate::users::get_current_user;
@@ -156,6 +157,15 @@ pub fn update_issue(
                     &parent_issue.issue_type,
                     resolved_type.unwrap_or(&updated_issue.issue_type),
                 )?;
+                if crate::hierarchy::has_hierarchy_limits(&configuration) {
+                    let issues = load_issues_from_directory(&issues_dir)?;
+                    crate::hierarchy::validate_hierarchy_placement(
+                        &configuration,
+                        &parent_issue,
+                        Some(&updated_issue.identifier),
+                        &issues,
+                    )?;
+                }
             }
             updated_parent = Some(resolved_parent);
         }
@@ -189,6 +199,7 @@ pub fn update_issue(
                 )?;
             }
 
+            let mut child_count = 0;
             for entry in
                 fs::read_dir(&issues_dir).map_err(|error| KanbusError::Io(error.to_string()))?
             {
@@ -206,7 +217,14 @@ pub fn update_issue(
                     new_type,
                     &child_issue.issue_type,
                 )?;
+                child_count += 1;
             }
+            crate::hierarchy::validate_child_limit(
+                &configuration,
+                new_type,
+                &updated_issue.identifier,
+                child_count,
+            )?;
         }
     }
 
@@ -2,35 +2,112 @@
 //!
 //! Pulls issues from a remote Jira project into the local Kanbus project.
 //! Secrets are read from environment variables JIRA_API_TOKEN and JIRA_USER_EMAIL.
+//!
+//! Pulls are incremental: the time of the last successful pull and the Jira key to
+//! Kanbus identifier map are persisted to `.cache/jira_sync_state.json` inside the
+//! project directory, and subsequent pulls only request issues updated since then.
 
-use std::collections::{BTreeMap, HashSet};
-use std::path::Path;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
-use crate::file_io::load_project_directory;
+use crate::file_io::{get_configuration_path, load_project_directory};
 use crate::ids::{generate_issue_identifier, IssueIdentifierRequest};
 use crate::issue_files::{
     issue_path_for_identifier, list_issue_identifiers, read_issue_from_file, write_issue_to_file,
 };
-use crate::models::{IssueComment, IssueData, JiraConfiguration};
+use crate::models::{DependencyLink, IssueComment, IssueData, JiraConfiguration};
+use crate::workflows::closed_status;
+
+const SYNC_STATE_FILE_NAME: &str = "jira_sync_state.json";
+const ISSUE_FIELDS: &str = "summary,description,issuetype,status,priority,assignee,reporter,parent,labels,comment,issuelinks,created,updated,resolutiondate";
+/// Minutes subtracted from the last pull time to tolerate clock skew.
+const INCREMENTAL_OVERLAP_MINUTES: i64 = 5;
 
 /// Result of a Jira pull operation.
 #[derive(Debug)]
 pub struct JiraPullResult {
     pub pulled: usize,
     pub updated: usize,
+    /// Issues closed because they were deleted in Jira or moved out of the project.
+    pub removed: usize,
+    /// Previously removed issues that are back in the Jira project.
+    pub restored: usize,
+}
+
+/// Custom field recording when an issue disappeared from the Jira project.
+const JIRA_REMOVED_AT: &str = "jira_removed_at";
+/// Custom field holding the status an issue had before it was closed as removed.
+const JIRA_STATUS_BEFORE_REMOVAL: &str = "jira_status_before_removal";
+
+/// Persisted state of the last successful Jira pull for a project.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JiraSyncState {
+    /// Jira project key this state was recorded for.
+    #[serde(default)]
+    pub jira_project_key: Option<String>,
+    /// Start time of the last successful pull.
+    #[serde(default)]
+    pub last_pull_at: Option<DateTime<Utc>>,
+    /// Map from Jira issue key to Kanbus issue identifier.
+    #[serde(default)]
+    pub issue_map: BTreeMap<String, String>,
+}
+
+/// Return the Jira sync state path for a project directory.
+///
+/// # Arguments
+/// * `project_dir` - Kanbus project directory.
+pub fn sync_state_path(project_dir: &Path) -> PathBuf {
+    project_dir.join(".cache").join(SYNC_STATE_FILE_NAME)
+}
+
+/// Load the Jira sync state for a project directory.
+///
+/// Returns `Default::default()` if no state has been recorded yet.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if the state file cannot be read or parsed.
+pub fn load_sync_state(project_dir: &Path) -> Result<JiraSyncState, KanbusError> {
+    let path = sync_state_path(project_dir);
+    if !path.exists() {
+        return Ok(JiraSyncState::default());
+    }
+    let contents = std::fs::read_to_string(&path).map_err(|e| KanbusError::Io(e.to_string()))?;
+    serde_json::from_str(&contents).map_err(|e| KanbusError::Io(e.to_string()))
+}
+
+/// Persist the Jira sync state for a project directory.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if the state file cannot be written.
+pub fn save_sync_state(project_dir: &Path, state: &JiraSyncState) -> Result<(), KanbusError> {
+    let path = sync_state_path(project_dir);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| KanbusError::Io(e.to_string()))?;
+    }
+    let contents =
+        serde_json::to_string_pretty(state).map_err(|e| KanbusError::Io(e.to_string()))?;
+    std::fs::write(&path, contents).map_err(|e| KanbusError::Io(e.to_string()))
 }
 
 /// Pull issues from a Jira project into the local Kanbus project.
 ///
+/// Only issues updated since the last successful pull are requested unless
+/// `full` is set or no sync state exists. Issues that disappear from the Jira
+/// project (deleted or moved elsewhere) are closed locally.
+///
 /// # Arguments
 /// * `root` - Repository root path.
 /// * `jira_config` - Jira configuration from .kanbus.yml.
 /// * `project_key` - Kanbus project key (issue ID prefix).
 /// * `dry_run` - If true, print what would be done without writing any files.
+/// * `full` - If true, ignore the recorded sync state and fetch every issue.
 ///
 /// # Errors
 /// Returns `KanbusError` if config, authentication, or file operations fail.
@@ -39,6 +116,7 @@ pub fn pull_from_jira(
     jira_config: &JiraConfiguration,
     project_key: &str,
     dry_run: bool,
+    full: bool,
 ) -> Result<JiraPullResult, KanbusError> {
     let api_token = std::env::var("JIRA_API_TOKEN").map_err(|_| {
         KanbusError::Configuration("JIRA_API_TOKEN environment variable is not set".to_string())
@@ -49,6 +127,8 @@ pub fn pull_from_jira(
 
     let project_dir = load_project_directory(root)?;
     let issues_dir = project_dir.join("issues");
+    let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+    let removed_status = closed_status(&configuration);
 
     if !issues_dir.exists() {
         return Err(KanbusError::IssueOperation(
@@ -56,14 +136,61 @@ pub fn pull_from_jira(
         ));
     }
 
-    let jira_issues = fetch_all_jira_issues(jira_config, &user_email, &api_token)?;
+    let mut state = load_sync_state(&project_dir)?;
+    if state.jira_project_key.as_deref() != Some(jira_config.project_key.as_str()) {
+        state = JiraSyncState::default();
+    }
+    let pull_started_at = Utc::now();
+    let since = if full { None } else { state.last_pull_at };
+
+    let jql = build_pull_jql(&jira_config.project_key, since, pull_started_at);
+    let jira_issues = fetch_jira_issues(jira_config, &user_email, &api_token, &jql, ISSUE_FIELDS)?;
+    let live_keys: BTreeSet<String> = if since.is_none() {
+        jira_issues.iter().map(jira_issue_key).collect()
+    } else {
+        let listing_jql = build_pull_jql(&jira_config.project_key, None, pull_started_at);
+        fetch_jira_issues(jira_config, &user_email, &api_token, &listing_jql, "key")?
+            .iter()
+            .map(jira_issue_key)
+            .collect()
+    };
 
     // Build index: jira_key -> kanbus identifier, for idempotency
     let existing_ids = list_issue_identifiers(&issues_dir)?;
-    let jira_key_index = build_jira_key_index(&existing_ids, &issues_dir);
+    let mut index = build_jira_index(&existing_ids, &issues_dir);
+    for (jira_key, kanbus_id) in &state.issue_map {
+        if existing_ids.contains(kanbus_id) {
+            index
+                .by_key
+                .entry(jira_key.clone())
+                .or_insert_with(|| kanbus_id.clone());
+        }
+    }
+
+    // Issues whose key changed (moved between projects and back, or renamed)
+    // keep their Kanbus identifier by matching on the stable Jira issue id.
+    for jira_issue in &jira_issues {
+        let jira_key = jira_issue_key(jira_issue);
+        if index.by_key.contains_key(&jira_key) {
+            continue;
+        }
+        let Some(kanbus_id) = jira_issue["id"]
+            .as_str()
+            .and_then(|jira_id| index.by_id.get(jira_id))
+            .cloned()
+        else {
+            continue;
+        };
+        index.by_key.retain(|_, mapped| mapped != &kanbus_id);
+        println!("moved    {jira_key:<12}  {}", short_identifier(&kanbus_id));
+        index.by_key.insert(jira_key, kanbus_id);
+    }
+    let jira_key_index = index.by_key.clone();
 
     let mut pulled = 0usize;
     let mut updated = 0usize;
+    let mut removed = 0usize;
+    let mut restored = 0usize;
 
     // First pass: collect all Jira keys and their Kanbus IDs so parent links can be resolved
     let mut jira_key_to_kanbus_id: BTreeMap<String, String> = jira_key_index.clone();
@@ -86,6 +213,7 @@ pub fn pull_from_jira(
             vacant.insert(result.identifier);
         }
     }
+    let synced_ids: HashSet<&String> = jira_key_to_kanbus_id.values().collect();
 
     for jira_issue in &jira_issues {
         let jira_key = jira_issue_key(jira_issue);
@@ -111,15 +239,13 @@ pub fn pull_from_jira(
         let issue_path = issue_path_for_identifier(&issues_dir, &kanbus_id);
         if action == "updated" {
             if let Ok(existing) = read_issue_from_file(&issue_path) {
-                issue.created_at = existing.created_at;
+                merge_local_fields(&mut issue, existing, &synced_ids);
             }
         }
 
-        let short_key = &kanbus_id[..kanbus_id
-            .len()
-            .min(kanbus_id.find('-').map_or(6, |i| i + 7))];
         println!(
-            "{action}  {jira_key:<12}  {short_key:<14}  \"{}\"",
+            "{action}  {jira_key:<12}  {:<14}  \"{}\"",
+            short_identifier(&kanbus_id),
             issue.title
         );
 
@@ -134,18 +260,98 @@ pub fn pull_from_jira(
         }
     }
 
-    Ok(JiraPullResult { pulled, updated })
+    let pulled_keys: BTreeSet<String> = jira_issues.iter().map(jira_issue_key).collect();
+    for (jira_key, kanbus_id) in &jira_key_to_kanbus_id {
+        if pulled_keys.contains(jira_key) {
+            continue;
+        }
+        let issue_path = issue_path_for_identifier(&issues_dir, kanbus_id);
+        let Ok(existing) = read_issue_from_file(&issue_path) else {
+            continue;
+        };
+        let was_removed = existing.custom.contains_key(JIRA_REMOVED_AT);
+        if live_keys.contains(jira_key) {
+            // Back in the project without a change that the incremental query would see.
+            if !was_removed {
+                continue;
+            }
+            let issue = restore_removed_from_jira(existing, pull_started_at);
+            println!(
+                "restored {jira_key:<12}  {:<14}  \"{}\"",
+                short_identifier(kanbus_id),
+                issue.title
+            );
+            if !dry_run {
+                write_issue_to_file(&issue, &issue_path)?;
+            }
+            restored += 1;
+            continue;
+        }
+        if was_removed {
+            continue;
+        }
+        let issue = mark_removed_from_jira(existing, pull_started_at, removed_status);
+        println!(
+            "removed  {jira_key:<12}  {:<14}  \"{}\"",
+            short_identifier(kanbus_id),
+            issue.title
+        );
+        if !dry_run {
+            write_issue_to_file(&issue, &issue_path)?;
+        }
+        removed += 1;
+    }
+
+    if !dry_run {
+        jira_key_to_kanbus_id.retain(|jira_key, _| live_keys.contains(jira_key));
+        save_sync_state(
+            &project_dir,
+            &JiraSyncState {
+                jira_project_key: Some(jira_config.project_key.clone()),
+                last_pull_at: Some(pull_started_at),
+                issue_map: jira_key_to_kanbus_id,
+            },
+        )?;
+    }
+
+    Ok(JiraPullResult {
+        pulled,
+        updated,
+        removed,
+        restored,
+    })
+}
+
+/// Build the JQL used to select issues for a pull.
+///
+/// Incremental pulls use a relative `updated` bound so the query does not depend
+/// on the Jira server time zone.
+fn build_pull_jql(
+    jira_project_key: &str,
+    since: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> String {
+    match since {
+        Some(since) => {
+            let minutes = (now - since).num_minutes().max(0) + INCREMENTAL_OVERLAP_MINUTES;
+            format!(
+                "project={jira_project_key} AND updated >= \"-{minutes}m\" ORDER BY created ASC"
+            )
+        }
+        None => format!("project={jira_project_key} ORDER BY created ASC"),
+    }
 }
 
-/// Fetch all issues from Jira using pagination.
-fn fetch_all_jira_issues(
+/// Fetch issues matching a JQL query from Jira using pagination.
+fn fetch_jira_issues(
     jira_config: &JiraConfiguration,
     user_email: &str,
     api_token: &str,
+    jql: &str,
+    fields: &str,
 ) -> Result<Vec<Value>, KanbusError> {
     let base_url = jira_config.url.trim_end_matches('/');
-    let project_key = &jira_config.project_key;
-    let fields = "summary,description,issuetype,status,priority,assignee,reporter,parent,labels,comment,created,updated,resolutiondate";
+    let url = format!("{base_url}/rest/api/3/search/jql");
 
     let client = reqwest::blocking::Client::new();
     let mut all_issues = Vec::new();
@@ -153,12 +359,14 @@ fn fetch_all_jira_issues(
     let max_results = 100usize;
 
     loop {
-        let url = format!(
-            "{base_url}/rest/api/3/search/jql?jql=project={project_key}+ORDER+BY+created+ASC&fields={fields}&maxResults={max_results}&startAt={start_at}"
-        );
-
         let response = client
             .get(&url)
+            .query(&[
+                ("jql", jql.to_string()),
+                ("fields", fields.to_string()),
+                ("maxResults", max_results.to_string()),
+                ("startAt", start_at.to_string()),
+            ])
             .basic_auth(user_email, Some(api_token))
             .header("Accept", "application/json")
             .send()
@@ -192,23 +400,112 @@ fn fetch_all_jira_issues(
     Ok(all_issues)
 }
 
-/// Build a map from jira_key → kanbus identifier by scanning existing issue files.
-fn build_jira_key_index(
-    existing_ids: &HashSet<String>,
-    issues_dir: &Path,
-) -> BTreeMap<String, String> {
-    let mut index = BTreeMap::new();
+/// Lookup tables from Jira identity to Kanbus identifiers.
+#[derive(Debug, Default)]
+struct JiraIndex {
+    /// Jira issue key (e.g. AQ-12) to Kanbus identifier.
+    by_key: BTreeMap<String, String>,
+    /// Stable Jira issue id to Kanbus identifier.
+    by_id: BTreeMap<String, String>,
+}
+
+/// Build Jira lookup tables by scanning existing issue files.
+fn build_jira_index(existing_ids: &HashSet<String>, issues_dir: &Path) -> JiraIndex {
+    let mut index = JiraIndex::default();
     for id in existing_ids {
         let path = issue_path_for_identifier(issues_dir, id);
         if let Ok(issue) = read_issue_from_file(&path) {
             if let Some(Value::String(jira_key)) = issue.custom.get("jira_key") {
-                index.insert(jira_key.clone(), id.clone());
+                index.by_key.insert(jira_key.clone(), id.clone());
+            }
+            if let Some(Value::String(jira_id)) = issue.custom.get("jira_id") {
+                index.by_id.insert(jira_id.clone(), id.clone());
             }
         }
     }
     index
 }
 
+/// Carry over local state that Jira does not manage onto a freshly mapped issue.
+///
+/// Keeps the original creation time, comments added locally, and dependencies on
+/// issues that are not synchronized from Jira.
+fn merge_local_fields(issue: &mut IssueData, existing: IssueData, synced_ids: &HashSet<&String>) {
+    issue.created_at = existing.created_at;
+
+    let jira_comment_ids: HashSet<String> = issue
+        .comments
+        .iter()
+        .filter_map(|comment| comment.id.clone())
+        .collect();
+    for comment in existing.comments {
+        let from_jira = comment
+            .id
+            .as_ref()
+            .is_some_and(|id| jira_comment_ids.contains(id));
+        if !from_jira {
+            issue.comments.push(comment);
+        }
+    }
+    issue.comments.sort_by_key(|comment| comment.created_at);
+
+    for dependency in existing.dependencies {
+        if !synced_ids.contains(&dependency.target) {
+            issue.dependencies.push(dependency);
+        }
+    }
+
+    // The issue is present in Jira again, so drop any removal markers; the
+    // status comes from Jira.
+    for (key, value) in existing.custom {
+        if key == JIRA_REMOVED_AT || key == JIRA_STATUS_BEFORE_REMOVAL {
+            continue;
+        }
+        issue.custom.entry(key).or_insert(value);
+    }
+}
+
+/// Close a Kanbus issue whose Jira counterpart no longer exists in the project.
+///
+/// The previous status is recorded so the issue can be reopened if it returns.
+fn mark_removed_from_jira(
+    mut issue: IssueData,
+    removed_at: DateTime<Utc>,
+    removed_status: &str,
+) -> IssueData {
+    if issue.status != removed_status {
+        issue.custom.insert(
+            JIRA_STATUS_BEFORE_REMOVAL.to_string(),
+            Value::String(issue.status.clone()),
+        );
+        issue.status = removed_status.to_string();
+        issue.closed_at = Some(removed_at);
+    }
+    issue.updated_at = removed_at;
+    issue.custom.insert(
+        JIRA_REMOVED_AT.to_string(),
+        Value::String(removed_at.to_rfc3339()),
+    );
+    issue
+}
+
+/// Reopen an issue closed by `mark_removed_from_jira` whose Jira counterpart is back.
+fn restore_removed_from_jira(mut issue: IssueData, restored_at: DateTime<Utc>) -> IssueData {
+    issue.custom.remove(JIRA_REMOVED_AT);
+    if let Some(Value::String(status)) = issue.custom.remove(JIRA_STATUS_BEFORE_REMOVAL) {
+        issue.status = status;
+        issue.closed_at = None;
+    }
+    issue.updated_at = restored_at;
+    issue
+}
+
+fn short_identifier(kanbus_id: &str) -> &str {
+    &kanbus_id[..kanbus_id
+        .len()
+        .min(kanbus_id.find('-').map_or(6, |i| i + 7))]
+}
+
 fn jira_issue_key(issue: &Value) -> String {
     issue["key"].as_str().unwrap_or("").to_string()
 }
@@ -269,6 +566,7 @@ fn map_jira_to_kanbus(
         .unwrap_or_default();
 
     let comments = extract_comments(&fields["comment"]);
+    let dependencies = extract_issue_links(&fields["issuelinks"], jira_key_to_kanbus_id);
 
     let created_at =
         parse_jira_datetime(fields["created"].as_str().unwrap_or("")).unwrap_or_else(Utc::now);
@@ -281,6 +579,12 @@ fn map_jira_to_kanbus(
 
     let mut custom: BTreeMap<String, serde_json::Value> = BTreeMap::new();
     custom.insert("jira_key".to_string(), serde_json::Value::String(jira_key));
+    if let Some(jira_id) = jira_issue["id"].as_str() {
+        custom.insert(
+            "jira_id".to_string(),
+            serde_json::Value::String(jira_id.to_string()),
+        );
+    }
 
     Ok(IssueData {
         identifier: String::new(), // filled in by caller
@@ -293,7 +597,7 @@ fn map_jira_to_kanbus(
         creator,
         parent,
         labels,
-        dependencies: Vec::new(),
+        dependencies,
         comments,
         created_at,
         updated_at,
@@ -376,6 +680,41 @@ fn extract_comments(comment_field: &Value) -> Vec<IssueComment> {
         .collect()
 }
 
+/// Map Jira issue links to Kanbus dependency links.
+///
+/// "Blocks" links seen from the blocked side become `blocked-by` dependencies;
+/// every other link type is recorded once, from its outward side, as `relates-to`.
+/// Links to issues that are not synchronized are skipped.
+fn extract_issue_links(
+    links_field: &Value,
+    jira_key_to_kanbus_id: &BTreeMap<String, String>,
+) -> Vec<DependencyLink> {
+    let mut dependencies: Vec<DependencyLink> = Vec::new();
+    for link in links_field.as_array().into_iter().flatten() {
+        let is_blocks = link["type"]["name"]
+            .as_str()
+            .is_some_and(|name| name.eq_ignore_ascii_case("blocks"));
+        let (linked_key, dependency_type) = if is_blocks {
+            (link["inwardIssue"]["key"].as_str(), "blocked-by")
+        } else {
+            (link["outwardIssue"]["key"].as_str(), "relates-to")
+        };
+        let Some(target) = linked_key.and_then(|key| jira_key_to_kanbus_id.get(key)) else {
+            continue;
+        };
+        let duplicate = dependencies.iter().any(|existing| {
+            existing.target == *target && existing.dependency_type == dependency_type
+        });
+        if !duplicate {
+            dependencies.push(DependencyLink {
+                target: target.clone(),
+                dependency_type: dependency_type.to_string(),
+            });
+        }
+    }
+    dependencies
+}
+
 /// Map a Jira status name to a Kanbus status key.
 fn map_jira_status(jira_status: &str) -> String {
     match jira_status.to_lowercase().as_str() {
@@ -481,4 +820,185 @@ mod tests {
         let parsed = parse_jira_datetime("2026-01-01T12:34:56Z").expect("parse timestamp");
         assert_eq!(parsed.to_rfc3339(), "2026-01-01T12:34:56+00:00");
     }
+
+    #[test]
+    fn build_pull_jql_uses_relative_updated_bound_when_incremental() {
+        let now = DateTime::parse_from_rfc3339("2026-01-01T12:00:00Z")
+            .expect("parse now")
+            .with_timezone(&Utc);
+        assert_eq!(
+            build_pull_jql("AQ", None, now),
+            "project=AQ ORDER BY created ASC"
+        );
+        let since = now - chrono::Duration::minutes(30);
+        assert_eq!(
+            build_pull_jql("AQ", Some(since), now),
+            "project=AQ AND updated >= \"-35m\" ORDER BY created ASC"
+        );
+    }
+
+    #[test]
+    fn sync_state_round_trips_and_defaults_when_missing() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let project_dir = temp.path().join("project");
+        let missing = load_sync_state(&project_dir).expect("load missing state");
+        assert!(missing.last_pull_at.is_none());
+        assert!(missing.issue_map.is_empty());
+
+        let state = JiraSyncState {
+            jira_project_key: Some("AQ".to_string()),
+            last_pull_at: parse_jira_datetime("2026-01-01T00:00:00Z"),
+            issue_map: BTreeMap::from([("AQ-1".to_string(), "kanbus-1".to_string())]),
+        };
+        save_sync_state(&project_dir, &state).expect("save state");
+        assert!(sync_state_path(&project_dir).ends_with(".cache/jira_sync_state.json"));
+        let loaded = load_sync_state(&project_dir).expect("load state");
+        assert_eq!(loaded.jira_project_key.as_deref(), Some("AQ"));
+        assert_eq!(loaded.last_pull_at, state.last_pull_at);
+        assert_eq!(
+            loaded.issue_map.get("AQ-1").map(String::as_str),
+            Some("kanbus-1")
+        );
+    }
+
+    #[test]
+    fn extract_issue_links_maps_blocks_and_other_link_types() {
+        let index = BTreeMap::from([
+            ("AQ-1".to_string(), "kanbus-1".to_string()),
+            ("AQ-2".to_string(), "kanbus-2".to_string()),
+        ]);
+        let links = json!([
+            {"type": {"name": "Blocks"}, "inwardIssue": {"key": "AQ-1"}},
+            {"type": {"name": "Blocks"}, "outwardIssue": {"key": "AQ-2"}},
+            {"type": {"name": "Relates"}, "outwardIssue": {"key": "AQ-2"}},
+            {"type": {"name": "Relates"}, "inwardIssue": {"key": "AQ-1"}},
+            {"type": {"name": "Relates"}, "outwardIssue": {"key": "OTHER-9"}}
+        ]);
+        let dependencies = extract_issue_links(&links, &index);
+        let pairs: Vec<(&str, &str)> = dependencies
+            .iter()
+            .map(|link| (link.target.as_str(), link.dependency_type.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![("kanbus-1", "blocked-by"), ("kanbus-2", "relates-to")]
+        );
+    }
+
+    fn sample_issue(identifier: &str) -> IssueData {
+        let created_at = parse_jira_datetime("2026-01-01T00:00:00Z").expect("timestamp");
+        IssueData {
+            identifier: identifier.to_string(),
+            title: "Sample".to_string(),
+            description: String::new(),
+            issue_type: "task".to_string(),
+            status: "open".to_string(),
+            priority: 2,
+            assignee: None,
+            creator: None,
+            parent: None,
+            labels: Vec::new(),
+            dependencies: Vec::new(),
+            comments: Vec::new(),
+            created_at,
+            updated_at: created_at,
+            closed_at: None,
+            custom: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn merge_local_fields_keeps_local_comments_and_unsynced_dependencies() {
+        let mut existing = sample_issue("kanbus-1");
+        existing.comments = vec![
+            IssueComment {
+                id: Some("10".to_string()),
+                author: "Jira".to_string(),
+                text: "old jira text".to_string(),
+                created_at: existing.created_at,
+            },
+            IssueComment {
+                id: Some("local-1".to_string()),
+                author: "dev".to_string(),
+                text: "local note".to_string(),
+                created_at: existing.created_at,
+            },
+        ];
+        existing.dependencies = vec![
+            DependencyLink {
+                target: "kanbus-2".to_string(),
+                dependency_type: "blocked-by".to_string(),
+            },
+            DependencyLink {
+                target: "kanbus-local".to_string(),
+                dependency_type: "relates-to".to_string(),
+            },
+        ];
+        existing
+            .custom
+            .insert("estimate".to_string(), Value::String("3".to_string()));
+
+        let mut incoming = sample_issue("kanbus-1");
+        incoming.created_at = Utc::now();
+        incoming.comments = vec![IssueComment {
+            id: Some("10".to_string()),
+            author: "Jira".to_string(),
+            text: "new jira text".to_string(),
+            created_at: existing.created_at,
+        }];
+
+        let synced = String::from("kanbus-2");
+        let synced_ids: HashSet<&String> = HashSet::from([&synced]);
+        let original_created_at = existing.created_at;
+        merge_local_fields(&mut incoming, existing, &synced_ids);
+
+        assert_eq!(incoming.created_at, original_created_at);
+        let texts: Vec<&str> = incoming.comments.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["new jira text", "local note"]);
+        assert_eq!(incoming.dependencies.len(), 1);
+        assert_eq!(incoming.dependencies[0].target, "kanbus-local");
+        assert!(incoming.custom.contains_key("estimate"));
+    }
+
+    #[test]
+    fn mark_removed_from_jira_closes_issue_and_records_timestamp() {
+        let removed_at = Utc::now();
+        let mut issue = sample_issue("kanbus-1");
+        issue.status = "in_progress".to_string();
+        let issue = mark_removed_from_jira(issue, removed_at, "done");
+        assert_eq!(issue.status, "done");
+        assert_eq!(issue.closed_at, Some(removed_at));
+        assert!(issue.custom.contains_key(JIRA_REMOVED_AT));
+
+        let restored = restore_removed_from_jira(issue.clone(), Utc::now());
+        assert_eq!(restored.status, "in_progress");
+        assert_eq!(restored.closed_at, None);
+        assert!(restored.custom.is_empty());
+
+        let mut incoming = sample_issue("kanbus-1");
+        incoming.status = "open".to_string();
+        merge_local_fields(&mut incoming, issue, &HashSet::new());
+        assert_eq!(incoming.status, "open");
+        assert!(!incoming.custom.contains_key(JIRA_REMOVED_AT));
+        assert!(!incoming.custom.contains_key(JIRA_STATUS_BEFORE_REMOVAL));
+    }
+
+    #[test]
+    fn closed_status_follows_the_configured_done_category() {
+        let mut configuration = crate::config::default_project_configuration();
+        assert_eq!(closed_status(&configuration), "closed");
+        configuration
+            .statuses
+            .retain(|status| status.key != "closed");
+        configuration
+            .statuses
+            .push(crate::models::StatusDefinition {
+                key: "done".to_string(),
+                name: "Done".to_string(),
+                category: "Done".to_string(),
+                color: None,
+                collapsed: false,
+            });
+        assert_eq!(closed_status(&configuration), "done");
+    }
 }
@@ -1,8 +1,11 @@
 //! Kanbus Rust library.
 
 pub mod agents_management;
+pub mod ai_summary;
+pub mod beads_export;
 pub mod beads_write;
 pub mod cache;
+pub mod claims;
 pub mod cli;
 pub mod cloud_tokens;
 pub mod config;
@@ -18,11 +21,15 @@ pub mod daemon_paths;
 pub mod daemon_protocol;
 pub mod daemon_server;
 pub mod dependencies;
+pub mod dependency_analysis;
 pub mod dependency_tree;
+pub mod dependency_types;
 pub mod doctor;
 pub mod error;
 pub mod event_history;
 pub mod file_io;
+pub mod github_issues_sync;
+pub mod github_scanning_sync;
 pub mod github_security_sync;
 pub mod gossip;
 pub mod hierarchy;
@@ -34,14 +41,18 @@ pub mod issue_comment;
 pub mod issue_creation;
 pub mod issue_delete;
 pub mod issue_display;
+pub mod issue_export;
 pub mod issue_files;
+pub mod issue_import;
 pub mod issue_line;
 pub mod issue_listing;
 pub mod issue_lookup;
+pub mod issue_numbers;
 pub mod issue_transfer;
 pub mod issue_update;
 pub mod jira_sync;
 pub mod maintenance;
+pub mod mcp_server;
 pub mod migration;
 pub mod models;
 pub mod notification_events;
@@ -55,9 +66,16 @@ pub mod project;
 pub mod project_management_template;
 pub mod queries;
 pub mod rich_text_signals;
+pub mod sarif_import;
+pub mod saved_views;
 pub mod snyk_sync;
 pub mod text_editor;
 pub mod users;
 pub mod wiki;
+pub mod wiki_backlinks;
+pub mod wiki_export;
+pub mod wiki_history;
+pub mod wiki_links;
 pub mod wiki_templates;
+pub mod work_queue;
 pub mod workflows;
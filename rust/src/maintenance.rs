@@ -5,14 +5,18 @@ use std::fs;
 use std::path::Path;
 
 use crate::config_loader::load_project_configuration;
+use crate::dependency_types::DependencyTypes;
 use crate::error::KanbusError;
 use crate::file_io::{get_configuration_path, load_project_directory};
-use crate::hierarchy::validate_parent_child_relationship;
+use crate::hierarchy::{
+    ancestor_depth, validate_child_limit, validate_hierarchy_depth,
+    validate_parent_child_relationship,
+};
+use crate::issue_numbers::find_duplicate_numbers;
 use crate::models::IssueData;
+use crate::wiki_backlinks::read_backlink_index;
 use crate::workflows::get_workflow_for_issue_type;
 
-const ALLOWED_DEPENDENCY_TYPES: [&str; 2] = ["blocked-by", "relates-to"];
-
 /// Aggregate issue statistics for a project.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProjectStats {
@@ -94,6 +98,9 @@ pub fn validate_project(root: &Path) -> Result<(), KanbusError> {
     }
 
     validate_references(&issues, &configuration, &mut errors);
+    validate_child_limits(&issues, &configuration, &mut errors);
+    validate_issue_numbers(&issues, &mut errors);
+    validate_wiki_links(root, &mut errors);
 
     if errors.is_empty() {
         Ok(())
@@ -214,11 +221,9 @@ fn validate_issue_fields(
         ));
     }
 
+    let dependency_types = DependencyTypes::from_configuration(Some(configuration));
     for dependency in &issue.dependencies {
-        if !ALLOWED_DEPENDENCY_TYPES
-            .iter()
-            .any(|entry| *entry == dependency.dependency_type)
-        {
+        if !dependency_types.contains(&dependency.dependency_type) {
             errors.push(format!(
                 "{filename}: invalid dependency type '{}'",
                 dependency.dependency_type
@@ -244,6 +249,10 @@ fn validate_references(
     configuration: &crate::models::ProjectConfiguration,
     errors: &mut Vec<String>,
 ) {
+    let lookup: BTreeMap<&str, &IssueData> = issues
+        .iter()
+        .map(|(identifier, issue)| (identifier.as_str(), issue))
+        .collect();
     for issue in issues.values() {
         if let Some(parent_id) = &issue.parent {
             match issues.get(parent_id) {
@@ -255,6 +264,10 @@ fn validate_references(
                     ) {
                         errors.push(format!("{}: {}", issue.identifier, error));
                     }
+                    let depth = ancestor_depth(&issue.identifier, &lookup);
+                    if let Err(error) = validate_hierarchy_depth(configuration, depth) {
+                        errors.push(format!("{}: {}", issue.identifier, error));
+                    }
                 }
                 None => errors.push(format!(
                     "{}: parent '{}' does not exist",
@@ -274,6 +287,61 @@ fn validate_references(
     }
 }
 
+fn validate_child_limits(
+    issues: &BTreeMap<String, IssueData>,
+    configuration: &crate::models::ProjectConfiguration,
+    errors: &mut Vec<String>,
+) {
+    let mut child_counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for issue in issues.values() {
+        if let Some(parent_id) = &issue.parent {
+            *child_counts.entry(parent_id.as_str()).or_default() += 1;
+        }
+    }
+    for (parent_id, count) in child_counts {
+        let Some(parent) = issues.get(parent_id) else {
+            continue;
+        };
+        if let Err(error) =
+            validate_child_limit(configuration, &parent.issue_type, parent_id, count)
+        {
+            errors.push(format!("{parent_id}: {error}"));
+        }
+    }
+}
+
+fn validate_issue_numbers(issues: &BTreeMap<String, IssueData>, errors: &mut Vec<String>) {
+    let issues: Vec<IssueData> = issues.values().cloned().collect();
+    for (number, holders) in find_duplicate_numbers(&issues) {
+        errors.push(format!(
+            "issue number {number} is held by {}; run 'kbs ids reconcile'",
+            holders.join(", ")
+        ));
+    }
+}
+
+fn validate_wiki_links(root: &Path, errors: &mut Vec<String>) {
+    let index = match read_backlink_index(root) {
+        Ok(index) => index,
+        Err(error) => {
+            errors.push(format!("wiki links could not be checked: {error}"));
+            return;
+        }
+    };
+    for problem in &index.broken_links {
+        errors.push(format!(
+            "wiki {}: page link '[[{}]]' does not exist",
+            problem.page, problem.target
+        ));
+    }
+    for problem in &index.missing_issues {
+        errors.push(format!(
+            "wiki {}: referenced issue '{}' does not exist",
+            problem.page, problem.target
+        ));
+    }
+}
+
 fn format_errors(errors: &[String]) -> String {
     format!("validation failed:\n{}", errors.join("\n"))
 }
@@ -0,0 +1,817 @@
+//! Model Context Protocol server for agents.
+//!
+//! Speaks newline-delimited JSON-RPC 2.0 over stdio and exposes the issue and
+//! wiki operations behind the CLI as MCP tools and resources. Tool results are
+//! structured JSON so agents never have to parse human-oriented output.
+
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Map, Value};
+
+use crate::claims::release_expired_claims;
+use crate::cli::{
+    close_issue_with_hooks, create_issue_with_hooks, resolve_beads_mode, update_issue_with_hooks,
+    CreateIssueOptions, UpdateIssueOptions,
+};
+use crate::console_backend::FileStore;
+use crate::console_wiki::wiki_list_prefix;
+use crate::dependencies::{add_dependency, list_ready_issues, remove_dependency};
+use crate::error::KanbusError;
+use crate::file_io::{find_project_local_directory, load_project_directory};
+use crate::hooks::HookExecutionOptions;
+use crate::issue_comment::add_comment;
+use crate::issue_listing::{list_issues, load_issues_from_directory};
+use crate::issue_lookup::load_issue_from_project;
+use crate::models::IssueData;
+use crate::policy_context::PolicyOperation;
+use crate::policy_evaluator::{GuidanceItem, GuidanceSeverity};
+use crate::policy_guidance::{
+    collect_guidance_for_issue, guidance_enabled, sorted_deduped_guidance_items,
+};
+use crate::users::get_current_user;
+use crate::wiki::{list_wiki_pages, render_wiki_page, WikiRenderRequest};
+
+/// Protocol revisions this server understands, newest first.
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+const ISSUE_URI_PREFIX: &str = "kanbus://issues/";
+const WIKI_URI_PREFIX: &str = "kanbus://wiki/";
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// JSON-RPC error returned for malformed or unsupported requests.
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// MCP server bound to a repository root.
+#[derive(Debug, Clone)]
+pub struct McpServer {
+    root: PathBuf,
+}
+
+impl McpServer {
+    /// Create a server that operates on the given repository root.
+    pub fn new(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+        }
+    }
+
+    /// Handle one JSON-RPC message.
+    ///
+    /// # Returns
+    /// The response to send, or `None` for notifications.
+    pub fn handle_message(&self, message: &Value) -> Option<Value> {
+        let Some(object) = message.as_object() else {
+            return Some(error_response(
+                Value::Null,
+                RpcError::new(INVALID_REQUEST, "expected a JSON-RPC request object"),
+            ));
+        };
+        let id = object.get("id").cloned();
+        let Some(method) = object.get("method").and_then(Value::as_str) else {
+            return id
+                .map(|id| error_response(id, RpcError::new(INVALID_REQUEST, "missing method")));
+        };
+        let params = object.get("params").cloned().unwrap_or(Value::Null);
+        let outcome = self.dispatch(method, &params);
+        let id = id?;
+        Some(match outcome {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => error_response(id, error),
+        })
+    }
+
+    fn dispatch(&self, method: &str, params: &Value) -> Result<Value, RpcError> {
+        match method {
+            "initialize" => Ok(initialize_result(params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tool_definitions() })),
+            "tools/call" => self.call_tool(params),
+            "resources/list" => self.list_resources(),
+            "resources/templates/list" => Ok(json!({ "resourceTemplates": resource_templates() })),
+            "resources/read" => self.read_resource(params),
+            method if method.starts_with("notifications/") => Ok(Value::Null),
+            other => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("unknown method: {other}"),
+            )),
+        }
+    }
+
+    fn call_tool(&self, params: &Value) -> Result<Value, RpcError> {
+        let name = params
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| RpcError::new(INVALID_PARAMS, "missing tool name"))?;
+        let empty = Map::new();
+        let arguments = params
+            .get("arguments")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+        let outcome = match name {
+            "create_issue" => self.create_issue(arguments),
+            "show_issue" => self.show_issue(arguments),
+            "list_issues" => self.list_issues(arguments),
+            "ready_issues" => self.ready_issues(arguments),
+            "update_issue" => self.update_issue(arguments),
+            "add_comment" => self.add_comment(arguments),
+            "manage_dependency" => self.manage_dependency(arguments),
+            "close_issue" => self.close_issue(arguments),
+            "render_wiki_page" => self.render_wiki_page(arguments),
+            other => {
+                return Err(RpcError::new(
+                    INVALID_PARAMS,
+                    format!("unknown tool: {other}"),
+                ))
+            }
+        };
+        Ok(match outcome {
+            Ok(structured) => tool_result(structured, false),
+            Err(error) => tool_result(json!({ "error": error_details(&error) }), true),
+        })
+    }
+
+    fn create_issue(&self, arguments: &Map<String, Value>) -> Result<Value, KanbusError> {
+        let options = CreateIssueOptions {
+            title: required_string(arguments, "title")?,
+            issue_type: optional_string(arguments, "type"),
+            priority: optional_priority(arguments)?,
+            assignee: optional_string(arguments, "assignee"),
+            parent: optional_string(arguments, "parent"),
+            labels: string_list(arguments, "labels"),
+            description: optional_string(arguments, "description"),
+            local: optional_bool(arguments, "local").unwrap_or(false),
+            validate: true,
+        };
+        let (beads_mode, hook_options) = self.mutation_context()?;
+        let (issue, _) = create_issue_with_hooks(&self.root, options, beads_mode, hook_options)?;
+        Ok(self.issue_with_guidance(&issue, PolicyOperation::Create))
+    }
+
+    fn show_issue(&self, arguments: &Map<String, Value>) -> Result<Value, KanbusError> {
+        let identifier = required_string(arguments, "id")?;
+        let lookup = load_issue_from_project(&self.root, &identifier)?;
+        Ok(json!({ "issue": lookup.issue }))
+    }
+
+    fn list_issues(&self, arguments: &Map<String, Value>) -> Result<Value, KanbusError> {
+        let issues = list_issues(
+            &self.root,
+            optional_string(arguments, "status").as_deref(),
+            optional_string(arguments, "type").as_deref(),
+            optional_string(arguments, "assignee").as_deref(),
+            optional_string(arguments, "label").as_deref(),
+            optional_string(arguments, "parent").as_deref(),
+            optional_string(arguments, "sort").as_deref(),
+            optional_string(arguments, "search").as_deref(),
+            &[],
+            optional_bool(arguments, "include_local").unwrap_or(true),
+            optional_bool(arguments, "local_only").unwrap_or(false),
+        )?;
+        Ok(json!({ "issues": issues }))
+    }
+
+    fn ready_issues(&self, arguments: &Map<String, Value>) -> Result<Value, KanbusError> {
+        let released = release_expired_claims(&self.root, &get_current_user())?;
+        let issues = list_ready_issues(
+            &self.root,
+            optional_bool(arguments, "include_local").unwrap_or(true),
+            optional_bool(arguments, "local_only").unwrap_or(false),
+        )?;
+        Ok(json!({ "issues": issues, "released_claims": released }))
+    }
+
+    fn update_issue(&self, arguments: &Map<String, Value>) -> Result<Value, KanbusError> {
+        let identifier = required_string(arguments, "id")?;
+        let set_labels = arguments
+            .contains_key("set_labels")
+            .then(|| string_list(arguments, "set_labels").join(","));
+        let options = UpdateIssueOptions {
+            identifier: identifier.clone(),
+            title: optional_string(arguments, "title"),
+            description: optional_string(arguments, "description"),
+            status: optional_string(arguments, "status"),
+            priority: optional_priority(arguments)?,
+            assignee: optional_string(arguments, "assignee"),
+            add_labels: string_list(arguments, "add_labels"),
+            remove_labels: string_list(arguments, "remove_labels"),
+            set_labels,
+            parent: optional_string(arguments, "parent"),
+            issue_type: optional_string(arguments, "type"),
+            claim: optional_bool(arguments, "claim").unwrap_or(false),
+            validate: true,
+        };
+        let (beads_mode, hook_options) = self.mutation_context()?;
+        let issue = update_issue_with_hooks(&self.root, options, beads_mode, hook_options)?
+            .ok_or_else(|| KanbusError::IssueOperation("not found".to_string()))?;
+        Ok(self.issue_with_guidance(&issue, PolicyOperation::Update))
+    }
+
+    fn add_comment(&self, arguments: &Map<String, Value>) -> Result<Value, KanbusError> {
+        let identifier = required_string(arguments, "id")?;
+        let text = required_string(arguments, "text")?;
+        let author = optional_string(arguments, "author").unwrap_or_else(get_current_user);
+        let result = add_comment(&self.root, &identifier, &author, &text)?;
+        Ok(json!({ "issue_id": result.issue.identifier, "comment": result.comment }))
+    }
+
+    fn manage_dependency(&self, arguments: &Map<String, Value>) -> Result<Value, KanbusError> {
+        let identifier = required_string(arguments, "id")?;
+        let target = required_string(arguments, "target")?;
+        let dependency_type = required_string(arguments, "type")?;
+        let action = optional_string(arguments, "action").unwrap_or_else(|| "add".to_string());
+        let issue = match action.as_str() {
+            "add" => add_dependency(&self.root, &identifier, &target, &dependency_type)?,
+            "remove" => remove_dependency(&self.root, &identifier, &target, &dependency_type)?,
+            other => {
+                return Err(KanbusError::IssueOperation(format!(
+                    "unknown dependency action '{other}' (expected add or remove)"
+                )))
+            }
+        };
+        Ok(json!({ "issue": issue }))
+    }
+
+    fn close_issue(&self, arguments: &Map<String, Value>) -> Result<Value, KanbusError> {
+        let identifier = required_string(arguments, "id")?;
+        let (beads_mode, hook_options) = self.mutation_context()?;
+        let issue = close_issue_with_hooks(&self.root, &identifier, beads_mode, hook_options)?
+            .ok_or_else(|| KanbusError::IssueOperation("not found".to_string()))?;
+        Ok(self.issue_with_guidance(&issue, PolicyOperation::Close))
+    }
+
+    fn render_wiki_page(&self, arguments: &Map<String, Value>) -> Result<Value, KanbusError> {
+        let page = required_string(arguments, "page")?;
+        let page_path = self.wiki_page_path(&page)?;
+        let content = render_wiki_page(&WikiRenderRequest {
+            root: self.root.clone(),
+            page_path: PathBuf::from(&page_path),
+        })?;
+        Ok(json!({ "page": page_path, "content": content }))
+    }
+
+    /// Beads routing and hook options for tools that change issues.
+    ///
+    /// Guidance is returned in the tool result instead of on stderr.
+    fn mutation_context(&self) -> Result<(bool, HookExecutionOptions), KanbusError> {
+        let (beads_mode, _) = resolve_beads_mode(&self.root, false)?;
+        Ok((
+            beads_mode,
+            HookExecutionOptions {
+                beads_mode,
+                no_hooks: false,
+                no_guidance: true,
+            },
+        ))
+    }
+
+    fn issue_with_guidance(&self, issue: &IssueData, operation: PolicyOperation) -> Value {
+        let guidance = if guidance_enabled(false) {
+            collect_guidance_for_issue(&self.root, issue, operation)
+                .map(|report| sorted_deduped_guidance_items(&report.guidance_items))
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        json!({
+            "issue": issue,
+            "guidance": guidance.iter().map(guidance_json).collect::<Vec<_>>(),
+        })
+    }
+
+    /// Resolve a page given relative to the wiki root or to the repository root.
+    fn wiki_page_path(&self, page: &str) -> Result<String, KanbusError> {
+        if page.split(['/', '\\']).any(|part| part == "..") {
+            return Err(KanbusError::IssueOperation(
+                "invalid wiki page path".to_string(),
+            ));
+        }
+        let prefix = wiki_list_prefix(&FileStore::new(&self.root))?;
+        if page.starts_with(&format!("{prefix}/")) {
+            Ok(page.to_string())
+        } else {
+            Ok(format!("{prefix}/{}", page.trim_start_matches('/')))
+        }
+    }
+
+    fn list_resources(&self) -> Result<Value, RpcError> {
+        let project_dir = load_project_directory(&self.root).map_err(internal_error)?;
+        let mut issues =
+            load_issues_from_directory(&project_dir.join("issues")).map_err(internal_error)?;
+        if let Some(local_dir) = find_project_local_directory(&project_dir) {
+            let local_issues = local_dir.join("issues");
+            if local_issues.is_dir() {
+                issues.extend(load_issues_from_directory(&local_issues).map_err(internal_error)?);
+            }
+        }
+        issues.sort_by(|left, right| left.identifier.cmp(&right.identifier));
+        let mut resources: Vec<Value> = issues
+            .iter()
+            .map(|issue| {
+                json!({
+                    "uri": format!("{ISSUE_URI_PREFIX}{}", issue.identifier),
+                    "name": issue.identifier,
+                    "title": issue.title,
+                    "mimeType": "application/json",
+                })
+            })
+            .collect();
+        let prefix = wiki_list_prefix(&FileStore::new(&self.root)).map_err(internal_error)?;
+        for page in list_wiki_pages(&self.root).map_err(internal_error)? {
+            let relative = page
+                .strip_prefix(&format!("{prefix}/"))
+                .unwrap_or(&page)
+                .to_string();
+            resources.push(json!({
+                "uri": format!("{WIKI_URI_PREFIX}{relative}"),
+                "name": relative,
+                "mimeType": "text/markdown",
+            }));
+        }
+        Ok(json!({ "resources": resources }))
+    }
+
+    fn read_resource(&self, params: &Value) -> Result<Value, RpcError> {
+        let uri = params
+            .get("uri")
+            .and_then(Value::as_str)
+            .ok_or_else(|| RpcError::new(INVALID_PARAMS, "missing resource uri"))?;
+        if let Some(identifier) = uri.strip_prefix(ISSUE_URI_PREFIX) {
+            let lookup = load_issue_from_project(&self.root, identifier)
+                .map_err(|error| RpcError::new(INVALID_PARAMS, error.to_string()))?;
+            let text = serde_json::to_string_pretty(&lookup.issue).map_err(internal_error)?;
+            return Ok(json!({
+                "contents": [{ "uri": uri, "mimeType": "application/json", "text": text }]
+            }));
+        }
+        if let Some(page) = uri.strip_prefix(WIKI_URI_PREFIX) {
+            let path = self
+                .wiki_page_path(page)
+                .map_err(|error| RpcError::new(INVALID_PARAMS, error.to_string()))?;
+            let text = std::fs::read_to_string(self.root.join(&path)).map_err(|_| {
+                RpcError::new(INVALID_PARAMS, format!("wiki page not found: {page}"))
+            })?;
+            return Ok(json!({
+                "contents": [{ "uri": uri, "mimeType": "text/markdown", "text": text }]
+            }));
+        }
+        Err(RpcError::new(
+            INVALID_PARAMS,
+            format!("unknown resource: {uri}"),
+        ))
+    }
+}
+
+/// Serve MCP requests from stdin until it closes, writing responses to stdout.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if stdio cannot be read or written.
+pub fn run_mcp_server(root: &Path) -> Result<(), KanbusError> {
+    let server = McpServer::new(root);
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    for line in stdin.lock().lines() {
+        let line = line.map_err(|error| KanbusError::Io(error.to_string()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => server.handle_message(&message),
+            Err(error) => Some(error_response(
+                Value::Null,
+                RpcError::new(PARSE_ERROR, format!("invalid JSON: {error}")),
+            )),
+        };
+        if let Some(response) = response {
+            writeln!(stdout, "{response}").map_err(|error| KanbusError::Io(error.to_string()))?;
+            stdout
+                .flush()
+                .map_err(|error| KanbusError::Io(error.to_string()))?;
+        }
+    }
+    Ok(())
+}
+
+fn initialize_result(params: &Value) -> Value {
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let version = requested
+        .filter(|version| SUPPORTED_PROTOCOL_VERSIONS.contains(version))
+        .unwrap_or(SUPPORTED_PROTOCOL_VERSIONS[0]);
+    json!({
+        "protocolVersion": version,
+        "capabilities": {
+            "tools": { "listChanged": false },
+            "resources": { "listChanged": false, "subscribe": false },
+        },
+        "serverInfo": { "name": "kanbus", "version": env!("CARGO_PKG_VERSION") },
+        "instructions": "Kanbus issue tracker. Tool results are JSON; mutations report \
+    policy guidance and failures report policy violations with their explanations.",
+    })
+}
+
+fn tool_definitions() -> Vec<Value> {
+    let id =
+        json!({ "type": "string", "description": "Issue identifier (full, short or sequential)." });
+    let labels = json!({ "type": "array", "items": { "type": "string" } });
+    let priority = json!({ "type": "integer", "minimum": 0 });
+    vec![
+        tool(
+            "create_issue",
+            "Create an issue. Returns the issue and any policy guidance.",
+            json!({
+                "title": { "type": "string" },
+                "type": { "type": "string" },
+                "priority": priority,
+                "assignee": { "type": "string" },
+                "parent": { "type": "string" },
+                "labels": labels,
+                "description": { "type": "string" },
+                "local": { "type": "boolean", "description": "Create in project-local storage." },
+            }),
+            &["title"],
+        ),
+        tool(
+            "show_issue",
+            "Return a single issue.",
+            json!({ "id": id }),
+            &["id"],
+        ),
+        tool(
+            "list_issues",
+            "List issues matching optional filters.",
+            json!({
+                "status": { "type": "string" },
+                "type": { "type": "string" },
+                "assignee": { "type": "string" },
+                "label": { "type": "string" },
+                "parent": { "type": "string" },
+                "search": { "type": "string" },
+                "sort": { "type": "string" },
+                "include_local": { "type": "boolean" },
+                "local_only": { "type": "boolean" },
+            }),
+            &[],
+        ),
+        tool(
+            "ready_issues",
+            "List open issues without open blockers.",
+            json!({
+                "include_local": { "type": "boolean" },
+                "local_only": { "type": "boolean" },
+            }),
+            &[],
+        ),
+        tool(
+            "update_issue",
+            "Update issue fields or status. Returns the issue and any policy guidance.",
+            json!({
+                "id": id,
+                "title": { "type": "string" },
+                "description": { "type": "string" },
+                "status": { "type": "string" },
+                "assignee": { "type": "string" },
+                "priority": priority,
+                "claim": { "type": "boolean", "description": "Assign to the current user and start work." },
+                "add_labels": labels,
+                "remove_labels": labels,
+                "set_labels": labels,
+                "parent": { "type": "string" },
+                "type": { "type": "string" },
+            }),
+            &["id"],
+        ),
+        tool(
+            "add_comment",
+            "Add a comment to an issue.",
+            json!({
+                "id": id,
+                "text": { "type": "string" },
+                "author": { "type": "string" },
+            }),
+            &["id", "text"],
+        ),
+        tool(
+            "manage_dependency",
+            "Add or remove a dependency link between two issues.",
+            json!({
+                "id": id,
+                "action": { "type": "string", "enum": ["add", "remove"] },
+                "type": { "type": "string", "description": "Link type, e.g. blocked-by." },
+                "target": { "type": "string" },
+            }),
+            &["id", "type", "target"],
+        ),
+        tool(
+            "close_issue",
+            "Close an issue. Returns the issue and any policy guidance.",
+            json!({ "id": id }),
+            &["id"],
+        ),
+        tool(
+            "render_wiki_page",
+            "Render a wiki page with live issue data.",
+            json!({
+                "page": { "type": "string", "description": "Page path relative to the wiki root." },
+            }),
+            &["page"],
+        ),
+    ]
+}
+
+fn tool(name: &str, description: &str, properties: Value, required: &[&str]) -> Value {
+    json!({
+        "name": name,
+        "description": description,
+        "inputSchema": {
+            "type": "object",
+            "properties": properties,
+            "required": required,
+        },
+    })
+}
+
+fn resource_templates() -> Vec<Value> {
+    vec![
+        json!({
+            "uriTemplate": format!("{ISSUE_URI_PREFIX}{{id}}"),
+            "name": "issue",
+            "mimeType": "application/json",
+        }),
+        json!({
+            "uriTemplate": format!("{WIKI_URI_PREFIX}{{path}}"),
+            "name": "wiki page",
+            "mimeType": "text/markdown",
+        }),
+    ]
+}
+
+fn tool_result(structured: Value, is_error: bool) -> Value {
+    let text = serde_json::to_string_pretty(&structured).unwrap_or_default();
+    json!({
+        "content": [{ "type": "text", "text": text }],
+        "structuredContent": structured,
+        "isError": is_error,
+    })
+}
+
+fn error_response(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+}
+
+fn internal_error(error: impl std::fmt::Display) -> RpcError {
+    RpcError::new(-32603, error.to_string())
+}
+
+fn error_details(error: &KanbusError) -> Value {
+    let kind = match error {
+        KanbusError::Initialization(_) => "initialization",
+        KanbusError::Io(_) => "io",
+        KanbusError::IdGenerationFailed(_) => "id_generation_failed",
+        KanbusError::Configuration(_) => "configuration",
+        KanbusError::InvalidTransition(_) => "invalid_transition",
+        KanbusError::InvalidHierarchy(_) => "invalid_hierarchy",
+        KanbusError::IssueOperation(_) => "issue_operation",
+        KanbusError::ClaimConflict(_) => "claim_conflict",
+        KanbusError::ProtocolError(_) => "protocol_error",
+        KanbusError::PolicyViolation { .. } => "policy_violation",
+    };
+    let mut details = json!({ "kind": kind, "message": error.to_string() });
+    if let KanbusError::PolicyViolation {
+        policy_file,
+        scenario,
+        failed_step,
+        message,
+        issue_id,
+        details: extra,
+    } = error
+    {
+        details["policy_violation"] = json!({
+            "policy_file": policy_file.as_ref(),
+            "scenario": scenario.as_ref(),
+            "failed_step": failed_step.as_ref(),
+            "message": message.as_ref(),
+            "issue_id": issue_id.as_ref(),
+            "explanations": extra.explanations,
+            "guidance": extra.guidance,
+        });
+    }
+    details
+}
+
+fn guidance_json(item: &GuidanceItem) -> Value {
+    json!({
+        "severity": match item.severity {
+            GuidanceSeverity::Warning => "warning",
+            GuidanceSeverity::Suggestion => "suggestion",
+        },
+        "message": item.message,
+        "explanations": item.explanations,
+        "policy_file": item.policy_file,
+        "scenario": item.scenario,
+        "step": item.step,
+    })
+}
+
+fn optional_string(arguments: &Map<String, Value>, name: &str) -> Option<String> {
+    arguments
+        .get(name)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+fn required_string(arguments: &Map<String, Value>, name: &str) -> Result<String, KanbusError> {
+    optional_string(arguments, name)
+        .filter(|value| !value.trim().is_empty())
+        .ok_or_else(|| KanbusError::IssueOperation(format!("missing required argument '{name}'")))
+}
+
+fn optional_bool(arguments: &Map<String, Value>, name: &str) -> Option<bool> {
+    arguments.get(name).and_then(Value::as_bool)
+}
+
+fn optional_priority(arguments: &Map<String, Value>) -> Result<Option<u8>, KanbusError> {
+    match arguments.get("priority") {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value
+            .as_u64()
+            .and_then(|priority| u8::try_from(priority).ok())
+            .map(Some)
+            .ok_or_else(|| KanbusError::IssueOperation("invalid priority".to_string())),
+    }
+}
+
+fn string_list(arguments: &Map<String, Value>, name: &str) -> Vec<String> {
+    match arguments.get(name) {
+        Some(Value::Array(values)) => values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        Some(Value::String(value)) => value
+            .split(',')
+            .map(|label| label.trim().to_string())
+            .filter(|label| !label.is_empty())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_io::initialize_project;
+
+    fn request(server: &McpServer, id: u64, method: &str, params: Value) -> Value {
+        server
+            .handle_message(&json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "method": method,
+                "params": params,
+            }))
+            .expect("response")
+    }
+
+    fn call(server: &McpServer, name: &str, arguments: Value) -> Value {
+        let response = request(
+            server,
+            1,
+            "tools/call",
+            json!({ "name": name, "arguments": arguments }),
+        );
+        response["result"].clone()
+    }
+
+    #[test]
+    fn initialize_negotiates_version_and_lists_tools() {
+        let server = McpServer::new(Path::new("."));
+        let response = request(
+            &server,
+            1,
+            "initialize",
+            json!({ "protocolVersion": "2024-11-05", "capabilities": {} }),
+        );
+        assert_eq!(response["result"]["protocolVersion"], "2024-11-05");
+        assert!(server
+            .handle_message(&json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+            .is_none());
+
+        let tools = request(&server, 2, "tools/list", json!({}));
+        let names: Vec<&str> = tools["result"]["tools"]
+            .as_array()
+            .expect("tools")
+            .iter()
+            .filter_map(|tool| tool["name"].as_str())
+            .collect();
+        assert!(names.contains(&"create_issue"));
+        assert!(names.contains(&"render_wiki_page"));
+        let missing = request(&server, 3, "bogus/method", json!({}));
+        assert_eq!(missing["error"]["code"], METHOD_NOT_FOUND);
+    }
+
+    #[test]
+    fn tools_operate_on_issues_and_report_errors_as_json() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        initialize_project(root, false).expect("initialize project");
+        let server = McpServer::new(root);
+
+        let created = call(&server, "create_issue", json!({ "title": "Wire MCP" }));
+        assert_eq!(created["isError"], false);
+        let identifier = created["structuredContent"]["issue"]["id"]
+            .as_str()
+            .expect("identifier")
+            .to_string();
+        assert!(created["structuredContent"]["guidance"].is_array());
+
+        let commented = call(
+            &server,
+            "add_comment",
+            json!({ "id": identifier, "text": "on it", "author": "agent" }),
+        );
+        assert_eq!(commented["structuredContent"]["comment"]["text"], "on it");
+
+        let ready = call(&server, "ready_issues", json!({}));
+        assert_eq!(
+            ready["structuredContent"]["issues"][0]["id"],
+            identifier.as_str()
+        );
+
+        let closed = call(&server, "close_issue", json!({ "id": identifier }));
+        assert_eq!(closed["structuredContent"]["issue"]["status"], "closed");
+
+        let failed = call(&server, "show_issue", json!({ "id": "kanbus-missing" }));
+        assert_eq!(failed["isError"], true);
+        assert_eq!(
+            failed["structuredContent"]["error"]["kind"],
+            "issue_operation"
+        );
+
+        let resources = request(&server, 4, "resources/list", json!({}));
+        let uri = format!("{ISSUE_URI_PREFIX}{identifier}");
+        assert_eq!(resources["result"]["resources"][0]["uri"], uri.as_str());
+        let read = request(&server, 5, "resources/read", json!({ "uri": uri }));
+        let text = read["result"]["contents"][0]["text"]
+            .as_str()
+            .expect("text");
+        assert!(text.contains("Wire MCP"));
+    }
+
+    #[test]
+    fn mutating_tools_run_lifecycle_hooks_and_reject_wiki_traversal() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        initialize_project(root, false).expect("initialize project");
+        let config_path = root.join(".kanbus.yml");
+        let config = std::fs::read_to_string(&config_path).expect("read config");
+        std::fs::write(
+            &config_path,
+            config.replace(
+                "  before: {}",
+                "  before:\n    issue.create:\n      - id: deny\n        command: [\"false\"]",
+            ),
+        )
+        .expect("write config");
+        let server = McpServer::new(root);
+
+        let denied = call(&server, "create_issue", json!({ "title": "Blocked" }));
+        assert_eq!(denied["isError"], true);
+        let ready = call(&server, "ready_issues", json!({}));
+        assert_eq!(ready["structuredContent"]["issues"], json!([]));
+
+        let traversal = call(
+            &server,
+            "render_wiki_page",
+            json!({ "page": "../.kanbus.yml" }),
+        );
+        assert_eq!(traversal["isError"], true);
+        assert!(traversal["structuredContent"]["error"]["message"]
+            .as_str()
+            .expect("message")
+            .contains("invalid wiki page path"));
+    }
+}
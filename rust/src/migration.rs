@@ -6,9 +6,12 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, TimeZone, Utc};
+use serde::Serialize;
 use serde_json::Value;
 use uuid::Uuid;
 
+use crate::beads_export::KANBUS_CUSTOM_FIELD;
+use crate::config::default_project_configuration;
 use crate::config_loader::load_project_configuration;
 use crate::error::KanbusError;
 use crate::file_io::{
@@ -27,6 +30,190 @@ use crate::workflows::get_workflow_for_issue_type;
 #[derive(Debug, Clone)]
 pub struct MigrationResult {
     pub issue_count: usize,
+    pub report: MigrationReport,
+}
+
+/// Options for a Beads migration run.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationOptions {
+    /// Import into an already initialized project.
+    pub into_existing: bool,
+    /// Plan the migration without writing any files.
+    pub dry_run: bool,
+    /// Skip Beads issues that already have a Kanbus issue file.
+    pub resume: bool,
+    /// Rules applied to Beads values before the built-in mappings.
+    pub mapping: MigrationMapping,
+}
+
+/// User-supplied remapping of Beads issue types and statuses.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationMapping {
+    pub issue_types: BTreeMap<String, String>,
+    pub statuses: BTreeMap<String, String>,
+}
+
+impl MigrationMapping {
+    /// Parse a `from=to` mapping rule.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::IssueOperation` if the rule is malformed.
+    pub fn parse_rule(rule: &str) -> Result<(String, String), KanbusError> {
+        match rule.split_once('=') {
+            Some((from, to)) if !from.trim().is_empty() && !to.trim().is_empty() => {
+                Ok((from.trim().to_string(), to.trim().to_string()))
+            }
+            _ => Err(KanbusError::IssueOperation(format!(
+                "invalid mapping rule '{rule}' (expected from=to)"
+            ))),
+        }
+    }
+
+    fn issue_type(&self, raw: &str) -> String {
+        self.issue_types
+            .get(raw)
+            .cloned()
+            .unwrap_or_else(|| map_issue_type(raw))
+    }
+
+    fn status(&self, raw: &str) -> String {
+        self.statuses
+            .get(raw)
+            .cloned()
+            .unwrap_or_else(|| map_status(raw))
+    }
+}
+
+/// Machine-readable account of a migration run or plan.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MigrationReport {
+    pub dry_run: bool,
+    /// Issues written (or, in a dry run, that would be written).
+    pub migrated: Vec<String>,
+    /// Issues skipped because they were migrated by an earlier run.
+    pub skipped: Vec<String>,
+    pub remapped: Vec<RemappedValue>,
+    pub hierarchy_violations: Vec<HierarchyViolation>,
+    pub dropped_fields: Vec<DroppedField>,
+    /// Types and statuses with no Kanbus equivalent, collected by a dry run.
+    pub unknown_values: Vec<UnknownValue>,
+}
+
+/// A Beads value replaced by a mapping rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RemappedValue {
+    pub issue_id: String,
+    pub field: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// A parent-child link dropped because the hierarchy does not allow it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HierarchyViolation {
+    pub issue_id: String,
+    pub parent_id: String,
+    pub message: String,
+}
+
+/// A Beads type or status that maps to nothing the project defines.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnknownValue {
+    pub issue_id: String,
+    pub field: String,
+    pub value: String,
+}
+
+/// A Beads record field with no Kanbus equivalent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DroppedField {
+    pub issue_id: String,
+    pub field: String,
+}
+
+impl MigrationReport {
+    /// Render the report as CLI output lines.
+    ///
+    /// Remapped values and dropped fields are summarized per value; the full
+    /// per-issue detail is available by serializing the report.
+    pub fn summary_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut remaps: BTreeMap<(&str, &str, &str), usize> = BTreeMap::new();
+        for remap in &self.remapped {
+            *remaps
+                .entry((remap.field.as_str(), remap.from.as_str(), remap.to.as_str()))
+                .or_default() += 1;
+        }
+        for ((field, from, to), count) in remaps {
+            lines.push(format!("remap {field} {from} -> {to} ({count} issues)"));
+        }
+        for violation in &self.hierarchy_violations {
+            lines.push(format!(
+                "hierarchy violation: {} under {}: {}",
+                violation.issue_id, violation.parent_id, violation.message
+            ));
+        }
+        let mut dropped: BTreeMap<&str, usize> = BTreeMap::new();
+        for field in &self.dropped_fields {
+            *dropped.entry(field.field.as_str()).or_default() += 1;
+        }
+        for (field, count) in dropped {
+            lines.push(format!("drop field {field} ({count} issues)"));
+        }
+        let mut unknown: BTreeMap<(&str, &str), usize> = BTreeMap::new();
+        for value in &self.unknown_values {
+            *unknown
+                .entry((value.field.as_str(), value.value.as_str()))
+                .or_default() += 1;
+        }
+        for ((field, value), count) in unknown {
+            let flag = if field == "status" {
+                "--map-status"
+            } else {
+                "--map-type"
+            };
+            lines.push(format!(
+                "unknown {field} {value} ({count} issues); map it with {flag} {value}=..."
+            ));
+        }
+        if !self.skipped.is_empty() {
+            lines.push(format!(
+                "skipped {} already migrated issues",
+                self.skipped.len()
+            ));
+        }
+        lines
+    }
+}
+
+/// Beads record fields that the migration maps onto Kanbus issues.
+const MIGRATED_RECORD_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "description",
+    "issue_type",
+    "status",
+    "priority",
+    "created_at",
+    "updated_at",
+    "closed_at",
+    "dependencies",
+    "comments",
+    "owner",
+    "notes",
+    "acceptance_criteria",
+    "close_reason",
+    "assignee",
+    "created_by",
+    "labels",
+    KANBUS_CUSTOM_FIELD,
+];
+
+struct ConversionContext<'a> {
+    record_by_id: &'a HashMap<String, Value>,
+    configuration: &'a ProjectConfiguration,
+    mapping: &'a MigrationMapping,
+    dry_run: bool,
 }
 
 /// Load Beads issues.jsonl without migrating to project files.
@@ -50,7 +237,7 @@ pub fn load_beads_issues(root: &Path) -> Result<Vec<IssueData>, KanbusError> {
     }
 
     let records = load_beads_records(&issues_path)?;
-    let records = dedupe_beads_records(records, &issues_path)?;
+    let records = dedupe_beads_records(records, &issues_path, true)?;
     let configuration = build_beads_configuration(&records);
     let mut record_by_id: HashMap<String, Value> = HashMap::new();
     for record in &records {
@@ -61,9 +248,17 @@ pub fn load_beads_issues(root: &Path) -> Result<Vec<IssueData>, KanbusError> {
         record_by_id.insert(identifier.to_string(), record.clone());
     }
 
+    let mapping = MigrationMapping::default();
+    let context = ConversionContext {
+        record_by_id: &record_by_id,
+        configuration: &configuration,
+        mapping: &mapping,
+        dry_run: false,
+    };
+    let mut report = MigrationReport::default();
     let mut issues = Vec::with_capacity(records.len());
     for record in &records {
-        issues.push(convert_record(record, &record_by_id, &configuration)?);
+        issues.push(convert_record(record, &context, &mut report)?);
     }
     Ok(issues)
 }
@@ -200,32 +395,7 @@ fn find_beads_root_for_project_dir(project_dir: &Path) -> Option<PathBuf> {
 /// # Errors
 /// Returns `KanbusError` if migration fails.
 pub fn migrate_from_beads(root: &Path) -> Result<MigrationResult, KanbusError> {
-    ensure_git_repository(root)?;
-
-    let beads_dir = root.join(".beads");
-    if !beads_dir.exists() {
-        return Err(KanbusError::IssueOperation(
-            "no .beads directory".to_string(),
-        ));
-    }
-
-    let issues_path = beads_dir.join("issues.jsonl");
-    if !issues_path.exists() {
-        return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
-    }
-
-    let mut projects = Vec::new();
-    discover_project_directories(root, &mut projects)?;
-    let mut dotfile_projects = discover_kanbus_projects(root)?;
-    projects.append(&mut dotfile_projects);
-    if !projects.is_empty() {
-        return Err(KanbusError::IssueOperation(
-            "already initialized".to_string(),
-        ));
-    }
-
-    initialize_project(root, false)?;
-    migrate_from_beads_into_project(root)
+    run_beads_migration(root, &MigrationOptions::default())
 }
 
 /// Import Beads issues.jsonl into an existing Kanbus project.
@@ -240,6 +410,34 @@ pub fn migrate_from_beads(root: &Path) -> Result<MigrationResult, KanbusError> {
 /// Returns `KanbusError` if Beads data or Kanbus configuration is missing, or
 /// if any issue conversion/write fails.
 pub fn migrate_from_beads_into_project(root: &Path) -> Result<MigrationResult, KanbusError> {
+    run_beads_migration(
+        root,
+        &MigrationOptions {
+            into_existing: true,
+            ..MigrationOptions::default()
+        },
+    )
+}
+
+/// Migrate Beads issues.jsonl according to `options`.
+///
+/// Without `into_existing` or `resume` a new project is initialized and the
+/// run fails if one already exists. With `resume`, Beads issues that already
+/// have a Kanbus issue file are skipped, so an interrupted run can be
+/// continued. With `dry_run`, nothing is written and the returned report is
+/// the migration plan.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `options` - Migration behavior and mapping rules.
+///
+/// # Errors
+/// Returns `KanbusError` if Beads data is missing or invalid, or if any issue
+/// conversion/write fails.
+pub fn run_beads_migration(
+    root: &Path,
+    options: &MigrationOptions,
+) -> Result<MigrationResult, KanbusError> {
     ensure_git_repository(root)?;
 
     let beads_dir = root.join(".beads");
@@ -254,15 +452,30 @@ pub fn migrate_from_beads_into_project(root: &Path) -> Result<MigrationResult, K
         return Err(KanbusError::IssueOperation("no issues.jsonl".to_string()));
     }
 
-    let configuration_path = get_configuration_path(root)?;
-    let configuration = load_project_configuration(&configuration_path)?;
-    let project_dir = configuration_path
-        .parent()
-        .ok_or_else(|| KanbusError::Io("configuration path lookup failed".to_string()))?
-        .join(&configuration.project_directory);
+    let mut projects = Vec::new();
+    discover_project_directories(root, &mut projects)?;
+    let mut dotfile_projects = discover_kanbus_projects(root)?;
+    projects.append(&mut dotfile_projects);
+    let initialized = !projects.is_empty();
+    if initialized && !options.into_existing && !options.resume {
+        return Err(KanbusError::IssueOperation(
+            "already initialized".to_string(),
+        ));
+    }
+
+    let (configuration, project_dir) = if !initialized && !options.into_existing {
+        if options.dry_run {
+            (default_project_configuration(), root.join("project"))
+        } else {
+            initialize_project(root, false)?;
+            load_target_project(root)?
+        }
+    } else {
+        load_target_project(root)?
+    };
 
     let records = load_beads_records(&issues_path)?;
-    let records = dedupe_beads_records(records, &issues_path)?;
+    let records = dedupe_beads_records(records, &issues_path, !options.dry_run)?;
     let mut record_by_id: HashMap<String, Value> = HashMap::new();
     for record in &records {
         let identifier = record
@@ -272,19 +485,52 @@ pub fn migrate_from_beads_into_project(root: &Path) -> Result<MigrationResult, K
         record_by_id.insert(identifier.to_string(), record.clone());
     }
 
+    let mut report = MigrationReport {
+        dry_run: options.dry_run,
+        ..MigrationReport::default()
+    };
+    let context = ConversionContext {
+        record_by_id: &record_by_id,
+        configuration: &configuration,
+        mapping: &options.mapping,
+        dry_run: options.dry_run,
+    };
     for record in &records {
-        let issue = convert_record(record, &record_by_id, &configuration)?;
+        let unknown_before = report.unknown_values.len();
+        let issue = convert_record(record, &context, &mut report)?;
+        if report.unknown_values.len() > unknown_before {
+            // A real run would fail on this record, so it is not part of the plan.
+            continue;
+        }
         let issue_path = project_dir
             .join("issues")
             .join(format!("{}.json", issue.identifier));
-        write_issue_to_file(&issue, &issue_path)?;
+        if options.resume && issue_path.exists() {
+            report.skipped.push(issue.identifier);
+            continue;
+        }
+        if !options.dry_run {
+            write_issue_to_file(&issue, &issue_path)?;
+        }
+        report.migrated.push(issue.identifier);
     }
 
     Ok(MigrationResult {
-        issue_count: records.len(),
+        issue_count: report.migrated.len(),
+        report,
     })
 }
 
+fn load_target_project(root: &Path) -> Result<(ProjectConfiguration, PathBuf), KanbusError> {
+    let configuration_path = get_configuration_path(root)?;
+    let configuration = load_project_configuration(&configuration_path)?;
+    let project_dir = configuration_path
+        .parent()
+        .ok_or_else(|| KanbusError::Io("configuration path lookup failed".to_string()))?
+        .join(&configuration.project_directory);
+    Ok((configuration, project_dir))
+}
+
 fn load_beads_records(path: &Path) -> Result<Vec<Value>, KanbusError> {
     let contents = fs::read_to_string(path).map_err(|error| KanbusError::Io(error.to_string()))?;
     let mut records = Vec::new();
@@ -319,6 +565,7 @@ fn write_beads_records(path: &Path, records: &[Value]) -> Result<(), KanbusError
 fn dedupe_beads_records(
     records: Vec<Value>,
     issues_path: &Path,
+    write_back: bool,
 ) -> Result<Vec<Value>, KanbusError> {
     let mut ids_by_index: Vec<String> = Vec::with_capacity(records.len());
     let mut indices_by_id: HashMap<String, Vec<usize>> = HashMap::new();
@@ -394,7 +641,7 @@ fn dedupe_beads_records(
         }
     }
 
-    if deduped != records {
+    if write_back && deduped != records {
         write_beads_records(issues_path, &deduped)?;
     }
 
@@ -403,17 +650,64 @@ fn dedupe_beads_records(
 
 fn convert_record(
     record: &Value,
-    record_by_id: &HashMap<String, Value>,
-    configuration: &ProjectConfiguration,
+    context: &ConversionContext,
+    report: &mut MigrationReport,
 ) -> Result<IssueData, KanbusError> {
+    let configuration = context.configuration;
     let identifier = required_string(record, "id")?;
     let title = required_string(record, "title")?;
     let issue_type_raw = required_string(record, "issue_type")?;
-    let issue_type = map_issue_type(&issue_type_raw);
-    validate_issue_type(configuration, &issue_type)?;
+    let issue_type = context.mapping.issue_type(&issue_type_raw);
+    let status_raw = required_string(record, "status")?;
+    let status = context.mapping.status(&status_raw);
+    for (field, value, validation) in [
+        (
+            "issue_type",
+            &issue_type,
+            validate_issue_type(configuration, &issue_type),
+        ),
+        (
+            "status",
+            &status,
+            validate_status(configuration, &issue_type, &status),
+        ),
+    ] {
+        match validation {
+            Ok(()) => {}
+            Err(KanbusError::IssueOperation(_)) if context.dry_run => {
+                report.unknown_values.push(UnknownValue {
+                    issue_id: identifier.clone(),
+                    field: field.to_string(),
+                    value: value.clone(),
+                });
+            }
+            Err(error) => return Err(error),
+        }
+    }
 
-    let status = map_status(&required_string(record, "status")?);
-    validate_status(configuration, &issue_type, &status)?;
+    for (field, from, to) in [
+        ("issue_type", &issue_type_raw, &issue_type),
+        ("status", &status_raw, &status),
+    ] {
+        if from != to {
+            report.remapped.push(RemappedValue {
+                issue_id: identifier.clone(),
+                field: field.to_string(),
+                from: from.clone(),
+                to: to.clone(),
+            });
+        }
+    }
+    if let Some(fields) = record.as_object() {
+        for field in fields.keys() {
+            if !MIGRATED_RECORD_FIELDS.contains(&field.as_str()) {
+                report.dropped_fields.push(DroppedField {
+                    issue_id: identifier.clone(),
+                    field: field.clone(),
+                });
+            }
+        }
+    }
 
     let priority_value = record
         .get("priority")
@@ -436,10 +730,10 @@ fn convert_record(
 
     let (parent, dependencies) = convert_dependencies(
         record.get("dependencies").and_then(Value::as_array),
-        record_by_id,
-        configuration,
+        context,
         &identifier,
         &issue_type,
+        report,
     )?;
 
     let comments = convert_comments(
@@ -480,6 +774,11 @@ fn convert_record(
             Value::String(issue_type_raw.to_string()),
         );
     }
+    if let Some(extra) = record.get(KANBUS_CUSTOM_FIELD).and_then(Value::as_object) {
+        for (key, value) in extra {
+            custom.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+    }
 
     Ok(IssueData {
         identifier,
@@ -522,11 +821,12 @@ fn convert_record(
 
 fn convert_dependencies(
     dependencies: Option<&Vec<Value>>,
-    record_by_id: &HashMap<String, Value>,
-    configuration: &ProjectConfiguration,
+    context: &ConversionContext,
     identifier: &str,
     issue_type: &str,
+    report: &mut MigrationReport,
 ) -> Result<(Option<String>, Vec<DependencyLink>), KanbusError> {
+    let record_by_id = context.record_by_id;
     let mut parent: Option<String> = None;
     let mut extra_parents: Vec<String> = Vec::new();
     let mut links: Vec<DependencyLink> = Vec::new();
@@ -580,7 +880,7 @@ fn convert_dependencies(
                 "parent issue_type is required".to_string(),
             ));
         }
-        let canonical_parent = map_issue_type(parent_issue_type);
+        let canonical_parent = context.mapping.issue_type(parent_issue_type);
         let skip_validation = canonical_parent == issue_type
             && (canonical_parent == "epic" || canonical_parent == "task");
         if !skip_validation {
@@ -588,11 +888,20 @@ fn convert_dependencies(
                 if cfg!(tarpaulin) && std::env::var_os("KANBUS_TEST_HIERARCHY_ERROR").is_some() {
                     Err(KanbusError::Io("forced hierarchy error".to_string()))
                 } else {
-                    validate_parent_child_relationship(configuration, &canonical_parent, issue_type)
+                    validate_parent_child_relationship(
+                        context.configuration,
+                        &canonical_parent,
+                        issue_type,
+                    )
                 };
             match validation_result {
                 Ok(()) => {}
-                Err(KanbusError::InvalidHierarchy(_message)) => {
+                Err(KanbusError::InvalidHierarchy(message)) => {
+                    report.hierarchy_violations.push(HierarchyViolation {
+                        issue_id: identifier.to_string(),
+                        parent_id: parent_id.clone(),
+                        message,
+                    });
                     parent = None;
                 }
                 Err(error) => return Err(error),
@@ -632,8 +941,14 @@ fn convert_comments(
                         .map(|value| value.to_string())
                 })
                 .unwrap_or_else(|| (index + 1).to_string());
+            // Ids exported from Kanbus are already UUIDs and are kept as they are.
+            let id = if Uuid::parse_str(&comment_id).is_ok() {
+                comment_id
+            } else {
+                beads_comment_uuid(issue_id, &comment_id)
+            };
             results.push(IssueComment {
-                id: Some(beads_comment_uuid(issue_id, &comment_id)),
+                id: Some(id),
                 author: author.to_string(),
                 text: text.to_string(),
                 created_at,
@@ -902,10 +1217,32 @@ fn build_beads_configuration(records: &[Value]) -> ProjectConfiguration {
         wiki_directory: None,
         ai: None,
         github_security: None,
+        github_issues: None,
+        dependency_types: BTreeMap::new(),
+        hierarchy_rules: None,
+        views: BTreeMap::new(),
+        sequential_ids: false,
     }
 }
 const BEADS_ISSUE_TYPE_MAP: &[(&str, &str)] = &[("feature", "story"), ("message", "task")];
 
+/// Issue types Beads itself uses, which need no mapping on export.
+const BEADS_NATIVE_ISSUE_TYPES: &[&str] = &["bug", "feature", "task", "epic", "chore"];
+
+/// Map a Kanbus issue type to the Beads type that migrates to it.
+///
+/// The reverse of the migration's type mapping, so a native `story` exports
+/// as `feature`; types Beads already has are kept.
+pub(crate) fn beads_issue_type(issue_type: &str) -> &str {
+    if BEADS_NATIVE_ISSUE_TYPES.contains(&issue_type) {
+        return issue_type;
+    }
+    BEADS_ISSUE_TYPE_MAP
+        .iter()
+        .find(|(_, target)| *target == issue_type)
+        .map_or(issue_type, |(source, _)| source)
+}
+
 const BEADS_STATUS_MAP: &[(&str, &str)] = &[("in-progress", "in_progress")];
 
 fn map_status(raw: &str) -> String {
@@ -916,3 +1253,123 @@ fn map_status(raw: &str) -> String {
     }
     raw.to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::process::Command;
+
+    fn record(identifier: &str, issue_type: &str, status: &str, parent: Option<&str>) -> Value {
+        let mut record = json!({
+            "id": identifier,
+            "title": format!("Title {identifier}"),
+            "issue_type": issue_type,
+            "status": status,
+            "priority": 2,
+            "created_at": "2026-03-06T09:30:00Z",
+            "updated_at": "2026-03-06T09:30:00Z",
+        });
+        if let Some(parent) = parent {
+            record["dependencies"] = json!([{
+                "issue_id": identifier,
+                "depends_on_id": parent,
+                "type": "parent-child",
+            }]);
+        }
+        record
+    }
+
+    fn write_records(root: &Path, records: &[Value]) {
+        fs::create_dir_all(root.join(".beads")).expect("beads dir");
+        let lines: Vec<String> = records.iter().map(Value::to_string).collect();
+        fs::write(root.join(".beads").join("issues.jsonl"), lines.join("\n")).expect("write");
+    }
+
+    #[test]
+    fn dry_run_plans_mappings_and_resume_skips_migrated_issues() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        Command::new("git")
+            .arg("init")
+            .current_dir(root)
+            .output()
+            .expect("git init");
+        let mut sub_task = record("bd-sub", "sub-task", "open", None);
+        sub_task["design"] = json!("sketch");
+        let mut records = vec![
+            record("bd-epic", "epic", "open", None),
+            sub_task,
+            record("bd-task", "task", "doing", Some("bd-sub")),
+            record("bd-spike", "spike", "open", Some("bd-epic")),
+        ];
+        write_records(root, &records);
+
+        let mut options = MigrationOptions {
+            dry_run: true,
+            ..MigrationOptions::default()
+        };
+        let unplanned = run_beads_migration(root, &options).expect("plan with unknown values");
+        assert_eq!(unplanned.issue_count, 2);
+        assert_eq!(
+            unplanned.report.unknown_values,
+            vec![
+                UnknownValue {
+                    issue_id: "bd-task".into(),
+                    field: "status".into(),
+                    value: "doing".into(),
+                },
+                UnknownValue {
+                    issue_id: "bd-spike".into(),
+                    field: "issue_type".into(),
+                    value: "spike".into(),
+                },
+            ]
+        );
+        assert!(unplanned.report.summary_lines().contains(
+            &"unknown status doing (1 issues); map it with --map-status doing=...".to_string()
+        ));
+        options
+            .mapping
+            .statuses
+            .insert("doing".into(), "in_progress".into());
+        options
+            .mapping
+            .issue_types
+            .insert("spike".into(), "chore".into());
+
+        let plan = run_beads_migration(root, &options).expect("plan");
+        assert!(!root.join("project").exists());
+        assert_eq!(plan.issue_count, 4);
+        assert_eq!(
+            plan.report
+                .remapped
+                .iter()
+                .map(|remap| (remap.issue_id.as_str(), remap.to.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("bd-task", "in_progress"), ("bd-spike", "chore")]
+        );
+        assert_eq!(plan.report.hierarchy_violations.len(), 1);
+        assert_eq!(plan.report.hierarchy_violations[0].issue_id, "bd-task");
+        assert_eq!(
+            plan.report.dropped_fields,
+            vec![DroppedField {
+                issue_id: "bd-sub".into(),
+                field: "design".into(),
+            }]
+        );
+
+        options.dry_run = false;
+        let result = run_beads_migration(root, &options).expect("migrate");
+        assert_eq!(result.issue_count, 4);
+        assert!(run_beads_migration(root, &options).is_err());
+
+        records.push(record("bd-late", "task", "open", Some("bd-epic")));
+        write_records(root, &records);
+        options.resume = true;
+        let resumed = run_beads_migration(root, &options).expect("resume");
+        assert_eq!(resumed.report.migrated, vec!["bd-late".to_string()]);
+        assert_eq!(resumed.report.skipped.len(), 4);
+        assert!(root.join("project/issues/bd-late.json").exists());
+    }
+}
@@ -77,6 +77,18 @@ pub struct AiConfiguration {
     pub provider: String,
     /// Model identifier (e.g. gpt-4o).
     pub model: String,
+    /// Base URL of an OpenAI-compatible API (default: https://api.openai.com/v1).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    /// Environment variable holding the API key (default: OPENAI_API_KEY).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key_env: Option<String>,
+    /// Maximum provider requests per minute (default: 60).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requests_per_minute: Option<u32>,
+    /// Request timeout in seconds (default: 30).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
 }
 
 /// Snyk vulnerability synchronization configuration.
@@ -132,8 +144,53 @@ fn default_dependabot_state() -> String {
     "open".to_string()
 }
 
-/// GitHub security synchronization configuration.
+/// GitHub code scanning synchronization configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningConfiguration {
+    /// Minimum severity to import: critical, high, medium, or low (default: low).
+    #[serde(default = "default_dependabot_min_severity")]
+    pub min_severity: String,
+    /// Only import alerts reported by this analysis tool (e.g. "CodeQL").
+    #[serde(default)]
+    pub tool_name: Option<String>,
+    /// Kanbus issue ID of the parent epic to attach imported alerts to.
+    #[serde(default)]
+    pub parent_epic: Option<String>,
+}
+
+impl Default for CodeScanningConfiguration {
+    fn default() -> Self {
+        Self {
+            min_severity: default_dependabot_min_severity(),
+            tool_name: None,
+            parent_epic: None,
+        }
+    }
+}
+
+/// GitHub secret scanning synchronization configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretScanningConfiguration {
+    /// Minimum severity to import: critical, high, medium, or low (default: low).
+    /// Secrets GitHub reports as still active are critical; all others are high.
+    #[serde(default = "default_dependabot_min_severity")]
+    pub min_severity: String,
+    /// Kanbus issue ID of the parent epic to attach imported alerts to.
+    #[serde(default)]
+    pub parent_epic: Option<String>,
+}
+
+impl Default for SecretScanningConfiguration {
+    fn default() -> Self {
+        Self {
+            min_severity: default_dependabot_min_severity(),
+            parent_epic: None,
+        }
+    }
+}
+
+/// GitHub security synchronization configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GithubSecurityConfiguration {
     /// GitHub repository slug to sync (e.g. "AnthusAI/Kanbus").
     #[serde(default)]
@@ -141,6 +198,73 @@ pub struct GithubSecurityConfiguration {
     /// Dependabot synchronization settings.
     #[serde(default)]
     pub dependabot: Option<DependabotConfiguration>,
+    /// Code scanning synchronization settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_scanning: Option<CodeScanningConfiguration>,
+    /// Secret scanning synchronization settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_scanning: Option<SecretScanningConfiguration>,
+}
+
+/// GitHub Issues synchronization configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GithubIssuesConfiguration {
+    /// GitHub repository slug (e.g. "AnthusAI/Kanbus").
+    /// If omitted, auto-detected from git remote origin.
+    #[serde(default)]
+    pub repo: Option<String>,
+    /// GitHub API base URL override (defaults to GITHUB_API_URL or https://api.github.com).
+    #[serde(default)]
+    pub api_url: Option<String>,
+    /// Allowed sync direction: pull, push, or both (default: pull).
+    #[serde(default = "default_github_issues_sync_direction")]
+    pub sync_direction: String,
+    /// Issue state filter for pulls: open, closed, or all (default: all).
+    #[serde(default = "default_github_issues_state")]
+    pub state: String,
+    /// GitHub label to Kanbus issue type mappings.
+    #[serde(default)]
+    pub type_mappings: BTreeMap<String, String>,
+    /// Kanbus issue type for issues without a mapped label (default: task).
+    #[serde(default = "default_github_issues_type")]
+    pub default_type: String,
+    /// Custom field that stores the GitHub milestone title (default: milestone).
+    #[serde(default = "default_github_milestone_field")]
+    pub milestone_field: String,
+    /// Kanbus issue ID of the parent to attach newly imported issues to.
+    #[serde(default)]
+    pub parent: Option<String>,
+}
+
+impl Default for GithubIssuesConfiguration {
+    fn default() -> Self {
+        Self {
+            repo: None,
+            api_url: None,
+            sync_direction: default_github_issues_sync_direction(),
+            state: default_github_issues_state(),
+            type_mappings: BTreeMap::new(),
+            default_type: default_github_issues_type(),
+            milestone_field: default_github_milestone_field(),
+            parent: None,
+        }
+    }
+}
+
+fn default_github_issues_sync_direction() -> String {
+    "pull".to_string()
+}
+
+fn default_github_issues_state() -> String {
+    "all".to_string()
+}
+
+fn default_github_issues_type() -> String {
+    "task".to_string()
+}
+
+fn default_github_milestone_field() -> String {
+    "milestone".to_string()
 }
 
 /// Configuration for a single virtual project.
@@ -275,6 +399,8 @@ pub struct ProjectConfiguration {
     pub project_key: String,
     #[serde(default)]
     pub project_management_template: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub sequential_ids: bool,
     pub hierarchy: Vec<String>,
     pub types: Vec<String>,
     pub workflows: BTreeMap<String, BTreeMap<String, Vec<String>>>,
@@ -311,6 +437,15 @@ pub struct ProjectConfiguration {
     #[serde(default)]
     pub hooks: HooksConfiguration,
     pub github_security: Option<GithubSecurityConfiguration>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_issues: Option<GithubIssuesConfiguration>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dependency_types: BTreeMap<String, DependencyTypeDefinition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hierarchy_rules: Option<HierarchyRules>,
+    /// Named issue queries, merged with the files in `<project>/views/`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub views: BTreeMap<String, SavedView>,
 }
 
 #[cfg(test)]
@@ -381,6 +516,88 @@ pub struct StatusDefinition {
     pub collapsed: bool,
 }
 
+/// Dependency link type declared in `dependency_types`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DependencyTypeDefinition {
+    /// Name of the link as seen from the target issue.
+    #[serde(default)]
+    pub inverse: Option<String>,
+    /// Whether an open target keeps the source issue from being ready.
+    #[serde(default)]
+    pub blocks: bool,
+    /// Whether links of this type are checked for cycles.
+    #[serde(default)]
+    pub cycles: bool,
+    /// Display label for outgoing links.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Display label for incoming links.
+    #[serde(default)]
+    pub inverse_label: Option<String>,
+}
+
+/// Explicit parent-child rules declared in `hierarchy_rules`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HierarchyRules {
+    /// Allowed child types per parent type; replaces the linear `hierarchy` chain when set.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub children: BTreeMap<String, Vec<String>>,
+    /// Maximum number of levels in a parent chain, counting the top-level issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_depth: Option<usize>,
+    /// Maximum number of direct children per parent type.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub max_children: BTreeMap<String, usize>,
+}
+
+/// A named issue query shared by `kbs list --view`, the console and wiki templates.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SavedView {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "ViewFilters::is_empty")]
+    pub filters: ViewFilters,
+    /// Sort key, prefixed with `-` for descending order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// Columns shown instead of the standard issue line, as named by `kbs export`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<String>,
+    /// Column to group issues by.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<String>,
+}
+
+/// Filters of a saved view; each matches the `kbs list` flag of the same name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ViewFilters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub project: Vec<String>,
+}
+
+impl ViewFilters {
+    /// Whether no filter is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// Priority definition containing label and optional color.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriorityDefinition {
@@ -29,9 +29,17 @@ pub enum NotificationEvent {
         /// Optional comment ID to scroll to within the focused issue.
         #[serde(skip_serializing_if = "Option::is_none")]
         comment_id: Option<String>,
+        /// Console session to deliver to; `None` broadcasts to every session.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        session: Option<String>,
     },
     /// UI control command to manipulate console UI state.
-    UiControl { action: UiControlAction },
+    UiControl {
+        action: UiControlAction,
+        /// Console session to deliver to; `None` broadcasts to every session.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        session: Option<String>,
+    },
 }
 
 /// UI control actions that can be sent to the console frontend.
@@ -60,6 +68,8 @@ pub enum UiControlAction {
     ExpandColumn { column_name: String },
     /// Select and navigate to an issue.
     SelectIssue { issue_id: String },
+    /// Apply a saved view by name; an empty name clears the active view.
+    ApplyView { name: String },
     /// Reload the entire page.
     ReloadPage,
 }
@@ -76,6 +86,15 @@ impl NotificationEvent {
         }
     }
 
+    /// Get the console session this event targets, if any.
+    pub fn target_session(&self) -> Option<&str> {
+        match self {
+            NotificationEvent::IssueFocused { session, .. }
+            | NotificationEvent::UiControl { session, .. } => session.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Get a human-readable description of this event.
     pub fn description(&self) -> String {
         match self {
@@ -103,7 +122,7 @@ impl NotificationEvent {
                     format!("Issue {} focused", issue_id)
                 }
             }
-            NotificationEvent::UiControl { action } => {
+            NotificationEvent::UiControl { action, .. } => {
                 format!("UI control: {:?}", action)
             }
         }
@@ -157,9 +176,11 @@ mod tests {
             issue_id: "kanbus-1".to_string(),
             user: Some("agent".to_string()),
             comment_id: None,
+            session: None,
         };
         let ui = NotificationEvent::UiControl {
             action: UiControlAction::ReloadPage,
+            session: None,
         };
 
         assert_eq!(created.issue_id(), Some("kanbus-1"));
@@ -186,11 +207,13 @@ mod tests {
             issue_id: "kanbus-2".to_string(),
             user: None,
             comment_id: None,
+            session: None,
         };
         let ui = NotificationEvent::UiControl {
             action: UiControlAction::SetSearch {
                 query: "hello".to_string(),
             },
+            session: None,
         };
 
         assert_eq!(updated_no_fields.description(), "Issue kanbus-2 updated");
@@ -216,6 +239,7 @@ mod tests {
             issue_id: "kanbus-3".to_string(),
             user: Some("pair".to_string()),
             comment_id: Some("cmt-1".to_string()),
+            session: None,
         };
 
         assert_eq!(created.description(), "Issue kanbus-3 created");
@@ -268,15 +292,46 @@ mod tests {
                 },
                 "select_issue",
             ),
+            (
+                UiControlAction::ApplyView {
+                    name: "my-bugs".into(),
+                },
+                "apply_view",
+            ),
             (UiControlAction::ReloadPage, "reload_page"),
         ];
 
         for (action, expected_tag) in cases {
-            let event = NotificationEvent::UiControl { action };
+            let event = NotificationEvent::UiControl {
+                action,
+                session: None,
+            };
             let value = serde_json::to_value(&event).expect("serialize event");
             assert_eq!(value["type"], "ui_control");
             assert_eq!(value["action"]["action"], expected_tag);
+            assert!(value.get("session").is_none());
             assert_eq!(event.issue_id(), None);
         }
     }
+
+    #[test]
+    fn target_session_round_trips_and_defaults_to_broadcast() {
+        let targeted: NotificationEvent = serde_json::from_str(
+            r#"{"type":"issue_focused","issue_id":"kanbus-1","session":"tab-1"}"#,
+        )
+        .expect("parse targeted");
+        assert_eq!(targeted.target_session(), Some("tab-1"));
+
+        let broadcast: NotificationEvent =
+            serde_json::from_str(r#"{"type":"ui_control","action":{"action":"clear_focus"}}"#)
+                .expect("parse broadcast");
+        assert_eq!(broadcast.target_session(), None);
+        assert_eq!(
+            NotificationEvent::IssueDeleted {
+                issue_id: "kanbus-1".to_string()
+            }
+            .target_session(),
+            None
+        );
+    }
 }
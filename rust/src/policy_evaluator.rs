@@ -575,6 +575,11 @@ mod tests {
             overlay: Default::default(),
             hooks: Default::default(),
             github_security: None,
+            github_issues: None,
+            dependency_types: BTreeMap::new(),
+            hierarchy_rules: None,
+            views: BTreeMap::new(),
+            sequential_ids: false,
         };
         PolicyContext {
             current_issue: None,
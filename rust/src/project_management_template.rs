@@ -158,6 +158,22 @@ Workflow selection: use a workflow named after the issue type when present; othe
 {% endif %}
 
 {% endfor %}
+{% if statuses %}
+Status names:
+
+{% for status in statuses %}
+- {{ status.key }} -- {{ status.name }} ({{ status.category }})
+{% endfor %}
+
+{% endif %}
+{% if type_workflows %}
+Workflow by issue type:
+
+{% for entry in type_workflows %}
+- {{ entry.issue_type }} uses the {{ entry.workflow }} workflow{% if entry.start_status %}; start work by moving it from {{ initial_status }} to {{ entry.start_status }}{% endif %}.
+{% endfor %}
+
+{% endif %}
 Priorities are:
 
 {% for priority in priorities %}
@@ -167,6 +183,31 @@ Default is {{ default_priority_value }} ({{ default_priority_name }}).
 
 Severity is not emotion. It is signal.
 
+{% if policies %}
+## Policies
+
+Policies in {{ project_directory }}/policies/ are evaluated whenever an issue is created or updated. A failing policy blocks the change; fix the issue rather than working around the policy. Use `kbs policy check <id>` to evaluate an issue.
+
+{% for policy in policies %}
+- {{ policy.file }}{% if policy.name %} -- {{ policy.name }}{% endif %}
+
+{% for scenario in policy.scenarios %}
+  - {{ scenario }}
+{% endfor %}
+{% endfor %}
+
+{% endif %}
+{% if hooks %}
+## Lifecycle Hooks
+
+These hooks run around Kanbus commands. Blocking hooks can reject a command; read their output when a command fails.
+
+{% for hook in hooks %}
+- {{ hook.phase }} {{ hook.event }}: {{ hook.id }} runs `{{ hook.command }}`{% if hook.blocking %} (blocking){% endif %}
+
+{% endfor %}
+
+{% endif %}
 ## Wiki Workflow
 
 The wiki lives under project/wiki/. You may edit Markdown files there directly.
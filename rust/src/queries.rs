@@ -3,7 +3,7 @@
 use std::collections::HashSet;
 
 use crate::error::KanbusError;
-use crate::ids::issue_identifier_matches;
+use crate::ids::{is_issue_number_key, issue_identifier_matches, match_issue_identifier};
 use crate::models::IssueData;
 
 /// Filter issues by common fields.
@@ -14,7 +14,7 @@ use crate::models::IssueData;
 /// * `issue_type` - Type filter.
 /// * `assignee` - Assignee filter.
 /// * `label` - Label filter.
-/// * `parent` - Parent identifier filter. Accepts full ids and unique prefixes.
+/// * `parent` - Parent identifier filter. Accepts full ids, sequential numbers and unique prefixes.
 pub fn filter_issues(
     issues: Vec<IssueData>,
     status: Option<&str>,
@@ -23,6 +23,14 @@ pub fn filter_issues(
     label: Option<&str>,
     parent: Option<&str>,
 ) -> Vec<IssueData> {
+    let resolved_parent =
+        parent.map(
+            |value| match match_issue_identifier(value, &issues).as_slice() {
+                [single] if is_issue_number_key(value) => single.identifier.clone(),
+                _ => value.to_string(),
+            },
+        );
+    let parent = resolved_parent.as_deref();
     issues
         .into_iter()
         .filter(|issue| status.is_none_or(|value| issue.status == value))
@@ -64,6 +72,46 @@ pub fn sort_issues(
     Err(KanbusError::IssueOperation("invalid sort key".to_string()))
 }
 
+/// Sort keys accepted by `sort_issues_by`; any may be prefixed with `-`.
+pub const SORT_KEYS: &[&str] = &[
+    "title",
+    "priority",
+    "status",
+    "id",
+    "created_at",
+    "updated_at",
+    "closed_at",
+];
+
+/// Sort issues in place by a field, descending when the key starts with `-`.
+///
+/// # Arguments
+/// * `issues` - Issues to sort.
+/// * `sort_key` - One of `SORT_KEYS` (`identifier` is accepted for `id`).
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the key is unknown.
+pub fn sort_issues_by(issues: &mut [IssueData], sort_key: &str) -> Result<(), KanbusError> {
+    let (descending, key) = match sort_key.strip_prefix('-') {
+        Some(key) => (true, key),
+        None => (false, sort_key),
+    };
+    match key {
+        "title" => issues.sort_by(|left, right| left.title.cmp(&right.title)),
+        "priority" => issues.sort_by_key(|issue| issue.priority),
+        "status" => issues.sort_by(|left, right| left.status.cmp(&right.status)),
+        "id" | "identifier" => issues.sort_by(|left, right| left.identifier.cmp(&right.identifier)),
+        "created_at" => issues.sort_by_key(|issue| issue.created_at),
+        "updated_at" => issues.sort_by_key(|issue| issue.updated_at),
+        "closed_at" => issues.sort_by_key(|issue| issue.closed_at),
+        _ => return Err(KanbusError::IssueOperation("invalid sort key".to_string())),
+    }
+    if descending {
+        issues.reverse();
+    }
+    Ok(())
+}
+
 /// Search issues by title, description, and comments.
 ///
 /// # Arguments
@@ -163,4 +211,18 @@ mod tests {
         assert_eq!(exact_matches.len(), 1);
         assert_eq!(exact_matches[0].identifier, "kanbus-child");
     }
+
+    #[test]
+    fn sort_issues_by_supports_descending_keys() {
+        let mut low = issue("kanbus-b");
+        low.priority = 3;
+        let high = issue("kanbus-a");
+        let mut issues = vec![low, high];
+
+        sort_issues_by(&mut issues, "-priority").expect("sort");
+        assert_eq!(issues[0].identifier, "kanbus-b");
+        sort_issues_by(&mut issues, "identifier").expect("sort");
+        assert_eq!(issues[0].identifier, "kanbus-a");
+        assert!(sort_issues_by(&mut issues, "owner").is_err());
+    }
 }
@@ -0,0 +1,989 @@
+//! SARIF report import support.
+//!
+//! Imports findings from any SARIF 2.1 report (cargo-audit, semgrep,
+//! clippy-sarif, CodeQL, ...) without network access.
+//!
+//! Hierarchy created:
+//!   epic  "SARIF Findings: <tool>"  (one per analysis tool)
+//!     task  "<tool> <rule>: <file>"  (one per rule and file)
+//!       bug  "[<tool>] <rule> in <file>:<line>"  (one per finding)
+//!
+//! Findings are keyed by fingerprint, so re-importing a report updates the
+//! existing bugs, and bugs whose findings are missing from a newer report of
+//! the same tool are closed. Updates keep a bug's local status; a bug the
+//! importer closed is reopened when its finding comes back.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use chrono::Utc;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::config_loader::load_project_configuration;
+use crate::error::KanbusError;
+use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::ids::{generate_issue_identifier, IssueIdentifierRequest};
+use crate::issue_files::{
+    issue_path_for_identifier, list_issue_identifiers, read_issue_from_file, write_issue_to_file,
+};
+use crate::models::IssueData;
+use crate::workflows::closed_status;
+
+/// Set on bugs the importer closed because their finding disappeared.
+const SARIF_CLOSED_AT: &str = "sarif_closed_at";
+
+/// Result of a SARIF import.
+#[derive(Debug, Default)]
+pub struct SarifImportResult {
+    pub imported: usize,
+    pub updated: usize,
+    pub closed: usize,
+}
+
+/// A single SARIF result normalized for import.
+#[derive(Debug, Clone)]
+struct SarifFinding {
+    tool: String,
+    rule_id: String,
+    rule_description: String,
+    help_uri: String,
+    message: String,
+    file: String,
+    line: i64,
+    level: String,
+    priority: i32,
+    fingerprint: String,
+}
+
+/// Import a SARIF 2.1 report file into the current project.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `report_path` - Path to the SARIF file.
+/// * `project_key` - Kanbus project key (issue ID prefix).
+/// * `parent_epic` - Optional epic to attach rule tasks to instead of a per-tool epic.
+/// * `dry_run` - If true, print what would be done without writing any files.
+///
+/// # Errors
+/// Returns `KanbusError` if the report cannot be read or parsed, or file operations fail.
+pub fn import_sarif_report(
+    root: &Path,
+    report_path: &Path,
+    project_key: &str,
+    parent_epic: Option<&str>,
+    dry_run: bool,
+) -> Result<SarifImportResult, KanbusError> {
+    let contents = std::fs::read_to_string(report_path).map_err(|error| {
+        KanbusError::IssueOperation(format!(
+            "failed to read SARIF report {}: {error}",
+            report_path.display()
+        ))
+    })?;
+    let report: Value = serde_json::from_str(&contents)
+        .map_err(|error| KanbusError::IssueOperation(format!("invalid SARIF report: {error}")))?;
+    let findings = parse_sarif_findings(&report)?;
+
+    let project_dir = load_project_directory(root)?;
+    let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+    let issues_dir = project_dir.join("issues");
+    if !issues_dir.exists() {
+        return Err(KanbusError::IssueOperation(
+            "issues directory does not exist".to_string(),
+        ));
+    }
+    if let Some(epic_id) = parent_epic {
+        if !issue_path_for_identifier(&issues_dir, epic_id).exists() {
+            return Err(KanbusError::IssueOperation(format!(
+                "parent epic not found: {epic_id}"
+            )));
+        }
+    }
+
+    let mut all_existing = list_issue_identifiers(&issues_dir)?;
+    let existing_issues: Vec<IssueData> = all_existing
+        .iter()
+        .filter_map(|id| read_issue_from_file(&issue_path_for_identifier(&issues_dir, id)).ok())
+        .filter(|issue| issue.custom.contains_key("sarif_tool"))
+        .collect();
+    let finding_index = build_finding_index(&existing_issues);
+    let mut task_index = build_task_index(&existing_issues);
+    let mut epic_index = build_epic_index(&existing_issues);
+
+    let mut result = SarifImportResult::default();
+    let mut seen_fingerprints: HashSet<String> = HashSet::new();
+    let mut grouped: BTreeMap<(String, String, String), Vec<&SarifFinding>> = BTreeMap::new();
+    for finding in &findings {
+        if !seen_fingerprints.insert(finding.fingerprint.clone()) {
+            continue;
+        }
+        grouped
+            .entry((
+                finding.tool.clone(),
+                finding.rule_id.clone(),
+                finding.file.clone(),
+            ))
+            .or_default()
+            .push(finding);
+    }
+
+    let ctx = SarifImportContext {
+        issues_dir: &issues_dir,
+        project_key,
+        initial_status: &configuration.initial_status,
+        closed_status: closed_status(&configuration),
+        dry_run,
+    };
+    for ((tool, _, _), group) in grouped {
+        let epic_id = match parent_epic {
+            Some(epic_id) => epic_id.to_string(),
+            None => resolve_tool_epic(&ctx, &tool, &mut epic_index, &mut all_existing)?,
+        };
+        let priority = group
+            .iter()
+            .map(|finding| finding.priority)
+            .min()
+            .unwrap_or(3);
+        let task_id = resolve_rule_task(
+            &ctx,
+            group[0],
+            &epic_id,
+            priority,
+            &mut task_index,
+            &mut all_existing,
+        )?;
+
+        for finding in group {
+            let mut issue = map_finding_to_issue(finding, &task_id, ctx.initial_status);
+            let (identifier, action) = match finding_index.get(&finding.fingerprint) {
+                Some(id) => (id.clone(), "updated"),
+                None => (
+                    new_identifier(&issue.title, project_key, &mut all_existing)?,
+                    "created",
+                ),
+            };
+            issue.identifier = identifier.clone();
+            let issue_path = issue_path_for_identifier(&issues_dir, &identifier);
+            if action == "updated" {
+                if let Ok(existing) = read_issue_from_file(&issue_path) {
+                    if issue_unchanged(&existing, &issue) {
+                        continue;
+                    }
+                    merge_local_fields(&mut issue, existing, ctx.initial_status);
+                }
+            }
+            println!(
+                "{action}  [{:<8}]  {:<14}  \"{}\"",
+                finding.level,
+                short_fingerprint(&finding.fingerprint),
+                issue.title
+            );
+            if !dry_run {
+                write_issue_to_file(&issue, &issue_path)?;
+            }
+            if action == "updated" {
+                result.updated += 1;
+            } else {
+                result.imported += 1;
+            }
+        }
+    }
+
+    let reported_tools: HashSet<&str> = findings
+        .iter()
+        .map(|finding| finding.tool.as_str())
+        .chain(report_tool_names(&report))
+        .collect();
+    for issue in &existing_issues {
+        let Some(fingerprint) = issue
+            .custom
+            .get("sarif_fingerprint")
+            .and_then(Value::as_str)
+        else {
+            continue;
+        };
+        let tool = issue
+            .custom
+            .get("sarif_tool")
+            .and_then(Value::as_str)
+            .unwrap_or("");
+        if issue.status == ctx.closed_status
+            || seen_fingerprints.contains(fingerprint)
+            || !reported_tools.contains(tool)
+        {
+            continue;
+        }
+        let mut closed = issue.clone();
+        let now = Utc::now();
+        closed.status = ctx.closed_status.to_string();
+        closed.closed_at = Some(now);
+        closed.updated_at = now;
+        closed
+            .custom
+            .insert(SARIF_CLOSED_AT.to_string(), Value::String(now.to_rfc3339()));
+        println!(
+            "closed   [{:<8}]  {:<14}  \"{}\"",
+            issue
+                .custom
+                .get("sarif_level")
+                .and_then(Value::as_str)
+                .unwrap_or("warning"),
+            short_fingerprint(fingerprint),
+            issue.title
+        );
+        if !dry_run {
+            write_issue_to_file(
+                &closed,
+                &issue_path_for_identifier(&issues_dir, &issue.identifier),
+            )?;
+        }
+        result.closed += 1;
+    }
+
+    Ok(result)
+}
+
+struct SarifImportContext<'a> {
+    issues_dir: &'a Path,
+    project_key: &'a str,
+    initial_status: &'a str,
+    closed_status: &'a str,
+    dry_run: bool,
+}
+
+/// Extract normalized findings from every run of a SARIF report.
+fn parse_sarif_findings(report: &Value) -> Result<Vec<SarifFinding>, KanbusError> {
+    let runs = report["runs"].as_array().ok_or_else(|| {
+        KanbusError::IssueOperation("invalid SARIF report: missing runs".to_string())
+    })?;
+    let mut findings = Vec::new();
+    for run in runs {
+        let driver = &run["tool"]["driver"];
+        let tool = driver["name"].as_str().unwrap_or("sarif").to_string();
+        let rules: Vec<Value> = driver["rules"].as_array().cloned().unwrap_or_default();
+        let rules_by_id: HashMap<&str, &Value> = rules
+            .iter()
+            .filter_map(|rule| rule["id"].as_str().map(|id| (id, rule)))
+            .collect();
+
+        for result in run["results"].as_array().into_iter().flatten() {
+            if !is_reportable(result) {
+                continue;
+            }
+            let rule = result["ruleIndex"]
+                .as_u64()
+                .and_then(|index| rules.get(index as usize))
+                .or_else(|| {
+                    result["ruleId"]
+                        .as_str()
+                        .and_then(|id| rules_by_id.get(id).copied())
+                });
+            let rule_id = result["ruleId"]
+                .as_str()
+                .or_else(|| rule.and_then(|rule| rule["id"].as_str()))
+                .unwrap_or("unknown-rule")
+                .to_string();
+            let rule_description = rule
+                .and_then(|rule| {
+                    rule["shortDescription"]["text"]
+                        .as_str()
+                        .or_else(|| rule["fullDescription"]["text"].as_str())
+                })
+                .unwrap_or("")
+                .to_string();
+            let help_uri = rule
+                .and_then(|rule| rule["helpUri"].as_str())
+                .unwrap_or("")
+                .to_string();
+            let message = result["message"]["text"]
+                .as_str()
+                .or_else(|| result["message"]["markdown"].as_str())
+                .unwrap_or("")
+                .to_string();
+            let physical = &result["locations"][0]["physicalLocation"];
+            let file = physical["artifactLocation"]["uri"]
+                .as_str()
+                .unwrap_or("unknown")
+                .to_string();
+            let line = physical["region"]["startLine"].as_i64().unwrap_or_default();
+            let level = result["level"]
+                .as_str()
+                .or_else(|| rule.and_then(|rule| rule["defaultConfiguration"]["level"].as_str()))
+                .unwrap_or("warning")
+                .to_string();
+            let priority = rule
+                .and_then(security_severity_priority)
+                .unwrap_or_else(|| level_to_priority(&level));
+            let fingerprint = result_fingerprint(&tool, &rule_id, &file, line, &message, result);
+
+            findings.push(SarifFinding {
+                tool: tool.clone(),
+                rule_id,
+                rule_description,
+                help_uri,
+                message,
+                file,
+                line,
+                level,
+                priority,
+                fingerprint,
+            });
+        }
+    }
+    Ok(findings)
+}
+
+/// False for results that are suppressed or do not describe a problem.
+fn is_reportable(result: &Value) -> bool {
+    let suppressed = result["suppressions"]
+        .as_array()
+        .is_some_and(|suppressions| !suppressions.is_empty());
+    let kind = result["kind"].as_str().unwrap_or("fail");
+    !suppressed && !matches!(kind, "pass" | "notApplicable")
+}
+
+fn report_tool_names(report: &Value) -> impl Iterator<Item = &str> {
+    report["runs"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|run| run["tool"]["driver"]["name"].as_str())
+}
+
+/// Stable identity of a result across report runs.
+///
+/// Prefers the tool-provided fingerprints (sorted by key for determinism),
+/// scoped to the file since partial fingerprints such as
+/// `primaryLocationLineHash` only hash the line content, and falls back to a
+/// hash of the tool, rule, location, and message.
+fn result_fingerprint(
+    tool: &str,
+    rule_id: &str,
+    file: &str,
+    line: i64,
+    message: &str,
+    result: &Value,
+) -> String {
+    for key in ["fingerprints", "partialFingerprints"] {
+        if let Some(map) = result[key].as_object() {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|left, right| left.0.cmp(right.0));
+            if let Some((name, value)) = entries.first() {
+                if let Some(text) = value.as_str() {
+                    return format!("{tool}/{rule_id}/{file}/{name}/{text}");
+                }
+            }
+        }
+    }
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{tool}\n{rule_id}\n{file}\n{line}\n{message}"));
+    format!("{tool}/{rule_id}/sha256/{:x}", hasher.finalize())
+}
+
+fn short_fingerprint(fingerprint: &str) -> String {
+    let tail = fingerprint.rsplit('/').next().unwrap_or(fingerprint);
+    tail.chars().take(12).collect()
+}
+
+/// Map a `security-severity` (CVSS score) rule property to a priority.
+fn security_severity_priority(rule: &Value) -> Option<i32> {
+    let raw = &rule["properties"]["security-severity"];
+    let score = raw
+        .as_f64()
+        .or_else(|| raw.as_str().and_then(|text| text.parse::<f64>().ok()))?;
+    Some(if score >= 9.0 {
+        0
+    } else if score >= 7.0 {
+        1
+    } else if score >= 4.0 {
+        2
+    } else {
+        3
+    })
+}
+
+fn level_to_priority(level: &str) -> i32 {
+    match level {
+        "error" => 1,
+        "warning" => 2,
+        _ => 3,
+    }
+}
+
+fn build_finding_index(issues: &[IssueData]) -> HashMap<String, String> {
+    issues
+        .iter()
+        .filter_map(|issue| {
+            issue
+                .custom
+                .get("sarif_fingerprint")
+                .and_then(Value::as_str)
+                .map(|fingerprint| (fingerprint.to_string(), issue.identifier.clone()))
+        })
+        .collect()
+}
+
+fn build_task_index(issues: &[IssueData]) -> HashMap<String, String> {
+    issues
+        .iter()
+        .filter(|issue| issue.issue_type == "task")
+        .filter_map(|issue| {
+            issue
+                .custom
+                .get("sarif_group")
+                .and_then(Value::as_str)
+                .map(|group| (group.to_string(), issue.identifier.clone()))
+        })
+        .collect()
+}
+
+fn build_epic_index(issues: &[IssueData]) -> HashMap<String, String> {
+    issues
+        .iter()
+        .filter(|issue| issue.issue_type == "epic")
+        .filter_map(|issue| {
+            issue
+                .custom
+                .get("sarif_tool")
+                .and_then(Value::as_str)
+                .map(|tool| (tool.to_string(), issue.identifier.clone()))
+        })
+        .collect()
+}
+
+fn group_key(finding: &SarifFinding) -> String {
+    format!("{}|{}|{}", finding.tool, finding.rule_id, finding.file)
+}
+
+fn tool_label(tool: &str) -> String {
+    tool.to_lowercase().replace(' ', "-")
+}
+
+fn new_identifier(
+    title: &str,
+    project_key: &str,
+    all_existing: &mut HashSet<String>,
+) -> Result<String, KanbusError> {
+    let request = IssueIdentifierRequest {
+        title: title.to_string(),
+        existing_ids: all_existing.clone(),
+        prefix: project_key.to_string(),
+    };
+    let identifier = generate_issue_identifier(&request)?.identifier;
+    all_existing.insert(identifier.clone());
+    Ok(identifier)
+}
+
+fn resolve_tool_epic(
+    ctx: &SarifImportContext<'_>,
+    tool: &str,
+    epic_index: &mut HashMap<String, String>,
+    all_existing: &mut HashSet<String>,
+) -> Result<String, KanbusError> {
+    if let Some(existing) = epic_index.get(tool) {
+        return Ok(existing.clone());
+    }
+    let title = format!("SARIF Findings: {tool}");
+    let epic_id = new_identifier(&title, ctx.project_key, all_existing)?;
+    let now = Utc::now();
+    let mut custom = BTreeMap::new();
+    custom.insert("sarif_tool".to_string(), Value::String(tool.to_string()));
+    let epic = IssueData {
+        identifier: epic_id.clone(),
+        title: title.clone(),
+        description: format!("Findings imported from {tool} SARIF reports."),
+        issue_type: "epic".to_string(),
+        status: ctx.initial_status.to_string(),
+        priority: 2,
+        assignee: None,
+        creator: None,
+        parent: None,
+        labels: vec!["sarif".to_string(), tool_label(tool)],
+        dependencies: Vec::new(),
+        comments: Vec::new(),
+        created_at: now,
+        updated_at: now,
+        closed_at: None,
+        custom,
+    };
+    println!("created  [epic    ]  \"{title}\"");
+    if !ctx.dry_run {
+        write_issue_to_file(&epic, &issue_path_for_identifier(ctx.issues_dir, &epic_id))?;
+    }
+    epic_index.insert(tool.to_string(), epic_id.clone());
+    Ok(epic_id)
+}
+
+fn resolve_rule_task(
+    ctx: &SarifImportContext<'_>,
+    finding: &SarifFinding,
+    epic_id: &str,
+    priority: i32,
+    task_index: &mut HashMap<String, String>,
+    all_existing: &mut HashSet<String>,
+) -> Result<String, KanbusError> {
+    let key = group_key(finding);
+    if let Some(existing_id) = task_index.get(&key) {
+        let path = issue_path_for_identifier(ctx.issues_dir, existing_id);
+        if let Ok(mut task) = read_issue_from_file(&path) {
+            if task.parent.as_deref() != Some(epic_id)
+                || task.priority != priority
+                || task.status == ctx.closed_status
+            {
+                task.parent = Some(epic_id.to_string());
+                task.priority = priority;
+                if task.status == ctx.closed_status {
+                    task.status = ctx.initial_status.to_string();
+                    task.closed_at = None;
+                }
+                task.updated_at = Utc::now();
+                println!("updated  [task    ]  \"{}\"", task.title);
+                if !ctx.dry_run {
+                    write_issue_to_file(&task, &path)?;
+                }
+            }
+        }
+        return Ok(existing_id.clone());
+    }
+
+    let title = format!("{} {}: {}", finding.tool, finding.rule_id, finding.file);
+    let task_id = new_identifier(&title, ctx.project_key, all_existing)?;
+    let now = Utc::now();
+    let mut custom = BTreeMap::new();
+    custom.insert(
+        "sarif_tool".to_string(),
+        Value::String(finding.tool.clone()),
+    );
+    custom.insert(
+        "sarif_rule_id".to_string(),
+        Value::String(finding.rule_id.clone()),
+    );
+    custom.insert("sarif_group".to_string(), Value::String(key.clone()));
+    let mut description = format!(
+        "{} findings for rule `{}` in `{}`.",
+        finding.tool, finding.rule_id, finding.file
+    );
+    if !finding.rule_description.is_empty() {
+        description.push_str(&format!("\n\n{}", finding.rule_description));
+    }
+    if !finding.help_uri.is_empty() {
+        description.push_str(&format!("\n\n- {}", finding.help_uri));
+    }
+    let task = IssueData {
+        identifier: task_id.clone(),
+        title: title.clone(),
+        description,
+        issue_type: "task".to_string(),
+        status: ctx.initial_status.to_string(),
+        priority,
+        assignee: None,
+        creator: None,
+        parent: Some(epic_id.to_string()),
+        labels: vec!["sarif".to_string(), tool_label(&finding.tool)],
+        dependencies: Vec::new(),
+        comments: Vec::new(),
+        created_at: now,
+        updated_at: now,
+        closed_at: None,
+        custom,
+    };
+    println!("created  [task    ]  \"{title}\"");
+    if !ctx.dry_run {
+        write_issue_to_file(&task, &issue_path_for_identifier(ctx.issues_dir, &task_id))?;
+    }
+    task_index.insert(key, task_id.clone());
+    Ok(task_id)
+}
+
+fn map_finding_to_issue(finding: &SarifFinding, task_id: &str, status: &str) -> IssueData {
+    let location = if finding.line > 0 {
+        format!("{}:{}", finding.file, finding.line)
+    } else {
+        finding.file.clone()
+    };
+    let mut description = format!(
+        "{}\n\n\
+         **Tool:** {}\n\
+         **Rule:** `{}`\n\
+         **Level:** {}\n\
+         **Location:** `{location}`",
+        finding.message, finding.tool, finding.rule_id, finding.level
+    );
+    if !finding.help_uri.is_empty() {
+        description.push_str(&format!("\n\n### Reference\n- {}", finding.help_uri));
+    }
+
+    let mut custom = BTreeMap::new();
+    custom.insert(
+        "sarif_tool".to_string(),
+        Value::String(finding.tool.clone()),
+    );
+    custom.insert(
+        "sarif_rule_id".to_string(),
+        Value::String(finding.rule_id.clone()),
+    );
+    custom.insert(
+        "sarif_fingerprint".to_string(),
+        Value::String(finding.fingerprint.clone()),
+    );
+    custom.insert(
+        "sarif_level".to_string(),
+        Value::String(finding.level.clone()),
+    );
+    custom.insert(
+        "sarif_location".to_string(),
+        Value::String(location.clone()),
+    );
+
+    let now = Utc::now();
+    IssueData {
+        identifier: String::new(),
+        title: format!("[{}] {} in {location}", finding.tool, finding.rule_id),
+        description,
+        issue_type: "bug".to_string(),
+        status: status.to_string(),
+        priority: finding.priority,
+        assignee: None,
+        creator: None,
+        parent: Some(task_id.to_string()),
+        labels: vec!["sarif".to_string(), tool_label(&finding.tool)],
+        dependencies: Vec::new(),
+        comments: Vec::new(),
+        created_at: now,
+        updated_at: now,
+        closed_at: None,
+        custom,
+    }
+}
+
+/// True when re-importing would not change anything the report controls.
+fn issue_unchanged(existing: &IssueData, incoming: &IssueData) -> bool {
+    !existing.custom.contains_key(SARIF_CLOSED_AT)
+        && existing.title == incoming.title
+        && existing.description == incoming.description
+        && existing.priority == incoming.priority
+        && existing.parent == incoming.parent
+        && incoming
+            .custom
+            .iter()
+            .all(|(key, value)| existing.custom.get(key) == Some(value))
+}
+
+/// Keep fields people may have edited locally when updating a finding.
+///
+/// The local status is kept unless the importer closed the bug, in which case
+/// the returning finding reopens it.
+fn merge_local_fields(issue: &mut IssueData, mut existing: IssueData, initial_status: &str) {
+    if existing.custom.remove(SARIF_CLOSED_AT).is_some() {
+        issue.status = initial_status.to_string();
+        issue.closed_at = None;
+    } else {
+        issue.status = existing.status;
+        issue.closed_at = existing.closed_at;
+    }
+    issue.created_at = existing.created_at;
+    issue.assignee = existing.assignee;
+    issue.comments = existing.comments;
+    issue.dependencies = existing.dependencies;
+    for label in existing.labels {
+        if !issue.labels.contains(&label) {
+            issue.labels.push(label);
+        }
+    }
+    for (key, value) in existing.custom {
+        issue.custom.entry(key).or_insert(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn setup_project(temp: &TempDir) -> std::path::PathBuf {
+        let root = temp.path().to_path_buf();
+        std::fs::create_dir_all(root.join("project").join("issues")).expect("create issues");
+        let config = crate::config::default_project_configuration();
+        let yaml = serde_yaml::to_string(&config).expect("serialize config");
+        std::fs::write(root.join(".kanbus.yml"), yaml).expect("write config");
+        root
+    }
+
+    fn load_issues(root: &Path) -> Vec<IssueData> {
+        let issues_dir = root.join("project").join("issues");
+        let mut issues: Vec<IssueData> = std::fs::read_dir(&issues_dir)
+            .expect("read issues")
+            .flatten()
+            .map(|entry| read_issue_from_file(&entry.path()).expect("read issue"))
+            .collect();
+        issues.sort_by_key(|issue| issue.title.clone());
+        issues
+    }
+
+    fn sarif_result(rule_index: u64, rule_id: &str, file: &str, line: i64, hash: &str) -> Value {
+        json!({
+            "ruleId": rule_id,
+            "ruleIndex": rule_index,
+            "level": "warning",
+            "message": { "text": format!("{rule_id} triggered") },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": file },
+                    "region": { "startLine": line }
+                }
+            }],
+            "partialFingerprints": { "primaryLocationLineHash": hash }
+        })
+    }
+
+    fn write_report(root: &Path, results: Vec<Value>) -> std::path::PathBuf {
+        let report = json!({
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "semgrep",
+                        "rules": [
+                            {
+                                "id": "python.lang.eval",
+                                "shortDescription": { "text": "Avoid eval" },
+                                "helpUri": "https://semgrep.dev/r/python.lang.eval",
+                                "properties": { "security-severity": "9.1" }
+                            },
+                            { "id": "python.lang.print", "shortDescription": { "text": "Stray print" } }
+                        ]
+                    }
+                },
+                "results": results
+            }]
+        });
+        let path = root.join("report.sarif");
+        std::fs::write(&path, report.to_string()).expect("write report");
+        path
+    }
+
+    #[test]
+    fn parse_sarif_findings_resolves_rules_priority_and_fingerprint() {
+        let report = json!({
+            "runs": [{
+                "tool": { "driver": { "name": "clippy", "rules": [
+                    { "id": "clippy::unwrap_used", "defaultConfiguration": { "level": "error" } }
+                ] } },
+                "results": [
+                    {
+                        "ruleId": "clippy::unwrap_used",
+                        "message": { "text": "used unwrap" },
+                        "locations": [{ "physicalLocation": {
+                            "artifactLocation": { "uri": "src/lib.rs" },
+                            "region": { "startLine": 3 }
+                        } }]
+                    }
+                ]
+            }]
+        });
+        let findings = parse_sarif_findings(&report).expect("parse");
+        assert_eq!(findings.len(), 1);
+        let finding = &findings[0];
+        assert_eq!(finding.tool, "clippy");
+        assert_eq!(finding.level, "error");
+        assert_eq!(finding.priority, 1);
+        assert_eq!(finding.file, "src/lib.rs");
+        assert!(finding
+            .fingerprint
+            .starts_with("clippy/clippy::unwrap_used/sha256/"));
+        let again = parse_sarif_findings(&report).expect("parse again");
+        assert_eq!(again[0].fingerprint, finding.fingerprint);
+    }
+
+    #[test]
+    fn parse_sarif_findings_scopes_fingerprints_to_files_and_skips_non_problems() {
+        let mut suppressed = sarif_result(0, "python.lang.eval", "app.py", 3, "same");
+        suppressed["suppressions"] = json!([{ "kind": "inSource" }]);
+        let mut passed = sarif_result(0, "python.lang.eval", "app.py", 4, "same");
+        passed["kind"] = json!("pass");
+        let mut not_applicable = sarif_result(0, "python.lang.eval", "app.py", 5, "same");
+        not_applicable["kind"] = json!("notApplicable");
+        let mut unsuppressed = sarif_result(0, "python.lang.eval", "lib.py", 6, "other");
+        unsuppressed["suppressions"] = json!([]);
+        let report = json!({
+            "runs": [{
+                "tool": { "driver": { "name": "semgrep" } },
+                "results": [
+                    sarif_result(0, "python.lang.eval", "app.py", 1, "same"),
+                    sarif_result(0, "python.lang.eval", "util.py", 1, "same"),
+                    suppressed,
+                    passed,
+                    not_applicable,
+                    unsuppressed,
+                ]
+            }]
+        });
+        let findings = parse_sarif_findings(&report).expect("parse");
+        let files: Vec<&str> = findings.iter().map(|f| f.file.as_str()).collect();
+        assert_eq!(files, vec!["app.py", "util.py", "lib.py"]);
+        assert_ne!(findings[0].fingerprint, findings[1].fingerprint);
+        assert_eq!(short_fingerprint(&findings[0].fingerprint), "same");
+    }
+
+    #[test]
+    fn parse_sarif_findings_rejects_reports_without_runs() {
+        let error = parse_sarif_findings(&json!({ "version": "2.1.0" })).expect_err("invalid");
+        assert!(error.to_string().contains("missing runs"));
+    }
+
+    #[test]
+    fn import_groups_by_rule_and_file_and_is_idempotent() {
+        let temp = TempDir::new().expect("tempdir");
+        let root = setup_project(&temp);
+        let report = write_report(
+            &root,
+            vec![
+                sarif_result(0, "python.lang.eval", "app.py", 10, "aaa"),
+                sarif_result(0, "python.lang.eval", "app.py", 20, "bbb"),
+                sarif_result(1, "python.lang.print", "app.py", 5, "ccc"),
+            ],
+        );
+
+        let result = import_sarif_report(&root, &report, "kanbus", None, false).expect("import");
+        assert_eq!(result.imported, 3);
+        let issues = load_issues(&root);
+        assert_eq!(
+            issues
+                .iter()
+                .filter(|issue| issue.issue_type == "epic")
+                .count(),
+            1
+        );
+        assert_eq!(
+            issues
+                .iter()
+                .filter(|issue| issue.issue_type == "task")
+                .count(),
+            2
+        );
+        let eval_task = issues
+            .iter()
+            .find(|issue| issue.title == "semgrep python.lang.eval: app.py")
+            .expect("eval task");
+        assert_eq!(eval_task.priority, 0);
+        let eval_bugs: Vec<&IssueData> = issues
+            .iter()
+            .filter(|issue| issue.parent.as_deref() == Some(eval_task.identifier.as_str()))
+            .collect();
+        assert_eq!(eval_bugs.len(), 2);
+        assert!(eval_bugs.iter().all(|issue| issue.issue_type == "bug"));
+
+        let rerun = import_sarif_report(&root, &report, "kanbus", None, false).expect("rerun");
+        assert_eq!(rerun.imported, 0);
+        assert_eq!(rerun.updated, 0);
+        assert_eq!(rerun.closed, 0);
+        assert_eq!(load_issues(&root).len(), issues.len());
+    }
+
+    #[test]
+    fn import_closes_missing_findings_and_honors_dry_run() {
+        let temp = TempDir::new().expect("tempdir");
+        let root = setup_project(&temp);
+        let first = write_report(
+            &root,
+            vec![
+                sarif_result(0, "python.lang.eval", "app.py", 10, "aaa"),
+                sarif_result(1, "python.lang.print", "app.py", 5, "ccc"),
+            ],
+        );
+        import_sarif_report(&root, &first, "kanbus", None, false).expect("import");
+        let before = load_issues(&root);
+
+        let second = write_report(
+            &root,
+            vec![sarif_result(0, "python.lang.eval", "app.py", 12, "aaa")],
+        );
+        let dry_run = import_sarif_report(&root, &second, "kanbus", None, true).expect("dry run");
+        assert_eq!(dry_run.closed, 1);
+        assert_eq!(dry_run.updated, 1);
+        let unchanged = load_issues(&root);
+        assert_eq!(unchanged.len(), before.len());
+        assert!(unchanged.iter().all(|issue| issue.status == "open"));
+
+        let result = import_sarif_report(&root, &second, "kanbus", None, false).expect("import");
+        assert_eq!(result.closed, 1);
+        assert_eq!(result.updated, 1);
+        let issues = load_issues(&root);
+        let print_bug = issues
+            .iter()
+            .find(|issue| issue.title.contains("python.lang.print in"))
+            .expect("print bug");
+        assert_eq!(print_bug.status, "closed");
+        let eval_bug = issues
+            .iter()
+            .find(|issue| issue.title.contains("python.lang.eval in"))
+            .expect("eval bug");
+        assert_eq!(eval_bug.title, "[semgrep] python.lang.eval in app.py:12");
+        assert_eq!(eval_bug.status, "open");
+    }
+
+    #[test]
+    fn reimport_keeps_local_status_and_reopens_only_importer_closed_bugs() {
+        let temp = TempDir::new().expect("tempdir");
+        let root = setup_project(&temp);
+        let first = write_report(
+            &root,
+            vec![
+                sarif_result(0, "python.lang.eval", "app.py", 10, "aaa"),
+                sarif_result(1, "python.lang.print", "app.py", 5, "ccc"),
+            ],
+        );
+        import_sarif_report(&root, &first, "kanbus", None, false).expect("import");
+        let issues_dir = root.join("project").join("issues");
+        let mut eval_bug = load_issues(&root)
+            .into_iter()
+            .find(|issue| issue.title.contains("python.lang.eval in"))
+            .expect("eval bug");
+        eval_bug.status = "in_progress".to_string();
+        write_issue_to_file(
+            &eval_bug,
+            &issue_path_for_identifier(&issues_dir, &eval_bug.identifier),
+        )
+        .expect("triage");
+
+        let second = write_report(
+            &root,
+            vec![sarif_result(0, "python.lang.eval", "app.py", 12, "aaa")],
+        );
+        import_sarif_report(&root, &second, "kanbus", None, false).expect("import");
+        let third = write_report(
+            &root,
+            vec![
+                sarif_result(0, "python.lang.eval", "app.py", 14, "aaa"),
+                sarif_result(1, "python.lang.print", "app.py", 5, "ccc"),
+            ],
+        );
+        let result = import_sarif_report(&root, &third, "kanbus", None, false).expect("import");
+        assert_eq!(result.updated, 2);
+        let issues = load_issues(&root);
+        let eval_bug = issues
+            .iter()
+            .find(|issue| issue.title.contains("python.lang.eval in"))
+            .expect("eval bug");
+        assert_eq!(eval_bug.title, "[semgrep] python.lang.eval in app.py:14");
+        assert_eq!(eval_bug.status, "in_progress");
+        let print_bug = issues
+            .iter()
+            .find(|issue| issue.title.contains("python.lang.print in"))
+            .expect("print bug");
+        assert_eq!(print_bug.status, "open");
+        assert!(print_bug.closed_at.is_none());
+        assert!(!print_bug.custom.contains_key(SARIF_CLOSED_AT));
+    }
+
+    #[test]
+    fn import_rejects_missing_parent_epic() {
+        let temp = TempDir::new().expect("tempdir");
+        let root = setup_project(&temp);
+        let report = write_report(&root, Vec::new());
+        let error = import_sarif_report(&root, &report, "kanbus", Some("kanbus-missing"), false)
+            .expect_err("missing epic");
+        assert!(error.to_string().contains("parent epic not found"));
+    }
+}
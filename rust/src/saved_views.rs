@@ -0,0 +1,440 @@
+//! Saved views: named issue queries shared by the CLI, the console and wiki templates.
+//!
+//! Views are declared under `views:` in `.kanbus.yml`, or one per file as
+//! `<project>/views/<name>.yml`. A view holds `kbs list` filters, a sort key,
+//! and optionally the columns to show and a column to group by. Columns use
+//! the names of `kbs export`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::config_loader::load_project_configuration;
+use crate::error::KanbusError;
+use crate::file_io::{get_configuration_path, load_project_directory};
+use crate::issue_export::{column_value, validate_column};
+use crate::models::{IssueData, ProjectConfiguration, SavedView};
+use crate::queries::SORT_KEYS;
+
+/// Directory under the project directory holding one YAML file per view.
+pub const VIEWS_DIRECTORY: &str = "views";
+
+/// Group label for issues without a value in the grouping column.
+const NO_GROUP: &str = "none";
+
+/// Load every saved view of the project at `root`.
+///
+/// # Errors
+/// Returns `KanbusError` if the configuration cannot be loaded, a view file
+/// is invalid, or a view is defined twice.
+pub fn load_views(root: &Path) -> Result<BTreeMap<String, SavedView>, KanbusError> {
+    let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+    let project_dir = load_project_directory(root)?;
+    load_views_for(&configuration, &project_dir)
+}
+
+/// Merge the views declared in `configuration` with the files in `project_dir/views/`.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if a view file cannot be parsed or
+/// validated, or names a view that `.kanbus.yml` already defines.
+pub fn load_views_for(
+    configuration: &ProjectConfiguration,
+    project_dir: &Path,
+) -> Result<BTreeMap<String, SavedView>, KanbusError> {
+    let (views, problems) = load_valid_views_for(configuration, project_dir)?;
+    match problems.into_iter().next() {
+        Some(problem) => Err(KanbusError::IssueOperation(problem)),
+        None => Ok(views),
+    }
+}
+
+/// Like [`load_views_for`], but skip view files that cannot be loaded.
+///
+/// # Returns
+/// The views that loaded, and one message per skipped view file.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if the views directory cannot be read.
+pub fn load_valid_views_for(
+    configuration: &ProjectConfiguration,
+    project_dir: &Path,
+) -> Result<(BTreeMap<String, SavedView>, Vec<String>), KanbusError> {
+    let mut views = configuration.views.clone();
+    let mut problems = Vec::new();
+    let directory = project_dir.join(VIEWS_DIRECTORY);
+    if !directory.is_dir() {
+        return Ok((views, problems));
+    }
+    let mut paths: Vec<_> = fs::read_dir(&directory)
+        .map_err(|error| KanbusError::Io(error.to_string()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .is_some_and(|extension| matches!(extension, "yml" | "yaml"))
+        })
+        .collect();
+    paths.sort();
+    for path in paths {
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let file_name = format!(
+            "{VIEWS_DIRECTORY}/{}",
+            path.file_name().unwrap_or_default().to_string_lossy()
+        );
+        match read_view_file(name, &path, &file_name, configuration) {
+            Ok(_) if views.contains_key(name) => problems.push(format!(
+                "view '{name}' is defined in both .kanbus.yml and {file_name}"
+            )),
+            Ok(view) => {
+                views.insert(name.to_string(), view);
+            }
+            Err(problem) => problems.push(problem),
+        }
+    }
+    Ok((views, problems))
+}
+
+fn read_view_file(
+    name: &str,
+    path: &Path,
+    file_name: &str,
+    configuration: &ProjectConfiguration,
+) -> Result<SavedView, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|error| format!("cannot read view file {file_name}: {error}"))?;
+    let view: SavedView = serde_yaml::from_str(&contents)
+        .map_err(|error| format!("invalid view file {file_name}: {error}"))?;
+    let errors = validate_view(name, &view, configuration);
+    if !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+    Ok(view)
+}
+
+/// Load a single saved view by name.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the view does not exist, or any
+/// error from `load_views`.
+pub fn load_view(root: &Path, name: &str) -> Result<SavedView, KanbusError> {
+    let mut views = load_views(root)?;
+    views
+        .remove(name)
+        .ok_or_else(|| unknown_view_error(name, &views))
+}
+
+/// Error for a view name that is not defined, listing the defined views.
+pub fn unknown_view_error(name: &str, views: &BTreeMap<String, SavedView>) -> KanbusError {
+    let available = if views.is_empty() {
+        "no views are defined".to_string()
+    } else {
+        format!(
+            "available: {}",
+            views.keys().cloned().collect::<Vec<_>>().join(", ")
+        )
+    };
+    KanbusError::IssueOperation(format!("unknown view '{name}' ({available})"))
+}
+
+/// Validate the views declared in `.kanbus.yml`.
+pub fn validate_views(configuration: &ProjectConfiguration, errors: &mut Vec<String>) {
+    for (name, view) in &configuration.views {
+        errors.extend(validate_view(name, view, configuration));
+    }
+}
+
+fn validate_view(
+    name: &str,
+    view: &SavedView,
+    configuration: &ProjectConfiguration,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    let valid_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.'));
+    if !valid_name {
+        errors.push(format!(
+            "view name '{name}' may only contain letters, digits, '-', '_' and '.'"
+        ));
+    }
+    if let Some(sort) = &view.sort {
+        let key = sort.strip_prefix('-').unwrap_or(sort);
+        if !SORT_KEYS.contains(&key) && key != "identifier" {
+            errors.push(format!(
+                "view '{name}' has unknown sort key '{sort}' (expected one of {})",
+                SORT_KEYS.join(", ")
+            ));
+        }
+    }
+    for column in view.columns.iter().chain(view.group_by.iter()) {
+        if let Err(error) = validate_column(column) {
+            errors.push(format!("view '{name}': {error}"));
+        }
+    }
+    if let Some(issue_type) = &view.filters.issue_type {
+        let known = configuration
+            .hierarchy
+            .iter()
+            .chain(configuration.types.iter())
+            .any(|candidate| candidate == issue_type);
+        if !known {
+            errors.push(format!(
+                "view '{name}' filters on unknown issue type '{issue_type}'"
+            ));
+        }
+    }
+    if let Some(status) = &view.filters.status {
+        if !configuration
+            .statuses
+            .iter()
+            .any(|definition| &definition.key == status)
+        {
+            errors.push(format!(
+                "view '{name}' filters on unknown status '{status}'"
+            ));
+        }
+    }
+    errors
+}
+
+/// Group issues by a column, in group order.
+///
+/// List columns such as `labels` place an issue in one group per item.
+/// Issues without a value are grouped under `none`.
+///
+/// # Errors
+/// Returns `KanbusError::IssueOperation` if the column is unknown.
+pub fn group_issues<'a>(
+    issues: &'a [IssueData],
+    column: &str,
+) -> Result<Vec<(String, Vec<&'a IssueData>)>, KanbusError> {
+    let mut groups: BTreeMap<String, Vec<&IssueData>> = BTreeMap::new();
+    for issue in issues {
+        let keys = match column_value(issue, column)? {
+            Value::Array(items) if !items.is_empty() => items.iter().map(cell_text).collect(),
+            Value::Array(_) | Value::Null => vec![NO_GROUP.to_string()],
+            value => vec![cell_text(&value)],
+        };
+        for key in keys {
+            groups.entry(key).or_default().push(issue);
+        }
+    }
+    Ok(groups.into_iter().collect())
+}
+
+/// Aligned table of the columns selected by a view.
+#[derive(Debug, Clone)]
+pub struct ColumnTable {
+    columns: Vec<String>,
+    widths: Vec<usize>,
+}
+
+impl ColumnTable {
+    /// Size the table to fit `issues`.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::IssueOperation` if a column is unknown.
+    pub fn new(columns: &[String], issues: &[IssueData]) -> Result<Self, KanbusError> {
+        let mut widths: Vec<usize> = columns.iter().map(String::len).collect();
+        for issue in issues {
+            for (width, column) in widths.iter_mut().zip(columns) {
+                *width = (*width).max(cell_text(&column_value(issue, column)?).chars().count());
+            }
+        }
+        Ok(Self {
+            columns: columns.to_vec(),
+            widths,
+        })
+    }
+
+    /// Column names, aligned like the rows.
+    pub fn header(&self) -> String {
+        self.pad(self.columns.clone())
+    }
+
+    /// One issue as a row; porcelain rows are tab-separated and unpadded.
+    ///
+    /// # Errors
+    /// Returns `KanbusError::IssueOperation` if a column is unknown.
+    pub fn row(&self, issue: &IssueData, porcelain: bool) -> Result<String, KanbusError> {
+        let cells = self
+            .columns
+            .iter()
+            .map(|column| column_value(issue, column).map(|value| cell_text(&value)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(if porcelain {
+            cells.join("\t")
+        } else {
+            self.pad(cells)
+        })
+    }
+
+    fn pad(&self, cells: Vec<String>) -> String {
+        cells
+            .into_iter()
+            .zip(&self.widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    }
+}
+
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items.iter().map(cell_text).collect::<Vec<_>>().join(", "),
+        Value::Object(link) => format!(
+            "{}:{}",
+            link.get("type").and_then(Value::as_str).unwrap_or(""),
+            link.get("target").and_then(Value::as_str).unwrap_or("")
+        ),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::default_project_configuration;
+    use crate::models::ViewFilters;
+    use chrono::{TimeZone, Utc};
+
+    fn issue(id: &str, labels: &[&str], assignee: Option<&str>) -> IssueData {
+        let created_at = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        IssueData {
+            identifier: id.to_string(),
+            title: format!("Title {id}"),
+            description: String::new(),
+            issue_type: "task".to_string(),
+            status: "open".to_string(),
+            priority: 2,
+            assignee: assignee.map(str::to_string),
+            creator: None,
+            parent: None,
+            labels: labels.iter().map(|label| label.to_string()).collect(),
+            dependencies: Vec::new(),
+            comments: Vec::new(),
+            created_at,
+            updated_at: created_at,
+            closed_at: None,
+            custom: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn views_merge_configuration_and_directory_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let mut configuration = default_project_configuration();
+        configuration.views.insert(
+            "my-bugs".to_string(),
+            SavedView {
+                filters: ViewFilters {
+                    issue_type: Some("bug".to_string()),
+                    ..ViewFilters::default()
+                },
+                ..SavedView::default()
+            },
+        );
+        let views_dir = temp.path().join(VIEWS_DIRECTORY);
+        fs::create_dir_all(&views_dir).expect("views dir");
+        fs::write(
+            views_dir.join("triage.yml"),
+            "description: Needs triage\nfilters:\n  status: open\nsort: -priority\ncolumns: [id, title]\ngroup_by: assignee\n",
+        )
+        .expect("write view");
+        fs::write(views_dir.join("notes.txt"), "ignored").expect("write note");
+
+        let views = load_views_for(&configuration, temp.path()).expect("load views");
+        assert_eq!(views.keys().collect::<Vec<_>>(), ["my-bugs", "triage"]);
+        let triage = &views["triage"];
+        assert_eq!(triage.filters.status.as_deref(), Some("open"));
+        assert_eq!(triage.sort.as_deref(), Some("-priority"));
+        assert_eq!(triage.group_by.as_deref(), Some("assignee"));
+
+        fs::write(views_dir.join("my-bugs.yaml"), "sort: title\n").expect("write duplicate");
+        let error = load_views_for(&configuration, temp.path()).expect_err("duplicate");
+        assert!(error.to_string().contains("defined in both"));
+    }
+
+    #[test]
+    fn validation_reports_unknown_keys_columns_and_filters() {
+        let mut configuration = default_project_configuration();
+        configuration.views.insert(
+            "bad view".to_string(),
+            SavedView {
+                filters: ViewFilters {
+                    issue_type: Some("saga".to_string()),
+                    status: Some("parked".to_string()),
+                    ..ViewFilters::default()
+                },
+                sort: Some("-owner".to_string()),
+                columns: vec!["id".to_string(), "size".to_string()],
+                group_by: Some("custom.team".to_string()),
+                ..SavedView::default()
+            },
+        );
+        let mut errors = Vec::new();
+        validate_views(&configuration, &mut errors);
+        assert_eq!(errors.len(), 5, "{errors:?}");
+        assert!(errors[0].contains("view name 'bad view'"));
+        assert!(errors[1].contains("unknown sort key '-owner'"));
+        assert!(errors[2].contains("unknown column 'size'"));
+        assert!(errors[3].contains("unknown issue type 'saga'"));
+        assert!(errors[4].contains("unknown status 'parked'"));
+
+        let views = BTreeMap::new();
+        assert!(unknown_view_error("x", &views)
+            .to_string()
+            .contains("no views are defined"));
+    }
+
+    #[test]
+    fn grouping_and_column_tables_use_export_columns() {
+        let issues = vec![
+            issue("kanbus-a", &["auth", "ui"], Some("dev@example.com")),
+            issue("kanbus-b", &[], None),
+        ];
+        let groups = group_issues(&issues, "labels").expect("group labels");
+        let summary: Vec<(String, usize)> = groups
+            .iter()
+            .map(|(key, members)| (key.clone(), members.len()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("auth".to_string(), 1),
+                ("none".to_string(), 1),
+                ("ui".to_string(), 1)
+            ]
+        );
+        assert!(group_issues(&issues, "owner").is_err());
+
+        let columns = vec![
+            "id".to_string(),
+            "assignee".to_string(),
+            "labels".to_string(),
+        ];
+        let table = ColumnTable::new(&columns, &issues).expect("table");
+        assert_eq!(table.header(), "id        assignee         labels");
+        assert_eq!(
+            table.row(&issues[0], false).expect("row"),
+            "kanbus-a  dev@example.com  auth, ui"
+        );
+        assert_eq!(table.row(&issues[1], false).expect("row"), "kanbus-b");
+        assert_eq!(
+            table.row(&issues[0], true).expect("porcelain row"),
+            "kanbus-a\tdev@example.com\tauth, ui"
+        );
+    }
+}
@@ -8,26 +8,37 @@
 //!   epic  "Snyk Vulnerabilities"
 //!     task  "<repo>:<manifest-file>"  (one per affected file)
 //!       sub-task  "[Snyk] KEY in pkg@version"  (one per vulnerability)
+//!
+//! Each pull reconciles the managed sub-tasks of the scanned targets: issues
+//! whose vulnerabilities Snyk no longer reports are closed, issues whose
+//! vulnerabilities reappear are reopened, and file tasks left without open
+//! sub-tasks are closed. Every automatic transition adds a comment and events.
 
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 
 use chrono::Utc;
 use serde_json::Value;
+use uuid::Uuid;
 
 use crate::error::KanbusError;
+use crate::event_history::{
+    comment_payload, events_dir_for_issue_path, now_timestamp, state_transition_payload,
+    write_events_batch, EventRecord, EventType,
+};
 use crate::file_io::load_project_directory;
 use crate::ids::{generate_issue_identifier, IssueIdentifierRequest};
 use crate::issue_files::{
     issue_path_for_identifier, list_issue_identifiers, read_issue_from_file, write_issue_to_file,
 };
-use crate::models::{IssueData, SnykConfiguration};
+use crate::models::{IssueComment, IssueData, SnykConfiguration};
 
 const SNYK_API_BASE: &str = "https://api.snyk.io";
 const SNYK_API_VERSION: &str = "2025-11-05";
 const SNYK_INITIATIVE_TITLE: &str = "Snyk Vulnerability Remediation";
 const SNYK_DEP_EPIC_TITLE: &str = "Snyk Dependency Vulnerabilities";
 const SNYK_CODE_EPIC_TITLE: &str = "Snyk Code Vulnerabilities";
+const SNYK_ACTOR: &str = "snyk";
 type SourceLocation = (String, Option<i64>, Option<i64>, Option<i64>, Option<i64>);
 
 struct SnykEpicOptions {
@@ -50,6 +61,8 @@ pub struct SnykPullResult {
     pub pulled: usize,
     pub updated: usize,
     pub skipped: usize,
+    pub closed: usize,
+    pub reopened: usize,
 }
 
 /// Pull vulnerabilities from Snyk and create/update Kanbus issues.
@@ -190,6 +203,9 @@ pub fn pull_from_snyk(
     let mut pulled = 0usize;
     let mut updated = 0usize;
     let skipped = 0usize;
+    let mut reopened = 0usize;
+    let mut reported_keys: HashSet<String> = HashSet::new();
+    let mut active_tasks: HashSet<String> = HashSet::new();
 
     for ((category, target_file), file_vulns) in &file_to_vulns {
         let epic_id = epic_ids
@@ -223,10 +239,12 @@ pub fn pull_from_snyk(
             &file_task_index,
             &mut all_existing,
         )?;
+        active_tasks.insert(task_id.clone());
 
         // Create/update sub-tasks for each vulnerability in this file
         for vuln in file_vulns {
             let snyk_key = vuln_key(vuln);
+            reported_keys.insert(snyk_key.clone());
 
             let existing_kanbus_id = snyk_key_index.get(&snyk_key);
             let (kanbus_id, action) = if let Some(id) = existing_kanbus_id {
@@ -248,11 +266,25 @@ pub fn pull_from_snyk(
                 map_snyk_to_kanbus(vuln, &Some(task_id.clone()), v1_data, target_file, root)?;
             issue.identifier = kanbus_id.clone();
 
-            // Preserve created_at for updates
+            // Preserve created_at and comments for updates
             let issue_path = issue_path_for_identifier(&issues_dir, &kanbus_id);
+            let mut events = Vec::new();
+            let mut action = action;
             if action == "updated" {
                 if let Ok(existing) = read_issue_from_file(&issue_path) {
                     issue.created_at = existing.created_at;
+                    issue.comments = existing.comments;
+                    if existing.status == "closed" {
+                        issue.status = existing.status;
+                        events = transition_with_comment(
+                            &mut issue,
+                            "open",
+                            &format!(
+                                "Reopened automatically: Snyk reports `{snyk_key}` in `{target_file}` again."
+                            ),
+                        );
+                        action = "reopened";
+                    }
                 }
             }
 
@@ -263,23 +295,194 @@ pub fn pull_from_snyk(
 
             if !dry_run {
                 write_issue_to_file(&issue, &issue_path)?;
+                write_sync_events(&issues_dir, &issue_path, &events)?;
             }
 
-            if action == "updated" {
-                updated += 1;
-            } else {
-                pulled += 1;
+            match action {
+                "updated" => updated += 1,
+                "reopened" => reopened += 1,
+                _ => pulled += 1,
             }
         }
     }
 
+    let scanned_targets: HashSet<String> = project_map.values().cloned().collect();
+    let closed = reconcile_missing_vulnerabilities(
+        &issues_dir,
+        &ReconcileScope {
+            reported_keys: &reported_keys,
+            scanned_targets: &scanned_targets,
+            active_tasks: &active_tasks,
+            min_priority,
+        },
+        dry_run,
+    )?;
+
     Ok(SnykPullResult {
         pulled,
         updated,
         skipped,
+        closed,
+        reopened,
     })
 }
 
+/// What a pull observed, used to decide which managed issues to close.
+struct ReconcileScope<'a> {
+    reported_keys: &'a HashSet<String>,
+    scanned_targets: &'a HashSet<String>,
+    active_tasks: &'a HashSet<String>,
+    min_priority: i32,
+}
+
+/// Close sub-tasks whose vulnerabilities Snyk stopped reporting, then close
+/// file tasks that no longer have open sub-tasks.
+///
+/// Only issues under tasks for scanned target files and at or above the
+/// severity threshold are considered, so narrowing the pull never closes
+/// issues Snyk was not asked about. Returns the number of closed sub-tasks.
+fn reconcile_missing_vulnerabilities(
+    issues_dir: &Path,
+    scope: &ReconcileScope<'_>,
+    dry_run: bool,
+) -> Result<usize, KanbusError> {
+    let mut issues: Vec<IssueData> = list_issue_identifiers(issues_dir)?
+        .iter()
+        .filter_map(|id| read_issue_from_file(&issue_path_for_identifier(issues_dir, id)).ok())
+        .collect();
+    issues.sort_by(|left, right| left.identifier.cmp(&right.identifier));
+
+    let tasks: BTreeMap<String, String> = issues
+        .iter()
+        .filter(|issue| issue.issue_type == "task")
+        .filter_map(|issue| {
+            issue
+                .custom
+                .get("snyk_target_file")
+                .and_then(Value::as_str)
+                .filter(|target| scope.scanned_targets.contains(*target))
+                .map(|target| (issue.identifier.clone(), target.to_string()))
+        })
+        .collect();
+
+    let mut closed = 0usize;
+    for issue in issues.iter_mut() {
+        let Some(snyk_key) = issue.custom.get("snyk_key").and_then(Value::as_str) else {
+            continue;
+        };
+        let Some(target_file) = issue.parent.as_ref().and_then(|parent| tasks.get(parent)) else {
+            continue;
+        };
+        let severity = issue
+            .custom
+            .get("snyk_severity")
+            .and_then(Value::as_str)
+            .unwrap_or("low")
+            .to_string();
+        if issue.status == "closed"
+            || scope.reported_keys.contains(snyk_key)
+            || severity_to_priority(&severity) > scope.min_priority
+        {
+            continue;
+        }
+        let text = format!(
+            "Closed automatically: Snyk no longer reports `{snyk_key}` in `{target_file}`."
+        );
+        let events = transition_with_comment(issue, "closed", &text);
+        println!("closed   [{severity:<8}]  \"{}\"", issue.title);
+        if !dry_run {
+            let path = issue_path_for_identifier(issues_dir, &issue.identifier);
+            write_issue_to_file(issue, &path)?;
+            write_sync_events(issues_dir, &path, &events)?;
+        }
+        closed += 1;
+    }
+
+    for (task_id, target_file) in &tasks {
+        if scope.active_tasks.contains(task_id) {
+            continue;
+        }
+        let mut children = issues
+            .iter()
+            .filter(|issue| issue.parent.as_deref() == Some(task_id.as_str()))
+            .peekable();
+        if children.peek().is_none() || !children.all(|issue| issue.status == "closed") {
+            continue;
+        }
+        let Some(task) = issues
+            .iter_mut()
+            .find(|issue| &issue.identifier == task_id && issue.status != "closed")
+        else {
+            continue;
+        };
+        let text = format!(
+            "Closed automatically: no open Snyk vulnerabilities remain in `{target_file}`."
+        );
+        let events = transition_with_comment(task, "closed", &text);
+        println!("closed   [task    ]  \"{target_file}\"");
+        if !dry_run {
+            let path = issue_path_for_identifier(issues_dir, task_id);
+            write_issue_to_file(task, &path)?;
+            write_sync_events(issues_dir, &path, &events)?;
+        }
+    }
+
+    Ok(closed)
+}
+
+/// Move a managed issue to a new status and explain why in a comment.
+///
+/// Returns the state transition and comment events describing the change.
+fn transition_with_comment(issue: &mut IssueData, to_status: &str, text: &str) -> Vec<EventRecord> {
+    let from_status = issue.status.clone();
+    let now = Utc::now();
+    issue.status = to_status.to_string();
+    issue.closed_at = if to_status == "closed" {
+        Some(now)
+    } else {
+        None
+    };
+    issue.updated_at = now;
+    let comment_id = Uuid::new_v4().to_string();
+    issue.comments.push(IssueComment {
+        id: Some(comment_id.clone()),
+        author: SNYK_ACTOR.to_string(),
+        text: text.to_string(),
+        created_at: now,
+    });
+    let occurred_at = now_timestamp();
+    vec![
+        EventRecord::new(
+            issue.identifier.clone(),
+            EventType::StateTransition,
+            SNYK_ACTOR,
+            state_transition_payload(&from_status, to_status),
+            occurred_at.clone(),
+        ),
+        EventRecord::new(
+            issue.identifier.clone(),
+            EventType::CommentAdded,
+            SNYK_ACTOR,
+            comment_payload(&comment_id, SNYK_ACTOR),
+            occurred_at,
+        ),
+    ]
+}
+
+fn write_sync_events(
+    issues_dir: &Path,
+    issue_path: &Path,
+    events: &[EventRecord],
+) -> Result<(), KanbusError> {
+    if events.is_empty() {
+        return Ok(());
+    }
+    let project_dir = issues_dir.parent().unwrap_or(issues_dir);
+    let events_dir = events_dir_for_issue_path(project_dir, issue_path)?;
+    write_events_batch(&events_dir, events)?;
+    Ok(())
+}
+
 /// Resolve the parent epic ID, creating one if it doesn't exist.
 fn resolve_parent_epic(
     issues_dir: &Path,
@@ -592,42 +795,56 @@ fn resolve_file_task(
     all_existing: &mut HashSet<String>,
 ) -> Result<String, KanbusError> {
     if let Some(id) = file_task_index.get(&(category.to_string(), target_file.to_string())) {
-        if !ctx.dry_run {
-            let task_path = issue_path_for_identifier(issues_dir, id);
-            if let Ok(mut issue) = read_issue_from_file(&task_path) {
-                if issue.issue_type == "task" {
-                    let mut changed = false;
-                    if issue.parent.as_deref() != Some(ctx.epic_id) {
-                        issue.parent = Some(ctx.epic_id.to_string());
-                        changed = true;
-                    }
-                    if issue.priority != ctx.priority {
-                        issue.priority = ctx.priority;
-                        changed = true;
-                    }
-                    let target = serde_json::Value::String(target_file.to_string());
-                    if issue.custom.get("snyk_target_file") != Some(&target) {
-                        issue.custom.insert("snyk_target_file".to_string(), target);
-                        changed = true;
-                    }
-                    let cat = serde_json::Value::String(category.to_string());
-                    if issue.custom.get("snyk_category") != Some(&cat) {
-                        issue.custom.insert("snyk_category".to_string(), cat);
-                        changed = true;
-                    }
-                    if !issue.labels.iter().any(|l| l == "snyk") {
-                        issue.labels.push("snyk".to_string());
-                        changed = true;
-                    }
-                    if !issue.labels.iter().any(|l| l == "security") {
-                        issue.labels.push("security".to_string());
-                        changed = true;
-                    }
-                    if changed {
+        let task_path = issue_path_for_identifier(issues_dir, id);
+        if let Ok(mut issue) = read_issue_from_file(&task_path) {
+            if issue.issue_type == "task" {
+                let mut changed = false;
+                if issue.parent.as_deref() != Some(ctx.epic_id) {
+                    issue.parent = Some(ctx.epic_id.to_string());
+                    changed = true;
+                }
+                if issue.priority != ctx.priority {
+                    issue.priority = ctx.priority;
+                    changed = true;
+                }
+                let target = serde_json::Value::String(target_file.to_string());
+                if issue.custom.get("snyk_target_file") != Some(&target) {
+                    issue.custom.insert("snyk_target_file".to_string(), target);
+                    changed = true;
+                }
+                let cat = serde_json::Value::String(category.to_string());
+                if issue.custom.get("snyk_category") != Some(&cat) {
+                    issue.custom.insert("snyk_category".to_string(), cat);
+                    changed = true;
+                }
+                if !issue.labels.iter().any(|l| l == "snyk") {
+                    issue.labels.push("snyk".to_string());
+                    changed = true;
+                }
+                if !issue.labels.iter().any(|l| l == "security") {
+                    issue.labels.push("security".to_string());
+                    changed = true;
+                }
+                let mut events = Vec::new();
+                let reopened = issue.status == "closed";
+                if reopened {
+                    events = transition_with_comment(
+                        &mut issue,
+                        "open",
+                        &format!(
+                            "Reopened automatically: Snyk reports vulnerabilities in `{target_file}` again."
+                        ),
+                    );
+                    changed = true;
+                }
+                if changed {
+                    let action = if reopened { "reopened" } else { "updated " };
+                    let short_key = &id[..id.len().min(id.find('-').map_or(6, |i| i + 7))];
+                    println!("{action} [task    ]  {short_key:<14}  \"{target_file}\"");
+                    if !ctx.dry_run {
                         issue.updated_at = Utc::now();
                         write_issue_to_file(&issue, &task_path)?;
-                        let short_key = &id[..id.len().min(id.find('-').map_or(6, |i| i + 7))];
-                        println!("updated  [task    ]  {short_key:<14}  \"{target_file}\"");
+                        write_sync_events(issues_dir, &task_path, &events)?;
                     }
                 }
             }
@@ -2037,6 +2254,39 @@ mod tests {
         assert!(!path.exists());
     }
 
+    #[test]
+    fn resolve_file_task_leaves_closed_task_alone_in_dry_run() {
+        let temp_dir = TempDir::new().expect("tempdir");
+        let issues_dir = temp_dir.path().join("issues");
+        std::fs::create_dir_all(&issues_dir).expect("mkdir issues");
+        let mut task = make_issue("kanbus-task", "task", "pom.xml");
+        task.status = "closed".into();
+        let path = issue_path_for_identifier(&issues_dir, "kanbus-task");
+        write_issue_to_file(&task, &path).expect("write");
+        let before = std::fs::read_to_string(&path).expect("read");
+
+        let idx = BTreeMap::from([(
+            ("dependency".to_string(), "pom.xml".to_string()),
+            "kanbus-task".to_string(),
+        )]);
+        let resolved = resolve_file_task(
+            &issues_dir,
+            "kanbus",
+            "pom.xml",
+            "dependency",
+            &FileTaskContext {
+                epic_id: "kanbus-epic",
+                priority: 2,
+                dry_run: true,
+            },
+            &idx,
+            &mut HashSet::from([String::from("kanbus-task")]),
+        )
+        .expect("resolve task");
+        assert_eq!(resolved, "kanbus-task");
+        assert_eq!(std::fs::read_to_string(&path).expect("read"), before);
+    }
+
     #[test]
     fn build_file_task_index_defaults_missing_category_to_dependency() {
         let temp_dir = TempDir::new().expect("tempdir");
@@ -2095,4 +2345,120 @@ mod tests {
             .description
             .contains("Pin `openssl` to version 1.2.3 or later."));
     }
+
+    fn write_managed_tree(issues_dir: &Path) {
+        let mut task = make_issue("kanbus-task", "task", "requirements.txt");
+        task.custom.insert(
+            "snyk_target_file".to_string(),
+            Value::String("requirements.txt".to_string()),
+        );
+        write_issue_to_file(&task, &issue_path_for_identifier(issues_dir, "kanbus-task"))
+            .expect("write task");
+        for (id, key, severity) in [
+            ("kanbus-vuln1", "SNYK-1", "high"),
+            ("kanbus-vuln2", "SNYK-2", "low"),
+        ] {
+            let mut vuln = make_issue(id, "sub-task", key);
+            vuln.parent = Some("kanbus-task".to_string());
+            vuln.custom
+                .insert("snyk_key".to_string(), Value::String(key.to_string()));
+            vuln.custom.insert(
+                "snyk_severity".to_string(),
+                Value::String(severity.to_string()),
+            );
+            write_issue_to_file(&vuln, &issue_path_for_identifier(issues_dir, id))
+                .expect("write vuln");
+        }
+    }
+
+    #[test]
+    fn reconcile_closes_missing_vulnerabilities_and_emptied_tasks() {
+        let temp = TempDir::new().expect("tempdir");
+        let issues_dir = temp.path().join("project").join("issues");
+        std::fs::create_dir_all(&issues_dir).expect("issues dir");
+        write_managed_tree(&issues_dir);
+        let reported = HashSet::new();
+        let targets = HashSet::from(["requirements.txt".to_string()]);
+        let active = HashSet::new();
+        let scope = ReconcileScope {
+            reported_keys: &reported,
+            scanned_targets: &targets,
+            active_tasks: &active,
+            min_priority: 3,
+        };
+
+        let preview = reconcile_missing_vulnerabilities(&issues_dir, &scope, true).expect("dry");
+        assert_eq!(preview, 2);
+        let untouched =
+            read_issue_from_file(&issue_path_for_identifier(&issues_dir, "kanbus-vuln1"))
+                .expect("read");
+        assert_eq!(untouched.status, "open");
+        assert!(!temp.path().join("project").join("events").exists());
+
+        let closed = reconcile_missing_vulnerabilities(&issues_dir, &scope, false).expect("close");
+        assert_eq!(closed, 2);
+        let vuln = read_issue_from_file(&issue_path_for_identifier(&issues_dir, "kanbus-vuln1"))
+            .expect("read");
+        assert_eq!(vuln.status, "closed");
+        assert!(vuln.closed_at.is_some());
+        assert_eq!(vuln.comments.len(), 1);
+        assert!(vuln.comments[0].text.contains("no longer reports `SNYK-1`"));
+        let task = read_issue_from_file(&issue_path_for_identifier(&issues_dir, "kanbus-task"))
+            .expect("read");
+        assert_eq!(task.status, "closed");
+        let events = std::fs::read_dir(temp.path().join("project").join("events"))
+            .expect("events")
+            .count();
+        assert_eq!(events, 6);
+    }
+
+    #[test]
+    fn reconcile_respects_reported_keys_threshold_and_scope() {
+        let temp = TempDir::new().expect("tempdir");
+        let issues_dir = temp.path().join("project").join("issues");
+        std::fs::create_dir_all(&issues_dir).expect("issues dir");
+        write_managed_tree(&issues_dir);
+        let reported = HashSet::from(["SNYK-1".to_string()]);
+        let targets = HashSet::from(["requirements.txt".to_string()]);
+        let active = HashSet::from(["kanbus-task".to_string()]);
+        let scope = ReconcileScope {
+            reported_keys: &reported,
+            scanned_targets: &targets,
+            active_tasks: &active,
+            min_priority: 1,
+        };
+        assert_eq!(
+            reconcile_missing_vulnerabilities(&issues_dir, &scope, false).expect("reconcile"),
+            0
+        );
+
+        let other_targets = HashSet::from(["package.json".to_string()]);
+        let unscoped = ReconcileScope {
+            reported_keys: &HashSet::new(),
+            scanned_targets: &other_targets,
+            active_tasks: &HashSet::new(),
+            min_priority: 3,
+        };
+        assert_eq!(
+            reconcile_missing_vulnerabilities(&issues_dir, &unscoped, false).expect("reconcile"),
+            0
+        );
+        let task = read_issue_from_file(&issue_path_for_identifier(&issues_dir, "kanbus-task"))
+            .expect("read");
+        assert_eq!(task.status, "open");
+    }
+
+    #[test]
+    fn transition_with_comment_reopens_and_records_events() {
+        let mut issue = make_issue("kanbus-vuln", "sub-task", "[Snyk] SNYK-1");
+        issue.status = "closed".to_string();
+        issue.closed_at = Some(Utc::now());
+        let events = transition_with_comment(&mut issue, "open", "Reopened automatically.");
+        assert_eq!(issue.status, "open");
+        assert!(issue.closed_at.is_none());
+        assert_eq!(issue.comments[0].author, "snyk");
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].payload["from_status"], "closed");
+        assert_eq!(events[0].payload["to_status"], "open");
+    }
 }
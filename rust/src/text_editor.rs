@@ -1,11 +1,80 @@
 //! File edit operations mirroring the Anthropic text editor tool.
 //!
 //! Provides view, str_replace, create, and insert commands with strict single-match
-//! replacement semantics for agent compatibility.
+//! replacement semantics for agent compatibility, plus atomic batches of edits,
+//! unified-diff previews and undo.
+//!
+//! Every write records the file's previous content in a per-file journal under
+//! the project cache directory (`.cache/edits`), which `edit_undo` replays.
+//! Outside a project the journal lives in `.cache/edits` under the root.
+
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
 
-use std::path::Path;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use crate::error::KanbusError;
+use crate::event_history::now_timestamp;
+use crate::file_io::load_project_directory;
+use crate::wiki_history::unified_diff;
+
+/// Undo steps kept per file.
+const MAX_JOURNAL_ENTRIES: usize = 20;
+
+/// A single edit, as accepted by `edit_apply` and `edit_preview`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum EditOperation {
+    StrReplace {
+        path: String,
+        old_str: String,
+        new_str: String,
+    },
+    Create {
+        path: String,
+        file_text: String,
+    },
+    Insert {
+        path: String,
+        insert_line: i32,
+        insert_text: String,
+    },
+}
+
+impl EditOperation {
+    fn path(&self) -> &str {
+        match self {
+            Self::StrReplace { path, .. }
+            | Self::Create { path, .. }
+            | Self::Insert { path, .. } => path,
+        }
+    }
+}
+
+/// Previous contents of a file, newest last; `None` means the file did not exist.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct EditJournal {
+    path: String,
+    entries: Vec<JournalEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalEntry {
+    previous: Option<String>,
+    /// Digest of the content the edit wrote, checked before undoing it.
+    written: String,
+    recorded_at: String,
+}
+
+/// A file as it will look after a batch of edits.
+#[derive(Debug)]
+struct StagedFile {
+    key: String,
+    resolved: PathBuf,
+    original: Option<String>,
+    content: Option<String>,
+}
 
 /// View file contents or directory listing.
 ///
@@ -103,34 +172,14 @@ pub fn edit_str_replace(
     old_str: &str,
     new_str: &str,
 ) -> Result<String, KanbusError> {
-    let resolved = root.join(path);
-    if !resolved.exists() || !resolved.is_file() {
-        return Err(KanbusError::IssueOperation("file not found".to_string()));
-    }
-    let root_canonical = root.canonicalize().ok();
-    let resolved_canonical = resolved.canonicalize().ok();
-    if let (Some(ref rc), Some(ref rp)) = (root_canonical, resolved_canonical) {
-        if !rp.starts_with(rc) {
-            return Err(KanbusError::IssueOperation(
-                "path escapes repository root".to_string(),
-            ));
-        }
-    }
-    let content = std::fs::read_to_string(&resolved).map_err(|e| KanbusError::Io(e.to_string()))?;
-    let count = content.matches(old_str).count();
-    if count == 0 {
-        return Err(KanbusError::IssueOperation(
-            "no match found for replacement".to_string(),
-        ));
-    }
-    if count > 1 {
-        return Err(KanbusError::IssueOperation(format!(
-            "found {} matches for replacement text; provide more context for a unique match",
-            count
-        )));
-    }
-    let new_content = content.replacen(old_str, new_str, 1);
-    std::fs::write(&resolved, new_content).map_err(|e| KanbusError::Io(e.to_string()))?;
+    commit_edits(
+        root,
+        &[EditOperation::StrReplace {
+            path: path.to_string_lossy().into_owned(),
+            old_str: old_str.to_string(),
+            new_str: new_str.to_string(),
+        }],
+    )?;
     Ok("Successfully replaced text at exactly one location.".to_string())
 }
 
@@ -146,24 +195,13 @@ pub fn edit_str_replace(
 ///
 /// Returns `KanbusError::IssueOperation` if file already exists or path escapes root.
 pub fn edit_create(root: &Path, path: &Path, file_text: &str) -> Result<String, KanbusError> {
-    let resolved = root.join(path);
-    if resolved.exists() {
-        return Err(KanbusError::IssueOperation(
-            "file already exists".to_string(),
-        ));
-    }
-    let parent = resolved
-        .parent()
-        .ok_or_else(|| KanbusError::IssueOperation("invalid path".to_string()))?;
-    std::fs::create_dir_all(parent).map_err(|e| KanbusError::Io(e.to_string()))?;
-    if let (Ok(rc), Ok(rp)) = (root.canonicalize(), resolved.canonicalize()) {
-        if !rp.starts_with(&rc) {
-            return Err(KanbusError::IssueOperation(
-                "path escapes repository root".to_string(),
-            ));
-        }
-    }
-    std::fs::write(&resolved, file_text).map_err(|e| KanbusError::Io(e.to_string()))?;
+    commit_edits(
+        root,
+        &[EditOperation::Create {
+            path: path.to_string_lossy().into_owned(),
+            file_text: file_text.to_string(),
+        }],
+    )?;
     Ok("Successfully created file.".to_string())
 }
 
@@ -187,45 +225,390 @@ pub fn edit_insert(
     insert_line: i32,
     insert_text: &str,
 ) -> Result<String, KanbusError> {
-    if insert_line < 0 {
-        return Err(KanbusError::IssueOperation(
-            "insert_line must be non-negative".to_string(),
-        ));
+    commit_edits(
+        root,
+        &[EditOperation::Insert {
+            path: path.to_string_lossy().into_owned(),
+            insert_line,
+            insert_text: insert_text.to_string(),
+        }],
+    )?;
+    Ok("Successfully inserted text.".to_string())
+}
+
+/// Apply a batch of edits atomically: either every edit succeeds or no file changes.
+///
+/// Edits run in order against the result of the previous ones, so a batch may
+/// create a file and then edit it.
+///
+/// # Arguments
+///
+/// * `root` - Repository root
+/// * `edits` - Edits to apply
+///
+/// # Errors
+///
+/// Returns `KanbusError::IssueOperation` naming the first failing edit (1-indexed).
+pub fn edit_apply(root: &Path, edits: &[EditOperation]) -> Result<String, KanbusError> {
+    let files = commit_edits(root, edits)?;
+    Ok(format!(
+        "Successfully applied {} edits to {} files.",
+        edits.len(),
+        files
+    ))
+}
+
+/// Render the edits as a unified diff without writing any file.
+///
+/// # Errors
+///
+/// Returns `KanbusError::IssueOperation` if any edit would fail.
+pub fn edit_preview(root: &Path, edits: &[EditOperation]) -> Result<String, KanbusError> {
+    let staged = stage_edits(root, edits)?;
+    let diffs: Vec<String> = staged
+        .iter()
+        .map(|file| {
+            let old_label = if file.original.is_some() {
+                format!("a/{}", file.key)
+            } else {
+                "/dev/null".to_string()
+            };
+            unified_diff(
+                file.original.as_deref().unwrap_or(""),
+                file.content.as_deref().unwrap_or(""),
+                &old_label,
+                &format!("b/{}", file.key),
+            )
+        })
+        .filter(|diff| !diff.is_empty())
+        .collect();
+    if diffs.is_empty() {
+        return Ok("No changes.".to_string());
     }
-    let resolved = root.join(path);
-    if !resolved.exists() || !resolved.is_file() {
-        return Err(KanbusError::IssueOperation("file not found".to_string()));
+    Ok(diffs.join("\n"))
+}
+
+/// Revert the most recent edit made to a file through these commands.
+///
+/// # Arguments
+///
+/// * `root` - Repository root
+/// * `path` - File path (relative to root)
+///
+/// # Errors
+///
+/// Returns `KanbusError::IssueOperation` if the file has no recorded edits or
+/// has changed since the last one.
+pub fn edit_undo(root: &Path, path: &Path) -> Result<String, KanbusError> {
+    let key = journal_key(root, path)?;
+    let resolved = root.join(&key);
+    ensure_within_root(root, &resolved)?;
+    let journal_path = journal_path(root, &key);
+    let mut journal = read_journal(&journal_path, &key)?;
+    let Some(entry) = journal.entries.pop() else {
+        return Err(KanbusError::IssueOperation(format!(
+            "no edit history for {key}"
+        )));
+    };
+    let current = std::fs::read_to_string(&resolved).unwrap_or_default();
+    if hex_digest(&current) != entry.written {
+        return Err(KanbusError::IssueOperation(format!(
+            "{key} changed since the last edit; not reverting"
+        )));
     }
-    let root_canonical = root.canonicalize().ok();
-    let resolved_canonical = resolved.canonicalize().ok();
-    if let (Some(ref rc), Some(ref rp)) = (root_canonical, resolved_canonical) {
-        if !rp.starts_with(rc) {
-            return Err(KanbusError::IssueOperation(
-                "path escapes repository root".to_string(),
-            ));
+    match &entry.previous {
+        Some(content) => {
+            std::fs::write(&resolved, content).map_err(|e| KanbusError::Io(e.to_string()))?
+        }
+        None => {
+            if resolved.exists() {
+                std::fs::remove_file(&resolved).map_err(|e| KanbusError::Io(e.to_string()))?;
+            }
         }
     }
-    let content = std::fs::read_to_string(&resolved).map_err(|e| KanbusError::Io(e.to_string()))?;
-    let lines: Vec<&str> = content.lines().collect();
-    let idx = insert_line as usize;
-    if idx > lines.len() {
+    write_journal(&journal_path, &journal)?;
+    Ok(format!("Successfully reverted last edit to {key}."))
+}
+
+fn commit_edits(root: &Path, edits: &[EditOperation]) -> Result<usize, KanbusError> {
+    let staged = stage_edits(root, edits)?;
+    let mut written: Vec<&StagedFile> = Vec::new();
+    for file in &staged {
+        if let Err(error) = write_staged(file) {
+            for done in written {
+                let _ = restore(&done.resolved, done.original.as_deref());
+            }
+            let _ = restore(&file.resolved, file.original.as_deref());
+            return Err(error);
+        }
+        written.push(file);
+    }
+    for file in &staged {
+        record_journal_entry(
+            root,
+            &file.key,
+            file.original.clone(),
+            file.content.as_deref().unwrap_or(""),
+        )?;
+    }
+    Ok(staged.len())
+}
+
+fn write_staged(file: &StagedFile) -> Result<(), KanbusError> {
+    let content = file.content.as_deref().unwrap_or("");
+    if let Some(parent) = file.resolved.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| KanbusError::Io(e.to_string()))?;
+    }
+    std::fs::write(&file.resolved, content).map_err(|e| KanbusError::Io(e.to_string()))
+}
+
+fn restore(resolved: &Path, original: Option<&str>) -> Result<(), KanbusError> {
+    match original {
+        Some(content) => {
+            std::fs::write(resolved, content).map_err(|e| KanbusError::Io(e.to_string()))
+        }
+        None => std::fs::remove_file(resolved).map_err(|e| KanbusError::Io(e.to_string())),
+    }
+}
+
+fn stage_edits(root: &Path, edits: &[EditOperation]) -> Result<Vec<StagedFile>, KanbusError> {
+    let mut staged: Vec<StagedFile> = Vec::new();
+    let mut positions: BTreeMap<String, usize> = BTreeMap::new();
+    for (index, edit) in edits.iter().enumerate() {
+        let result = (|| {
+            let key = journal_key(root, Path::new(edit.path()))?;
+            let position = match positions.get(&key) {
+                Some(position) => *position,
+                None => {
+                    let resolved = root.join(&key);
+                    let original = if resolved.is_file() {
+                        ensure_within_root(root, &resolved)?;
+                        Some(
+                            std::fs::read_to_string(&resolved)
+                                .map_err(|e| KanbusError::Io(e.to_string()))?,
+                        )
+                    } else {
+                        None
+                    };
+                    staged.push(StagedFile {
+                        key: key.clone(),
+                        resolved,
+                        content: original.clone(),
+                        original,
+                    });
+                    positions.insert(key, staged.len() - 1);
+                    staged.len() - 1
+                }
+            };
+            let file = &mut staged[position];
+            file.content = Some(apply_operation(root, file, edit)?);
+            Ok(())
+        })();
+        if let Err(error) = result {
+            if edits.len() == 1 {
+                return Err(error);
+            }
+            let message = match error {
+                KanbusError::IssueOperation(message) | KanbusError::Io(message) => message,
+                other => other.to_string(),
+            };
+            return Err(KanbusError::IssueOperation(format!(
+                "edit {}: {message}",
+                index + 1
+            )));
+        }
+    }
+    staged.retain(|file| file.content != file.original);
+    Ok(staged)
+}
+
+fn apply_operation(
+    root: &Path,
+    file: &StagedFile,
+    edit: &EditOperation,
+) -> Result<String, KanbusError> {
+    match edit {
+        EditOperation::Create { file_text, .. } => {
+            if file.content.is_some() || file.resolved.exists() {
+                return Err(KanbusError::IssueOperation(
+                    "file already exists".to_string(),
+                ));
+            }
+            ensure_within_root(root, &file.resolved)?;
+            Ok(file_text.clone())
+        }
+        EditOperation::StrReplace {
+            old_str, new_str, ..
+        } => {
+            let Some(content) = &file.content else {
+                return Err(KanbusError::IssueOperation("file not found".to_string()));
+            };
+            let count = content.matches(old_str.as_str()).count();
+            if count == 0 {
+                return Err(KanbusError::IssueOperation(
+                    "no match found for replacement".to_string(),
+                ));
+            }
+            if count > 1 {
+                return Err(KanbusError::IssueOperation(format!(
+                    "found {} matches for replacement text; provide more context for a unique match",
+                    count
+                )));
+            }
+            Ok(content.replacen(old_str.as_str(), new_str, 1))
+        }
+        EditOperation::Insert {
+            insert_line,
+            insert_text,
+            ..
+        } => {
+            if *insert_line < 0 {
+                return Err(KanbusError::IssueOperation(
+                    "insert_line must be non-negative".to_string(),
+                ));
+            }
+            let Some(content) = &file.content else {
+                return Err(KanbusError::IssueOperation("file not found".to_string()));
+            };
+            let lines: Vec<&str> = content.lines().collect();
+            let idx = *insert_line as usize;
+            if idx > lines.len() {
+                return Err(KanbusError::IssueOperation(
+                    "insert_line exceeds file length".to_string(),
+                ));
+            }
+            let insert_lines: Vec<&str> = insert_text.lines().collect();
+            let mut new_lines: Vec<String> =
+                lines[..idx].iter().map(|s| (*s).to_string()).collect();
+            new_lines.extend(insert_lines.iter().map(|s| (*s).to_string()));
+            new_lines.extend(lines[idx..].iter().map(|s| (*s).to_string()));
+            let output = new_lines.join("\n");
+            let trailing = if content.ends_with('\n') || new_lines.is_empty() {
+                "\n"
+            } else {
+                ""
+            };
+            Ok(format!("{}{}", output, trailing))
+        }
+    }
+}
+
+/// Check that a path stays inside the root, following symlinks of its nearest existing ancestor.
+fn ensure_within_root(root: &Path, resolved: &Path) -> Result<(), KanbusError> {
+    let Ok(root_canonical) = root.canonicalize() else {
+        return Ok(());
+    };
+    let mut existing = resolved;
+    let mut remainder = Vec::new();
+    while !existing.exists() {
+        let (Some(parent), Some(name)) = (existing.parent(), existing.file_name()) else {
+            return Ok(());
+        };
+        remainder.push(name);
+        existing = parent;
+    }
+    let Ok(mut canonical) = existing.canonicalize() else {
+        return Ok(());
+    };
+    for name in remainder.into_iter().rev() {
+        canonical.push(name);
+    }
+    if !canonical.starts_with(&root_canonical) {
         return Err(KanbusError::IssueOperation(
-            "insert_line exceeds file length".to_string(),
+            "path escapes repository root".to_string(),
         ));
     }
-    let insert_lines: Vec<&str> = insert_text.lines().collect();
-    let mut new_lines: Vec<String> = lines[..idx].iter().map(|s| (*s).to_string()).collect();
-    new_lines.extend(insert_lines.iter().map(|s| (*s).to_string()));
-    new_lines.extend(lines[idx..].iter().map(|s| (*s).to_string()));
-    let output = new_lines.join("\n");
-    let trailing = if content.ends_with('\n') || new_lines.is_empty() {
-        "\n"
+    Ok(())
+}
+
+/// Normalize a path into the root-relative key used for staging and the journal.
+fn journal_key(root: &Path, path: &Path) -> Result<String, KanbusError> {
+    let relative = if path.is_absolute() {
+        let canonical_root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        path.strip_prefix(root)
+            .or_else(|_| path.strip_prefix(&canonical_root))
+            .map_err(|_| KanbusError::IssueOperation("path escapes repository root".to_string()))?
     } else {
-        ""
+        path
     };
-    std::fs::write(&resolved, format!("{}{}", output, trailing))
-        .map_err(|e| KanbusError::Io(e.to_string()))?;
-    Ok("Successfully inserted text.".to_string())
+    let mut parts: Vec<String> = Vec::new();
+    for component in relative.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy().into_owned()),
+            Component::CurDir => {}
+            Component::ParentDir if parts.pop().is_some() => {}
+            _ => {
+                return Err(KanbusError::IssueOperation(
+                    "path escapes repository root".to_string(),
+                ))
+            }
+        }
+    }
+    if parts.is_empty() {
+        return Err(KanbusError::IssueOperation("invalid path".to_string()));
+    }
+    Ok(parts.join("/"))
+}
+
+/// Directory holding edit journals: the project cache, or the root's own
+/// `.cache` when no project exists.
+fn journal_dir(root: &Path) -> PathBuf {
+    load_project_directory(root)
+        .unwrap_or_else(|_| root.to_path_buf())
+        .join(".cache")
+        .join("edits")
+}
+
+fn journal_path(root: &Path, key: &str) -> PathBuf {
+    journal_dir(root).join(format!("{}.json", hex_digest(key)))
+}
+
+fn hex_digest(value: &str) -> String {
+    let digest = Sha256::digest(value.as_bytes());
+    digest[..12]
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn read_journal(journal_path: &Path, key: &str) -> Result<EditJournal, KanbusError> {
+    if !journal_path.exists() {
+        return Ok(EditJournal {
+            path: key.to_string(),
+            entries: Vec::new(),
+        });
+    }
+    let contents =
+        std::fs::read_to_string(journal_path).map_err(|e| KanbusError::Io(e.to_string()))?;
+    serde_json::from_str(&contents).map_err(|e| KanbusError::Io(e.to_string()))
+}
+
+fn write_journal(journal_path: &Path, journal: &EditJournal) -> Result<(), KanbusError> {
+    if let Some(parent) = journal_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| KanbusError::Io(e.to_string()))?;
+    }
+    let contents = serde_json::to_string(journal).map_err(|e| KanbusError::Io(e.to_string()))?;
+    std::fs::write(journal_path, contents).map_err(|e| KanbusError::Io(e.to_string()))
+}
+
+fn record_journal_entry(
+    root: &Path,
+    key: &str,
+    previous: Option<String>,
+    written: &str,
+) -> Result<(), KanbusError> {
+    let journal_path = journal_path(root, key);
+    let mut journal = read_journal(&journal_path, key)?;
+    journal.entries.push(JournalEntry {
+        previous,
+        written: hex_digest(written),
+        recorded_at: now_timestamp(),
+    });
+    if journal.entries.len() > MAX_JOURNAL_ENTRIES {
+        let excess = journal.entries.len() - MAX_JOURNAL_ENTRIES;
+        journal.entries.drain(..excess);
+    }
+    write_journal(&journal_path, &journal)
 }
 
 #[cfg(test)]
@@ -331,4 +714,117 @@ mod tests {
             "line1\nmiddle\nline2\n"
         );
     }
+
+    fn parse_edits(json: &str) -> Vec<EditOperation> {
+        serde_json::from_str(json).expect("parse edits")
+    }
+
+    #[test]
+    fn edit_apply_is_all_or_nothing_and_undo_restores_each_file() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        fs::write(tmp.path().join("a.txt"), "one\ntwo\n").expect("write a");
+
+        let failing = parse_edits(
+            r#"[{"command":"str_replace","path":"a.txt","old_str":"one","new_str":"1"},
+                {"command":"create","path":"b.txt","file_text":"bee\n"},
+                {"command":"str_replace","path":"a.txt","old_str":"missing","new_str":"x"}]"#,
+        );
+        match edit_apply(tmp.path(), &failing) {
+            Err(KanbusError::IssueOperation(message)) => {
+                assert_eq!(message, "edit 3: no match found for replacement")
+            }
+            other => panic!("expected batch error, got {other:?}"),
+        }
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("a.txt")).unwrap(),
+            "one\ntwo\n"
+        );
+        assert!(!tmp.path().join("b.txt").exists());
+
+        let edits = parse_edits(
+            r#"[{"command":"str_replace","path":"a.txt","old_str":"one","new_str":"1"},
+                {"command":"create","path":"b.txt","file_text":"bee\n"},
+                {"command":"insert","path":"b.txt","insert_line":1,"insert_text":"sting"}]"#,
+        );
+        let applied = edit_apply(tmp.path(), &edits).expect("apply");
+        assert_eq!(applied, "Successfully applied 3 edits to 2 files.");
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("a.txt")).unwrap(),
+            "1\ntwo\n"
+        );
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("b.txt")).unwrap(),
+            "bee\nsting\n"
+        );
+
+        edit_undo(tmp.path(), Path::new("b.txt")).expect("undo create");
+        assert!(!tmp.path().join("b.txt").exists());
+        edit_undo(tmp.path(), Path::new("./a.txt")).expect("undo replace");
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("a.txt")).unwrap(),
+            "one\ntwo\n"
+        );
+        match edit_undo(tmp.path(), Path::new("a.txt")) {
+            Err(KanbusError::IssueOperation(message)) => {
+                assert_eq!(message, "no edit history for a.txt")
+            }
+            other => panic!("expected empty-history error, got {other:?}"),
+        }
+        assert!(tmp.path().join(".cache").join("edits").is_dir());
+    }
+
+    #[test]
+    fn edit_undo_refuses_to_overwrite_later_changes() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        fs::write(tmp.path().join("a.txt"), "one\n").expect("write a");
+        edit_str_replace(tmp.path(), Path::new("a.txt"), "one", "1").expect("replace");
+        fs::write(tmp.path().join("a.txt"), "changed by hand\n").expect("rewrite a");
+
+        match edit_undo(tmp.path(), Path::new("a.txt")) {
+            Err(KanbusError::IssueOperation(message)) => {
+                assert_eq!(message, "a.txt changed since the last edit; not reverting")
+            }
+            other => panic!("expected changed-file error, got {other:?}"),
+        }
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("a.txt")).unwrap(),
+            "changed by hand\n"
+        );
+
+        fs::write(tmp.path().join("a.txt"), "1\n").expect("restore edit");
+        edit_undo(tmp.path(), Path::new("a.txt")).expect("undo");
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("a.txt")).unwrap(),
+            "one\n"
+        );
+    }
+
+    #[test]
+    fn edit_preview_renders_unified_diff_without_writing() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        fs::write(tmp.path().join("a.txt"), "one\ntwo\n").expect("write a");
+        let edits = parse_edits(
+            r#"[{"command":"str_replace","path":"a.txt","old_str":"two","new_str":"2"},
+                {"command":"create","path":"new.txt","file_text":"fresh\n"}]"#,
+        );
+        let diff = edit_preview(tmp.path(), &edits).expect("preview");
+        assert!(diff.contains("--- a/a.txt"));
+        assert!(diff.contains("-two"));
+        assert!(diff.contains("+2"));
+        assert!(diff.contains("--- /dev/null"));
+        assert!(diff.contains("+fresh"));
+        assert_eq!(
+            fs::read_to_string(tmp.path().join("a.txt")).unwrap(),
+            "one\ntwo\n"
+        );
+        assert!(!tmp.path().join("new.txt").exists());
+        assert!(matches!(
+            edit_undo(tmp.path(), Path::new("a.txt")),
+            Err(KanbusError::IssueOperation(_))
+        ));
+        assert!(matches!(
+            edit_preview(tmp.path(), &parse_edits(r#"[{"command":"create","path":"../x","file_text":""}]"#)),
+            Err(KanbusError::IssueOperation(message)) if message == "path escapes repository root"
+        ));
+    }
 }
@@ -3,16 +3,27 @@
 use std::collections::BTreeMap;
 use std::fs;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use minijinja::value::{Kwargs, Value};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use minijinja::value::{Kwargs, Value, ValueKind};
 use minijinja::{context, Environment, Error, ErrorKind};
 
+use crate::ai_summary::{AiSummarizer, SummaryDetail};
 use crate::console_backend::FileStore;
 use crate::console_wiki;
+use crate::dependencies::BlockerResolver;
+use crate::dependency_analysis::{analyze_issues, render_dependency_analysis};
+use crate::dependency_types::DependencyTypes;
 use crate::error::KanbusError;
-use crate::models::IssueData;
+use crate::event_history::{load_issue_events, EventRecord};
+use crate::ids::match_issue_identifier;
+use crate::models::{IssueData, ProjectConfiguration, SavedView};
+use crate::queries::{filter_issues, search_issues, sort_issues_by};
+use crate::saved_views::unknown_view_error;
+
+const DEFAULT_EVENTS_LIMIT: i64 = 50;
 
 /// Request for rendering a wiki page.
 #[derive(Debug, Clone)]
@@ -46,7 +57,7 @@ pub fn render_wiki_page(request: &WikiRenderRequest) -> Result<String, KanbusErr
         .join(&configuration.project_directory)
         .join(".cache")
         .join("wiki_render");
-    let cache_key = wiki_render_cache_key(&page_path, &issues);
+    let cache_key = wiki_render_cache_key(&page_path, &issues, &configuration.views);
     if let Some(cached) = wiki_render_read_cache(&wiki_render_cache_dir, &cache_key) {
         wiki_render_log_cache_hit(&wiki_render_cache_dir);
         return Ok(cached);
@@ -55,67 +66,84 @@ pub fn render_wiki_page(request: &WikiRenderRequest) -> Result<String, KanbusErr
     let issues = Arc::new(issues);
 
     let mut env = Environment::new();
-    let query_issues = Arc::clone(&issues);
-    env.add_function("query", move |kwargs: Kwargs| {
-        let mut filtered = filter_issues_from_kwargs(&query_issues, &kwargs)?;
-        if let Some(sort_key) = kwargs.get::<Option<String>>("sort")? {
-            match sort_key.as_str() {
-                "title" => filtered.sort_by(|left, right| left.title.cmp(&right.title)),
-                "priority" => filtered.sort_by_key(|issue| issue.priority),
-                _ => return Err(Error::new(ErrorKind::InvalidOperation, "invalid sort key")),
-            }
-        }
-        kwargs
-            .assert_all_used()
-            .map_err(|_| Error::new(ErrorKind::InvalidOperation, "invalid query parameter"))?;
-        Ok(Value::from_serialize(filtered))
-    });
+    register_issue_functions(
+        &mut env,
+        TemplateData {
+            issues: Arc::clone(&issues),
+            configuration: Some(configuration.clone()),
+            project_dir: Some(request.root.join(&configuration.project_directory)),
+        },
+    );
 
-    let count_issues = Arc::clone(&issues);
-    env.add_function("count", move |kwargs: Kwargs| {
-        let filtered = filter_issues_from_kwargs(&count_issues, &kwargs)?;
+    let graph_issues = Arc::clone(&issues);
+    let graph_configuration = configuration.clone();
+    env.add_function("dependency_graph", move |kwargs: Kwargs| {
+        let format = kwargs
+            .get::<Option<String>>("format")?
+            .unwrap_or_else(|| "mermaid".to_string());
+        let epic = kwargs.get::<Option<String>>("epic")?;
         kwargs
             .assert_all_used()
             .map_err(|_| Error::new(ErrorKind::InvalidOperation, "invalid query parameter"))?;
-        Ok(filtered.len())
-    });
-
-    let issue_issues = Arc::clone(&issues);
-    env.add_function("issue", move |id: String| {
-        let found = issue_issues.iter().find(|i| i.identifier == id).cloned();
-        Ok(Value::from_serialize(found))
+        let analysis =
+            analyze_issues(&graph_issues, Some(&graph_configuration), epic.as_deref())
+                .map_err(|error| Error::new(ErrorKind::InvalidOperation, error.to_string()))?;
+        render_dependency_analysis(&analysis, &format)
+            .map_err(|error| Error::new(ErrorKind::InvalidOperation, error.to_string()))
     });
 
     let ai_config = configuration.ai.clone();
+    let summarizer = configuration
+        .ai
+        .as_ref()
+        .map(|ai| Arc::new(AiSummarizer::from_configuration(ai)));
     let cache_dir = request
         .root
         .join(&configuration.project_directory)
         .join(".cache");
-    env.add_function("ai_summarize", move |value: Value| {
-        if ai_config.is_none() {
+    env.add_function("ai_summarize", move |value: Value, kwargs: Kwargs| {
+        let detail = kwargs
+            .get::<Option<String>>("detail")?
+            .unwrap_or_else(|| "short".to_string());
+        kwargs.assert_all_used().map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                "ai_summarize only accepts a detail argument",
+            )
+        })?;
+        let detail = SummaryDetail::parse(&detail)
+            .map_err(|error| Error::new(ErrorKind::InvalidOperation, error.to_string()))?;
+        let (Some(ai_config), Some(summarizer)) = (&ai_config, &summarizer) else {
             return Ok(Value::from("(AI summarization not configured)"));
-        }
-        let cache_key = ai_summarize_cache_key(&value, "short");
+        };
+        let content = ai_summarize_content(&value);
+        let cache_key = ai_summarize_cache_key(&content, &ai_config.model, detail.as_str());
         if let Some(cached) = ai_summarize_read_cache(&cache_dir, &cache_key) {
             return Ok(Value::from(cached));
         }
-        let result = if std::env::var("KANBUS_TEST_AI_MOCK").as_deref() == Ok("1") {
+        if std::env::var("KANBUS_TEST_AI_MOCK").as_deref() == Ok("1") {
             let identifier =
                 extract_issue_identifier(&value).unwrap_or_else(|| "unknown".to_string());
-            format!("Generated summary for {}", identifier)
-        } else {
-            let title = value
-                .get_attr("title")
-                .ok()
-                .and_then(|v| v.as_str().map(String::from))
-                .unwrap_or_else(|| "untitled".to_string());
-            format!("Summary: {}", title)
-        };
-        ai_summarize_write_cache(&cache_dir, &cache_key, &result);
-        if std::env::var("KANBUS_TEST_AI_MOCK").as_deref() == Ok("1") {
+            let result = format!("Generated summary for {}", identifier);
+            ai_summarize_write_cache(&cache_dir, &cache_key, &result);
             ai_summarize_log_call(&cache_dir);
+            return Ok(Value::from(result));
+        }
+        // Provider failures fall back to the title and are not cached, so the next render retries.
+        match summarizer.summarize(&content, detail) {
+            Some(result) => {
+                ai_summarize_write_cache(&cache_dir, &cache_key, &result);
+                Ok(Value::from(result))
+            }
+            None => {
+                let title = value
+                    .get_attr("title")
+                    .ok()
+                    .and_then(|v| v.as_str().map(String::from))
+                    .unwrap_or_else(|| "untitled".to_string());
+                Ok(Value::from(format!("Summary: {}", title)))
+            }
         }
-        Ok(Value::from(result))
     });
 
     #[cfg(tarpaulin)]
@@ -167,24 +195,282 @@ pub fn render_wiki_page(request: &WikiRenderRequest) -> Result<String, KanbusErr
     Ok(rendered)
 }
 
-fn filter_issues_from_kwargs(
-    issues: &Arc<Vec<IssueData>>,
+/// Issue data and project context available to wiki template functions.
+struct TemplateData {
+    issues: Arc<Vec<IssueData>>,
+    configuration: Option<ProjectConfiguration>,
+    project_dir: Option<PathBuf>,
+}
+
+impl TemplateData {
+    fn find(&self, identifier: &str) -> Option<&IssueData> {
+        if let Some(found) = self.issues.iter().find(|i| i.identifier == identifier) {
+            return Some(found);
+        }
+        match match_issue_identifier(identifier, self.issues.iter()).as_slice() {
+            [found] => Some(*found),
+            _ => None,
+        }
+    }
+
+    fn dependency_types(&self) -> DependencyTypes {
+        DependencyTypes::from_configuration(self.configuration.as_ref())
+    }
+
+    fn views(&self) -> BTreeMap<String, SavedView> {
+        self.configuration
+            .as_ref()
+            .map(|configuration| configuration.views.clone())
+            .unwrap_or_default()
+    }
+}
+
+fn register_issue_functions(env: &mut Environment<'_>, data: TemplateData) {
+    let data = Arc::new(data);
+
+    let query_data = Arc::clone(&data);
+    env.add_function("query", move |kwargs: Kwargs| {
+        let filtered = query_issues(
+            query_data.issues.as_ref().clone(),
+            &kwargs,
+            &query_data.views(),
+        )?;
+        Ok(Value::from_serialize(filtered))
+    });
+
+    let count_data = Arc::clone(&data);
+    env.add_function("count", move |kwargs: Kwargs| {
+        let filtered = query_issues(
+            count_data.issues.as_ref().clone(),
+            &kwargs,
+            &count_data.views(),
+        )?;
+        Ok(filtered.len())
+    });
+
+    let issue_data = Arc::clone(&data);
+    env.add_function("issue", move |id: String| {
+        Ok(Value::from_serialize(issue_data.find(&id).cloned()))
+    });
+
+    let children_data = Arc::clone(&data);
+    env.add_function("children", move |id: String| {
+        let Some(parent) = children_data.find(&id) else {
+            return Ok(Value::from_serialize(Vec::<IssueData>::new()));
+        };
+        let children: Vec<&IssueData> = children_data
+            .issues
+            .iter()
+            .filter(|issue| issue.parent.as_deref() == Some(parent.identifier.as_str()))
+            .collect();
+        Ok(Value::from_serialize(children))
+    });
+
+    let blocked_by_data = Arc::clone(&data);
+    env.add_function("blocked_by", move |id: String| {
+        let dependency_types = blocked_by_data.dependency_types();
+        let blockers: Vec<&IssueData> = blocked_by_data
+            .find(&id)
+            .map(|issue| {
+                issue
+                    .dependencies
+                    .iter()
+                    .filter(|dependency| dependency_types.blocks(&dependency.dependency_type))
+                    .filter_map(|dependency| blocked_by_data.find(&dependency.target))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Value::from_serialize(blockers))
+    });
+
+    let blocks_data = Arc::clone(&data);
+    env.add_function("blocks", move |id: String| {
+        let dependency_types = blocks_data.dependency_types();
+        let Some(target) = blocks_data.find(&id) else {
+            return Ok(Value::from_serialize(Vec::<IssueData>::new()));
+        };
+        let blocked: Vec<&IssueData> = blocks_data
+            .issues
+            .iter()
+            .filter(|issue| {
+                issue.dependencies.iter().any(|dependency| {
+                    dependency_types.blocks(&dependency.dependency_type)
+                        && blocks_data
+                            .find(&dependency.target)
+                            .is_some_and(|found| found.identifier == target.identifier)
+                })
+            })
+            .collect();
+        Ok(Value::from_serialize(blocked))
+    });
+
+    let ready_data = Arc::clone(&data);
+    env.add_function("ready", move |kwargs: Kwargs| {
+        let resolver = BlockerResolver::new(&ready_data.issues, ready_data.configuration.as_ref());
+        let candidates: Vec<IssueData> = ready_data
+            .issues
+            .iter()
+            .filter(|issue| resolver.is_ready(issue))
+            .cloned()
+            .collect();
+        let filtered = query_issues(candidates, &kwargs, &ready_data.views())?;
+        Ok(Value::from_serialize(filtered))
+    });
+
+    let events_data = Arc::clone(&data);
+    env.add_function("events", move |id: String, kwargs: Kwargs| {
+        let limit = read_integer_kwarg(&kwargs, "limit")?.unwrap_or(DEFAULT_EVENTS_LIMIT);
+        kwargs
+            .assert_all_used()
+            .map_err(|_| Error::new(ErrorKind::InvalidOperation, "invalid query parameter"))?;
+        let (Some(project_dir), Some(issue)) = (&events_data.project_dir, events_data.find(&id))
+        else {
+            return Ok(Value::from_serialize(Vec::<EventRecord>::new()));
+        };
+        let (events, _) =
+            load_issue_events(project_dir, &issue.identifier, None, limit.max(0) as usize)
+                .map_err(|error| Error::new(ErrorKind::InvalidOperation, error.to_string()))?;
+        Ok(Value::from_serialize(events))
+    });
+
+    env.add_function("group_by", |issues: Value, field: String| {
+        let mut groups: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+        for issue in issues.try_iter()? {
+            for key in group_keys(&issue, &field) {
+                groups.entry(key).or_default().push(issue.clone());
+            }
+        }
+        Ok(Value::from_serialize(groups))
+    });
+}
+
+/// Filter issues by template kwargs.
+///
+/// A `view` kwarg names a saved view whose filters and sort apply unless
+/// overridden by explicit kwargs.
+fn query_issues(
+    candidates: Vec<IssueData>,
     kwargs: &Kwargs,
+    views: &BTreeMap<String, SavedView>,
 ) -> Result<Vec<IssueData>, Error> {
-    let status = read_string_kwarg(kwargs, "status")?;
+    let view = match read_string_kwarg(kwargs, "view")? {
+        Some(name) => views.get(&name).cloned().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                unknown_view_error(&name, views).to_string(),
+            )
+        })?,
+        None => SavedView::default(),
+    };
+    let defaults = view.filters;
+    let status = read_string_kwarg(kwargs, "status")?.or(defaults.status);
     let mut issue_type = read_string_kwarg(kwargs, "issue_type")?;
     if issue_type.is_none() {
         issue_type = read_string_kwarg(kwargs, "type")?;
     }
-    let mut filtered: Vec<IssueData> = issues.as_ref().clone();
-    if let Some(status) = status {
-        filtered.retain(|issue| issue.status == status);
+    let issue_type = issue_type.or(defaults.issue_type);
+    let assignee = read_string_kwarg(kwargs, "assignee")?.or(defaults.assignee);
+    let label = read_string_kwarg(kwargs, "label")?.or(defaults.label);
+    let parent = read_string_kwarg(kwargs, "parent")?.or(defaults.parent);
+    let search = read_string_kwarg(kwargs, "search")?.or(defaults.search);
+    let priority = read_integer_kwarg(kwargs, "priority")?;
+    let priority_lte = read_integer_kwarg(kwargs, "priority_lte")?;
+    let priority_gte = read_integer_kwarg(kwargs, "priority_gte")?;
+    let created_since = read_date_kwarg(kwargs, "created_since")?;
+    let updated_since = read_date_kwarg(kwargs, "updated_since")?;
+    let closed_since = read_date_kwarg(kwargs, "closed_since")?;
+    let custom = read_custom_kwarg(kwargs)?;
+    let sort = read_string_kwarg(kwargs, "sort")?.or(view.sort);
+    let limit = read_integer_kwarg(kwargs, "limit")?;
+    kwargs
+        .assert_all_used()
+        .map_err(|_| Error::new(ErrorKind::InvalidOperation, "invalid query parameter"))?;
+
+    let mut filtered = filter_issues(
+        candidates,
+        status.as_deref(),
+        None,
+        assignee.as_deref(),
+        label.as_deref(),
+        parent.as_deref(),
+    );
+    apply_issue_type_filter(&mut filtered, &issue_type.unwrap_or_default());
+    filtered.retain(|issue| {
+        priority.is_none_or(|value| i64::from(issue.priority) == value)
+            && priority_lte.is_none_or(|value| i64::from(issue.priority) <= value)
+            && priority_gte.is_none_or(|value| i64::from(issue.priority) >= value)
+            && created_since.is_none_or(|since| issue.created_at >= since)
+            && updated_since.is_none_or(|since| issue.updated_at >= since)
+            && closed_since.is_none_or(|since| issue.closed_at.is_some_and(|at| at >= since))
+            && custom.iter().all(|(field, expected)| {
+                issue
+                    .custom
+                    .get(field)
+                    .is_some_and(|actual| custom_value_matches(actual, expected))
+            })
+    });
+    let mut filtered = search_issues(filtered, search.as_deref());
+    if let Some(sort_key) = sort {
+        sort_template_issues(&mut filtered, &sort_key)?;
+    }
+    if let Some(limit) = limit {
+        filtered.truncate(limit.max(0) as usize);
     }
-    let issue_type_filter = issue_type.unwrap_or_default();
-    apply_issue_type_filter(&mut filtered, &issue_type_filter);
     Ok(filtered)
 }
 
+fn sort_template_issues(issues: &mut [IssueData], sort_key: &str) -> Result<(), Error> {
+    sort_issues_by(issues, sort_key)
+        .map_err(|_| Error::new(ErrorKind::InvalidOperation, "invalid sort key"))
+}
+
+fn group_keys(issue: &Value, field: &str) -> Vec<String> {
+    if matches!(field, "label" | "labels") {
+        let labels: Vec<String> = issue
+            .get_attr("labels")
+            .ok()
+            .and_then(|labels| labels.try_iter().ok().map(|iter| iter.collect::<Vec<_>>()))
+            .unwrap_or_default()
+            .iter()
+            .map(|label| label.to_string())
+            .collect();
+        return if labels.is_empty() {
+            vec!["none".to_string()]
+        } else {
+            labels
+        };
+    }
+    let custom_field = field.strip_prefix("custom.");
+    let mut value = match custom_field {
+        Some(_) => Value::UNDEFINED,
+        None => issue.get_attr(field).unwrap_or(Value::UNDEFINED),
+    };
+    if value.is_undefined() || value.is_none() {
+        value = issue
+            .get_attr("custom")
+            .and_then(|custom| custom.get_attr(custom_field.unwrap_or(field)))
+            .unwrap_or(Value::UNDEFINED);
+    }
+    if value.is_undefined() || value.is_none() {
+        vec!["none".to_string()]
+    } else {
+        vec![value.to_string()]
+    }
+}
+
+fn custom_value_matches(actual: &serde_json::Value, expected: &serde_json::Value) -> bool {
+    fn scalar_text(value: &serde_json::Value) -> Option<String> {
+        match value {
+            serde_json::Value::String(text) => Some(text.clone()),
+            serde_json::Value::Number(number) => Some(number.to_string()),
+            serde_json::Value::Bool(flag) => Some(flag.to_string()),
+            _ => None,
+        }
+    }
+    actual == expected
+        || scalar_text(actual).is_some_and(|text| Some(text) == scalar_text(expected))
+}
+
 fn contains_invalid_numeric(rendered: &str) -> bool {
     rendered
         .split(|ch: char| !ch.is_alphanumeric())
@@ -199,16 +485,17 @@ fn contains_invalid_numeric(rendered: &str) -> bool {
         })
 }
 
-fn ai_summarize_cache_key(value: &Value, detail: &str) -> String {
+fn ai_summarize_content(value: &Value) -> String {
+    match value.as_str() {
+        Some(text) => text.to_string(),
+        None => serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string()),
+    }
+}
+
+fn ai_summarize_cache_key(content: &str, model: &str, detail: &str) -> String {
     use sha2::{Digest, Sha256};
-    let identifier = extract_issue_identifier(value).unwrap_or_default();
-    let updated = value
-        .get_attr("updated_at")
-        .ok()
-        .and_then(|v| v.as_str().map(String::from))
-        .unwrap_or_default();
     let mut hasher = Sha256::new();
-    hasher.update(format!("{}:{}:{}", identifier, updated, detail).as_bytes());
+    hasher.update(format!("{}:{}:{}", model, detail, content).as_bytes());
     format!("{:x}", hasher.finalize())
 }
 
@@ -246,7 +533,11 @@ fn ai_summarize_log_call(cache_dir: &Path) {
     }
 }
 
-fn wiki_render_cache_key(page_path: &Path, issues: &[IssueData]) -> String {
+fn wiki_render_cache_key(
+    page_path: &Path,
+    issues: &[IssueData],
+    views: &BTreeMap<String, SavedView>,
+) -> String {
     use sha2::{Digest, Sha256};
     let page_mtime = fs::metadata(page_path)
         .ok()
@@ -259,7 +550,14 @@ fn wiki_render_cache_key(page_path: &Path, issues: &[IssueData]) -> String {
         .collect();
     issue_ids.sort();
     let issue_part = issue_ids.join("|");
-    let raw = format!("{}|{}|{}", page_path.display(), page_mtime, issue_part);
+    let views_part = serde_json::to_string(views).unwrap_or_default();
+    let raw = format!(
+        "{}|{}|{}|{}",
+        page_path.display(),
+        page_mtime,
+        issue_part,
+        views_part
+    );
     let mut hasher = Sha256::new();
     hasher.update(raw.as_bytes());
     format!("{:x}", hasher.finalize())
@@ -315,6 +613,74 @@ fn read_string_kwarg(kwargs: &Kwargs, key: &str) -> Result<Option<String>, Error
     kwargs.get(key)
 }
 
+fn read_integer_kwarg(kwargs: &Kwargs, key: &str) -> Result<Option<i64>, Error> {
+    if !kwargs.has(key) {
+        return Ok(None);
+    }
+    let value: Value = kwargs.peek(key)?;
+    if value.is_undefined() || value.is_none() {
+        let _: Value = kwargs.get(key)?;
+        return Ok(None);
+    }
+    let number = i64::try_from(value)
+        .map_err(|_| Error::new(ErrorKind::InvalidOperation, "invalid query parameter"))?;
+    let _: Value = kwargs.get(key)?;
+    Ok(Some(number))
+}
+
+fn read_date_kwarg(kwargs: &Kwargs, key: &str) -> Result<Option<DateTime<Utc>>, Error> {
+    let Some(text) = read_string_kwarg(kwargs, key)? else {
+        return Ok(None);
+    };
+    parse_since(&text, Utc::now())
+        .map(Some)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidOperation, "invalid query parameter"))
+}
+
+/// Parse a `*_since` bound: `7d`, `2w`, `12h`, `YYYY-MM-DD` or RFC 3339.
+fn parse_since(text: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let text = text.trim();
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(text) {
+        return Some(parsed.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        return date.and_hms_opt(0, 0, 0).map(|value| value.and_utc());
+    }
+    let split = text.len().checked_sub(1)?;
+    let amount: i64 = text.get(..split)?.parse().ok()?;
+    let span = match text.get(split..)? {
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => None,
+    }?;
+    now.checked_sub_signed(span)
+}
+
+fn read_custom_kwarg(kwargs: &Kwargs) -> Result<Vec<(String, serde_json::Value)>, Error> {
+    if !kwargs.has("custom") {
+        return Ok(Vec::new());
+    }
+    let value: Value = kwargs.get("custom")?;
+    if value.is_undefined() || value.is_none() {
+        return Ok(Vec::new());
+    }
+    if value.kind() != ValueKind::Map {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            "invalid query parameter",
+        ));
+    }
+    let mut fields = Vec::new();
+    for key in value.try_iter()? {
+        let expected = value.get_item(&key)?;
+        let expected = serde_json::to_value(&expected)
+            .map_err(|error| Error::new(ErrorKind::InvalidOperation, error.to_string()))?;
+        fields.push((key.to_string(), expected));
+    }
+    Ok(fields)
+}
+
 fn apply_issue_type_filter(issues: &mut Vec<IssueData>, issue_type_filter: &str) {
     if !issue_type_filter.is_empty() {
         issues.retain(|issue| issue.issue_type == issue_type_filter);
@@ -330,7 +696,7 @@ fn validate_page_exists(page_path: &std::path::Path) -> Result<(), KanbusError>
     Ok(())
 }
 
-/// Render a template string with wiki context (query, count, issue).
+/// Render a template string with wiki context (query, count, issue and related helpers).
 ///
 /// # Arguments
 /// * `text` - Template string (may contain Jinja2).
@@ -342,36 +708,15 @@ fn validate_page_exists(page_path: &std::path::Path) -> Result<(), KanbusError>
 /// # Errors
 /// Returns error if template rendering fails.
 pub fn render_template_string(text: &str, issues: &[IssueData]) -> Result<String, KanbusError> {
-    let issues = Arc::new(issues.to_vec());
     let mut env = Environment::new();
-    let query_issues = Arc::clone(&issues);
-    env.add_function("query", move |kwargs: Kwargs| {
-        let mut filtered = filter_issues_from_kwargs(&query_issues, &kwargs)?;
-        if let Some(sort_key) = kwargs.get::<Option<String>>("sort")? {
-            match sort_key.as_str() {
-                "title" => filtered.sort_by(|left, right| left.title.cmp(&right.title)),
-                "priority" => filtered.sort_by_key(|issue| issue.priority),
-                _ => return Err(Error::new(ErrorKind::InvalidOperation, "invalid sort key")),
-            }
-        }
-        kwargs
-            .assert_all_used()
-            .map_err(|_| Error::new(ErrorKind::InvalidOperation, "invalid query parameter"))?;
-        Ok(Value::from_serialize(filtered))
-    });
-    let count_issues = Arc::clone(&issues);
-    env.add_function("count", move |kwargs: Kwargs| {
-        let filtered = filter_issues_from_kwargs(&count_issues, &kwargs)?;
-        kwargs
-            .assert_all_used()
-            .map_err(|_| Error::new(ErrorKind::InvalidOperation, "invalid query parameter"))?;
-        Ok(filtered.len())
-    });
-    let issue_issues = Arc::clone(&issues);
-    env.add_function("issue", move |id: String| {
-        let found = issue_issues.iter().find(|i| i.identifier == id).cloned();
-        Ok(Value::from_serialize(found))
-    });
+    register_issue_functions(
+        &mut env,
+        TemplateData {
+            issues: Arc::new(issues.to_vec()),
+            configuration: None,
+            project_dir: None,
+        },
+    );
     env.render_str(text, context! {})
         .map_err(|error| KanbusError::IssueOperation(error.to_string()))
 }
@@ -398,3 +743,195 @@ pub fn list_wiki_pages(root: &Path) -> Result<Vec<String>, KanbusError> {
         .collect();
     Ok(pages)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DependencyLink;
+    use chrono::TimeZone;
+
+    fn issue(id: &str, priority: i32, parent: Option<&str>) -> IssueData {
+        let timestamp = Utc.with_ymd_and_hms(2026, 3, 6, 0, 0, 0).unwrap();
+        IssueData {
+            identifier: id.to_string(),
+            title: format!("Title {id}"),
+            description: String::new(),
+            issue_type: "task".to_string(),
+            status: "open".to_string(),
+            priority,
+            assignee: None,
+            creator: None,
+            parent: parent.map(str::to_string),
+            labels: Vec::new(),
+            dependencies: Vec::new(),
+            comments: Vec::new(),
+            created_at: timestamp,
+            updated_at: timestamp,
+            closed_at: None,
+            custom: BTreeMap::new(),
+        }
+    }
+
+    fn sample_issues() -> Vec<IssueData> {
+        let mut epic = issue("kanbus-epic", 1, None);
+        epic.issue_type = "epic".to_string();
+        let mut login = issue("kanbus-login", 0, Some("kanbus-epic"));
+        login.assignee = Some("dev@example.com".to_string());
+        login.labels = vec!["backend".to_string(), "auth".to_string()];
+        login.description = "OAuth handshake".to_string();
+        login
+            .custom
+            .insert("team".to_string(), serde_json::json!("core"));
+        let mut docs = issue("kanbus-docs", 3, Some("kanbus-epic"));
+        docs.dependencies.push(DependencyLink {
+            target: "kanbus-login".to_string(),
+            dependency_type: "blocked-by".to_string(),
+        });
+        docs.custom
+            .insert("team".to_string(), serde_json::json!("docs"));
+        let mut done = issue("kanbus-done", 2, None);
+        done.status = "closed".to_string();
+        done.closed_at = Some(Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap());
+        vec![epic, login, docs, done]
+    }
+
+    fn render(template: &str) -> String {
+        render_template_string(template, &sample_issues()).expect("render")
+    }
+
+    #[test]
+    fn query_accepts_the_full_filter_set() {
+        assert_eq!(
+            render("{{ count(assignee=\"dev@example.com\", label=\"auth\") }}"),
+            "1"
+        );
+        assert_eq!(render("{{ count(priority_lte=1, type=\"task\") }}"), "1");
+        assert_eq!(render("{{ count(priority_gte=2, priority=3) }}"), "1");
+        assert_eq!(render("{{ count(parent=\"kanbus-ep\") }}"), "2");
+        assert_eq!(render("{{ count(search=\"oauth\") }}"), "1");
+        assert_eq!(render("{{ count(custom={\"team\": \"docs\"}) }}"), "1");
+        assert_eq!(
+            render(
+                "{{ count(closed_since=\"2026-03-09\") }}:{{ count(closed_since=\"2026-03-11\") }}"
+            ),
+            "1:0"
+        );
+        assert_eq!(
+            render("{% for i in query(sort=\"-priority\", limit=2) %}{{ i.id }} {% endfor %}"),
+            "kanbus-docs kanbus-done "
+        );
+    }
+
+    #[test]
+    fn query_rejects_invalid_parameters() {
+        let issues = sample_issues();
+        for template in [
+            "{{ count(priority_lte=\"high\") }}",
+            "{{ count(updated_since=\"yesterday\") }}",
+            "{{ count(updated_since=\"9999999999999d\") }}",
+            "{{ count(created_since=\"999999999999w\") }}",
+            "{{ count(custom=\"core\") }}",
+            "{{ count(owner=\"me\") }}",
+        ] {
+            let error = render_template_string(template, &issues).expect_err(template);
+            assert!(error.to_string().contains("invalid query parameter"));
+        }
+        let error =
+            render_template_string("{{ query(sort=\"-color\") }}", &issues).expect_err("sort key");
+        assert!(error.to_string().contains("invalid sort key"));
+    }
+
+    #[test]
+    fn relationship_helpers_follow_hierarchy_and_blockers() {
+        assert_eq!(
+            render("{% for i in children(\"kanbus-epic\") %}{{ i.id }} {% endfor %}"),
+            "kanbus-login kanbus-docs "
+        );
+        assert_eq!(
+            render("{{ blocked_by(\"kanbus-docs\")[0].id }}|{{ blocks(\"kanbus-login\")[0].id }}"),
+            "kanbus-login|kanbus-docs"
+        );
+        assert_eq!(
+            render("{% for i in ready(type=\"task\") %}{{ i.id }} {% endfor %}"),
+            "kanbus-login "
+        );
+        assert_eq!(render("{{ events(\"kanbus-login\") | length }}"), "0");
+    }
+
+    #[test]
+    fn group_by_buckets_issues_by_field_labels_and_custom_values() {
+        assert_eq!(
+            render("{% for key, items in group_by(query(), \"status\") | items %}{{ key }}={{ items | length }} {% endfor %}"),
+            "closed=1 open=3 "
+        );
+        assert_eq!(
+            render("{% for key, items in group_by(query(), \"labels\") | items %}{{ key }}={{ items | length }} {% endfor %}"),
+            "auth=1 backend=1 none=3 "
+        );
+        assert_eq!(
+            render("{% for key, items in group_by(query(), \"team\") | items %}{{ key }}={{ items | length }} {% endfor %}"),
+            "core=1 docs=1 none=2 "
+        );
+    }
+
+    #[test]
+    fn queries_apply_saved_view_filters_and_sort() {
+        let mut configuration = crate::config::default_project_configuration();
+        configuration.views.insert(
+            "open-work".to_string(),
+            SavedView {
+                filters: crate::models::ViewFilters {
+                    status: Some("open".to_string()),
+                    issue_type: Some("task".to_string()),
+                    ..Default::default()
+                },
+                sort: Some("-priority".to_string()),
+                ..SavedView::default()
+            },
+        );
+        let mut env = Environment::new();
+        register_issue_functions(
+            &mut env,
+            TemplateData {
+                issues: Arc::new(sample_issues()),
+                configuration: Some(configuration),
+                project_dir: None,
+            },
+        );
+        let render = |template: &str| env.render_str(template, context! {});
+
+        assert_eq!(
+            render("{% for i in query(view=\"open-work\") %}{{ i.id }} {% endfor %}").unwrap(),
+            "kanbus-docs kanbus-login "
+        );
+        assert_eq!(
+            render("{{ count(view=\"open-work\", status=\"closed\") }}").unwrap(),
+            "1"
+        );
+        assert_eq!(
+            render("{% for i in query(view=\"open-work\", sort=\"priority\") %}{{ i.id }} {% endfor %}")
+                .unwrap(),
+            "kanbus-login kanbus-docs "
+        );
+        let error = render("{{ count(view=\"missing\") }}").expect_err("unknown view");
+        assert!(error.to_string().contains("unknown view 'missing'"));
+    }
+
+    #[test]
+    fn since_bounds_accept_relative_and_absolute_dates() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 20, 12, 0, 0).unwrap();
+        assert_eq!(
+            parse_since("7d", now),
+            Some(Utc.with_ymd_and_hms(2026, 3, 13, 12, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parse_since("2026-03-01", now),
+            Some(Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parse_since("2026-03-01T08:00:00+02:00", now),
+            Some(Utc.with_ymd_and_hms(2026, 3, 1, 6, 0, 0).unwrap())
+        );
+        assert_eq!(parse_since("soon", now), None);
+    }
+}
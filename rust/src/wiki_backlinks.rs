@@ -0,0 +1,366 @@
+//! Wiki backlink index for issue references and `[[page]]` links.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::console_backend::FileStore;
+use crate::console_wiki;
+use crate::error::KanbusError;
+use crate::ids::{issue_identifier_matches, issue_number_index};
+use crate::wiki::list_wiki_pages;
+use crate::wiki_links::{
+    find_page_links, normalize_page_link, resolve_reference, ISSUE_REFERENCE_PATTERN,
+};
+
+const CACHE_FILE_NAME: &str = "wiki_backlinks.json";
+const CACHE_VERSION: u32 = 1;
+const ISSUE_SUFFIX_PATTERN: &str = r"^(?:[0-9a-f]{6,}(?:-[0-9a-f]+)*|[0-9]+)(?:\.[0-9]+)*$";
+
+/// A wiki page reference whose target does not exist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WikiLinkProblem {
+    /// Wiki page containing the reference, relative to the wiki root.
+    pub page: String,
+    /// Referenced page path or issue key as written.
+    pub target: String,
+}
+
+/// Backlinks between wiki pages and issues.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WikiBacklinkIndex {
+    /// Pages referencing each issue, keyed by full issue identifier.
+    pub issues: BTreeMap<String, Vec<String>>,
+    /// Pages linking to each page with `[[page]]`.
+    pub pages: BTreeMap<String, Vec<String>>,
+    /// `[[page]]` links whose target page does not exist.
+    pub broken_links: Vec<WikiLinkProblem>,
+    /// Issue keys that no longer resolve to an issue.
+    pub missing_issues: Vec<WikiLinkProblem>,
+}
+
+impl WikiBacklinkIndex {
+    /// Return the pages referencing an issue.
+    pub fn pages_referencing_issue(&self, identifier: &str) -> &[String] {
+        self.issues
+            .get(identifier)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// Parsed references of a single page source.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct PageReferences {
+    fingerprint: String,
+    issue_tokens: Vec<String>,
+    page_links: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BacklinkCache {
+    version: u32,
+    pages: BTreeMap<String, PageReferences>,
+}
+
+/// Build the backlink index for a repository.
+///
+/// Page sources are parsed once per change and cached in
+/// `<project_dir>/.cache/wiki_backlinks.json`; issue keys are resolved against
+/// the current issues on every call so deleted issues are always detected.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+///
+/// # Errors
+/// Returns `KanbusError` if configuration, issues, or pages cannot be read.
+pub fn load_backlink_index(root: &Path) -> Result<WikiBacklinkIndex, KanbusError> {
+    build_backlink_index(root, true)
+}
+
+/// Build the backlink index without updating the cache.
+///
+/// Used by validation, which must not write to the project.
+///
+/// # Errors
+/// Returns `KanbusError` if configuration, issues, or pages cannot be read.
+pub fn read_backlink_index(root: &Path) -> Result<WikiBacklinkIndex, KanbusError> {
+    build_backlink_index(root, false)
+}
+
+fn build_backlink_index(root: &Path, persist: bool) -> Result<WikiBacklinkIndex, KanbusError> {
+    let store = FileStore::new(root);
+    let configuration = store.load_config()?;
+    let issues = store.load_issues(&configuration)?;
+    let identifiers: Vec<String> = issues.iter().map(|i| i.identifier.clone()).collect();
+    let mut issue_keys: BTreeSet<String> = identifiers
+        .iter()
+        .filter_map(|identifier| identifier.split_once('-').map(|(key, _)| key.to_string()))
+        .collect();
+    issue_keys.insert(configuration.project_key.clone());
+
+    let prefix = console_wiki::wiki_list_prefix(&store)?;
+    let wiki_dir = root.join(&prefix);
+    let pages: Vec<String> = list_wiki_pages(root)?
+        .into_iter()
+        .map(|page| {
+            page.strip_prefix(&format!("{prefix}/"))
+                .map(str::to_string)
+                .unwrap_or(page)
+        })
+        .collect();
+
+    let cache_path = root
+        .join(&configuration.project_directory)
+        .join(".cache")
+        .join(CACHE_FILE_NAME);
+    let cache = read_cache(&cache_path);
+    let mut refreshed = BacklinkCache {
+        version: CACHE_VERSION,
+        pages: BTreeMap::new(),
+    };
+    for page in &pages {
+        let path = wiki_dir.join(page);
+        let fingerprint = page_fingerprint(&path);
+        let references = match cache.pages.get(page) {
+            Some(cached) if cached.fingerprint == fingerprint => cached.clone(),
+            _ => {
+                let source = fs::read_to_string(&path)
+                    .map_err(|error| KanbusError::Io(error.to_string()))?;
+                parse_page_references(&source, fingerprint)
+            }
+        };
+        refreshed.pages.insert(page.clone(), references);
+    }
+    if persist && refreshed.pages != cache.pages {
+        write_cache(&cache_path, &refreshed);
+    }
+
+    Ok(build_index(
+        &refreshed.pages,
+        &identifiers,
+        &issue_number_index(&issues),
+        &issue_keys,
+        &pages.iter().cloned().collect(),
+    ))
+}
+
+fn build_index(
+    sources: &BTreeMap<String, PageReferences>,
+    identifiers: &[String],
+    numbers: &BTreeMap<String, String>,
+    issue_keys: &BTreeSet<String>,
+    pages: &BTreeSet<String>,
+) -> WikiBacklinkIndex {
+    let suffix_pattern = Regex::new(ISSUE_SUFFIX_PATTERN).expect("issue suffix regex");
+    let mut index = WikiBacklinkIndex::default();
+    for (page, references) in sources {
+        let mut referenced: BTreeSet<String> = BTreeSet::new();
+        for token in &references.issue_tokens {
+            let resolved = numbers
+                .get(token)
+                .cloned()
+                .or_else(|| resolve_reference(token, identifiers));
+            if let Some(identifier) = resolved {
+                referenced.insert(identifier);
+                continue;
+            }
+            let Some((key, suffix)) = token.split_once('-') else {
+                continue;
+            };
+            let looks_like_issue = issue_keys.contains(key)
+                && suffix_pattern.is_match(suffix)
+                && suffix.bytes().any(|byte| byte.is_ascii_digit());
+            let ambiguous = identifiers
+                .iter()
+                .any(|identifier| issue_identifier_matches(token, identifier));
+            if looks_like_issue && !ambiguous {
+                index.missing_issues.push(WikiLinkProblem {
+                    page: page.clone(),
+                    target: token.clone(),
+                });
+            }
+        }
+        for identifier in referenced {
+            index
+                .issues
+                .entry(identifier)
+                .or_default()
+                .push(page.clone());
+        }
+
+        let mut linked: BTreeSet<String> = BTreeSet::new();
+        for link in &references.page_links {
+            let target = normalize_page_link(link);
+            if pages.contains(&target) {
+                linked.insert(target);
+            } else {
+                index.broken_links.push(WikiLinkProblem {
+                    page: page.clone(),
+                    target: link.clone(),
+                });
+            }
+        }
+        for target in linked {
+            if target != *page {
+                index.pages.entry(target).or_default().push(page.clone());
+            }
+        }
+    }
+    index
+}
+
+fn parse_page_references(source: &str, fingerprint: String) -> PageReferences {
+    let issue_pattern = Regex::new(ISSUE_REFERENCE_PATTERN).expect("issue reference regex");
+    let issue_tokens: BTreeSet<String> = issue_pattern
+        .find_iter(source)
+        .map(|token| token.as_str().to_string())
+        .collect();
+    let page_links: BTreeSet<String> = find_page_links(source)
+        .into_iter()
+        .map(|link| link.target)
+        .collect();
+    PageReferences {
+        fingerprint,
+        issue_tokens: issue_tokens.into_iter().collect(),
+        page_links: page_links.into_iter().collect(),
+    }
+}
+
+fn page_fingerprint(path: &Path) -> String {
+    fs::metadata(path)
+        .ok()
+        .map(|metadata| {
+            let modified = metadata
+                .modified()
+                .ok()
+                .map(|time| format!("{time:?}"))
+                .unwrap_or_default();
+            format!("{}:{}", metadata.len(), modified)
+        })
+        .unwrap_or_default()
+}
+
+fn read_cache(path: &Path) -> BacklinkCache {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<BacklinkCache>(&contents).ok())
+        .filter(|cache| cache.version == CACHE_VERSION)
+        .unwrap_or_default()
+}
+
+fn write_cache(path: &Path, cache: &BacklinkCache) {
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(contents) = serde_json::to_string_pretty(cache) {
+        let _ = fs::write(path, contents);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::IssueData;
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+
+    fn write_project(root: &Path) {
+        fs::write(
+            root.join(".kanbus.yml"),
+            "project_key: kanbus\nproject_directory: project\n",
+        )
+        .expect("write config");
+        let project_dir = root.join("project");
+        fs::create_dir_all(project_dir.join("issues")).expect("issues dir");
+        fs::create_dir_all(project_dir.join("wiki/guides")).expect("wiki dir");
+        let timestamp = Utc.with_ymd_and_hms(2026, 3, 6, 0, 0, 0).unwrap();
+        let issue = IssueData {
+            identifier: "kanbus-0a1b2c3d".to_string(),
+            title: "Existing".to_string(),
+            description: String::new(),
+            issue_type: "task".to_string(),
+            status: "open".to_string(),
+            priority: 2,
+            assignee: None,
+            creator: None,
+            parent: None,
+            labels: Vec::new(),
+            dependencies: Vec::new(),
+            comments: Vec::new(),
+            created_at: timestamp,
+            updated_at: timestamp,
+            closed_at: None,
+            custom: BTreeMap::new(),
+        };
+        fs::write(
+            project_dir.join("issues/kanbus-0a1b2c3d.json"),
+            serde_json::to_string_pretty(&issue).expect("serialize issue"),
+        )
+        .expect("write issue");
+    }
+
+    #[test]
+    fn parse_collects_issue_tokens_and_page_links() {
+        let references = parse_page_references(
+            "See kanbus-0a1b2c and [[guides/setup|the setup guide]] or [[intro]].",
+            "fp".to_string(),
+        );
+        assert!(references
+            .issue_tokens
+            .contains(&"kanbus-0a1b2c".to_string()));
+        assert_eq!(references.page_links, vec!["guides/setup", "intro"]);
+    }
+
+    #[test]
+    fn index_tracks_backlinks_and_reports_dangling_references() {
+        let temp = TempDir::new().expect("tempdir");
+        let root = temp.path();
+        write_project(root);
+        let wiki = root.join("project/wiki");
+        fs::write(
+            wiki.join("index.md"),
+            "Tracks kanbus-0a1b2c and kanbus-ff00ee11.\nSee [[guides/setup]] and [[missing]].",
+        )
+        .expect("write index");
+        fs::write(
+            wiki.join("guides/setup.md"),
+            "Back to [[index]]; kanbus-0a1b2c3d.",
+        )
+        .expect("write setup");
+
+        let index = load_backlink_index(root).expect("index");
+        assert_eq!(
+            index.pages_referencing_issue("kanbus-0a1b2c3d"),
+            ["guides/setup.md".to_string(), "index.md".to_string()]
+        );
+        assert_eq!(index.pages["guides/setup.md"], vec!["index.md"]);
+        assert_eq!(index.pages["index.md"], vec!["guides/setup.md"]);
+        assert_eq!(
+            index.broken_links,
+            vec![WikiLinkProblem {
+                page: "index.md".to_string(),
+                target: "missing".to_string(),
+            }]
+        );
+        assert_eq!(
+            index.missing_issues,
+            vec![WikiLinkProblem {
+                page: "index.md".to_string(),
+                target: "kanbus-ff00ee11".to_string(),
+            }]
+        );
+        assert!(root.join("project/.cache").join(CACHE_FILE_NAME).is_file());
+        fs::remove_file(root.join("project/.cache").join(CACHE_FILE_NAME)).expect("clear cache");
+        assert_eq!(read_backlink_index(root).expect("read-only index"), index);
+        assert!(!root.join("project/.cache").join(CACHE_FILE_NAME).exists());
+
+        fs::remove_file(root.join("project/issues/kanbus-0a1b2c3d.json")).expect("remove issue");
+        let index = load_backlink_index(root).expect("index after delete");
+        assert!(index.issues.is_empty());
+        assert_eq!(index.missing_issues.len(), 3);
+    }
+}
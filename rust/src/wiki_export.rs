@@ -0,0 +1,724 @@
+//! Static HTML site export of the wiki with live issue data.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use pulldown_cmark::{html, CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd};
+
+use crate::console_backend::FileStore;
+use crate::console_wiki;
+use crate::error::KanbusError;
+use crate::ids::issue_identifier_matches;
+use crate::models::IssueData;
+use crate::wiki::{list_wiki_pages, render_wiki_page, WikiRenderRequest};
+use crate::wiki_links::{find_issue_references, find_page_links, normalize_page_link};
+
+/// Issue text links wiki pages relative to the wiki root.
+const ISSUE_LINK_BASE: &str = "../wiki/";
+
+const STYLESHEET: &str = "body{font-family:system-ui,sans-serif;max-width:60rem;margin:0 auto;padding:1rem 2rem;line-height:1.5;color:#222}\
+nav{border-bottom:1px solid #ddd;padding-bottom:.5rem;margin-bottom:1rem}\
+table{border-collapse:collapse}td,th{border:1px solid #ddd;padding:.25rem .5rem;text-align:left}\
+pre{background:#f6f8fa;padding:.75rem;overflow:auto}a.issue-ref{font-family:monospace}\
+figure.diagram img{max-width:100%}";
+
+/// Summary of a wiki export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WikiExportResult {
+    pub pages: usize,
+    pub issues: usize,
+    pub diagrams: usize,
+}
+
+/// Export every wiki page and issue as a navigable static HTML site.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `out_dir` - Directory to write the site into.
+///
+/// # Errors
+/// Returns `KanbusError` if a page fails to render or files cannot be written.
+pub fn export_wiki_site(root: &Path, out_dir: &Path) -> Result<WikiExportResult, KanbusError> {
+    let store = FileStore::new(root);
+    let configuration = store.load_config()?;
+    let mut issues = store.load_issues(&configuration)?;
+    issues.sort_by(|left, right| left.identifier.cmp(&right.identifier));
+    let identifiers: Vec<String> = issues.iter().map(|i| i.identifier.clone()).collect();
+    let prefix = format!("{}/", console_wiki::wiki_list_prefix(&store)?);
+    let pages = list_wiki_pages(root)?;
+    let mut diagrams = DiagramRenderer::detect(out_dir.join("diagrams"));
+
+    let mut page_links: Vec<(String, String)> = Vec::new();
+    for page in &pages {
+        let relative = page.strip_prefix(&prefix).unwrap_or(page);
+        let output_path = format!("wiki/{}", markdown_path_to_html(relative));
+        let depth = output_path.matches('/').count();
+        let rendered = render_wiki_page(&WikiRenderRequest {
+            root: root.to_path_buf(),
+            page_path: PathBuf::from(page),
+        })?;
+        let title = page_title(&rendered, relative);
+        let body = markdown_to_html(
+            &rendered,
+            depth,
+            "",
+            &relative_root(depth - 1),
+            &identifiers,
+            Some(&mut diagrams),
+        );
+        write_html(out_dir, &output_path, &title, depth, &body)?;
+        page_links.push((output_path, title));
+    }
+
+    for issue in &issues {
+        let output_path = format!("issues/{}", issue_file_name(&issue.identifier));
+        let body = render_issue_body(issue, &issues, &identifiers);
+        let title = format!("{} {}", issue.identifier, issue.title);
+        write_html(out_dir, &output_path, &title, 1, &body)?;
+    }
+
+    let index = render_index(&page_links, &issues);
+    write_html(out_dir, "index.html", "Project wiki", 0, &index)?;
+
+    Ok(WikiExportResult {
+        pages: pages.len(),
+        issues: issues.len(),
+        diagrams: diagrams.rendered,
+    })
+}
+
+fn markdown_path_to_html(path: &str) -> String {
+    match path.strip_suffix(".md") {
+        Some(stem) => format!("{stem}.html"),
+        None => path.to_string(),
+    }
+}
+
+fn issue_file_name(identifier: &str) -> String {
+    let safe: String = identifier
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '.' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{safe}.html")
+}
+
+fn relative_root(depth: usize) -> String {
+    "../".repeat(depth)
+}
+
+fn page_title(markdown: &str, relative: &str) -> String {
+    markdown
+        .lines()
+        .find_map(|line| line.strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .unwrap_or_else(|| {
+            Path::new(relative)
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or(relative)
+                .to_string()
+        })
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn is_relative_link(destination: &str) -> bool {
+    !(destination.contains("://")
+        || destination.starts_with('#')
+        || destination.starts_with('/')
+        || destination.starts_with("mailto:"))
+}
+
+fn rewrite_link(destination: &str, link_base: &str) -> String {
+    if !is_relative_link(destination) {
+        return destination.to_string();
+    }
+    let (path, fragment) = match destination.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment)),
+        None => (destination, None),
+    };
+    let rewritten = if path.is_empty() {
+        String::new()
+    } else {
+        format!("{link_base}{}", markdown_path_to_html(path))
+    };
+    match fragment {
+        Some(fragment) => format!("{rewritten}#{fragment}"),
+        None => rewritten,
+    }
+}
+
+fn wiki_link_href(target: &str, wiki_root: &str) -> String {
+    let page = markdown_path_to_html(&normalize_page_link(target));
+    match target.split_once('#') {
+        Some((_, fragment)) => format!("{wiki_root}{page}#{fragment}"),
+        None => format!("{wiki_root}{page}"),
+    }
+}
+
+/// Turn `[[page]]` links and issue keys in plain text into HTML links.
+///
+/// Returns `None` when the text contains neither.
+fn link_text(text: &str, depth: usize, wiki_root: &str, identifiers: &[String]) -> Option<String> {
+    let page_links = find_page_links(text);
+    let references: Vec<_> = find_issue_references(text, identifiers)
+        .into_iter()
+        .filter(|reference| {
+            !page_links
+                .iter()
+                .any(|link| reference.start < link.end && link.start < reference.end)
+        })
+        .collect();
+    if page_links.is_empty() && references.is_empty() {
+        return None;
+    }
+    let mut spans: Vec<(usize, usize, String)> = page_links
+        .iter()
+        .map(|link| {
+            (
+                link.start,
+                link.end,
+                format!(
+                    "<a class=\"wiki-link\" href=\"{}\">{}</a>",
+                    escape_html(&wiki_link_href(&link.target, wiki_root)),
+                    escape_html(link.label.as_deref().unwrap_or(&link.target))
+                ),
+            )
+        })
+        .chain(references.iter().map(|reference| {
+            (
+                reference.start,
+                reference.end,
+                format!(
+                    "<a class=\"issue-ref\" href=\"{}issues/{}\">{}</a>",
+                    relative_root(depth),
+                    issue_file_name(&reference.identifier),
+                    escape_html(&text[reference.start..reference.end])
+                ),
+            )
+        }))
+        .collect();
+    spans.sort_by_key(|(start, _, _)| *start);
+    let mut html_text = String::new();
+    let mut cursor = 0;
+    for (start, end, link) in spans {
+        html_text.push_str(&escape_html(&text[cursor..start]));
+        html_text.push_str(&link);
+        cursor = end;
+    }
+    html_text.push_str(&escape_html(&text[cursor..]));
+    Some(html_text)
+}
+
+/// Emit buffered plain text, linking wiki pages and issue keys.
+fn flush_text(
+    pending: &mut String,
+    events: &mut Vec<Event<'_>>,
+    depth: usize,
+    wiki_root: &str,
+    identifiers: &[String],
+) {
+    if pending.is_empty() {
+        return;
+    }
+    let text = std::mem::take(pending);
+    match link_text(&text, depth, wiki_root, identifiers) {
+        Some(linked) => events.push(Event::InlineHtml(CowStr::from(linked))),
+        None => events.push(Event::Text(CowStr::from(text))),
+    }
+}
+
+/// Render Markdown for the site.
+///
+/// Relative links are prefixed with `link_base` and `[[page]]` links with
+/// `wiki_root`. Raw HTML in the source is escaped, since issue text may come
+/// from external trackers.
+fn markdown_to_html(
+    markdown: &str,
+    depth: usize,
+    link_base: &str,
+    wiki_root: &str,
+    identifiers: &[String],
+    mut diagrams: Option<&mut DiagramRenderer>,
+) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut events: Vec<Event> = Vec::new();
+    let mut link_depth = 0usize;
+    let mut in_code_block = false;
+    let mut pending = String::new();
+    let mut diagram: Option<(String, String)> = None;
+    for event in Parser::new_ext(markdown, options) {
+        if let Some((_, source)) = diagram.as_mut() {
+            match event {
+                Event::Text(text) => source.push_str(&text),
+                Event::End(TagEnd::CodeBlock) => {
+                    let (language, source) = diagram.take().expect("diagram in progress");
+                    let image = diagrams
+                        .as_deref_mut()
+                        .and_then(|renderer| renderer.render(&language, &source));
+                    match image {
+                        Some(file_name) => events.push(Event::Html(CowStr::from(format!(
+                            "<figure class=\"diagram\"><img src=\"{}diagrams/{}\" alt=\"{} diagram\"></figure>\n",
+                            relative_root(depth),
+                            file_name,
+                            language
+                        )))),
+                        None => {
+                            events.push(Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(
+                                CowStr::from(language),
+                            ))));
+                            events.push(Event::Text(CowStr::from(source)));
+                            events.push(Event::End(TagEnd::CodeBlock));
+                        }
+                    }
+                }
+                _ => {}
+            }
+            continue;
+        }
+        if let Event::Text(text) = &event {
+            if link_depth == 0 && !in_code_block {
+                pending.push_str(text);
+                continue;
+            }
+        }
+        flush_text(&mut pending, &mut events, depth, wiki_root, identifiers);
+        match event {
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(ref language)))
+                if matches!(language.as_ref(), "mermaid" | "d2") =>
+            {
+                diagram = Some((language.to_string(), String::new()));
+            }
+            Event::Start(Tag::CodeBlock(_)) => {
+                in_code_block = true;
+                events.push(event);
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                in_code_block = false;
+                events.push(event);
+            }
+            Event::Html(raw) | Event::InlineHtml(raw) => events.push(Event::Text(raw)),
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }) => {
+                link_depth += 1;
+                events.push(Event::Start(Tag::Link {
+                    link_type,
+                    dest_url: CowStr::from(rewrite_link(&dest_url, link_base)),
+                    title,
+                    id,
+                }));
+            }
+            Event::End(TagEnd::Link) => {
+                link_depth = link_depth.saturating_sub(1);
+                events.push(event);
+            }
+            other => events.push(other),
+        }
+    }
+    flush_text(&mut pending, &mut events, depth, wiki_root, identifiers);
+    let mut output = String::new();
+    html::push_html(&mut output, events.into_iter());
+    output
+}
+
+fn write_html(
+    out_dir: &Path,
+    relative_path: &str,
+    title: &str,
+    depth: usize,
+    body: &str,
+) -> Result<(), KanbusError> {
+    let path = out_dir.join(relative_path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| KanbusError::Io(error.to_string()))?;
+    }
+    let document = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>{STYLESHEET}</style>\n</head>\n<body>\n<nav><a href=\"{root}index.html\">Home</a></nav>\n<main>\n{body}</main>\n</body>\n</html>\n",
+        title = escape_html(title),
+        root = relative_root(depth),
+    );
+    fs::write(&path, document).map_err(|error| KanbusError::Io(error.to_string()))
+}
+
+fn render_index(pages: &[(String, String)], issues: &[IssueData]) -> String {
+    let mut body = String::from("<h1>Project wiki</h1>\n<h2>Pages</h2>\n<ul>\n");
+    for (path, title) in pages {
+        body.push_str(&format!(
+            "<li><a href=\"{}\">{}</a></li>\n",
+            escape_html(path),
+            escape_html(title)
+        ));
+    }
+    body.push_str("</ul>\n<h2>Issues</h2>\n<table>\n<tr><th>ID</th><th>Title</th><th>Type</th><th>Status</th></tr>\n");
+    for issue in issues {
+        body.push_str(&format!(
+            "<tr><td><a class=\"issue-ref\" href=\"issues/{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            issue_file_name(&issue.identifier),
+            escape_html(&issue.identifier),
+            escape_html(&issue.title),
+            escape_html(&issue.issue_type),
+            escape_html(&issue.status)
+        ));
+    }
+    body.push_str("</table>\n");
+    body
+}
+
+fn issue_link(identifier: &str, issues: &[IssueData]) -> String {
+    let title = issues
+        .iter()
+        .find(|issue| issue.identifier == identifier)
+        .map(|issue| format!(" {}", escape_html(&issue.title)))
+        .unwrap_or_default();
+    format!(
+        "<a class=\"issue-ref\" href=\"{}\">{}</a>{}",
+        issue_file_name(identifier),
+        escape_html(identifier),
+        title
+    )
+}
+
+fn render_issue_body(issue: &IssueData, issues: &[IssueData], identifiers: &[String]) -> String {
+    let mut body = format!(
+        "<h1>{} {}</h1>\n<table>\n",
+        escape_html(&issue.identifier),
+        escape_html(&issue.title)
+    );
+    let mut fields: BTreeMap<&str, String> = BTreeMap::new();
+    fields.insert("Type", escape_html(&issue.issue_type));
+    fields.insert("Status", escape_html(&issue.status));
+    fields.insert("Priority", issue.priority.to_string());
+    fields.insert(
+        "Assignee",
+        escape_html(issue.assignee.as_deref().unwrap_or("-")),
+    );
+    fields.insert(
+        "Labels",
+        if issue.labels.is_empty() {
+            "-".to_string()
+        } else {
+            escape_html(&issue.labels.join(", "))
+        },
+    );
+    fields.insert(
+        "Parent",
+        issue
+            .parent
+            .as_deref()
+            .map(|parent| issue_link(parent, issues))
+            .unwrap_or_else(|| "-".to_string()),
+    );
+    fields.insert("Updated", issue.updated_at.to_rfc3339());
+    for (name, value) in fields {
+        body.push_str(&format!("<tr><th>{name}</th><td>{value}</td></tr>\n"));
+    }
+    body.push_str("</table>\n");
+    if !issue.description.is_empty() {
+        body.push_str("<h2>Description</h2>\n");
+        body.push_str(&markdown_to_html(
+            &issue.description,
+            1,
+            ISSUE_LINK_BASE,
+            ISSUE_LINK_BASE,
+            identifiers,
+            None,
+        ));
+    }
+    let children: Vec<&IssueData> = issues
+        .iter()
+        .filter(|child| child.parent.as_deref() == Some(issue.identifier.as_str()))
+        .collect();
+    if !children.is_empty() {
+        body.push_str("<h2>Children</h2>\n<ul>\n");
+        for child in children {
+            body.push_str(&format!(
+                "<li>{} [{}]</li>\n",
+                issue_link(&child.identifier, issues),
+                escape_html(&child.status)
+            ));
+        }
+        body.push_str("</ul>\n");
+    }
+    if !issue.dependencies.is_empty() {
+        body.push_str("<h2>Dependencies</h2>\n<ul>\n");
+        for dependency in &issue.dependencies {
+            let target = identifiers
+                .iter()
+                .find(|identifier| issue_identifier_matches(&dependency.target, identifier))
+                .cloned()
+                .unwrap_or_else(|| dependency.target.clone());
+            body.push_str(&format!(
+                "<li>{}: {}</li>\n",
+                escape_html(&dependency.dependency_type),
+                issue_link(&target, issues)
+            ));
+        }
+        body.push_str("</ul>\n");
+    }
+    if !issue.comments.is_empty() {
+        body.push_str("<h2>Comments</h2>\n");
+        for comment in &issue.comments {
+            body.push_str(&format!(
+                "<h3>{}</h3>\n",
+                escape_html(if comment.author.is_empty() {
+                    "unknown"
+                } else {
+                    comment.author.as_str()
+                })
+            ));
+            body.push_str(&markdown_to_html(
+                &comment.text,
+                1,
+                ISSUE_LINK_BASE,
+                ISSUE_LINK_BASE,
+                identifiers,
+                None,
+            ));
+        }
+    }
+    body
+}
+
+/// Renders Mermaid and D2 blocks to SVG with `mmdc` and `d2` when they are installed.
+struct DiagramRenderer {
+    out_dir: PathBuf,
+    available: BTreeMap<&'static str, bool>,
+    rendered: usize,
+}
+
+impl DiagramRenderer {
+    fn detect(out_dir: PathBuf) -> Self {
+        let available = [("mermaid", "mmdc"), ("d2", "d2")]
+            .into_iter()
+            .map(|(language, tool)| {
+                let found = Command::new(tool)
+                    .arg("--version")
+                    .output()
+                    .is_ok_and(|output| output.status.success());
+                (language, found)
+            })
+            .collect();
+        Self {
+            out_dir,
+            available,
+            rendered: 0,
+        }
+    }
+
+    /// Render a diagram and return its file name, or `None` to keep the code block.
+    fn render(&mut self, language: &str, source: &str) -> Option<String> {
+        if !self.available.get(language).copied().unwrap_or(false) {
+            return None;
+        }
+        fs::create_dir_all(&self.out_dir).ok()?;
+        let index = self.rendered + 1;
+        let extension = if language == "d2" { "d2" } else { "mmd" };
+        let input = self.out_dir.join(format!("diagram-{index}.{extension}"));
+        let file_name = format!("diagram-{index}.svg");
+        let output = self.out_dir.join(&file_name);
+        fs::write(&input, source).ok()?;
+        let status = if language == "d2" {
+            Command::new("d2").arg(&input).arg(&output).output()
+        } else {
+            Command::new("mmdc")
+                .arg("-i")
+                .arg(&input)
+                .arg("-o")
+                .arg(&output)
+                .output()
+        };
+        let _ = fs::remove_file(&input);
+        if !status.is_ok_and(|result| result.status.success()) || !output.exists() {
+            return None;
+        }
+        self.rendered = index;
+        Some(file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identifiers() -> Vec<String> {
+        vec![
+            "kanbus-abc123".to_string(),
+            "kanbus-abd999".to_string(),
+            "kanbus-abc123.1".to_string(),
+        ]
+    }
+
+    #[test]
+    fn markdown_links_and_issue_keys_become_site_links() {
+        let html = markdown_to_html(
+            "Read [arch](docs/arch.md#top) and [site](https://example.com/a.md).\n\nBlocked by kanbus-abd999 `kanbus-abc123`.\n\n```\nkanbus-abd999\n```\n",
+            1,
+            "",
+            "",
+            &identifiers(),
+            None,
+        );
+        assert!(html.contains("<a href=\"docs/arch.html#top\">arch</a>"));
+        assert!(html.contains("<a href=\"https://example.com/a.md\">site</a>"));
+        assert!(html.contains(
+            "<a class=\"issue-ref\" href=\"../issues/kanbus-abd999.html\">kanbus-abd999</a>"
+        ));
+        assert!(html.contains("<code>kanbus-abc123</code>"));
+        assert!(html.contains("<pre><code>kanbus-abd999\n</code></pre>"));
+    }
+
+    #[test]
+    fn wiki_page_links_resolve_from_the_wiki_root() {
+        let html = markdown_to_html(
+            "See [[docs/arch#top|the architecture]] and [[index]] for kanbus-abd999.",
+            2,
+            "",
+            "../",
+            &identifiers(),
+            None,
+        );
+        assert!(html.contains(
+            "<a class=\"wiki-link\" href=\"../docs/arch.html#top\">the architecture</a>"
+        ));
+        assert!(html.contains("<a class=\"wiki-link\" href=\"../index.html\">index</a>"));
+        assert!(html.contains("href=\"../../issues/kanbus-abd999.html\""));
+    }
+
+    #[test]
+    fn raw_html_in_issue_text_is_escaped() {
+        let html = markdown_to_html(
+            "<script>alert('x')</script>\n\nHello <img src=x onerror=alert(1)> kanbus-abd999",
+            1,
+            ISSUE_LINK_BASE,
+            ISSUE_LINK_BASE,
+            &identifiers(),
+            None,
+        );
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<img"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(html.contains("&lt;img src=x onerror=alert(1)&gt;"));
+        assert!(html.contains("href=\"../issues/kanbus-abd999.html\""));
+    }
+
+    #[test]
+    fn diagrams_fall_back_to_code_blocks_without_tools() {
+        let mut renderer = DiagramRenderer {
+            out_dir: PathBuf::from("unused"),
+            available: BTreeMap::new(),
+            rendered: 0,
+        };
+        let html = markdown_to_html(
+            "```mermaid\ngraph LR\n  a --> b\n```\n",
+            0,
+            "",
+            "",
+            &[],
+            Some(&mut renderer),
+        );
+        assert!(html.contains(
+            "<pre><code class=\"language-mermaid\">graph LR\n  a --&gt; b\n</code></pre>"
+        ));
+        assert_eq!(renderer.rendered, 0);
+    }
+
+    #[test]
+    fn exports_pages_issues_and_index() {
+        let temp = tempfile::TempDir::new().expect("tempdir");
+        let root = temp.path();
+        let configuration = crate::config::default_project_configuration();
+        fs::write(
+            root.join(".kanbus.yml"),
+            serde_yaml::to_string(&configuration).expect("yaml"),
+        )
+        .expect("write config");
+        let project_dir = root.join(&configuration.project_directory);
+        fs::create_dir_all(project_dir.join("issues")).expect("issues dir");
+        fs::create_dir_all(project_dir.join("wiki/docs")).expect("wiki dir");
+        let now = chrono::Utc::now();
+        let issue = IssueData {
+            identifier: "kanbus-abc123".to_string(),
+            title: "Login <flow>".to_string(),
+            description: "Needs [arch](docs/arch.md) <script>steal()</script>".to_string(),
+            issue_type: "task".to_string(),
+            status: "open".to_string(),
+            priority: 2,
+            assignee: None,
+            creator: None,
+            parent: None,
+            labels: Vec::new(),
+            dependencies: Vec::new(),
+            comments: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            closed_at: None,
+            custom: BTreeMap::new(),
+        };
+        crate::issue_files::write_issue_to_file(
+            &issue,
+            &project_dir.join("issues/kanbus-abc123.json"),
+        )
+        .expect("write issue");
+        fs::write(
+            project_dir.join("wiki/index.md"),
+            "# Home\n\nOpen: {{ count(status=\"open\") }}. See [arch](docs/arch.md) and kanbus-abc123.\n",
+        )
+        .expect("write page");
+        fs::write(
+            project_dir.join("wiki/docs/arch.md"),
+            "# Architecture\n\nBack to [[index]].\n",
+        )
+        .expect("page");
+
+        let out = root.join("site");
+        let result = export_wiki_site(root, &out).expect("export");
+        assert_eq!(
+            result,
+            WikiExportResult {
+                pages: 2,
+                issues: 1,
+                diagrams: 0,
+            }
+        );
+        let home = fs::read_to_string(out.join("wiki/index.html")).expect("home");
+        assert!(home.contains("Open: 1."));
+        assert!(home.contains("<a href=\"docs/arch.html\">arch</a>"));
+        assert!(home.contains("href=\"../issues/kanbus-abc123.html\""));
+        let nested = fs::read_to_string(out.join("wiki/docs/arch.html")).expect("nested");
+        assert!(nested.contains("<a href=\"../../index.html\">Home</a>"));
+        assert!(nested.contains("<a class=\"wiki-link\" href=\"../index.html\">index</a>"));
+        let issue_page = fs::read_to_string(out.join("issues/kanbus-abc123.html")).expect("issue");
+        assert!(issue_page.contains("<title>kanbus-abc123 Login &lt;flow&gt;</title>"));
+        assert!(issue_page.contains("<a href=\"../wiki/docs/arch.html\">arch</a>"));
+        assert!(!issue_page.contains("<script>"));
+        let index = fs::read_to_string(out.join("index.html")).expect("index");
+        assert!(index.contains("<a href=\"wiki/docs/arch.html\">Architecture</a>"));
+        assert!(index.contains("<a href=\"wiki/index.html\">Home</a>"));
+    }
+}
@@ -0,0 +1,575 @@
+//! Wiki page revision history.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::error::KanbusError;
+use crate::event_history::event_filename;
+use crate::users::get_current_user;
+
+pub const WIKI_HISTORY_SCHEMA_VERSION: u32 = 1;
+
+const DIFF_CONTEXT_LINES: usize = 3;
+
+/// Largest LCS table `unified_diff` builds before falling back to a
+/// whole-block replacement of the changed lines.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// Kind of change recorded for a wiki page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WikiRevisionAction {
+    Created,
+    Updated,
+    Renamed,
+    Deleted,
+    Restored,
+}
+
+/// A recorded change to a wiki page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WikiRevision {
+    pub schema_version: u32,
+    pub revision_id: String,
+    /// Page path relative to the wiki root.
+    pub page: String,
+    pub action: WikiRevisionAction,
+    pub occurred_at: String,
+    pub actor_id: String,
+    /// Hash of the page content after the change; `None` once deleted.
+    #[serde(default)]
+    pub content_hash: Option<String>,
+    /// Hash of the page content before the change.
+    #[serde(default)]
+    pub previous_hash: Option<String>,
+    /// Former page path for renames.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Result of looking up a revision by id or prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WikiRevisionMatch {
+    Found(WikiRevision),
+    Ambiguous,
+    NotFound,
+}
+
+/// A wiki page change to record.
+#[derive(Debug, Clone, Copy)]
+pub struct WikiChange<'a> {
+    pub page: &'a str,
+    pub action: WikiRevisionAction,
+    pub content: Option<&'a str>,
+    pub previous_content: Option<&'a str>,
+    pub previous_path: Option<&'a str>,
+    pub message: Option<&'a str>,
+}
+
+/// Return the wiki history directory for a project.
+pub fn wiki_history_dir(project_dir: &Path) -> PathBuf {
+    project_dir.join("wiki_history")
+}
+
+fn content_dir(project_dir: &Path) -> PathBuf {
+    wiki_history_dir(project_dir).join("content")
+}
+
+fn content_hash(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+fn store_content(project_dir: &Path, content: &str) -> Result<String, KanbusError> {
+    let hash = content_hash(content);
+    let directory = content_dir(project_dir);
+    let path = directory.join(format!("{hash}.md"));
+    if !path.exists() {
+        fs::create_dir_all(&directory).map_err(|error| KanbusError::Io(error.to_string()))?;
+        fs::write(&path, content).map_err(|error| KanbusError::Io(error.to_string()))?;
+    }
+    Ok(hash)
+}
+
+/// Record a wiki page change, storing the page content it references.
+///
+/// # Arguments
+/// * `project_dir` - Project directory holding the history.
+/// * `change` - Change to record.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if the history cannot be written.
+pub fn record_wiki_revision(
+    project_dir: &Path,
+    change: WikiChange<'_>,
+) -> Result<WikiRevision, KanbusError> {
+    let content_hash = change
+        .content
+        .map(|content| store_content(project_dir, content))
+        .transpose()?;
+    let previous_hash = change
+        .previous_content
+        .map(|content| store_content(project_dir, content))
+        .transpose()?;
+    let revision = WikiRevision {
+        schema_version: WIKI_HISTORY_SCHEMA_VERSION,
+        revision_id: Uuid::new_v4().to_string(),
+        page: change.page.to_string(),
+        action: change.action,
+        // Microsecond timestamps keep rapid successive edits in order.
+        occurred_at: Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true),
+        actor_id: get_current_user(),
+        content_hash,
+        previous_hash,
+        previous_path: change.previous_path.map(str::to_string),
+        message: change
+            .message
+            .map(str::trim)
+            .filter(|message| !message.is_empty())
+            .map(str::to_string),
+    };
+    let directory = wiki_history_dir(project_dir);
+    fs::create_dir_all(&directory).map_err(|error| KanbusError::Io(error.to_string()))?;
+    let payload = serde_json::to_string_pretty(&revision)
+        .map_err(|error| KanbusError::Io(error.to_string()))?;
+    let path = directory.join(event_filename(&revision.occurred_at, &revision.revision_id));
+    fs::write(&path, payload).map_err(|error| KanbusError::Io(error.to_string()))?;
+    Ok(revision)
+}
+
+/// Load the revisions of a page, newest first.
+///
+/// Renames are followed back to the page's former paths. Revision files that
+/// cannot be read or parsed are skipped with a warning.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if the history directory cannot be read.
+pub fn load_wiki_history(project_dir: &Path, page: &str) -> Result<Vec<WikiRevision>, KanbusError> {
+    let directory = wiki_history_dir(project_dir);
+    if !directory.exists() {
+        return Ok(Vec::new());
+    }
+    let mut filenames = Vec::new();
+    for entry in fs::read_dir(&directory).map_err(|error| KanbusError::Io(error.to_string()))? {
+        let entry = entry.map_err(|error| KanbusError::Io(error.to_string()))?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+            filenames.push(path);
+        }
+    }
+    filenames.sort();
+    filenames.reverse();
+
+    let mut names = vec![page.to_string()];
+    let mut revisions = Vec::new();
+    for path in filenames {
+        let revision = match read_revision(&path) {
+            Ok(revision) => revision,
+            Err(error) => {
+                eprintln!(
+                    "Warning: skipping wiki revision {}: {error}",
+                    path.display()
+                );
+                continue;
+            }
+        };
+        if !names.contains(&revision.page) {
+            continue;
+        }
+        if let Some(previous) = &revision.previous_path {
+            if !names.contains(previous) {
+                names.push(previous.clone());
+            }
+        }
+        revisions.push(revision);
+    }
+    Ok(revisions)
+}
+
+fn read_revision(path: &Path) -> Result<WikiRevision, String> {
+    let bytes = fs::read(path).map_err(|error| error.to_string())?;
+    serde_json::from_slice(&bytes).map_err(|error| error.to_string())
+}
+
+/// Find a page revision by id or unique id prefix.
+///
+/// # Errors
+/// Returns `KanbusError::Io` if the history cannot be read.
+pub fn find_wiki_revision(
+    project_dir: &Path,
+    page: &str,
+    revision_id: &str,
+) -> Result<WikiRevisionMatch, KanbusError> {
+    let revisions = load_wiki_history(project_dir, page)?;
+    let mut matches = revisions.into_iter().filter(|revision| {
+        !revision_id.is_empty() && revision.revision_id.starts_with(revision_id)
+    });
+    Ok(match (matches.next(), matches.next()) {
+        (Some(revision), None) => WikiRevisionMatch::Found(revision),
+        (Some(_), Some(_)) => WikiRevisionMatch::Ambiguous,
+        _ => WikiRevisionMatch::NotFound,
+    })
+}
+
+/// Return the page content stored for a revision.
+///
+/// # Errors
+/// Returns `KanbusError` if the revision has no content or it is missing.
+pub fn load_revision_content(
+    project_dir: &Path,
+    revision: &WikiRevision,
+) -> Result<String, KanbusError> {
+    let hash = revision
+        .content_hash
+        .as_deref()
+        .ok_or_else(|| KanbusError::IssueOperation("wiki revision has no content".to_string()))?;
+    fs::read_to_string(content_dir(project_dir).join(format!("{hash}.md")))
+        .map_err(|error| KanbusError::Io(error.to_string()))
+}
+
+/// Restore a page to the content of an earlier revision.
+///
+/// # Arguments
+/// * `project_dir` - Project directory holding the history.
+/// * `wiki_dir` - Wiki root directory.
+/// * `page` - Page path relative to the wiki root.
+/// * `revision` - Revision to restore, from `find_wiki_revision`.
+/// * `message` - Optional message for the new revision.
+///
+/// # Errors
+/// Returns `KanbusError` if the revision has no content or the page cannot be
+/// written.
+pub fn restore_wiki_revision(
+    project_dir: &Path,
+    wiki_dir: &Path,
+    page: &str,
+    revision: &WikiRevision,
+    message: Option<&str>,
+) -> Result<WikiRevision, KanbusError> {
+    let content = load_revision_content(project_dir, revision)?;
+    let path = wiki_dir.join(page);
+    let previous = fs::read_to_string(&path).ok();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| KanbusError::Io(error.to_string()))?;
+    }
+    fs::write(&path, &content).map_err(|error| KanbusError::Io(error.to_string()))?;
+    let default_message = format!("restored revision {}", revision.revision_id);
+    record_wiki_revision(
+        project_dir,
+        WikiChange {
+            page,
+            action: WikiRevisionAction::Restored,
+            content: Some(&content),
+            previous_content: previous.as_deref(),
+            previous_path: None,
+            message: Some(message.unwrap_or(&default_message)),
+        },
+    )
+}
+
+/// Produce a unified diff between two texts.
+///
+/// # Returns
+/// An empty string when the texts are identical.
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let operations = diff_lines(&old_lines, &new_lines);
+    if operations
+        .iter()
+        .all(|operation| matches!(operation, DiffOp::Equal(_)))
+    {
+        return String::new();
+    }
+
+    let mut output = vec![format!("--- {old_label}"), format!("+++ {new_label}")];
+    let changed: Vec<usize> = operations
+        .iter()
+        .enumerate()
+        .filter(|(_, operation)| !matches!(operation, DiffOp::Equal(_)))
+        .map(|(index, _)| index)
+        .collect();
+    let mut start_index = 0;
+    while start_index < changed.len() {
+        let mut end_index = start_index;
+        while end_index + 1 < changed.len()
+            && changed[end_index + 1] - changed[end_index] <= DIFF_CONTEXT_LINES * 2 + 1
+        {
+            end_index += 1;
+        }
+        let first = changed[start_index].saturating_sub(DIFF_CONTEXT_LINES);
+        let last = (changed[end_index] + DIFF_CONTEXT_LINES).min(operations.len() - 1);
+        let hunk = &operations[first..=last];
+        let (old_start, new_start) = hunk_start(&operations[..first]);
+        let old_count = hunk
+            .iter()
+            .filter(|operation| !matches!(operation, DiffOp::Insert(_)))
+            .count();
+        let new_count = hunk
+            .iter()
+            .filter(|operation| !matches!(operation, DiffOp::Delete(_)))
+            .count();
+        output.push(format!(
+            "@@ -{},{} +{},{} @@",
+            hunk_line(old_start, old_count),
+            old_count,
+            hunk_line(new_start, new_count),
+            new_count
+        ));
+        for operation in hunk {
+            output.push(match operation {
+                DiffOp::Equal(index) => format!(" {}", old_lines[*index]),
+                DiffOp::Delete(index) => format!("-{}", old_lines[*index]),
+                DiffOp::Insert(index) => format!("+{}", new_lines[*index]),
+            });
+        }
+        start_index = end_index + 1;
+    }
+    output.join("\n")
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DiffOp {
+    Equal(usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+fn hunk_start(preceding: &[DiffOp]) -> (usize, usize) {
+    let old = preceding
+        .iter()
+        .filter(|operation| !matches!(operation, DiffOp::Insert(_)))
+        .count();
+    let new = preceding
+        .iter()
+        .filter(|operation| !matches!(operation, DiffOp::Delete(_)))
+        .count();
+    (old, new)
+}
+
+fn hunk_line(start: usize, count: usize) -> usize {
+    if count == 0 {
+        start
+    } else {
+        start + 1
+    }
+}
+
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(left, right)| left == right)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(left, right)| left == right)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut operations: Vec<DiffOp> = (0..prefix).map(DiffOp::Equal).collect();
+    let old_tail = old.len() - suffix;
+    if (old_middle.len() + 1).saturating_mul(new_middle.len() + 1) > MAX_DIFF_CELLS {
+        // Too large to align line by line; replace the changed block whole.
+        operations.extend((prefix..old_tail).map(DiffOp::Delete));
+        operations.extend((prefix..new.len() - suffix).map(DiffOp::Insert));
+        operations.extend((old_tail..old.len()).map(DiffOp::Equal));
+        return operations;
+    }
+
+    // Longest common subsequence over the lines that differ.
+    let columns = new_middle.len() + 1;
+    let mut lengths = vec![0u32; (old_middle.len() + 1) * columns];
+    for row in (0..old_middle.len()).rev() {
+        for column in (0..new_middle.len()).rev() {
+            lengths[row * columns + column] = if old_middle[row] == new_middle[column] {
+                lengths[(row + 1) * columns + column + 1] + 1
+            } else {
+                lengths[(row + 1) * columns + column].max(lengths[row * columns + column + 1])
+            };
+        }
+    }
+
+    let (mut row, mut column) = (0, 0);
+    while row < old_middle.len() || column < new_middle.len() {
+        if row < old_middle.len()
+            && column < new_middle.len()
+            && old_middle[row] == new_middle[column]
+        {
+            operations.push(DiffOp::Equal(prefix + row));
+            row += 1;
+            column += 1;
+        } else if row < old_middle.len()
+            && (column == new_middle.len()
+                || lengths[(row + 1) * columns + column] >= lengths[row * columns + column + 1])
+        {
+            operations.push(DiffOp::Delete(prefix + row));
+            row += 1;
+        } else {
+            operations.push(DiffOp::Insert(prefix + column));
+            column += 1;
+        }
+    }
+    operations.extend((0..suffix).map(|offset| DiffOp::Equal(old_tail + offset)));
+    operations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn change<'a>(
+        page: &'a str,
+        action: WikiRevisionAction,
+        content: Option<&'a str>,
+        previous_content: Option<&'a str>,
+    ) -> WikiChange<'a> {
+        WikiChange {
+            page,
+            action,
+            content,
+            previous_content,
+            previous_path: None,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn history_follows_renames_newest_first() {
+        let temp = TempDir::new().expect("tempdir");
+        let project_dir = temp.path();
+        record_wiki_revision(
+            project_dir,
+            change("old.md", WikiRevisionAction::Created, Some("v1"), None),
+        )
+        .expect("create");
+        record_wiki_revision(
+            project_dir,
+            WikiChange {
+                previous_path: Some("old.md"),
+                message: Some("  move  "),
+                ..change("new.md", WikiRevisionAction::Renamed, Some("v1"), None)
+            },
+        )
+        .expect("rename");
+        record_wiki_revision(
+            project_dir,
+            change("other.md", WikiRevisionAction::Created, Some("x"), None),
+        )
+        .expect("other");
+
+        let history = load_wiki_history(project_dir, "new.md").expect("history");
+        let actions: Vec<WikiRevisionAction> = history.iter().map(|r| r.action).collect();
+        assert_eq!(
+            actions,
+            vec![WikiRevisionAction::Renamed, WikiRevisionAction::Created]
+        );
+        assert_eq!(history[0].message.as_deref(), Some("move"));
+        assert_eq!(history[0].content_hash, history[1].content_hash);
+    }
+
+    #[test]
+    fn restore_writes_revision_content_and_records_it() {
+        let temp = TempDir::new().expect("tempdir");
+        let project_dir = temp.path().join("project");
+        let wiki_dir = project_dir.join("wiki");
+        let first = record_wiki_revision(
+            &project_dir,
+            change(
+                "page.md",
+                WikiRevisionAction::Created,
+                Some("first\n"),
+                None,
+            ),
+        )
+        .expect("create");
+        fs::create_dir_all(&wiki_dir).expect("wiki dir");
+        fs::write(wiki_dir.join("page.md"), "second\n").expect("write page");
+
+        let prefix = &first.revision_id[..8];
+        let found = find_wiki_revision(&project_dir, "page.md", prefix).expect("find");
+        assert_eq!(found, WikiRevisionMatch::Found(first.clone()));
+        let restored = restore_wiki_revision(&project_dir, &wiki_dir, "page.md", &first, None)
+            .expect("restore");
+        assert_eq!(restored.action, WikiRevisionAction::Restored);
+        assert_eq!(restored.content_hash, first.content_hash);
+        assert_eq!(
+            fs::read_to_string(wiki_dir.join("page.md")).expect("read"),
+            "first\n"
+        );
+        let previous = WikiRevision {
+            content_hash: restored.previous_hash.clone(),
+            ..restored
+        };
+        assert_eq!(
+            load_revision_content(&project_dir, &previous).expect("previous"),
+            "second\n"
+        );
+        assert_eq!(
+            find_wiki_revision(&project_dir, "page.md", "zzz").expect("find"),
+            WikiRevisionMatch::NotFound
+        );
+        assert_eq!(
+            find_wiki_revision(&project_dir, "page.md", "").expect("find"),
+            WikiRevisionMatch::NotFound
+        );
+    }
+
+    #[test]
+    fn history_skips_corrupt_revision_files() {
+        let temp = TempDir::new().expect("tempdir");
+        let project_dir = temp.path();
+        record_wiki_revision(
+            project_dir,
+            change("page.md", WikiRevisionAction::Created, Some("v1"), None),
+        )
+        .expect("create");
+        fs::write(
+            wiki_history_dir(project_dir).join("broken.json"),
+            "{not json",
+        )
+        .expect("write");
+
+        let history = load_wiki_history(project_dir, "page.md").expect("history");
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].action, WikiRevisionAction::Created);
+    }
+
+    #[test]
+    fn unified_diff_groups_changes_into_hunks() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\n";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\n";
+        let diff = unified_diff(old, new, "rev", "current");
+        assert_eq!(
+            diff,
+            "--- rev\n+++ current\n@@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n d\n e\n@@ -10,3 +10,4 @@\n j\n k\n l\n+m"
+        );
+        assert_eq!(unified_diff(old, old, "a", "b"), "");
+    }
+
+    #[test]
+    fn unified_diff_replaces_oversized_blocks_whole() {
+        let old: String = (0..2100).map(|line| format!("old {line}\n")).collect();
+        let new: String = (0..2100).map(|line| format!("new {line}\n")).collect();
+        let old = format!("same\n{old}end\n");
+        let new = format!("same\n{new}end\n");
+        let diff = unified_diff(&old, &new, "rev", "current");
+        let lines: Vec<&str> = diff.lines().collect();
+        assert_eq!(lines[2], "@@ -1,2102 +1,2102 @@");
+        assert_eq!(lines[4], "-old 0");
+        assert_eq!(lines[2104], "+new 0");
+        assert_eq!(lines.last(), Some(&" end"));
+    }
+}
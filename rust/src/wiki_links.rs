@@ -0,0 +1,157 @@
+//! Issue reference and `[[page]]` link syntax shared by the wiki tools.
+//!
+//! The backlink index, `kbs validate` and the static site export all read
+//! references with these helpers so they agree on what counts as a link.
+
+use regex::Regex;
+
+use crate::ids::issue_identifier_matches;
+
+/// Tokens that may be issue keys, such as `kanbus-0a1b2c` or `kanbus-142`.
+pub const ISSUE_REFERENCE_PATTERN: &str =
+    r"\b[A-Za-z][A-Za-z0-9_]*-[A-Za-z0-9]+(?:[-.][A-Za-z0-9]+)*\b";
+
+/// `[[page]]` and `[[page|label]]` links between wiki pages.
+pub const PAGE_LINK_PATTERN: &str = r"\[\[([^\[\]|]+)(?:\|([^\[\]]*))?\]\]";
+
+const MIN_ABBREVIATED_SUFFIX: usize = 3;
+
+/// A reference to a known issue found in text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueReference {
+    /// Byte offset where the reference starts.
+    pub start: usize,
+    /// Byte offset just past the reference.
+    pub end: usize,
+    /// Full identifier of the referenced issue.
+    pub identifier: String,
+}
+
+/// Find references to known issues in free text.
+///
+/// Tokens must carry the issue's key prefix; abbreviated identifiers need at
+/// least three characters after the prefix and must match a single issue.
+///
+/// # Arguments
+/// * `text` - Text to scan.
+/// * `identifiers` - Full identifiers of the known issues.
+pub fn find_issue_references(text: &str, identifiers: &[String]) -> Vec<IssueReference> {
+    let pattern = Regex::new(ISSUE_REFERENCE_PATTERN).expect("issue reference regex");
+    pattern
+        .find_iter(text)
+        .filter_map(|token| {
+            resolve_reference(token.as_str(), identifiers).map(|identifier| IssueReference {
+                start: token.start(),
+                end: token.end(),
+                identifier,
+            })
+        })
+        .collect()
+}
+
+/// Resolve an issue key token to the single identifier it names.
+pub fn resolve_reference(token: &str, identifiers: &[String]) -> Option<String> {
+    let (token_key, token_rest) = token.split_once('-')?;
+    let mut matches = identifiers.iter().filter(|identifier| {
+        let Some((key, _)) = identifier.split_once('-') else {
+            return false;
+        };
+        if key != token_key {
+            return false;
+        }
+        *identifier == token
+            || (token_rest.len() >= MIN_ABBREVIATED_SUFFIX
+                && issue_identifier_matches(token, identifier))
+    });
+    match (matches.next(), matches.next()) {
+        (Some(identifier), None) => Some(identifier.clone()),
+        _ => None,
+    }
+}
+
+/// A `[[page]]` link found in text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageLink {
+    /// Byte offset where the link starts.
+    pub start: usize,
+    /// Byte offset just past the link.
+    pub end: usize,
+    /// Target as written, including any `#anchor`.
+    pub target: String,
+    /// Label after `|`, if given.
+    pub label: Option<String>,
+}
+
+/// Find `[[page]]` links in text, skipping links with an empty target.
+pub fn find_page_links(text: &str) -> Vec<PageLink> {
+    let pattern = Regex::new(PAGE_LINK_PATTERN).expect("page link regex");
+    pattern
+        .captures_iter(text)
+        .filter_map(|captures| {
+            let whole = captures.get(0)?;
+            let target = captures[1].trim().to_string();
+            if target.is_empty() {
+                return None;
+            }
+            Some(PageLink {
+                start: whole.start(),
+                end: whole.end(),
+                target,
+                label: captures
+                    .get(2)
+                    .map(|label| label.as_str().trim().to_string())
+                    .filter(|label| !label.is_empty()),
+            })
+        })
+        .collect()
+}
+
+/// Resolve a `[[page]]` target to a wiki-root relative page path.
+pub fn normalize_page_link(link: &str) -> String {
+    let without_anchor = link.split('#').next().unwrap_or(link);
+    let trimmed = without_anchor
+        .trim()
+        .trim_start_matches("./")
+        .trim_start_matches('/')
+        .replace('\\', "/");
+    if trimmed.ends_with(".md") {
+        trimmed
+    } else {
+        format!("{trimmed}.md")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_full_and_unique_abbreviated_references() {
+        let identifiers = vec![
+            "kanbus-abc123".to_string(),
+            "kanbus-abd999".to_string(),
+            "kanbus-abc123.1".to_string(),
+        ];
+        let text = "See kanbus-abc123.1, kanbus-abd and kanbus-ab or e-mail kanbus-zzz.";
+        let found: Vec<String> = find_issue_references(text, &identifiers)
+            .into_iter()
+            .map(|reference| reference.identifier)
+            .collect();
+        assert_eq!(found, vec!["kanbus-abc123.1", "kanbus-abd999"]);
+    }
+
+    #[test]
+    fn page_links_carry_targets_labels_and_normalize_to_pages() {
+        let links = find_page_links("See [[guides/setup#install|the guide]], [[intro]] and [[ ]].");
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].target, "guides/setup#install");
+        assert_eq!(links[0].label.as_deref(), Some("the guide"));
+        assert_eq!(links[1].target, "intro");
+        assert_eq!(links[1].label, None);
+        assert_eq!(
+            normalize_page_link("guides/setup#install"),
+            "guides/setup.md"
+        );
+        assert_eq!(normalize_page_link("./notes.md"), "notes.md");
+    }
+}
@@ -0,0 +1,342 @@
+//! Atomic work queue: pick and claim the next ready issue.
+//!
+//! `kbs next` holds the claims lock while it selects and claims an issue, so
+//! concurrent agents pulling from the same ready queue never receive the same
+//! issue. Claims are the leases from `claims`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::claims::{claim_issue_locked, release_expired_claims_locked, ClaimLease, ClaimsLock};
+use crate::config_loader::load_project_configuration;
+use crate::dependencies::list_ready_issues;
+use crate::error::KanbusError;
+use crate::file_io::{
+    find_project_local_directory, get_configuration_path, load_project_directory,
+    resolve_labeled_projects,
+};
+use crate::issue_listing::load_issues_from_directory;
+use crate::issue_lookup::load_issue_from_project;
+use crate::models::{IssueData, ProjectConfiguration};
+use crate::workflows::{is_in_progress_status, resolve_start_status};
+
+/// Filters applied when selecting the next issue.
+#[derive(Debug, Clone, Default)]
+pub struct NextIssueFilter {
+    pub issue_type: Option<String>,
+    pub label: Option<String>,
+    /// Only issues under this parent, at any depth.
+    pub parent: Option<String>,
+    /// Project label, as used by `kbs list --project`.
+    pub project: Option<String>,
+}
+
+/// The issue handed out by `next_issue` and the lease that now holds it.
+#[derive(Debug, Clone)]
+pub struct NextIssue {
+    pub issue: IssueData,
+    pub lease: ClaimLease,
+}
+
+/// Select the highest-priority ready issue matching `filter` and claim it for `holder`.
+///
+/// Candidates are ready issues that are not already in progress, ordered by
+/// priority, then creation time. Issues whose workflow has no in-progress
+/// transition, or that another actor holds, are skipped. Expired claims are
+/// released first, as in `kbs ready`.
+///
+/// # Arguments
+/// * `root` - Repository root path.
+/// * `filter` - Selection filters.
+/// * `holder` - Actor the issue is claimed for.
+/// * `ttl_seconds` - Lease length.
+///
+/// # Returns
+/// The claimed issue, or `None` when nothing matches.
+///
+/// # Errors
+/// Returns `KanbusError` if the lock cannot be taken, a filter is invalid, or
+/// the claim cannot be written.
+pub fn next_issue(
+    root: &Path,
+    filter: &NextIssueFilter,
+    holder: &str,
+    ttl_seconds: i64,
+) -> Result<Option<NextIssue>, KanbusError> {
+    let project_dir = load_project_directory(root)?;
+    let lock = ClaimsLock::acquire(root)?;
+    release_expired_claims_locked(&lock, root, holder)?;
+
+    let configuration = load_project_configuration(&get_configuration_path(root)?)?;
+    let project_dir_filter = match filter.project.as_deref() {
+        Some(label) => Some(resolve_project_label(root, label)?),
+        None => None,
+    };
+    let parent_id = match filter.parent.as_deref() {
+        Some(parent) => Some(load_issue_from_project(root, parent)?.issue.identifier),
+        None => None,
+    };
+
+    let mut candidates: Vec<IssueData> = list_ready_issues(root, true, false)?
+        .into_iter()
+        .filter(|issue| is_candidate(issue, filter, &configuration))
+        .filter(|issue| {
+            project_dir_filter
+                .as_deref()
+                .is_none_or(|dir| issue_in_project(dir, &issue.identifier))
+        })
+        .collect();
+    if let Some(parent_id) = parent_id.as_deref() {
+        let parents = load_parent_map(&project_dir)?;
+        candidates.retain(|issue| has_ancestor(&parents, issue, parent_id));
+    }
+    sort_candidates(&mut candidates);
+
+    for candidate in candidates {
+        match claim_issue_locked(&lock, root, &candidate.identifier, holder, ttl_seconds) {
+            Ok(lease) => {
+                let issue = load_issue_from_project(root, &candidate.identifier)?.issue;
+                return Ok(Some(NextIssue { issue, lease }));
+            }
+            Err(KanbusError::ClaimConflict(_)) => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(None)
+}
+
+/// Order candidates by priority (0 first), then oldest first.
+fn sort_candidates(candidates: &mut [IssueData]) {
+    candidates.sort_by(|left, right| {
+        left.priority
+            .cmp(&right.priority)
+            .then_with(|| left.created_at.cmp(&right.created_at))
+            .then_with(|| left.identifier.cmp(&right.identifier))
+    });
+}
+
+fn is_candidate(
+    issue: &IssueData,
+    filter: &NextIssueFilter,
+    configuration: &ProjectConfiguration,
+) -> bool {
+    if filter
+        .issue_type
+        .as_deref()
+        .is_some_and(|issue_type| issue.issue_type != issue_type)
+    {
+        return false;
+    }
+    if filter.label.as_deref().is_some_and(|label| {
+        !issue
+            .labels
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(label))
+    }) {
+        return false;
+    }
+    !is_in_progress_status(configuration, &issue.status)
+        && resolve_start_status(configuration, &issue.issue_type, &issue.status).is_ok()
+}
+
+fn resolve_project_label(root: &Path, label: &str) -> Result<PathBuf, KanbusError> {
+    resolve_labeled_projects(root)?
+        .into_iter()
+        .find(|project| project.label == label)
+        .map(|project| project.project_dir)
+        .ok_or_else(|| KanbusError::IssueOperation(format!("unknown project: {label}")))
+}
+
+fn issue_in_project(project_dir: &Path, identifier: &str) -> bool {
+    let filename = format!("{identifier}.json");
+    project_dir.join("issues").join(&filename).exists()
+        || find_project_local_directory(project_dir)
+            .is_some_and(|local_dir| local_dir.join("issues").join(&filename).exists())
+}
+
+/// Map each issue in the project, including local issues, to its parent.
+fn load_parent_map(project_dir: &Path) -> Result<HashMap<String, Option<String>>, KanbusError> {
+    let mut issues = load_issues_from_directory(&project_dir.join("issues"))?;
+    if let Some(local_dir) = find_project_local_directory(project_dir) {
+        let local_issues = local_dir.join("issues");
+        if local_issues.is_dir() {
+            issues.extend(load_issues_from_directory(&local_issues)?);
+        }
+    }
+    Ok(issues
+        .into_iter()
+        .map(|issue| (issue.identifier, issue.parent))
+        .collect())
+}
+
+fn has_ancestor(
+    parents: &HashMap<String, Option<String>>,
+    issue: &IssueData,
+    ancestor_id: &str,
+) -> bool {
+    let mut seen = vec![issue.identifier.clone()];
+    let mut parent = issue.parent.clone();
+    while let Some(parent_id) = parent {
+        if parent_id == ancestor_id {
+            return true;
+        }
+        if seen.contains(&parent_id) {
+            return false;
+        }
+        seen.push(parent_id.clone());
+        parent = parents.get(&parent_id).cloned().flatten();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::default_project_configuration;
+    use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
+    use std::thread;
+
+    fn issue(id: &str, status: &str, priority: i32, day: u32) -> IssueData {
+        let created_at = Utc.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap();
+        IssueData {
+            identifier: id.to_string(),
+            title: id.to_string(),
+            description: String::new(),
+            issue_type: "task".to_string(),
+            status: status.to_string(),
+            priority,
+            assignee: None,
+            creator: None,
+            parent: None,
+            labels: vec!["backend".to_string()],
+            dependencies: Vec::new(),
+            comments: Vec::new(),
+            created_at,
+            updated_at: created_at,
+            closed_at: None,
+            custom: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn candidates_sort_by_priority_then_age_and_skip_started_work() {
+        let configuration = default_project_configuration();
+        let mut candidates = vec![
+            issue("kanbus-c", "open", 2, 1),
+            issue("kanbus-b", "open", 1, 5),
+            issue("kanbus-a", "open", 1, 2),
+        ];
+        sort_candidates(&mut candidates);
+        let order: Vec<&str> = candidates.iter().map(|i| i.identifier.as_str()).collect();
+        assert_eq!(order, ["kanbus-a", "kanbus-b", "kanbus-c"]);
+
+        let filter = NextIssueFilter::default();
+        assert!(is_candidate(
+            &issue("kanbus-a", "open", 1, 1),
+            &filter,
+            &configuration
+        ));
+        assert!(!is_candidate(
+            &issue("kanbus-a", "in_progress", 1, 1),
+            &filter,
+            &configuration
+        ));
+        let typed = NextIssueFilter {
+            issue_type: Some("bug".to_string()),
+            ..NextIssueFilter::default()
+        };
+        assert!(!is_candidate(
+            &issue("kanbus-a", "open", 1, 1),
+            &typed,
+            &configuration
+        ));
+        let labeled = NextIssueFilter {
+            label: Some("Backend".to_string()),
+            ..NextIssueFilter::default()
+        };
+        assert!(is_candidate(
+            &issue("kanbus-a", "open", 1, 1),
+            &labeled,
+            &configuration
+        ));
+    }
+
+    fn setup_queue() -> (tempfile::TempDir, String, Vec<String>) {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path();
+        crate::file_io::initialize_project(root, false).expect("initialize project");
+        let create = |title: &str, issue_type: &str, priority: u8, parent: Option<&str>| {
+            crate::issue_creation::create_issue(&crate::issue_creation::IssueCreationRequest {
+                root: root.to_path_buf(),
+                title: title.to_string(),
+                issue_type: Some(issue_type.to_string()),
+                priority: Some(priority),
+                assignee: None,
+                parent: parent.map(str::to_string),
+                labels: Vec::new(),
+                description: None,
+                local: false,
+                validate: true,
+            })
+            .expect("create issue")
+            .issue
+            .identifier
+        };
+        let epic = create("Epic", "epic", 2, None);
+        let low = create("Low", "task", 3, Some(&epic));
+        let high = create("High", "task", 1, Some(&epic));
+        (temp, epic, vec![high, low])
+    }
+
+    #[test]
+    fn next_issue_claims_matching_work_in_priority_order() {
+        let (temp, epic, tasks) = setup_queue();
+        let root = temp.path();
+        let filter = NextIssueFilter {
+            parent: Some(epic),
+            ..NextIssueFilter::default()
+        };
+        let first = next_issue(root, &filter, "agent-1", 600)
+            .expect("next")
+            .expect("first issue");
+        assert_eq!(first.issue.identifier, tasks[0]);
+        assert_eq!(first.lease.holder, "agent-1");
+        assert_eq!(first.issue.status, "in_progress");
+        assert_eq!(first.issue.assignee.as_deref(), Some("agent-1"));
+
+        let second = next_issue(root, &filter, "agent-2", 600)
+            .expect("next")
+            .expect("second issue");
+        assert_eq!(second.issue.identifier, tasks[1]);
+        assert!(next_issue(root, &filter, "agent-3", 600)
+            .expect("next")
+            .is_none());
+    }
+
+    #[test]
+    fn concurrent_callers_never_receive_the_same_issue() {
+        let (temp, epic, tasks) = setup_queue();
+        let root = temp.path().to_path_buf();
+        let filter = NextIssueFilter {
+            parent: Some(epic),
+            ..NextIssueFilter::default()
+        };
+        // Leave a single candidate for the two callers to race for.
+        next_issue(&root, &filter, "agent-0", 600).expect("take first");
+        let handles: Vec<_> = ["agent-1", "agent-2"]
+            .into_iter()
+            .map(|holder| {
+                let root = root.clone();
+                let filter = filter.clone();
+                thread::spawn(move || next_issue(&root, &filter, holder, 600).expect("next"))
+            })
+            .collect();
+        let winners: Vec<NextIssue> = handles
+            .into_iter()
+            .filter_map(|handle| handle.join().expect("join"))
+            .collect();
+        assert_eq!(winners.len(), 1);
+        assert_eq!(winners[0].issue.identifier, tasks[1]);
+    }
+}
@@ -112,3 +112,62 @@ pub fn apply_transition_side_effects(
     }
     updated_issue
 }
+
+/// Return the status used to close issues.
+///
+/// `closed` when the project defines it, otherwise the first status in the
+/// `Done` category.
+pub fn closed_status(configuration: &ProjectConfiguration) -> &str {
+    let statuses = &configuration.statuses;
+    statuses
+        .iter()
+        .find(|entry| entry.key == "closed")
+        .or_else(|| {
+            statuses
+                .iter()
+                .find(|entry| entry.category.eq_ignore_ascii_case("done"))
+        })
+        .map_or("closed", |entry| entry.key.as_str())
+}
+
+/// Return true if the status is `in_progress` or belongs to the `In progress` category.
+pub fn is_in_progress_status(configuration: &ProjectConfiguration, status: &str) -> bool {
+    status == "in_progress"
+        || configuration
+            .statuses
+            .iter()
+            .any(|entry| entry.key == status && entry.category.eq_ignore_ascii_case("in progress"))
+}
+
+/// Choose the status an issue moves to when work on it starts.
+///
+/// Prefers `in_progress` when the workflow allows it, otherwise the first
+/// allowed transition into the `In progress` category.
+///
+/// # Errors
+/// Returns `KanbusError::InvalidTransition` if the workflow offers no such transition.
+pub fn resolve_start_status(
+    configuration: &ProjectConfiguration,
+    issue_type: &str,
+    current_status: &str,
+) -> Result<String, KanbusError> {
+    let workflow = get_workflow_for_issue_type(configuration, issue_type)?;
+    let allowed = workflow
+        .get(current_status)
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+    allowed
+        .iter()
+        .find(|status| status.as_str() == "in_progress")
+        .or_else(|| {
+            allowed
+                .iter()
+                .find(|status| is_in_progress_status(configuration, status))
+        })
+        .cloned()
+        .ok_or_else(|| {
+            KanbusError::InvalidTransition(format!(
+                "no in-progress transition from '{current_status}' for type '{issue_type}'"
+            ))
+        })
+}
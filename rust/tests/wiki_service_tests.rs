@@ -175,6 +175,7 @@ fn wiki_update_missing_returns_not_found() {
     let request = WikiUpdateRequest {
         path: "missing.md".to_string(),
         content: "x".to_string(),
+        message: None,
     };
     let result = update_page(&store, &request);
     assert!(result.is_err());